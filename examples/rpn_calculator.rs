@@ -40,9 +40,9 @@ use dynamic_cli::prelude::*;
 use std::any::Any;
 use std::collections::HashMap;
 
-/// ================================================================================================
-/// Execution context
-/// ================================================================================================
+// ================================================================================================
+// Execution context
+// ================================================================================================
 
 /// Execution context for the simple rpn calculator
 ///
@@ -64,7 +64,7 @@ impl SimpleRpnContext {
 
     /// Push a value onto the stack and lastx register
     fn push_x(&mut self, value: f64) {
-        self.last_x = value.clone();
+        self.last_x = value;
         self.push(value);
     }
 
@@ -79,7 +79,7 @@ impl SimpleRpnContext {
 
     /// Show the last x register
     fn last_x(&self) -> f64 {
-        self.last_x.clone()
+        self.last_x
     }
 
     /// Swap registers
@@ -153,13 +153,13 @@ impl ExecutionContext for SimpleRpnContext {
     }
 }
 
-/// ================================================================================================
-/// Command handlers
-///
-///  - Stack commands
-///  - Arithmetic functions
-///
-/// ================================================================================================
+// ================================================================================================
+// Command handlers
+//
+//  - Stack commands
+//  - Arithmetic functions
+//
+// ================================================================================================
 
 /// handler for push command
 ///
@@ -207,14 +207,13 @@ impl CommandHandler for PushCommand {
 /// Handler for pop command
 ///
 /// Removes and display the top value
-
 struct PopCommand;
 
 impl CommandHandler for PopCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -233,14 +232,13 @@ impl CommandHandler for PopCommand {
 /// Handler for lastx command
 ///
 /// Displays the last x register which stores the last value pushed
-
 struct LastXCommand;
 
 impl CommandHandler for LastXCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -259,14 +257,13 @@ impl CommandHandler for LastXCommand {
 /// Handler for swap command
 ///
 /// Exchange x register and y register in the stack
-
 struct SwapCommand;
 
 impl CommandHandler for SwapCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -285,14 +282,13 @@ impl CommandHandler for SwapCommand {
 /// Handler for peek command
 ///
 /// Displays the top value without removes it
-
 struct PeekCommand;
 
 impl CommandHandler for PeekCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -313,14 +309,13 @@ impl CommandHandler for PeekCommand {
 /// Handler for show command
 ///
 /// shows the entire stack as a list
-
 struct ShowCommand;
 
 impl CommandHandler for ShowCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -337,14 +332,13 @@ impl CommandHandler for ShowCommand {
 /// Handler for clear command
 ///
 /// sets the rpn context to default values
-
 struct ClearCommand;
 
 impl CommandHandler for ClearCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -361,14 +355,13 @@ impl CommandHandler for ClearCommand {
 /// Handler for add function
 ///
 /// Pops two values and pushes their sum
-
 struct AddCommand;
 
 impl CommandHandler for AddCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -384,14 +377,13 @@ impl CommandHandler for AddCommand {
 /// Handler for sub function
 ///
 /// Pops two value and pushes their difference
-
 struct SubCommand;
 
 impl CommandHandler for SubCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -408,14 +400,13 @@ impl CommandHandler for SubCommand {
 /// Handler for mul function
 ///
 /// Pops two values and pushed their product
-
 struct MulCommand;
 
 impl CommandHandler for MulCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -431,14 +422,13 @@ impl CommandHandler for MulCommand {
 /// Handler for div function
 ///
 /// Pops two values and pushes their quotient
-
 struct DivCommand;
 
 impl CommandHandler for DivCommand {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -455,14 +445,13 @@ impl CommandHandler for DivCommand {
 /// Hander fon natural logarithm
 ///
 /// Pops the value and pushes the natural logarithm
-
 struct LnFunction;
 
 impl CommandHandler for LnFunction {
     fn execute(
         &self,
         context: &mut dyn ExecutionContext,
-        args: &HashMap<String, String>,
+        _args: &HashMap<String, String>,
     ) -> Result<()> {
         let rpn_ctx = downcast_mut::<SimpleRpnContext>(context).ok_or_else(|| {
             DynamicCliError::Execution(dynamic_cli::error::ExecutionError::ContextDowncastFailed {
@@ -476,13 +465,13 @@ impl CommandHandler for LnFunction {
     }
 }
 
-/// ================================================================================================
-/// Main application
-///
-///  - Load the configuration file
-///  - Register command and function
-///  - Build and run the app
-/// ================================================================================================
+// ================================================================================================
+// Main application
+//
+//  - Load the configuration file
+//  - Register command and function
+//  - Build and run the app
+// ================================================================================================
 fn main() -> Result<()> {
     println!("🔢 Simple RPN Calculator - Powered by dynamic-cli");
     println!("═════════════════════════════════════════════════\n");
@@ -507,9 +496,9 @@ fn main() -> Result<()> {
     app.run()
 }
 
-/// ================================================================================================
-/// Tests
-/// ================================================================================================
+// ================================================================================================
+// Tests
+// ================================================================================================
 
 #[cfg(test)]
 mod tests {
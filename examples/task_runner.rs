@@ -4,6 +4,7 @@
 //! - Complex state management with Vec<Task>
 //! - Priority levels (Low, Medium, High)
 //! - Custom validation
+//! - Arguments deserialized into structs with serde
 //! - Advanced statistics
 //! - Business logic implementation
 //!
@@ -21,7 +22,8 @@
 
 use dynamic_cli::context::downcast_mut;
 use dynamic_cli::prelude::*;
-use dynamic_cli::utils::{is_blank, parse_int};
+use dynamic_cli::utils::is_blank;
+use serde::Deserialize;
 use std::collections::HashMap;
 
 // ============================================================================
@@ -29,7 +31,10 @@ use std::collections::HashMap;
 // ============================================================================
 
 /// Task priority levels
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Deserialized from the `priority` option (`low`, `medium`, `high`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum Priority {
     Low,
     Medium,
@@ -37,22 +42,6 @@ enum Priority {
 }
 
 impl Priority {
-    /// Parse priority from string
-    fn from_str(s: &str) -> Result<Self> {
-        match s.to_lowercase().as_str() {
-            "low" | "l" => Ok(Priority::Low),
-            "medium" | "med" | "m" => Ok(Priority::Medium),
-            "high" | "hi" | "h" => Ok(Priority::High),
-            _ => Err(DynamicCliError::Validation(
-                dynamic_cli::error::ValidationError::CustomConstraint {
-                    arg_name: "priority".to_string(),
-                    reason: format!("Invalid priority '{}'. Must be: low, medium, or high", s),
-                    suggestion: None,
-                },
-            )),
-        }
-    }
-
     /// Get priority as display string
     fn as_str(&self) -> &'static str {
         match self {
@@ -240,6 +229,23 @@ struct TaskStats {
     total_completed_ever: usize,
 }
 
+// ============================================================================
+// COMMAND ARGUMENTS
+// ============================================================================
+
+/// Arguments of the `add` command
+#[derive(Debug, Deserialize)]
+struct AddArgs {
+    description: String,
+    priority: Priority,
+}
+
+/// Arguments of the `complete` and `delete` commands
+#[derive(Debug, Deserialize)]
+struct TaskIdArgs {
+    id: usize,
+}
+
 // ============================================================================
 // COMMAND HANDLERS
 // ============================================================================
@@ -260,16 +266,12 @@ impl CommandHandler for AddCommand {
             })
         })?;
 
-        // Get and validate description
-        let description = args.get("description").ok_or_else(|| {
-            DynamicCliError::Parse(dynamic_cli::error::ParseError::MissingArgument {
-                argument: "description".to_string(),
-                command: "add".to_string(),
-                suggestion: None,
-            })
-        })?;
+        let AddArgs {
+            description,
+            priority,
+        } = ParsedArgs::from(args.clone()).deserialize()?;
 
-        if is_blank(description) {
+        if is_blank(&description) {
             return Err(DynamicCliError::Validation(
                 dynamic_cli::error::ValidationError::CustomConstraint {
                     arg_name: "description".to_string(),
@@ -279,10 +281,6 @@ impl CommandHandler for AddCommand {
             ));
         }
 
-        // Parse priority
-        let priority_str = args.get("priority").map(|s| s.as_str()).unwrap_or("medium");
-        let priority = Priority::from_str(priority_str)?;
-
         // Add task
         let id = ctx.add_task(description.clone(), priority);
 
@@ -360,14 +358,7 @@ impl CommandHandler for CompleteCommand {
             })
         })?;
 
-        let id_str = args.get("id").ok_or_else(|| {
-            DynamicCliError::Parse(dynamic_cli::error::ParseError::MissingArgument {
-                argument: "id".to_string(),
-                command: "complete".to_string(),
-                suggestion: None,
-            })
-        })?;
-        let id = parse_int(id_str, "id")? as usize;
+        let TaskIdArgs { id } = ParsedArgs::from(args.clone()).deserialize()?;
 
         let description = ctx
             .tasks
@@ -406,14 +397,7 @@ impl CommandHandler for DeleteCommand {
             })
         })?;
 
-        let id_str = args.get("id").ok_or_else(|| {
-            DynamicCliError::Parse(dynamic_cli::error::ParseError::MissingArgument {
-                argument: "id".to_string(),
-                command: "delete".to_string(),
-                suggestion: None,
-            })
-        })?;
-        let id = parse_int(id_str, "id")? as usize;
+        let TaskIdArgs { id } = ParsedArgs::from(args.clone()).deserialize()?;
 
         let description = ctx.delete_task(id)?;
 
@@ -43,14 +43,18 @@
 //! # }
 //! ```
 
-use crate::config::loader::load_config;
-use crate::config::schema::CommandsConfig;
+use crate::completion::{generate_completions, Shell, COMPLETIONS_COMMAND};
+use crate::config::layers::{load_layered_with, Layer};
+use crate::config::loader::LoadOptions;
+use crate::config::schema::{CommandDefinition, CommandsConfig, ValidationRule};
+use crate::config::validator::validate_config;
 use crate::context::ExecutionContext;
-use crate::error::{ConfigError, DynamicCliError, Result};
+use crate::error::{ConfigError, DynamicCliError, ParseError, Result};
 use crate::executor::CommandHandler;
 use crate::help::{DefaultHelpFormatter, HelpFormatter};
 use crate::interface::{CliInterface, ReplInterface};
 use crate::registry::CommandRegistry;
+use crate::validator::ArgumentValidator;
 use std::collections::HashMap;
 use std::path::PathBuf;
 
@@ -95,6 +99,12 @@ pub struct CliBuilder {
     /// Path to configuration file
     config_path: Option<PathBuf>,
 
+    /// Overlay files applied on top of the configuration file
+    overlays: Vec<(Layer, PathBuf)>,
+
+    /// Expand `${...}` references when loading the configuration file
+    interpolate_env: bool,
+
     /// Loaded configuration
     config: Option<CommandsConfig>,
 
@@ -104,6 +114,9 @@ pub struct CliBuilder {
     /// Registered command handlers (name -> handler)
     handlers: HashMap<String, Box<dyn CommandHandler>>,
 
+    /// Registered custom validators (name -> validator)
+    validators: HashMap<String, Box<dyn ArgumentValidator>>,
+
     /// REPL prompt (if None, will use config default or "cli")
     prompt: Option<String>,
 
@@ -124,9 +137,12 @@ impl CliBuilder {
     pub fn new() -> Self {
         Self {
             config_path: None,
+            overlays: Vec::new(),
+            interpolate_env: false,
             config: None,
             context: None,
             handlers: HashMap::new(),
+            validators: HashMap::new(),
             prompt: None,
             help_formatter: None,
         }
@@ -134,11 +150,11 @@ impl CliBuilder {
 
     /// Specify the configuration file
     ///
-    /// The file will be loaded during `build()`. Supports YAML and JSON formats.
+    /// The file will be loaded during `build()`. Supports YAML, JSON and TOML formats.
     ///
     /// # Arguments
     ///
-    /// * `path` - Path to the configuration file (`.yaml`, `.yml`, or `.json`)
+    /// * `path` - Path to the configuration file (`.yaml`, `.yml`, `.json` or `.toml`)
     ///
     /// # Example
     ///
@@ -153,6 +169,54 @@ impl CliBuilder {
         self
     }
 
+    /// Apply overlay files on top of the configuration file
+    ///
+    /// Overlays can add aliases, change defaults and descriptions, and
+    /// hide commands; see [`config::layers`](crate::config::layers).
+    /// Missing files are skipped. Overlays only apply to a configuration
+    /// given with `config_file()`.
+    ///
+    /// # Arguments
+    ///
+    /// * `overlays` - Overlay files with their layer, in application order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::config::layers::default_overlays;
+    /// use dynamic_cli::CliBuilder;
+    ///
+    /// let builder = CliBuilder::new()
+    ///     .config_file("commands.yaml")
+    ///     .overlays(default_overlays("myapp"));
+    /// ```
+    pub fn overlays(mut self, overlays: Vec<(Layer, PathBuf)>) -> Self {
+        self.overlays = overlays;
+        self
+    }
+
+    /// Expand environment variables in the configuration file
+    ///
+    /// When enabled, string values such as `"${HOME}/data"` or
+    /// `"${APP_ENV:-dev}"` are expanded while loading the file given with
+    /// `config_file()`, its includes and its overlays; see
+    /// [`config::loader`](crate::config::loader). Disabled by default, so
+    /// `$` strings are kept literally.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::CliBuilder;
+    ///
+    /// let builder = CliBuilder::new()
+    ///     .config_file("commands.yaml")
+    ///     .interpolate_env(true);
+    /// ```
+    pub fn interpolate_env(mut self, enabled: bool) -> Self {
+        self.interpolate_env = enabled;
+        self
+    }
+
     /// Provide a pre-loaded configuration
     ///
     /// Use this instead of `config_file()` if you want to load and potentially
@@ -254,6 +318,61 @@ impl CliBuilder {
         self
     }
 
+    /// Register a custom argument validator
+    ///
+    /// Backs the `custom:` validation rule: an argument declaring
+    /// `- custom: project_exists` is checked by the validator registered
+    /// as `"project_exists"`, together with the built-in rules, before
+    /// the command handler runs. Rules naming a validator that was never
+    /// registered make [`build()`](Self::build) fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name used by `custom:` rules in the configuration
+    /// * `validator` - Boxed validator implementing `ArgumentValidator`
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::prelude::*;
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// struct ProjectExists;
+    ///
+    /// impl ArgumentValidator for ProjectExists {
+    ///     fn validate(
+    ///         &self,
+    ///         _context: &dyn ExecutionContext,
+    ///         arg_name: &str,
+    ///         value: &str,
+    ///         params: &serde_json::Value,
+    ///     ) -> dynamic_cli::Result<()> {
+    ///         let registry = params["registry"].as_str().unwrap_or("projects.txt");
+    ///         let known = std::fs::read_to_string(registry).unwrap_or_default();
+    ///         if known.lines().any(|line| line == value) {
+    ///             return Ok(());
+    ///         }
+    ///         Err(ValidationError::CustomConstraint {
+    ///             arg_name: arg_name.to_string(),
+    ///             reason: format!("project '{}' does not exist", value),
+    ///             suggestion: Some(format!("Add it to {} first.", registry)),
+    ///         }
+    ///         .into())
+    ///     }
+    /// }
+    ///
+    /// let builder = CliBuilder::new()
+    ///     .register_validator("project_exists", Box::new(ProjectExists));
+    /// ```
+    pub fn register_validator(
+        mut self,
+        name: impl Into<String>,
+        validator: Box<dyn ArgumentValidator>,
+    ) -> Self {
+        self.validators.insert(name.into(), validator);
+        self
+    }
+
     /// Set the REPL prompt
     ///
     /// Only used in REPL mode. If not specified, uses the prompt from
@@ -314,15 +433,113 @@ impl CliBuilder {
         self
     }
 
+    /// Check that every `custom:` rule names a registered validator
+    ///
+    /// Walks the commands and their subcommands; `parent` is the path of
+    /// the enclosing command (empty at the top level).
+    ///
+    /// # Errors
+    ///
+    /// [`ConfigError::InvalidSchema`] for the first unknown validator name
+    fn check_custom_rules(&self, commands: &[CommandDefinition], parent: &str) -> Result<()> {
+        for command in commands {
+            let path = if parent.is_empty() {
+                command.name.clone()
+            } else {
+                format!("{} {}", parent, command.name)
+            };
+
+            for (arg_idx, arg) in command.arguments.iter().enumerate() {
+                for (rule_idx, rule) in arg.validation.iter().enumerate() {
+                    if let ValidationRule::Custom { custom, .. } = rule {
+                        if !self.validators.contains_key(custom) {
+                            let mut registered: Vec<String> =
+                                self.validators.keys().cloned().collect();
+                            registered.sort();
+                            return Err(ConfigError::unknown_validator(
+                                custom,
+                                &arg.name,
+                                Some(format!(
+                                    "{}.arguments[{}].validation[{}]",
+                                    path, arg_idx, rule_idx
+                                )),
+                                &registered,
+                            )
+                            .into());
+                        }
+                    }
+                }
+            }
+
+            self.check_custom_rules(&command.subcommands, &path)?;
+        }
+
+        Ok(())
+    }
+
+    /// Take the handlers of a command tree out of the builder
+    ///
+    /// Handlers are pushed to `found` with the canonical path of their
+    /// command, children before parents. Returns the definition to
+    /// register, without the subcommands that have no handler (and no
+    /// implemented subcommands of their own), or `None` if nothing in
+    /// the tree is implemented.
+    ///
+    /// # Errors
+    ///
+    /// [`ConfigError::InvalidSchema`] if a required command has no handler
+    fn collect_handlers(
+        &mut self,
+        definition: &CommandDefinition,
+        path: &str,
+        found: &mut Vec<(String, Box<dyn CommandHandler>)>,
+    ) -> Result<Option<CommandDefinition>> {
+        // Find handler for this command
+        let handler = self.handlers.remove(&definition.implementation);
+
+        // Check if handler is required
+        if definition.required && handler.is_none() {
+            return Err(DynamicCliError::Config(ConfigError::InvalidSchema {
+                reason: format!(
+                    "Required command '{}' has no registered handler (implementation: '{}'). \
+                    Use register_handler() to register it.",
+                    path, definition.implementation
+                ),
+                path: None,
+                suggestion: None,
+            }));
+        }
+
+        let mut implemented = CommandDefinition {
+            subcommands: Vec::new(),
+            ..definition.clone()
+        };
+        for child in &definition.subcommands {
+            let child_path = format!("{} {}", path, child.name);
+            if let Some(child) = self.collect_handlers(child, &child_path, found)? {
+                implemented.subcommands.push(child);
+            }
+        }
+
+        match handler {
+            Some(handler) => found.push((path.to_string(), handler)),
+            None if implemented.subcommands.is_empty() => return Ok(None),
+            None => {}
+        }
+
+        Ok(Some(implemented))
+    }
+
     /// Build the application
     ///
     /// Performs the following steps:
     /// 1. Load configuration (if `config_file()` was used)
-    /// 2. Validate that a context was provided
-    /// 3. Create the command registry
-    /// 4. Register all command handlers
-    /// 5. Verify that all required commands have handlers
-    /// 6. Create the `CliApp`
+    /// 2. Validate the configuration
+    /// 3. Validate that a context was provided
+    /// 4. Create the command registry
+    /// 5. Register all command handlers
+    /// 6. Verify that all required commands have handlers
+    /// 7. Create the `CliApp`
     ///
     /// # Returns
     ///
@@ -331,6 +548,9 @@ impl CliBuilder {
     /// # Errors
     ///
     /// - Configuration errors (file not found, invalid format, etc.)
+    /// - Any error of [`validate_config`]; configurations loaded with
+    ///   `config_file()` report it in [`ConfigError::Located`] when the
+    ///   problem is found in the file
     /// - Missing context
     /// - Missing required handlers
     /// - Registry errors
@@ -364,9 +584,15 @@ impl CliBuilder {
     pub fn build(mut self) -> Result<CliApp> {
         // Load configuration if path was specified
         let config = if let Some(config) = self.config.take() {
+            validate_config(&config)?;
             config
         } else if let Some(path) = self.config_path.take() {
-            load_config(path)?
+            let options = LoadOptions {
+                interpolate_env: self.interpolate_env,
+            };
+            let layered = load_layered_with(path, &self.overlays, options)?;
+            layered.validate()?;
+            layered.config
         } else {
             return Err(DynamicCliError::Config(ConfigError::InvalidSchema {
                 reason: "No configuration provided. Use config_file() or config()".to_string(),
@@ -384,29 +610,47 @@ impl CliBuilder {
             })
         })?;
 
+        // Every `custom:` rule must name a registered validator
+        self.check_custom_rules(&config.commands, "")?;
+
         // Create registry and register commands
         let mut registry = CommandRegistry::new();
+        registry.set_global_options(config.global_options.clone());
+        for (name, validator) in self.validators.drain() {
+            registry.register_validator(name, validator);
+        }
 
         for command_def in &config.commands {
-            // Find handler for this command
-            let handler = self.handlers.remove(&command_def.implementation);
-
-            // Check if handler is required
-            if command_def.required && handler.is_none() {
-                return Err(DynamicCliError::Config(ConfigError::InvalidSchema {
-                    reason: format!(
-                        "Required command '{}' has no registered handler (implementation: '{}'). \
-                        Use register_handler() to register it.",
-                        command_def.name, command_def.implementation
-                    ),
-                    path: None,
-                    suggestion: None,
-                }));
+            // Collect the handlers of the command and its subcommands
+            let mut handlers = Vec::new();
+            let Some(tree) =
+                self.collect_handlers(command_def, &command_def.name, &mut handlers)?
+            else {
+                continue;
+            };
+
+            // Register the command itself, with its handler if one exists
+            match handlers.pop() {
+                Some((path, handler)) if path == tree.name => {
+                    registry.register(tree, handler)?;
+                }
+                other => {
+                    handlers.extend(other);
+                    registry.register_group(tree)?;
+                }
             }
 
-            // Register command if handler exists
-            if let Some(handler) = handler {
-                registry.register(command_def.clone(), handler)?;
+            // Attach subcommand handlers to their declared entries
+            for (path, handler) in handlers {
+                let (parent, _) = path.rsplit_once(' ').unwrap_or_default();
+                let definition = registry.get_definition(&path).cloned().ok_or_else(|| {
+                    ConfigError::InvalidSchema {
+                        reason: format!("Subcommand '{}' could not be resolved", path),
+                        path: Some(path.clone()),
+                        suggestion: None,
+                    }
+                })?;
+                registry.register_subcommand(parent, definition, handler)?;
             }
         }
 
@@ -543,11 +787,46 @@ impl CliApp {
                 print!("{}", formatter.format_app(&self.config));
                 return Ok(());
             }
-            [flag, command] if flag == "--help" => {
+            [flag, path @ ..] if flag == "--help" => {
+                // `--help remote add` shows help for a subcommand
                 let formatter: Box<dyn HelpFormatter> = self
                     .help_formatter
                     .unwrap_or_else(|| Box::new(DefaultHelpFormatter::new()));
-                print!("{}", formatter.format_command(&self.config, command));
+                print!(
+                    "{}",
+                    formatter.format_command(&self.config, &path.join(" "))
+                );
+                return Ok(());
+            }
+            [cmd, rest @ ..]
+                if cmd == COMPLETIONS_COMMAND
+                    && self.registry.resolve_name(COMPLETIONS_COMMAND).is_none() =>
+            {
+                // Hidden built-in: `completions <shell>` prints a completion script
+                let shell = match rest {
+                    [shell] => shell.parse::<Shell>()?,
+                    [] => {
+                        return Err(ParseError::MissingArgument {
+                            argument: "shell".to_string(),
+                            command: COMPLETIONS_COMMAND.to_string(),
+                            suggestion: Some("Supported shells: bash, zsh, fish.".to_string()),
+                        }
+                        .into())
+                    }
+                    _ => {
+                        return Err(ParseError::TooManyArguments {
+                            command: COMPLETIONS_COMMAND.to_string(),
+                            expected: 1,
+                            got: rest.len(),
+                            suggestion: Some(format!(
+                                "Usage: {} {} <bash|zsh|fish>",
+                                self.prompt, COMPLETIONS_COMMAND
+                            )),
+                        }
+                        .into())
+                    }
+                };
+                print!("{}", self.generate_completions(shell));
                 return Ok(());
             }
             _ => {}
@@ -557,6 +836,47 @@ impl CliApp {
         cli.run(args)
     }
 
+    /// Generate a shell completion script for CLI mode
+    ///
+    /// The script completes command and subcommand names, aliases, flags,
+    /// option choices and file names for `path` values. It is also printed
+    /// by the hidden built-in command `completions <shell>` (unless the
+    /// configuration defines its own `completions` command).
+    ///
+    /// # Arguments
+    ///
+    /// * `shell` - Target shell
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dynamic_cli::prelude::*;
+    /// # use dynamic_cli::Shell;
+    /// # #[derive(Default)]
+    /// # struct MyContext;
+    /// # impl ExecutionContext for MyContext {
+    /// #     fn as_any(&self) -> &dyn std::any::Any { self }
+    /// #     fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+    /// # }
+    /// # struct MyHandler;
+    /// # impl CommandHandler for MyHandler {
+    /// #     fn execute(&self, _: &mut dyn ExecutionContext, _: &std::collections::HashMap<String, String>) -> dynamic_cli::Result<()> { Ok(()) }
+    /// # }
+    /// # fn main() -> dynamic_cli::Result<()> {
+    /// # let app = CliBuilder::new()
+    /// #     .config_file("commands.yaml")
+    /// #     .context(Box::new(MyContext::default()))
+    /// #     .register_handler("handler", Box::new(MyHandler))
+    /// #     .build()?;
+    /// // Equivalent to `myapp completions bash`
+    /// print!("{}", app.generate_completions(Shell::Bash));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate_completions(&self, shell: Shell) -> String {
+        generate_completions(&self.config, shell)
+    }
+
     /// Run in REPL mode
     ///
     /// Enters an interactive loop that continues until the user exits.
@@ -654,7 +974,8 @@ impl CliApp {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::schema::{ArgumentDefinition, ArgumentType, CommandDefinition, Metadata};
+    use crate::config::schema::{CommandDefinition, Metadata, SCHEMA_VERSION};
+    use crate::error::ValidationError;
 
     // Test context
     #[derive(Default)]
@@ -705,8 +1026,11 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "test_handler".to_string(),
+                ..Default::default()
             }],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         }
     }
 
@@ -815,6 +1139,153 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_builder_registers_subcommands() {
+        let mut config = create_test_config();
+        let mut child = config.commands[0].clone();
+        child.name = "child".to_string();
+        child.implementation = "child_handler".to_string();
+        child.required = true;
+        let mut unimplemented = child.clone();
+        unimplemented.name = "todo".to_string();
+        unimplemented.implementation = "todo_handler".to_string();
+        unimplemented.required = false;
+        config.commands[0].required = false;
+        config.commands[0].subcommands = vec![child, unimplemented];
+
+        let app = CliBuilder::new()
+            .config(config)
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "child_handler",
+                Box::new(TestHandler {
+                    name: "child".to_string(),
+                }),
+            )
+            .build()
+            .unwrap();
+
+        // "test" has no handler and only groups "child";
+        // "todo" has no handler and is left out
+        assert!(app.registry.get_handler("test").is_none());
+        assert!(app.registry.get_handler("test child").is_some());
+        assert!(!app.registry.contains("test todo"));
+        assert!(app
+            .run_cli(vec!["test".to_string(), "child".to_string()])
+            .is_ok());
+    }
+
+    #[test]
+    fn test_builder_missing_required_subcommand_handler() {
+        let mut config = create_test_config();
+        let mut child = config.commands[0].clone();
+        child.name = "child".to_string();
+        child.implementation = "child_handler".to_string();
+        config.commands[0].subcommands = vec![child];
+
+        let result = CliBuilder::new()
+            .config(config)
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .build();
+
+        match result.unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { reason, .. }) => {
+                assert!(reason.contains("'test child'"));
+            }
+            other => panic!("Expected InvalidSchema error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_subcommand_names_with_whitespace() {
+        let mut config = create_test_config();
+        let mut child = config.commands[0].clone();
+        child.name = "two words".to_string();
+        child.implementation = "child_handler".to_string();
+        config.commands[0].subcommands = vec![child];
+
+        let result = CliBuilder::new()
+            .config(config)
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .register_handler(
+                "child_handler",
+                Box::new(TestHandler {
+                    name: "child".to_string(),
+                }),
+            )
+            .build();
+
+        match result.unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { reason, path, .. }) => {
+                assert!(reason.contains("cannot contain whitespace"));
+                assert_eq!(path.as_deref(), Some("commands[0].subcommands[0].name"));
+            }
+            other => panic!("Expected InvalidSchema error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_rejects_invalid_constraints() {
+        let mut config = create_test_config();
+        config.commands[0].constraints = vec![crate::config::schema::ConstraintDefinition {
+            check: "start > ".to_string(),
+            message: None,
+        }];
+
+        let result = CliBuilder::new()
+            .config(config)
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .build();
+
+        match result.unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { reason, path, .. }) => {
+                assert!(reason.contains("Invalid constraint 'start > '"), "{reason}");
+                assert_eq!(path.as_deref(), Some("test.constraints[0]"));
+            }
+            other => panic!("Expected InvalidSchema error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_builder_validates_loaded_config_with_location() {
+        let yaml = "metadata:\n  version: \"1.0.0\"\n  prompt: test\ncommands:\n  - name: test\n    description: Test\n    implementation: test_handler\n    options:\n      - name: level\n        option_type: string\n        description: Level\nglobal_options: []\n";
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("commands.yaml");
+        std::fs::write(&path, yaml).unwrap();
+
+        let result = CliBuilder::new()
+            .config_file(&path)
+            .context(Box::new(TestContext::default()))
+            .build();
+
+        match result.unwrap_err() {
+            DynamicCliError::Config(ConfigError::Located { error, location }) => {
+                assert!(error.to_string().contains("short or long"));
+                assert_eq!(location.file, path);
+                assert_eq!(location.line, 9);
+            }
+            other => panic!("Expected Located error, got: {:?}", other),
+        }
+    }
+
     #[test]
     fn test_builder_chaining() {
         let config = create_test_config();
@@ -854,6 +1325,60 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_builder_applies_overlays() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("commands.yaml");
+        std::fs::write(&base, serde_yaml::to_string(&create_test_config()).unwrap()).unwrap();
+        let overlay = dir.path().join("overrides.yaml");
+        std::fs::write(&overlay, "commands:\n  - name: test\n    aliases: [t]\n").unwrap();
+
+        let app = CliBuilder::new()
+            .config_file(&base)
+            .overlays(vec![(Layer::User, overlay)])
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .build()
+            .unwrap();
+
+        assert!(app.run_cli(vec!["t".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_builder_interpolate_env_is_opt_in() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = dir.path().join("commands.yaml");
+        let mut config = create_test_config();
+        config.metadata.prompt = "${DYNAMIC_CLI_TEST_BUILDER_PROMPT:-fallback}".to_string();
+        std::fs::write(&base, serde_yaml::to_string(&config).unwrap()).unwrap();
+
+        let build = |interpolate| {
+            CliBuilder::new()
+                .config_file(&base)
+                .interpolate_env(interpolate)
+                .context(Box::new(TestContext::default()))
+                .register_handler(
+                    "test_handler",
+                    Box::new(TestHandler {
+                        name: "test".to_string(),
+                    }),
+                )
+                .build()
+                .unwrap()
+        };
+
+        assert_eq!(
+            build(false).prompt,
+            "${DYNAMIC_CLI_TEST_BUILDER_PROMPT:-fallback}"
+        );
+        assert_eq!(build(true).prompt, "fallback");
+    }
+
     #[test]
     fn test_default_prompt_from_config() {
         let config = create_test_config();
@@ -962,4 +1487,189 @@ mod tests {
         let result = app.run_cli(vec!["--help".to_string(), "ghost".to_string()]);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_generate_completions() {
+        let app = CliBuilder::new()
+            .config(create_test_config())
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .build()
+            .unwrap();
+
+        let script = app.generate_completions(Shell::Fish);
+        assert!(script.contains("complete -c test"));
+        assert!(script.contains("-a 'test'"));
+    }
+
+    #[test]
+    fn test_run_cli_completions_command() {
+        let build = || {
+            CliBuilder::new()
+                .config(create_test_config())
+                .context(Box::new(TestContext::default()))
+                .register_handler(
+                    "test_handler",
+                    Box::new(TestHandler {
+                        name: "test".to_string(),
+                    }),
+                )
+                .build()
+                .unwrap()
+        };
+
+        let result = build().run_cli(vec!["completions".to_string(), "bash".to_string()]);
+        assert!(result.is_ok());
+
+        let result = build().run_cli(vec!["completions".to_string(), "bsh".to_string()]);
+        match result {
+            Err(DynamicCliError::Validation(ValidationError::InvalidChoice {
+                suggestions,
+                ..
+            })) => assert_eq!(suggestions.first().map(String::as_str), Some("bash")),
+            other => panic!("expected InvalidChoice, got {:?}", other),
+        }
+
+        let result = build().run_cli(vec!["completions".to_string()]);
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Parse(ParseError::MissingArgument { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_run_cli_user_completions_command_takes_precedence() {
+        let mut config = create_test_config();
+        config.commands[0].name = "completions".to_string();
+        let context = Box::new(TestContext::default());
+
+        let app = CliBuilder::new()
+            .config(config)
+            .context(context)
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "user".to_string(),
+                }),
+            )
+            .build()
+            .unwrap();
+
+        // The configured command has no arguments, so a shell name is rejected
+        let result = app.run_cli(vec!["completions".to_string(), "bash".to_string()]);
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Parse(ParseError::TooManyArguments { .. }))
+        ));
+    }
+
+    // Accepts the values listed in `params.allowed`
+    struct AllowedValues;
+
+    impl ArgumentValidator for AllowedValues {
+        fn validate(
+            &self,
+            context: &dyn ExecutionContext,
+            arg_name: &str,
+            value: &str,
+            params: &serde_json::Value,
+        ) -> Result<()> {
+            // The validator sees the application context
+            crate::context::downcast_ref::<TestContext>(context).expect("Failed to downcast");
+
+            let allowed = params["allowed"].as_array().cloned().unwrap_or_default();
+            if allowed.iter().any(|v| v.as_str() == Some(value)) {
+                return Ok(());
+            }
+            Err(ValidationError::CustomConstraint {
+                arg_name: arg_name.to_string(),
+                reason: format!("'{}' is not allowed", value),
+                suggestion: None,
+            }
+            .into())
+        }
+    }
+
+    fn config_with_custom_rule(name: &str) -> CommandsConfig {
+        let mut config = create_test_config();
+        config.commands[0].arguments = vec![crate::config::schema::ArgumentDefinition {
+            name: "project".to_string(),
+            arg_type: crate::config::schema::ArgumentType::String,
+            required: true,
+            description: "Project".to_string(),
+            validation: vec![ValidationRule::Custom {
+                custom: name.to_string(),
+                params: serde_json::json!({ "allowed": ["alpha"] }),
+            }],
+            secure: false,
+            ..Default::default()
+        }];
+        config
+    }
+
+    #[test]
+    fn test_builder_rejects_unknown_custom_validator() {
+        let result = CliBuilder::new()
+            .config(config_with_custom_rule("allowed_value"))
+            .context(Box::new(TestContext::default()))
+            .register_handler(
+                "test_handler",
+                Box::new(TestHandler {
+                    name: "test".to_string(),
+                }),
+            )
+            .register_validator("allowed_values", Box::new(AllowedValues))
+            .build();
+
+        match result {
+            Err(DynamicCliError::Config(ConfigError::InvalidSchema {
+                reason,
+                path,
+                suggestion,
+            })) => {
+                assert!(reason.contains("'allowed_value'"));
+                assert_eq!(path.as_deref(), Some("test.arguments[0].validation[0]"));
+                assert_eq!(
+                    suggestion.as_deref(),
+                    Some("Did you mean 'allowed_values'?")
+                );
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_run_cli_applies_custom_validator() {
+        let build = || {
+            CliBuilder::new()
+                .config(config_with_custom_rule("allowed_values"))
+                .context(Box::new(TestContext::default()))
+                .register_handler(
+                    "test_handler",
+                    Box::new(TestHandler {
+                        name: "test".to_string(),
+                    }),
+                )
+                .register_validator("allowed_values", Box::new(AllowedValues))
+                .build()
+                .unwrap()
+        };
+
+        assert!(build()
+            .run_cli(vec!["test".to_string(), "alpha".to_string()])
+            .is_ok());
+
+        let result = build().run_cli(vec!["test".to_string(), "beta".to_string()]);
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Validation(
+                ValidationError::CustomConstraint { .. }
+            ))
+        ));
+    }
 }
@@ -0,0 +1,863 @@
+//! Shell completion script generation
+//!
+//! The REPL completes commands interactively, but in CLI mode completion
+//! is the job of the user's shell. This module walks a [`CommandsConfig`]
+//! and generates a completion script for bash, zsh or fish.
+//!
+//! The scripts complete:
+//! - command and subcommand names, and their aliases
+//! - short and long flags of options (including global options)
+//! - option `choices`
+//! - file names for arguments and options of type `path`
+//!
+//! # Usage
+//!
+//! Applications built with [`CliBuilder`](crate::builder::CliBuilder) get a
+//! hidden `completions <shell>` command that prints the script:
+//!
+//! ```text
+//! $ myapp completions bash > /etc/bash_completion.d/myapp
+//! $ myapp completions zsh > ~/.zfunc/_myapp
+//! $ myapp completions fish > ~/.config/fish/completions/myapp.fish
+//! ```
+//!
+//! The program name is taken from `metadata.prompt`.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::completion::{generate_completions, Shell};
+//! use dynamic_cli::config::schema::{CommandDefinition, CommandsConfig, Metadata, SCHEMA_VERSION};
+//!
+//! let config = CommandsConfig {
+//!     metadata: Metadata {
+//!         version: "1.0.0".to_string(),
+//!         prompt: "myapp".to_string(),
+//!         prompt_suffix: " > ".to_string(),
+//!     },
+//!     commands: vec![CommandDefinition {
+//!         name: "build".to_string(),
+//!         aliases: vec!["b".to_string()],
+//!         description: "Build the project".to_string(),
+//!         required: false,
+//!         arguments: vec![],
+//!         options: vec![],
+//!         implementation: "build_handler".to_string(),
+//!         ..Default::default()
+//!     }],
+//!     global_options: vec![],
+//!     include: vec![],
+//!     schema_version: SCHEMA_VERSION,
+//! };
+//!
+//! let script = generate_completions(&config, Shell::Bash);
+//! assert!(script.contains("complete -F _myapp myapp"));
+//! assert!(script.contains("build"));
+//! ```
+
+use crate::config::schema::{ArgumentType, CommandDefinition, CommandsConfig, OptionDefinition};
+use crate::error::{DynamicCliError, ValidationError};
+use std::fmt;
+use std::str::FromStr;
+
+/// Name of the hidden built-in command that prints a completion script
+pub const COMPLETIONS_COMMAND: &str = "completions";
+
+// ============================================================================
+// Shell
+// ============================================================================
+
+/// Shells for which a completion script can be generated
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::completion::Shell;
+///
+/// let shell: Shell = "zsh".parse().unwrap();
+/// assert_eq!(shell, Shell::Zsh);
+/// assert_eq!(shell.to_string(), "zsh");
+/// assert!("tcsh".parse::<Shell>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shell {
+    /// GNU Bash
+    Bash,
+    /// Z shell
+    Zsh,
+    /// Friendly interactive shell
+    Fish,
+}
+
+impl Shell {
+    /// All supported shells
+    pub const ALL: [Shell; 3] = [Shell::Bash, Shell::Zsh, Shell::Fish];
+
+    /// Name of the shell, as accepted by `completions <shell>`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Shell::Bash => "bash",
+            Shell::Zsh => "zsh",
+            Shell::Fish => "fish",
+        }
+    }
+}
+
+impl fmt::Display for Shell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Shell {
+    type Err = DynamicCliError;
+
+    /// Parse a shell name (case-insensitive)
+    ///
+    /// Unknown names produce an [`ValidationError::InvalidChoice`] listing
+    /// the supported shells.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Shell::ALL
+            .into_iter()
+            .find(|shell| shell.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| {
+                let names: Vec<String> = Shell::ALL.iter().map(|s| s.to_string()).collect();
+                ValidationError::invalid_choice_with_suggestions("shell", s, &names).into()
+            })
+    }
+}
+
+// ============================================================================
+// Generation
+// ============================================================================
+
+/// Generate a completion script for `shell`
+///
+/// The script registers completion for the program named by
+/// `config.metadata.prompt`.
+pub fn generate_completions(config: &CommandsConfig, shell: Shell) -> String {
+    let spec = CompletionSpec::new(config);
+    match shell {
+        Shell::Bash => spec.bash(),
+        Shell::Zsh => spec.zsh(),
+        Shell::Fish => spec.fish(),
+    }
+}
+
+/// A position in the command tree where completion can happen
+struct Node<'a> {
+    /// Canonical command path ("" for the top level)
+    path: String,
+    /// Commands that can follow at this position
+    commands: &'a [CommandDefinition],
+    /// Options of the command at this position
+    options: &'a [OptionDefinition],
+    /// Whether the command takes a `path` positional argument
+    completes_files: bool,
+}
+
+/// Everything the script generators need, extracted from the configuration
+struct CompletionSpec<'a> {
+    /// Program name
+    program: &'a str,
+    /// Program name usable in shell function names
+    ident: String,
+    /// All positions of the command tree, top level first
+    nodes: Vec<Node<'a>>,
+    /// Options accepted at every position
+    global_options: &'a [OptionDefinition],
+}
+
+impl<'a> CompletionSpec<'a> {
+    fn new(config: &'a CommandsConfig) -> Self {
+        let mut nodes = vec![Node {
+            path: String::new(),
+            commands: &config.commands,
+            options: &[],
+            completes_files: false,
+        }];
+        collect_nodes(&config.commands, "", &mut nodes);
+
+        let program = config.metadata.prompt.as_str();
+        let ident = program
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+
+        Self {
+            program,
+            ident,
+            nodes,
+            global_options: &config.global_options,
+        }
+    }
+
+    /// `(parent/word patterns, child path)` for every command
+    ///
+    /// There is one pattern for the name and one for each alias.
+    fn transitions(&self) -> Vec<(Vec<String>, String)> {
+        let mut transitions = Vec::new();
+        for node in &self.nodes {
+            for cmd in node.commands {
+                let patterns = std::iter::once(&cmd.name)
+                    .chain(&cmd.aliases)
+                    .map(|word| format!("{}/{}", node.path, word))
+                    .collect();
+                transitions.push((patterns, child_path(&node.path, &cmd.name)));
+            }
+        }
+        transitions
+    }
+
+    /// Options taking a value, with the `path/flag` patterns matching them
+    ///
+    /// Global options match under any path.
+    fn value_options(&self) -> Vec<(Vec<FlagPattern>, &'a OptionDefinition)> {
+        let mut entries = Vec::new();
+        for node in &self.nodes {
+            for opt in node.options.iter().filter(|o| takes_value(o)) {
+                let patterns = flags(opt)
+                    .into_iter()
+                    .map(|flag| FlagPattern {
+                        path: Some(node.path.clone()),
+                        flag,
+                    })
+                    .collect();
+                entries.push((patterns, opt));
+            }
+        }
+        for opt in self.global_options.iter().filter(|o| takes_value(o)) {
+            let patterns = flags(opt)
+                .into_iter()
+                .map(|flag| FlagPattern { path: None, flag })
+                .collect();
+            entries.push((patterns, opt));
+        }
+        entries
+    }
+
+    /// All patterns of flags taking a value, formatted with `format`
+    fn value_flag_patterns(&self, format: fn(&FlagPattern) -> String) -> Vec<String> {
+        self.value_options()
+            .iter()
+            .flat_map(|(patterns, _)| patterns.iter().map(format))
+            .collect()
+    }
+
+    // ------------------------------------------------------------------------
+    // bash
+    // ------------------------------------------------------------------------
+
+    fn bash(&self) -> String {
+        let mut out = format!(
+            "# bash completion for {program}\n\
+             # Load with: source <({program} {cmd} bash)\n\n\
+             _{ident}() {{\n    \
+                 local cur prev word cmd_path=\"\" i=1\n    \
+                 cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    \
+                 prev=\"${{COMP_WORDS[COMP_CWORD-1]}}\"\n\n    \
+                 # Find the command path, skipping option values\n    \
+                 while [[ $i -lt $COMP_CWORD ]]; do\n        \
+                     word=\"${{COMP_WORDS[i]}}\"\n        \
+                     case \"${{cmd_path}}/${{word}}\" in\n",
+            program = self.program,
+            ident = self.ident,
+            cmd = COMPLETIONS_COMMAND,
+        );
+        push_sh_path_cases(
+            &mut out,
+            &self.transitions(),
+            &self.value_flag_patterns(FlagPattern::sh),
+            "((i++))",
+        );
+        out.push_str(
+            "        esac\n        \
+                 ((i++))\n    \
+             done\n\n    \
+             # Complete option values\n    \
+             case \"${cmd_path}/${prev}\" in\n",
+        );
+        for (patterns, opt) in self.value_options() {
+            let patterns: Vec<String> = patterns.iter().map(FlagPattern::sh).collect();
+            let action = match ValueKind::of(opt) {
+                ValueKind::Choices(choices) => format!(
+                    "COMPREPLY=($(compgen -W {} -- \"${{cur}}\"))",
+                    sh_quote(&choices.join(" "))
+                ),
+                ValueKind::Files => "COMPREPLY=($(compgen -f -- \"${cur}\"))".to_string(),
+                ValueKind::Any => "COMPREPLY=()".to_string(),
+            };
+            out.push_str(&format!(
+                "        {})\n            {}\n            return 0\n            ;;\n",
+                patterns.join("|"),
+                action
+            ));
+        }
+        out.push_str(
+            "    esac\n\n    \
+             # Complete commands, flags and file arguments\n    \
+             local names=\"\" flags=\"\" files=0\n    \
+             case \"${cmd_path}\" in\n",
+        );
+        for node in &self.nodes {
+            let words = command_words(node).join(" ");
+            let own_flags: Vec<String> = node.options.iter().flat_map(flags).collect();
+            let mut assignments = Vec::new();
+            if !words.is_empty() {
+                assignments.push(format!("names={}", sh_quote(&words)));
+            }
+            if !own_flags.is_empty() {
+                assignments.push(format!("flags={}", sh_quote(&own_flags.join(" "))));
+            }
+            if node.completes_files {
+                assignments.push("files=1".to_string());
+            }
+            if assignments.is_empty() {
+                continue;
+            }
+            out.push_str(&format!(
+                "        {})\n            {}\n            ;;\n",
+                sh_quote(&node.path),
+                assignments.join("\n            ")
+            ));
+        }
+        out.push_str("    esac\n");
+        let global_flags: Vec<String> = self.global_options.iter().flat_map(flags).collect();
+        if !global_flags.is_empty() {
+            out.push_str(&format!(
+                "    flags=\"${{flags}} \"{}\n",
+                sh_quote(&global_flags.join(" "))
+            ));
+        }
+        out.push_str(&format!(
+            "\n    \
+             if [[ \"${{cur}}\" == -* ]]; then\n        \
+                 COMPREPLY=($(compgen -W \"${{flags}}\" -- \"${{cur}}\"))\n    \
+             else\n        \
+                 COMPREPLY=($(compgen -W \"${{names}}\" -- \"${{cur}}\"))\n        \
+                 if [[ $files -eq 1 ]]; then\n            \
+                     COMPREPLY+=($(compgen -f -- \"${{cur}}\"))\n        \
+                 fi\n    \
+             fi\n\
+             }}\n\n\
+             complete -F _{ident} {program}\n",
+            ident = self.ident,
+            program = self.program,
+        ));
+        out
+    }
+
+    // ------------------------------------------------------------------------
+    // zsh
+    // ------------------------------------------------------------------------
+
+    fn zsh(&self) -> String {
+        let mut out = format!(
+            "#compdef {program}\n\
+             # zsh completion for {program}\n\
+             # Save as _{ident} in a directory of $fpath\n\n\
+             _{ident}() {{\n    \
+                 local cmd_path=\"\" word prev cur=\"${{words[CURRENT]}}\"\n    \
+                 local -a names flags\n    \
+                 local files=0 i=2\n\n    \
+                 # Find the command path, skipping option values\n    \
+                 while (( i < CURRENT )); do\n        \
+                     word=\"${{words[i]}}\"\n        \
+                     case \"${{cmd_path}}/${{word}}\" in\n",
+            program = self.program,
+            ident = self.ident,
+        );
+        push_sh_path_cases(
+            &mut out,
+            &self.transitions(),
+            &self.value_flag_patterns(FlagPattern::sh),
+            "(( i++ ))",
+        );
+        out.push_str(
+            "        esac\n        \
+                 (( i++ ))\n    \
+             done\n\n    \
+             # Complete option values\n    \
+             prev=\"${words[CURRENT-1]}\"\n    \
+             case \"${cmd_path}/${prev}\" in\n",
+        );
+        for (patterns, opt) in self.value_options() {
+            let patterns: Vec<String> = patterns.iter().map(FlagPattern::sh).collect();
+            let action = match ValueKind::of(opt) {
+                ValueKind::Choices(choices) => format!(
+                    "compadd -- {}",
+                    choices
+                        .iter()
+                        .map(|c| sh_quote(c))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                ),
+                ValueKind::Files => "_files".to_string(),
+                ValueKind::Any => "_message 'value'".to_string(),
+            };
+            out.push_str(&format!(
+                "        {})\n            {}\n            return\n            ;;\n",
+                patterns.join("|"),
+                action
+            ));
+        }
+        out.push_str(
+            "    esac\n\n    \
+             # Complete commands, flags and file arguments\n    \
+             case \"${cmd_path}\" in\n",
+        );
+        for node in &self.nodes {
+            let words: Vec<String> = command_words(node).iter().map(|w| sh_quote(w)).collect();
+            let own_flags: Vec<String> = node
+                .options
+                .iter()
+                .flat_map(flags)
+                .map(|f| sh_quote(&f))
+                .collect();
+            let mut assignments = Vec::new();
+            if !words.is_empty() {
+                assignments.push(format!("names=({})", words.join(" ")));
+            }
+            if !own_flags.is_empty() {
+                assignments.push(format!("flags=({})", own_flags.join(" ")));
+            }
+            if node.completes_files {
+                assignments.push("files=1".to_string());
+            }
+            if assignments.is_empty() {
+                continue;
+            }
+            out.push_str(&format!(
+                "        {})\n            {}\n            ;;\n",
+                sh_quote(&node.path),
+                assignments.join("\n            ")
+            ));
+        }
+        out.push_str("    esac\n");
+        let global_flags: Vec<String> = self
+            .global_options
+            .iter()
+            .flat_map(flags)
+            .map(|f| sh_quote(&f))
+            .collect();
+        if !global_flags.is_empty() {
+            out.push_str(&format!("    flags+=({})\n", global_flags.join(" ")));
+        }
+        out.push_str(&format!(
+            "\n    \
+             if [[ \"${{cur}}\" == -* ]]; then\n        \
+                 compadd -- \"${{flags[@]}}\"\n    \
+             else\n        \
+                 compadd -- \"${{names[@]}}\"\n        \
+                 (( files )) && _files\n    \
+             fi\n\
+             }}\n\n\
+             if [[ \"${{funcstack[1]}}\" == \"_{ident}\" ]]; then\n    \
+                 _{ident} \"$@\"\n\
+             else\n    \
+                 compdef _{ident} {program}\n\
+             fi\n",
+            ident = self.ident,
+            program = self.program,
+        ));
+        out
+    }
+
+    // ------------------------------------------------------------------------
+    // fish
+    // ------------------------------------------------------------------------
+
+    fn fish(&self) -> String {
+        let ident = &self.ident;
+        let program = self.program;
+        let mut out = format!(
+            "# fish completion for {program}\n\
+             # Save as ~/.config/fish/completions/{program}.fish\n\n\
+             function __{ident}_command_path\n    \
+                 set -l tokens (commandline -opc)\n    \
+                 set -l cmd_path ''\n    \
+                 set -l skip 0\n    \
+                 for word in $tokens[2..-1]\n        \
+                     if test $skip -eq 1\n            \
+                         set skip 0\n            \
+                         continue\n        \
+                     end\n        \
+                     switch \"$cmd_path/$word\"\n",
+        );
+        for (patterns, child) in self.transitions() {
+            let patterns: Vec<String> = patterns.iter().map(|p| fish_quote(p)).collect();
+            out.push_str(&format!(
+                "            case {}\n                set cmd_path {}\n",
+                patterns.join(" "),
+                fish_quote(&child)
+            ));
+        }
+        let skip_patterns = self.value_flag_patterns(FlagPattern::fish);
+        if !skip_patterns.is_empty() {
+            out.push_str(&format!(
+                "            case {}\n                set skip 1\n",
+                skip_patterns.join(" ")
+            ));
+        }
+        out.push_str(&format!(
+            "        end\n    \
+                 end\n    \
+                 echo $cmd_path\n\
+             end\n\n\
+             function __{ident}_at\n    \
+                 set -l cmd_path (__{ident}_command_path)\n    \
+                 test \"$cmd_path\" = \"$argv[1]\"\n\
+             end\n\n\
+             complete -c {program} -f\n",
+        ));
+
+        for node in &self.nodes {
+            let condition = fish_quote(&format!("__{}_at \"{}\"", ident, node.path));
+            for cmd in node.commands.iter().filter(|cmd| !cmd.hidden) {
+                out.push_str(&format!(
+                    "complete -c {} -n {} -a {} -d {}\n",
+                    program,
+                    condition,
+                    fish_quote(&cmd.name),
+                    fish_quote(&cmd.description)
+                ));
+                for alias in &cmd.aliases {
+                    out.push_str(&format!(
+                        "complete -c {} -n {} -a {} -d {}\n",
+                        program,
+                        condition,
+                        fish_quote(alias),
+                        fish_quote(&format!("Alias for {}", cmd.name))
+                    ));
+                }
+            }
+            for opt in node.options {
+                out.push_str(&format!(
+                    "complete -c {} -n {}{}\n",
+                    program,
+                    condition,
+                    fish_option(opt)
+                ));
+            }
+            if node.completes_files {
+                out.push_str(&format!("complete -c {} -n {} -F\n", program, condition));
+            }
+        }
+        for opt in self.global_options {
+            out.push_str(&format!("complete -c {}{}\n", program, fish_option(opt)));
+        }
+        out
+    }
+}
+
+/// A `path/flag` pattern matching a flag at a command path
+struct FlagPattern {
+    /// Command path, or `None` to match under any path (global options)
+    path: Option<String>,
+    /// The flag (`-o`, `--output`)
+    flag: String,
+}
+
+impl FlagPattern {
+    /// Pattern for a bash or zsh `case` arm
+    fn sh(&self) -> String {
+        match &self.path {
+            Some(path) => sh_quote(&format!("{}/{}", path, self.flag)),
+            None => format!("*{}", sh_quote(&format!("/{}", self.flag))),
+        }
+    }
+
+    /// Pattern for a fish `switch` case (quoted wildcards still match)
+    fn fish(&self) -> String {
+        match &self.path {
+            Some(path) => fish_quote(&format!("{}/{}", path, self.flag)),
+            None => fish_quote(&format!("*/{}", self.flag)),
+        }
+    }
+}
+
+/// How the value of an option is completed
+enum ValueKind<'a> {
+    /// One of the declared choices or `enum` variants
+    Choices(Vec<&'a str>),
+    /// A file name
+    Files,
+    /// Free-form value (nothing is offered)
+    Any,
+}
+
+impl<'a> ValueKind<'a> {
+    fn of(opt: &'a OptionDefinition) -> Self {
+        if opt.option_type == ArgumentType::Enum {
+            ValueKind::Choices(opt.variants.iter().map(|v| v.name.as_str()).collect())
+        } else if !opt.choices.is_empty() {
+            ValueKind::Choices(opt.choices.iter().map(String::as_str).collect())
+        } else if opt.option_type == ArgumentType::Path {
+            ValueKind::Files
+        } else {
+            ValueKind::Any
+        }
+    }
+}
+
+/// Add a node for every command of `commands` (recursively)
+fn collect_nodes<'a>(commands: &'a [CommandDefinition], parent: &str, nodes: &mut Vec<Node<'a>>) {
+    for cmd in commands {
+        let path = child_path(parent, &cmd.name);
+        nodes.push(Node {
+            path: path.clone(),
+            commands: &cmd.subcommands,
+            options: &cmd.options,
+            completes_files: cmd
+                .arguments
+                .iter()
+                .any(|arg| arg.arg_type == ArgumentType::Path),
+        });
+        collect_nodes(&cmd.subcommands, &path, nodes);
+    }
+}
+
+/// Canonical path of a subcommand
+fn child_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} {}", parent, name)
+    }
+}
+
+/// Names and aliases of the commands offered at a node
+///
+/// Hidden commands are left out; once typed, they are still followed.
+fn command_words<'n>(node: &'n Node<'_>) -> Vec<&'n str> {
+    node.commands
+        .iter()
+        .filter(|cmd| !cmd.hidden)
+        .flat_map(|cmd| std::iter::once(&cmd.name).chain(&cmd.aliases))
+        .map(String::as_str)
+        .collect()
+}
+
+/// Short and long flags of an option (`-v`, `--verbose`)
+fn flags(opt: &OptionDefinition) -> Vec<String> {
+    opt.short
+        .iter()
+        .map(|s| format!("-{}", s))
+        .chain(opt.long.iter().map(|l| format!("--{}", l)))
+        .collect()
+}
+
+/// Whether an option consumes the next word as its value
+fn takes_value(opt: &OptionDefinition) -> bool {
+    opt.option_type != ArgumentType::Bool
+}
+
+/// Emit the `case` arms resolving the command path (bash and zsh)
+fn push_sh_path_cases(
+    out: &mut String,
+    transitions: &[(Vec<String>, String)],
+    value_flags: &[String],
+    skip: &str,
+) {
+    for (patterns, child) in transitions {
+        let patterns: Vec<String> = patterns.iter().map(|p| sh_quote(p)).collect();
+        out.push_str(&format!(
+            "            {})\n                cmd_path={}\n                ;;\n",
+            patterns.join("|"),
+            sh_quote(child)
+        ));
+    }
+    if !value_flags.is_empty() {
+        out.push_str(&format!(
+            "            {})\n                {}\n                ;;\n",
+            value_flags.join("|"),
+            skip
+        ));
+    }
+}
+
+/// Quote a word for bash and zsh (single quotes)
+fn sh_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', r"'\''"))
+}
+
+/// Quote a word for fish (single quotes)
+fn fish_quote(s: &str) -> String {
+    format!("'{}'", s.replace('\\', r"\\").replace('\'', r"\'"))
+}
+
+/// Flags, description and value completion of an option for `complete`
+fn fish_option(opt: &OptionDefinition) -> String {
+    let mut out = String::new();
+    if let Some(short) = &opt.short {
+        out.push_str(&format!(" -s {}", fish_quote(short)));
+    }
+    if let Some(long) = &opt.long {
+        out.push_str(&format!(" -l {}", fish_quote(long)));
+    }
+    out.push_str(&format!(" -d {}", fish_quote(&opt.description)));
+    if takes_value(opt) {
+        match ValueKind::of(opt) {
+            ValueKind::Choices(choices) => {
+                out.push_str(&format!(" -x -a {}", fish_quote(&choices.join(" "))))
+            }
+            ValueKind::Files => out.push_str(" -r -F"),
+            ValueKind::Any => out.push_str(" -x"),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{ArgumentDefinition, Metadata, SCHEMA_VERSION};
+
+    fn option(
+        name: &str,
+        short: &str,
+        option_type: ArgumentType,
+        choices: &[&str],
+    ) -> OptionDefinition {
+        OptionDefinition {
+            name: name.to_string(),
+            short: Some(short.to_string()),
+            long: Some(name.to_string()),
+            option_type,
+            required: false,
+            default: None,
+            description: format!("The {} option", name),
+            choices: choices.iter().map(|c| c.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn command(name: &str, aliases: &[&str]) -> CommandDefinition {
+        CommandDefinition {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            description: format!("The {} command", name),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: format!("{}_handler", name),
+            ..Default::default()
+        }
+    }
+
+    /// `remote [r] add [a] --mode fetch|push`, `open <file: path>`,
+    /// global `--config <path>` and `--verbose`
+    fn make_config() -> CommandsConfig {
+        let mut add = command("add", &["a"]);
+        add.options = vec![option(
+            "mode",
+            "m",
+            ArgumentType::String,
+            &["fetch", "push"],
+        )];
+        let mut remote = command("remote", &["r"]);
+        remote.subcommands = vec![add];
+
+        let mut open = command("open", &[]);
+        open.arguments = vec![ArgumentDefinition {
+            name: "file".to_string(),
+            arg_type: ArgumentType::Path,
+            required: true,
+            description: "File to open".to_string(),
+            validation: vec![],
+            secure: false,
+            ..Default::default()
+        }];
+
+        CommandsConfig {
+            metadata: Metadata {
+                version: "1.0.0".to_string(),
+                prompt: "my-app".to_string(),
+                prompt_suffix: " > ".to_string(),
+            },
+            commands: vec![remote, open],
+            global_options: vec![
+                option("config", "c", ArgumentType::Path, &[]),
+                option("verbose", "v", ArgumentType::Bool, &[]),
+            ],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_shell_from_str() {
+        assert_eq!("bash".parse::<Shell>().unwrap(), Shell::Bash);
+        assert_eq!("Fish".parse::<Shell>().unwrap(), Shell::Fish);
+
+        let err = "zhs".parse::<Shell>().unwrap_err();
+        assert!(err.to_string().contains("bash, zsh, fish"));
+    }
+
+    #[test]
+    fn test_bash_script() {
+        let script = generate_completions(&make_config(), Shell::Bash);
+
+        // Function names cannot contain '-'
+        assert!(script.contains("_my_app() {"));
+        assert!(script.contains("complete -F _my_app my-app"));
+        // Names and aliases resolve to the canonical path
+        assert!(script.contains("'remote/add'|'remote/a')\n                cmd_path='remote add'"));
+        // Values of options are skipped while resolving the path
+        assert!(script.contains("'remote add/-m'|'remote add/--mode'|*'/-c'|*'/--config')"));
+        assert!(script.contains("compgen -W 'fetch push'"));
+        assert!(script.contains("names='remote r open'"));
+        assert!(script.contains("'open')\n            files=1"));
+        assert!(script.contains("flags=\"${flags} \"'-c --config -v --verbose'"));
+    }
+
+    #[test]
+    fn test_zsh_script() {
+        let script = generate_completions(&make_config(), Shell::Zsh);
+
+        assert!(script.starts_with("#compdef my-app\n"));
+        assert!(script.contains("compadd -- 'fetch' 'push'"));
+        assert!(script.contains("*'/-c'|*'/--config')\n            _files"));
+        assert!(script.contains("names=('add' 'a')"));
+        assert!(script.contains("compdef _my_app my-app"));
+    }
+
+    #[test]
+    fn test_fish_script() {
+        let script = generate_completions(&make_config(), Shell::Fish);
+
+        assert!(script
+            .contains("case 'remote/add' 'remote/a'\n                set cmd_path 'remote add'"));
+        assert!(script.contains("case 'remote add/-m' 'remote add/--mode' '*/-c' '*/--config'"));
+        assert!(script
+            .contains("complete -c my-app -n '__my_app_at \"remote\"' -a 'a' -d 'Alias for add'"));
+        assert!(script.contains("-s 'm' -l 'mode' -d 'The mode option' -x -a 'fetch push'"));
+        assert!(script.contains("complete -c my-app -n '__my_app_at \"open\"' -F"));
+        // Global options apply everywhere
+        assert!(
+            script.contains("complete -c my-app -s 'c' -l 'config' -d 'The config option' -r -F")
+        );
+        assert!(script.contains("complete -c my-app -s 'v' -l 'verbose' -d 'The verbose option'\n"));
+    }
+
+    #[test]
+    fn test_hidden_commands_are_not_offered() {
+        let mut config = make_config();
+        config.commands[0].subcommands[0].hidden = true;
+
+        let script = generate_completions(&config, Shell::Bash);
+        assert!(script.contains("compgen -W 'fetch push'"));
+        assert!(!script.contains("names='add a'"));
+        // The hidden command still resolves while completing its options
+        assert!(script.contains("'remote/add'|'remote/a')\n                cmd_path='remote add'"));
+
+        let script = generate_completions(&config, Shell::Fish);
+        assert!(!script.contains("-a 'a' -d 'Alias for add'"));
+    }
+
+    #[test]
+    fn test_quoting() {
+        assert_eq!(sh_quote("it's"), r"'it'\''s'");
+        assert_eq!(fish_quote("it's"), r"'it\'s'");
+    }
+}
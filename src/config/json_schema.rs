@@ -0,0 +1,703 @@
+//! JSON Schema of the configuration format
+//!
+//! [`json_schema`] describes [`CommandsConfig`] as a JSON Schema
+//! (draft-07) document, so that editors can validate and complete
+//! configuration files. Write it next to the configuration:
+//!
+//! ```no_run
+//! use dynamic_cli::config::json_schema::json_schema;
+//!
+//! let schema = serde_json::to_string_pretty(&json_schema()).unwrap();
+//! std::fs::write("commands.schema.json", schema)?;
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! and reference it from the file, e.g. for the YAML language server
+//! (VS Code, IntelliJ):
+//!
+//! ```yaml
+//! # yaml-language-server: $schema=./commands.schema.json
+//! metadata:
+//!   version: "1.0.0"
+//!   prompt: "myapp"
+//! commands: []
+//! ```
+//!
+//! The schema is stricter than the loader in one way: unknown keys, which
+//! the loader ignores, are reported so that typos are caught.
+//!
+//! [`CommandsConfig`]: crate::config::schema::CommandsConfig
+
+use crate::config::schema::SCHEMA_VERSION;
+use serde_json::{json, Value};
+
+/// Build the JSON Schema of the configuration format
+///
+/// Covers every type of [`config::schema`](crate::config::schema):
+/// [`CommandsConfig`](crate::config::schema::CommandsConfig) at the root,
+/// the others under `definitions`. [`ValidationRule`](crate::config::schema::ValidationRule)
+/// entries are described with `oneOf`, one shape per rule.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::json_schema::json_schema;
+///
+/// let schema = json_schema();
+/// assert_eq!(schema["required"], serde_json::json!(["metadata", "commands"]));
+/// assert!(schema["definitions"]["CommandDefinition"].is_object());
+/// ```
+pub fn json_schema() -> Value {
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "dynamic-cli configuration",
+        "description": "Commands, arguments and options of a dynamic-cli application",
+        "type": "object",
+        "properties": {
+            "schema_version": {
+                "description": "Version of the configuration format (1 when absent)",
+                "type": "integer",
+                "minimum": 1,
+                "maximum": SCHEMA_VERSION
+            },
+            "metadata": { "$ref": "#/definitions/Metadata" },
+            "commands": {
+                "description": "List of available commands",
+                "type": "array",
+                "items": { "$ref": "#/definitions/CommandDefinition" }
+            },
+            "global_options": {
+                "description": "Options available to all commands",
+                "type": "array",
+                "items": { "$ref": "#/definitions/OptionDefinition" }
+            },
+            "include": {
+                "description": "Glob patterns of files to merge, relative to this file",
+                "type": "array",
+                "items": { "type": "string" }
+            }
+        },
+        "required": ["metadata", "commands"],
+        "additionalProperties": false,
+        "definitions": {
+            "Metadata": metadata(),
+            "CommandDefinition": command_definition(),
+            "ArgumentDefinition": argument_definition(),
+            "OptionDefinition": option_definition(),
+            "ArgumentGroup": argument_group(),
+            "ConstraintDefinition": constraint_definition(),
+            "EnumVariant": enum_variant(),
+            "ArgumentType": argument_type(),
+            "ValidationRule": validation_rule(),
+            "StringFormat": string_format()
+        }
+    })
+}
+
+/// Array of strings
+fn strings(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "array",
+        "items": { "type": "string" }
+    })
+}
+
+/// Array of items of a definition
+fn list_of(definition: &str, description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "array",
+        "items": { "$ref": format!("#/definitions/{}", definition) }
+    })
+}
+
+/// Optional count of values (`min_values`, `max_values`)
+fn count(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": ["integer", "null"],
+        "minimum": 0
+    })
+}
+
+/// Map from a name to other names (`requires`, `conflicts_with`)
+fn relation(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "object",
+        "additionalProperties": { "type": "array", "items": { "type": "string" } }
+    })
+}
+
+fn metadata() -> Value {
+    json!({
+        "description": "Application metadata",
+        "type": "object",
+        "properties": {
+            "version": { "description": "Application version", "type": "string" },
+            "prompt": { "description": "Prompt prefix in REPL mode", "type": "string" },
+            "prompt_suffix": {
+                "description": "Prompt suffix in REPL mode",
+                "type": "string",
+                "default": " > "
+            }
+        },
+        "required": ["version", "prompt"],
+        "additionalProperties": false
+    })
+}
+
+fn command_definition() -> Value {
+    json!({
+        "description": "A command, with its arguments, options and subcommands",
+        "type": "object",
+        "properties": {
+            "name": { "description": "Command name", "type": "string" },
+            "aliases": strings("Alternative names for the command"),
+            "description": { "description": "Description shown in help", "type": "string" },
+            "required": {
+                "description": "Fail at startup if no handler is registered",
+                "type": "boolean",
+                "default": false
+            },
+            "arguments": list_of("ArgumentDefinition", "Positional arguments"),
+            "options": list_of("OptionDefinition", "Named options"),
+            "implementation": {
+                "description": "Name of the handler implementation",
+                "type": "string"
+            },
+            "subcommands": list_of("CommandDefinition", "Nested subcommands"),
+            "groups": list_of("ArgumentGroup", "Groups of mutually exclusive arguments and options"),
+            "requires": relation("Names that must also be given when the key is"),
+            "conflicts_with": relation("Names that must not be given when the key is"),
+            "constraints": list_of("ConstraintDefinition", "Comparisons between values"),
+            "hidden": {
+                "description": "Leave the command out of help, completions and documentation",
+                "type": "boolean",
+                "default": false
+            }
+        },
+        "required": ["name", "description", "implementation"],
+        "additionalProperties": false
+    })
+}
+
+fn argument_definition() -> Value {
+    json!({
+        "description": "A positional argument",
+        "type": "object",
+        "properties": {
+            "name": { "description": "Argument name", "type": "string" },
+            "arg_type": { "$ref": "#/definitions/ArgumentType" },
+            "required": { "description": "Whether the argument is mandatory", "type": "boolean" },
+            "description": { "description": "Description shown in help", "type": "string" },
+            "validation": list_of("ValidationRule", "Validation rules"),
+            "secure": {
+                "description": "Keep command lines with this argument out of the REPL history",
+                "type": "boolean",
+                "default": false
+            },
+            "multiple": {
+                "description": "Take all remaining values (last argument only)",
+                "type": "boolean",
+                "default": false
+            },
+            "min_values": count("Minimum number of values of a variadic argument"),
+            "max_values": count("Maximum number of values of a variadic argument"),
+            "variants": list_of("EnumVariant", "Accepted values of an enum argument")
+        },
+        "required": ["name", "arg_type", "required", "description"],
+        "additionalProperties": false
+    })
+}
+
+fn option_definition() -> Value {
+    json!({
+        "description": "A named option",
+        "type": "object",
+        "properties": {
+            "name": { "description": "Option name", "type": "string" },
+            "short": { "description": "Short form, e.g. \"o\" for -o", "type": ["string", "null"] },
+            "long": {
+                "description": "Long form, e.g. \"output\" for --output",
+                "type": ["string", "null"]
+            },
+            "option_type": { "$ref": "#/definitions/ArgumentType" },
+            "required": {
+                "description": "Whether the option is mandatory",
+                "type": "boolean",
+                "default": false
+            },
+            "default": { "description": "Value when not given", "type": ["string", "null"] },
+            "description": { "description": "Description shown in help", "type": "string" },
+            "choices": strings("Allowed values"),
+            "env": {
+                "description": "Environment variable used when the option is not given",
+                "type": ["string", "null"]
+            },
+            "multiple": {
+                "description": "Whether the option may be repeated",
+                "type": "boolean",
+                "default": false
+            },
+            "min_values": count("Minimum number of values of a repeatable option"),
+            "max_values": count("Maximum number of values of a repeatable option"),
+            "variants": list_of("EnumVariant", "Accepted values of an enum option")
+        },
+        "required": ["name", "option_type", "description"],
+        "additionalProperties": false
+    })
+}
+
+fn argument_group() -> Value {
+    json!({
+        "description": "Group of mutually exclusive arguments and options",
+        "type": "object",
+        "properties": {
+            "name": { "description": "Group name", "type": "string" },
+            "members": strings("Names of the arguments and options in the group"),
+            "required": {
+                "description": "Whether one member must be given",
+                "type": "boolean",
+                "default": false
+            }
+        },
+        "required": ["name", "members"],
+        "additionalProperties": false
+    })
+}
+
+fn constraint_definition() -> Value {
+    json!({
+        "description": "Comparison between argument and option values",
+        "type": "object",
+        "properties": {
+            "check": {
+                "description": "Comparison to enforce, e.g. \"end > start\"",
+                "type": "string",
+                "pattern": "(<|>|==|!=)"
+            },
+            "message": { "description": "Error message when the check fails", "type": "string" }
+        },
+        "required": ["check"],
+        "additionalProperties": false
+    })
+}
+
+fn enum_variant() -> Value {
+    json!({
+        "description": "A variant of an enum argument or option",
+        "type": "object",
+        "properties": {
+            "name": { "description": "Canonical name", "type": "string" },
+            "description": { "description": "Description shown in help", "type": "string" },
+            "aliases": strings("Alternative names accepted on input")
+        },
+        "required": ["name"],
+        "additionalProperties": false
+    })
+}
+
+fn argument_type() -> Value {
+    json!({
+        "description": "Type of an argument or option value",
+        "type": "string",
+        "enum": [
+            "string", "integer", "float", "bool", "path", "duration", "bytes", "url", "ip",
+            "datetime", "regex", "enum"
+        ]
+    })
+}
+
+fn string_format() -> Value {
+    json!({
+        "description": "Text format of a format rule",
+        "type": "string",
+        "enum": ["email"]
+    })
+}
+
+/// One entry of a `validation` list: exactly one rule
+fn validation_rule() -> Value {
+    /// Shape of one rule: an object with only `properties`
+    fn rule(description: &str, properties: Value, required: &[&str]) -> Value {
+        let mut shape = json!({
+            "description": description,
+            "type": "object",
+            "properties": properties,
+            "additionalProperties": false
+        });
+        if required.is_empty() {
+            // Either bound, or both
+            shape["minProperties"] = json!(1);
+        } else {
+            shape["required"] = json!(required);
+        }
+        shape
+    }
+
+    json!({
+        "description": "A validation rule; each list entry holds exactly one rule",
+        "oneOf": [
+            rule(
+                "Require that a path exists",
+                json!({ "must_exist": { "type": "boolean" } }),
+                &["must_exist"],
+            ),
+            rule(
+                "Restrict file extensions (without the dot)",
+                json!({ "extensions": { "type": "array", "items": { "type": "string" } } }),
+                &["extensions"],
+            ),
+            rule(
+                "Numeric bounds, inclusive",
+                json!({ "min": { "type": "number" }, "max": { "type": "number" } }),
+                &[],
+            ),
+            rule(
+                "Require a match of a regular expression",
+                json!({ "pattern": { "type": "string", "format": "regex" } }),
+                &["pattern"],
+            ),
+            rule(
+                "Length bounds in characters, inclusive",
+                json!({
+                    "min_length": { "type": "integer", "minimum": 0 },
+                    "max_length": { "type": "integer", "minimum": 0 }
+                }),
+                &[],
+            ),
+            rule(
+                "Reject empty or whitespace-only values",
+                json!({ "not_empty": { "type": "boolean" } }),
+                &["not_empty"],
+            ),
+            rule(
+                "Require a well-known text format",
+                json!({ "format": { "$ref": "#/definitions/StringFormat" } }),
+                &["format"],
+            ),
+            rule(
+                "Run a validator registered by the application",
+                json!({ "custom": { "type": "string" }, "params": {} }),
+                &["custom"],
+            ),
+        ]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{
+        ArgumentDefinition, ArgumentGroup, ArgumentType, CommandDefinition, CommandsConfig,
+        ConstraintDefinition, EnumVariant, Metadata, OptionDefinition, StringFormat,
+        ValidationRule,
+    };
+    use serde::de::{DeserializeOwned, Error as _, Visitor};
+    use serde::Deserializer;
+    use std::collections::BTreeSet;
+
+    /// Deserializer recording the field or variant names serde asks for
+    struct Names<'a>(&'a mut &'static [&'static str]);
+
+    impl<'de> Deserializer<'de> for Names<'_> {
+        type Error = serde::de::value::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+            Err(Self::Error::custom("not a struct or an enum"))
+        }
+
+        fn deserialize_struct<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            fields: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = fields;
+            Err(Self::Error::custom("recorded"))
+        }
+
+        fn deserialize_enum<V: Visitor<'de>>(
+            self,
+            _name: &'static str,
+            variants: &'static [&'static str],
+            _visitor: V,
+        ) -> Result<V::Value, Self::Error> {
+            *self.0 = variants;
+            Err(Self::Error::custom("recorded"))
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map identifier ignored_any
+        }
+    }
+
+    /// Names of the fields (or variants) of `T` as serde reads them
+    fn serde_names<T: DeserializeOwned>() -> BTreeSet<&'static str> {
+        let mut names: &'static [&'static str] = &[];
+        let _ = T::deserialize(Names(&mut names));
+        names.iter().copied().collect()
+    }
+
+    fn definition(name: &str) -> Value {
+        json_schema()["definitions"][name].clone()
+    }
+
+    fn keys(object: &Value) -> BTreeSet<&str> {
+        object
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn required(schema: &Value) -> BTreeSet<&str> {
+        schema["required"]
+            .as_array()
+            .map(|names| names.iter().map(|n| n.as_str().unwrap()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Check that removing a key from `sample` breaks deserialization of
+    /// `T` exactly when the schema lists it as required
+    fn check_required<T: DeserializeOwned>(schema: &Value, sample: Value) {
+        let expected = required(schema);
+        assert!(serde_json::from_value::<T>(sample.clone()).is_ok());
+        for key in keys(&sample) {
+            let mut partial = sample.clone();
+            partial.as_object_mut().unwrap().remove(key);
+            assert_eq!(
+                serde_json::from_value::<T>(partial).is_err(),
+                expected.contains(key),
+                "required status of '{key}'"
+            );
+        }
+    }
+
+    fn argument() -> ArgumentDefinition {
+        ArgumentDefinition {
+            name: "input".to_string(),
+            arg_type: ArgumentType::Path,
+            required: true,
+            description: "Input".to_string(),
+            validation: vec![ValidationRule::MustExist { must_exist: true }],
+            secure: false,
+            multiple: true,
+            min_values: Some(1),
+            max_values: Some(3),
+            ..Default::default()
+        }
+    }
+
+    fn option() -> OptionDefinition {
+        OptionDefinition {
+            name: "mode".to_string(),
+            short: Some("m".to_string()),
+            long: Some("mode".to_string()),
+            option_type: ArgumentType::Enum,
+            required: false,
+            default: Some("fast".to_string()),
+            description: "Mode".to_string(),
+            choices: vec![],
+            env: Some("MODE".to_string()),
+            variants: vec![variant()],
+            ..Default::default()
+        }
+    }
+
+    fn variant() -> EnumVariant {
+        EnumVariant {
+            name: "fast".to_string(),
+            description: "Fast".to_string(),
+            aliases: vec!["f".to_string()],
+        }
+    }
+
+    fn group() -> ArgumentGroup {
+        ArgumentGroup {
+            name: "source".to_string(),
+            members: vec!["input".to_string()],
+            required: true,
+        }
+    }
+
+    fn constraint() -> ConstraintDefinition {
+        ConstraintDefinition {
+            check: "end > start".to_string(),
+            message: Some("end must come after start".to_string()),
+        }
+    }
+
+    fn command() -> CommandDefinition {
+        CommandDefinition {
+            name: "run".to_string(),
+            aliases: vec!["r".to_string()],
+            description: "Run".to_string(),
+            required: true,
+            arguments: vec![argument()],
+            options: vec![option()],
+            implementation: "run_handler".to_string(),
+            groups: vec![group()],
+            requires: [("mode".to_string(), vec!["input".to_string()])].into(),
+            conflicts_with: [("input".to_string(), vec!["mode".to_string()])].into(),
+            constraints: vec![constraint()],
+            ..Default::default()
+        }
+    }
+
+    fn config() -> CommandsConfig {
+        CommandsConfig {
+            schema_version: SCHEMA_VERSION,
+            metadata: Metadata {
+                version: "1.0.0".to_string(),
+                prompt: "test".to_string(),
+                prompt_suffix: " > ".to_string(),
+            },
+            commands: vec![command()],
+            global_options: vec![option()],
+            include: vec!["teams/*.yaml".to_string()],
+        }
+    }
+
+    fn to_value<T: serde::Serialize>(value: T) -> Value {
+        serde_json::to_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_schema_properties_match_serde_fields() {
+        let schema = json_schema();
+        assert_eq!(
+            keys(&schema["properties"]),
+            serde_names::<CommandsConfig>(),
+            "CommandsConfig"
+        );
+
+        let structs = [
+            ("Metadata", serde_names::<Metadata>()),
+            ("CommandDefinition", serde_names::<CommandDefinition>()),
+            ("ArgumentDefinition", serde_names::<ArgumentDefinition>()),
+            ("OptionDefinition", serde_names::<OptionDefinition>()),
+            ("ArgumentGroup", serde_names::<ArgumentGroup>()),
+            (
+                "ConstraintDefinition",
+                serde_names::<ConstraintDefinition>(),
+            ),
+            ("EnumVariant", serde_names::<EnumVariant>()),
+        ];
+        for (name, fields) in structs {
+            assert!(!fields.is_empty(), "{name}");
+            assert_eq!(keys(&definition(name)["properties"]), fields, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_schema_required_matches_serde_defaults() {
+        let schema = json_schema();
+        check_required::<CommandsConfig>(&schema, to_value(config()));
+
+        check_required::<Metadata>(&definition("Metadata"), to_value(config().metadata));
+        check_required::<CommandDefinition>(&definition("CommandDefinition"), to_value(command()));
+        check_required::<ArgumentDefinition>(
+            &definition("ArgumentDefinition"),
+            to_value(argument()),
+        );
+        check_required::<OptionDefinition>(&definition("OptionDefinition"), to_value(option()));
+        check_required::<ArgumentGroup>(&definition("ArgumentGroup"), to_value(group()));
+        check_required::<ConstraintDefinition>(
+            &definition("ConstraintDefinition"),
+            to_value(constraint()),
+        );
+        check_required::<EnumVariant>(&definition("EnumVariant"), to_value(variant()));
+    }
+
+    #[test]
+    fn test_schema_enums_match_serde_variants() {
+        let names = |name: &str| -> BTreeSet<String> {
+            definition(name)["enum"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|v| v.as_str().unwrap().to_string())
+                .collect()
+        };
+        let owned = |names: BTreeSet<&str>| names.into_iter().map(String::from).collect();
+
+        assert_eq!(names("ArgumentType"), owned(serde_names::<ArgumentType>()));
+        assert_eq!(names("StringFormat"), owned(serde_names::<StringFormat>()));
+    }
+
+    #[test]
+    fn test_schema_validation_rules_match_variants() {
+        let samples = [
+            ValidationRule::MustExist { must_exist: true },
+            ValidationRule::Extensions {
+                extensions: vec!["yaml".to_string()],
+            },
+            ValidationRule::Range {
+                min: Some(0.0),
+                max: Some(1.0),
+            },
+            ValidationRule::Pattern {
+                pattern: "^a".to_string(),
+            },
+            ValidationRule::Length {
+                min_length: Some(1),
+                max_length: Some(8),
+            },
+            ValidationRule::NotEmpty { not_empty: true },
+            ValidationRule::Format {
+                format: StringFormat::Email,
+            },
+            ValidationRule::Custom {
+                custom: "project_exists".to_string(),
+                params: json!({ "registry": "projects.json" }),
+            },
+        ];
+        // A new variant must get a sample (and a shape in the schema)
+        for sample in &samples {
+            match sample {
+                ValidationRule::MustExist { .. }
+                | ValidationRule::Extensions { .. }
+                | ValidationRule::Range { .. }
+                | ValidationRule::Pattern { .. }
+                | ValidationRule::Length { .. }
+                | ValidationRule::NotEmpty { .. }
+                | ValidationRule::Format { .. }
+                | ValidationRule::Custom { .. } => {}
+            }
+        }
+
+        let shapes = definition("ValidationRule")["oneOf"]
+            .as_array()
+            .unwrap()
+            .clone();
+        assert_eq!(shapes.len(), samples.len());
+        for (shape, sample) in shapes.iter().zip(&samples) {
+            let sample = serde_json::to_value(sample).unwrap();
+            assert_eq!(keys(&shape["properties"]), keys(&sample));
+
+            // Required keys and lone optional bounds are enough on their own
+            for key in keys(&sample) {
+                let lone = json!({ key: sample[key].clone() });
+                let accepted = serde_json::from_value::<ValidationRule>(lone).is_ok();
+                let required = required(shape);
+                assert_eq!(
+                    accepted,
+                    required.is_empty() || required.contains(key),
+                    "{key}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_schema_version_bounds() {
+        let version = &json_schema()["properties"]["schema_version"];
+        assert_eq!(version["maximum"], json!(SCHEMA_VERSION));
+    }
+}
@@ -0,0 +1,645 @@
+//! Layered configuration
+//!
+//! An application ships a base command file; administrators, users and
+//! projects can adjust it with overlay files without copying it. Overlays
+//! are applied in [`Layer`] order, and a later layer overrides what an
+//! earlier one set:
+//!
+//! 1. [`Layer::Base`] - the application's configuration
+//! 2. [`Layer::System`] - `/etc/<app>/overrides.yaml`
+//! 3. [`Layer::User`] - `~/.config/<app>/overrides.yaml`
+//! 4. [`Layer::Project`] - `./.<app>.yaml`
+//!
+//! # Overlay Format
+//!
+//! An overlay names existing commands (subcommands by their path, e.g.
+//! `"remote add"`) and global options, and lists only what it changes:
+//!
+//! ```yaml
+//! commands:
+//!   - name: deploy
+//!     aliases: [d]          # added to the command's aliases
+//!     options:
+//!       - name: env
+//!         default: staging  # replaces the option's default
+//!   - name: legacy-import
+//!     hidden: true          # left out of help and completions
+//! global_options:
+//!   - name: color
+//!     default: never
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dynamic_cli::config::layers::{default_overlays, load_layered, Layer};
+//!
+//! let layered = load_layered("commands.yaml", &default_overlays("myapp"))?;
+//!
+//! let origin = layered.origin("deploy.options.env.default");
+//! if origin.layer != Layer::Base {
+//!     println!("default of --env set in {}", origin.path.display());
+//! }
+//! # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+//! ```
+
+use crate::config::loader::{load_config_sources, parse_error_in_file, parse_file, LoadOptions};
+use crate::config::schema::{CommandDefinition, CommandsConfig, OptionDefinition};
+use crate::config::spans::SourceMap;
+use crate::config::validator::{diagnose_config_in, validate_config_in};
+use crate::error::{find_similar_strings, ConfigError, Diagnostic, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Configuration layers, in the order they are applied
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Layer {
+    /// The application's own configuration
+    Base,
+    /// Machine-wide overrides
+    System,
+    /// Overrides of the current user
+    User,
+    /// Overrides of the current project (working directory)
+    Project,
+}
+
+impl Layer {
+    /// Get the layer name as a lowercase string
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Layer::Base => "base",
+            Layer::System => "system",
+            Layer::User => "user",
+            Layer::Project => "project",
+        }
+    }
+}
+
+/// Where an effective value comes from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueOrigin {
+    /// Layer that set the value
+    pub layer: Layer,
+    /// File of that layer
+    pub path: PathBuf,
+}
+
+/// Standard overlay files of an application
+///
+/// Returns, in application order, `/etc/<app>/overrides.yaml` (Unix
+/// only), `<config dir>/<app>/overrides.yaml` (`~/.config` on Linux) and
+/// `.<app>.yaml` in the working directory. The files need not exist.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::layers::{default_overlays, Layer};
+/// use std::path::PathBuf;
+///
+/// let overlays = default_overlays("myapp");
+/// assert_eq!(
+///     overlays.last(),
+///     Some(&(Layer::Project, PathBuf::from(".myapp.yaml")))
+/// );
+/// ```
+pub fn default_overlays(app_name: &str) -> Vec<(Layer, PathBuf)> {
+    let mut overlays = Vec::new();
+    if cfg!(unix) {
+        overlays.push((
+            Layer::System,
+            Path::new("/etc").join(app_name).join("overrides.yaml"),
+        ));
+    }
+    if let Some(config_dir) = dirs::config_dir() {
+        overlays.push((
+            Layer::User,
+            config_dir.join(app_name).join("overrides.yaml"),
+        ));
+    }
+    overlays.push((Layer::Project, PathBuf::from(format!(".{}.yaml", app_name))));
+    overlays
+}
+
+/// Configuration resulting from a base file and its overlays
+///
+/// Besides the effective [`CommandsConfig`], it records which layer set
+/// each value an overlay changed. Values are identified by keys:
+///
+/// | Key                                  | Value                         |
+/// |--------------------------------------|-------------------------------|
+/// | `<command>.description`              | Description of a command      |
+/// | `<command>.aliases.<alias>`          | One alias of a command        |
+/// | `<command>.hidden`                   | Whether a command is hidden   |
+/// | `<command>.options.<name>.default`   | Default of an option          |
+/// | `<command>.options.<name>.description` | Description of an option    |
+/// | `global_options.<name>.default`      | Default of a global option    |
+/// | `global_options.<name>.description`  | Description of a global option |
+///
+/// `<command>` is the command path, e.g. `deploy` or `remote add`.
+#[derive(Debug, Clone)]
+pub struct LayeredConfig {
+    /// The effective configuration
+    pub config: CommandsConfig,
+    base: ValueOrigin,
+    origins: BTreeMap<String, ValueOrigin>,
+    sources: SourceMap,
+}
+
+impl LayeredConfig {
+    /// Get the layer that set a value
+    ///
+    /// Values no overlay changed (and unknown keys) report the base file.
+    pub fn origin(&self, key: &str) -> &ValueOrigin {
+        self.origins.get(key).unwrap_or(&self.base)
+    }
+
+    /// Iterate over the values set by overlays, sorted by key
+    pub fn overrides(&self) -> impl Iterator<Item = (&str, &ValueOrigin)> {
+        self.origins
+            .iter()
+            .map(|(key, origin)| (key.as_str(), origin))
+    }
+
+    /// Get the span maps of the base file and of the files it includes
+    pub fn sources(&self) -> &SourceMap {
+        &self.sources
+    }
+
+    /// Validate the effective configuration, locating the first error
+    ///
+    /// Same as [`validate_config`](crate::config::validator::validate_config),
+    /// with errors found in the base file or its includes wrapped in
+    /// [`ConfigError::Located`].
+    pub fn validate(&self) -> Result<()> {
+        validate_config_in(&self.config, &self.sources)
+    }
+
+    /// List every problem of the effective configuration, with locations
+    ///
+    /// Same as [`diagnose_config`](crate::config::validator::diagnose_config),
+    /// with the [`location`](Diagnostic::location) of each diagnostic set
+    /// when it is found in the base file or its includes.
+    pub fn diagnose(&self) -> Vec<Diagnostic> {
+        diagnose_config_in(&self.config, &self.sources)
+    }
+}
+
+/// Load a configuration and apply its overlays
+///
+/// The base file is loaded with
+/// [`load_config`](crate::config::loader::load_config) (so `include:` works).
+/// Overlays are applied in the given order; missing overlay files are
+/// skipped.
+///
+/// # Errors
+///
+/// - Any error of [`load_config`](crate::config::loader::load_config) for
+///   the base file
+/// - [`ConfigError::InvalidSchema`] if an overlay is malformed or names a
+///   command or option that does not exist; its `path` starts with the
+///   overlay file
+/// - [`ConfigError::DuplicateCommand`] if an added alias is already used
+///   by a sibling command
+pub fn load_layered<P: AsRef<Path>>(
+    base: P,
+    overlays: &[(Layer, PathBuf)],
+) -> Result<LayeredConfig> {
+    load_layered_with(base, overlays, LoadOptions::default())
+}
+
+/// Load a configuration and apply its overlays with the given options
+///
+/// Same as [`load_layered`], with the options applied to the base file,
+/// its includes and the overlays.
+///
+/// # Errors
+///
+/// Those of [`load_layered`] and of
+/// [`load_config_with`](crate::config::loader::load_config_with).
+pub fn load_layered_with<P: AsRef<Path>>(
+    base: P,
+    overlays: &[(Layer, PathBuf)],
+    options: LoadOptions,
+) -> Result<LayeredConfig> {
+    let base = base.as_ref();
+    let (config, sources) = load_config_sources(base, options)?;
+    let mut layered = LayeredConfig {
+        config,
+        base: ValueOrigin {
+            layer: Layer::Base,
+            path: base.to_path_buf(),
+        },
+        origins: BTreeMap::new(),
+        sources,
+    };
+
+    for (layer, path) in overlays {
+        if !path.is_file() {
+            continue;
+        }
+        let overlay: ConfigOverlay =
+            parse_file(path, options).map_err(|e| parse_error_in_file(e, path))?;
+        let origin = ValueOrigin {
+            layer: *layer,
+            path: path.clone(),
+        };
+        layered
+            .apply(&overlay, &origin)
+            .map_err(|e| e.in_file(path))?;
+    }
+
+    Ok(layered)
+}
+
+impl LayeredConfig {
+    /// Apply one overlay, recording the origin of every value it sets
+    fn apply(
+        &mut self,
+        overlay: &ConfigOverlay,
+        origin: &ValueOrigin,
+    ) -> std::result::Result<(), ConfigError> {
+        let mut set = Vec::new();
+
+        for (idx, changes) in overlay.commands.iter().enumerate() {
+            let path: Vec<&str> = changes.name.split_whitespace().collect();
+            let key = path.join(" ");
+            let (siblings, pos) =
+                find_command(&mut self.config.commands, &path).map_err(|candidates| {
+                    let similar = find_similar_strings(&key, &candidates, 1);
+                    ConfigError::InvalidSchema {
+                        reason: format!("Unknown command '{}'", key),
+                        path: Some(format!("commands[{}].name", idx)),
+                        suggestion: similar
+                            .first()
+                            .map(|name| format!("Did you mean '{}'?", name)),
+                    }
+                })?;
+
+            if let Some(description) = &changes.description {
+                siblings[pos].description = description.clone();
+                set.push(format!("{}.description", key));
+            }
+
+            for alias in &changes.aliases {
+                let command = &siblings[pos];
+                if command.name == *alias || command.aliases.contains(alias) {
+                    continue;
+                }
+                if let Some(other) = siblings
+                    .iter()
+                    .find(|c| c.name == *alias || c.aliases.contains(alias))
+                {
+                    let parent = &path[..path.len() - 1];
+                    let other_key = parent
+                        .iter()
+                        .copied()
+                        .chain(std::iter::once(other.name.as_str()))
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    let defined_in = match self
+                        .origins
+                        .get(&format!("{}.aliases.{}", other_key, alias))
+                    {
+                        Some(previous) => previous.path.clone(),
+                        None => other
+                            .source
+                            .clone()
+                            .unwrap_or_else(|| self.base.path.clone()),
+                    };
+                    return Err(ConfigError::DuplicateCommand {
+                        name: alias.clone(),
+                        files: vec![defined_in, origin.path.clone()],
+                        suggestion: Some(format!(
+                            "'{}' already names '{}'; choose another alias.",
+                            alias, other_key
+                        )),
+                    });
+                }
+                siblings[pos].aliases.push(alias.clone());
+                set.push(format!("{}.aliases.{}", key, alias));
+            }
+
+            if let Some(hidden) = changes.hidden {
+                siblings[pos].hidden = hidden;
+                set.push(format!("{}.hidden", key));
+            }
+
+            for (option_idx, option) in changes.options.iter().enumerate() {
+                let at = format!("commands[{}].options[{}]", idx, option_idx);
+                let prefix = format!("{}.options", key);
+                apply_option(&mut siblings[pos].options, option, &at, &prefix, &mut set)?;
+            }
+        }
+
+        for (idx, option) in overlay.global_options.iter().enumerate() {
+            let at = format!("global_options[{}]", idx);
+            apply_option(
+                &mut self.config.global_options,
+                option,
+                &at,
+                "global_options",
+                &mut set,
+            )?;
+        }
+
+        for key in set {
+            self.origins.insert(key, origin.clone());
+        }
+        Ok(())
+    }
+}
+
+/// Apply the changes to one option, pushing the keys of the values set
+fn apply_option(
+    options: &mut [OptionDefinition],
+    changes: &OptionOverlay,
+    at: &str,
+    prefix: &str,
+    set: &mut Vec<String>,
+) -> std::result::Result<(), ConfigError> {
+    let Some(option) = options.iter_mut().find(|o| o.name == changes.name) else {
+        let names: Vec<String> = options.iter().map(|o| o.name.clone()).collect();
+        let similar = find_similar_strings(&changes.name, &names, 1);
+        return Err(ConfigError::InvalidSchema {
+            reason: format!("Unknown option '{}'", changes.name),
+            path: Some(format!("{}.name", at)),
+            suggestion: similar
+                .first()
+                .map(|name| format!("Did you mean '{}'?", name)),
+        });
+    };
+
+    if let Some(default) = &changes.default {
+        option.default = Some(default.clone());
+        set.push(format!("{}.{}.default", prefix, option.name));
+    }
+    if let Some(description) = &changes.description {
+        option.description = description.clone();
+        set.push(format!("{}.{}.description", prefix, option.name));
+    }
+    Ok(())
+}
+
+/// Find a command by path, returning its sibling list and position
+///
+/// On failure, returns the names available where the lookup stopped.
+fn find_command<'c>(
+    mut commands: &'c mut Vec<CommandDefinition>,
+    path: &[&str],
+) -> std::result::Result<(&'c mut Vec<CommandDefinition>, usize), Vec<String>> {
+    let names = |commands: &[CommandDefinition]| commands.iter().map(|c| c.name.clone()).collect();
+    let Some((last, parents)) = path.split_last() else {
+        return Err(names(commands));
+    };
+
+    for name in parents {
+        let Some(pos) = commands.iter().position(|c| c.name == *name) else {
+            return Err(names(commands));
+        };
+        let current = commands;
+        commands = &mut current[pos].subcommands;
+    }
+    match commands.iter().position(|c| c.name == *last) {
+        Some(pos) => Ok((commands, pos)),
+        None => Err(names(commands)),
+    }
+}
+
+/// Content of an overlay file
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigOverlay {
+    #[serde(default)]
+    commands: Vec<CommandOverlay>,
+    #[serde(default)]
+    global_options: Vec<OptionOverlay>,
+}
+
+/// Changes to one command
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CommandOverlay {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    aliases: Vec<String>,
+    #[serde(default)]
+    hidden: Option<bool>,
+    #[serde(default)]
+    options: Vec<OptionOverlay>,
+}
+
+/// Changes to one option
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OptionOverlay {
+    name: String,
+    #[serde(default)]
+    default: Option<String>,
+    #[serde(default)]
+    description: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DynamicCliError;
+
+    const BASE: &str = r#"
+metadata:
+  version: "1.0.0"
+  prompt: "test"
+commands:
+  - name: deploy
+    description: "Deploy"
+    implementation: "deploy_handler"
+    options:
+      - name: env
+        long: env
+        option_type: string
+        default: prod
+        description: "Target environment"
+  - name: remote
+    description: "Manage remotes"
+    implementation: "remote_handler"
+    subcommands:
+      - name: add
+        description: "Add a remote"
+        implementation: "remote_add_handler"
+  - name: legacy
+    aliases: [old]
+    description: "Old importer"
+    implementation: "legacy_handler"
+global_options:
+  - name: color
+    long: color
+    option_type: string
+    default: auto
+    description: "Colored output"
+"#;
+
+    /// Write the base file and the overlays into a fresh directory
+    fn setup(overlays: &[(Layer, &str)]) -> (tempfile::TempDir, Vec<(Layer, PathBuf)>) {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("commands.yaml"), BASE).unwrap();
+        let paths = overlays
+            .iter()
+            .map(|(layer, content)| {
+                let path = dir.path().join(format!("{}.yaml", layer.as_str()));
+                std::fs::write(&path, content).unwrap();
+                (*layer, path)
+            })
+            .collect();
+        (dir, paths)
+    }
+
+    fn load(overlays: &[(Layer, &str)]) -> Result<LayeredConfig> {
+        let (dir, paths) = setup(overlays);
+        load_layered(dir.path().join("commands.yaml"), &paths)
+    }
+
+    #[test]
+    fn test_later_layers_override_earlier_ones() {
+        let user = "commands:\n  - name: deploy\n    aliases: [d]\n    options:\n      - name: env\n        default: staging\n";
+        let project = "commands:\n  - name: deploy\n    options:\n      - name: env\n        default: dev\nglobal_options:\n  - name: color\n    default: never\n";
+        let (dir, paths) = setup(&[(Layer::User, user), (Layer::Project, project)]);
+        let layered = load_layered(dir.path().join("commands.yaml"), &paths).unwrap();
+
+        let deploy = &layered.config.commands[0];
+        assert_eq!(deploy.aliases, vec!["d"]);
+        assert_eq!(deploy.options[0].default.as_deref(), Some("dev"));
+        assert_eq!(
+            layered.config.global_options[0].default.as_deref(),
+            Some("never")
+        );
+
+        assert_eq!(
+            layered.origin("deploy.options.env.default"),
+            &ValueOrigin {
+                layer: Layer::Project,
+                path: paths[1].1.clone(),
+            }
+        );
+        assert_eq!(layered.origin("deploy.aliases.d").layer, Layer::User);
+        assert_eq!(
+            layered.origin("global_options.color.default").layer,
+            Layer::Project
+        );
+        assert_eq!(
+            layered.origin("deploy.description"),
+            &ValueOrigin {
+                layer: Layer::Base,
+                path: dir.path().join("commands.yaml"),
+            }
+        );
+
+        let keys: Vec<&str> = layered.overrides().map(|(key, _)| key).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "deploy.aliases.d",
+                "deploy.options.env.default",
+                "global_options.color.default"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_hidden_follows_the_last_layer() {
+        let system = "commands:\n  - name: legacy\n    hidden: true\n  - name: remote add\n    hidden: true\n";
+        let layered = load(&[(Layer::System, system)]).unwrap();
+        let visible: Vec<&str> = layered
+            .config
+            .visible_commands()
+            .iter()
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(visible, vec!["deploy", "remote"]);
+        // Hidden commands stay in the configuration
+        assert_eq!(layered.config.commands.len(), 3);
+        assert!(layered.config.commands[1].subcommands[0].hidden);
+        assert_eq!(layered.origin("remote add.hidden").layer, Layer::System);
+
+        let project = "commands:\n  - name: legacy\n    hidden: false\n";
+        let layered = load(&[(Layer::System, system), (Layer::Project, project)]).unwrap();
+        assert_eq!(layered.config.visible_commands().len(), 3);
+        assert_eq!(layered.origin("legacy.hidden").layer, Layer::Project);
+    }
+
+    #[test]
+    fn test_unknown_names_point_at_the_overlay() {
+        let user = "commands:\n  - name: deploy\n  - name: deplyo\n";
+        let (dir, paths) = setup(&[(Layer::User, user)]);
+        match load_layered(dir.path().join("commands.yaml"), &paths).unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema {
+                path, suggestion, ..
+            }) => {
+                let expected = format!("{}: commands[1].name", paths[0].1.display());
+                assert_eq!(path.as_deref(), Some(expected.as_str()));
+                assert_eq!(suggestion.as_deref(), Some("Did you mean 'deploy'?"));
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+
+        let user = "commands:\n  - name: deploy\n    options:\n      - name: target\n";
+        assert!(load(&[(Layer::User, user)]).is_err());
+
+        // Only commands and global options can be overridden
+        let user = "metadata:\n  version: \"2.0.0\"\n";
+        assert!(load(&[(Layer::User, user)]).is_err());
+    }
+
+    #[test]
+    fn test_alias_conflict_names_both_files() {
+        let user = "commands:\n  - name: deploy\n    aliases: [old]\n";
+        let (dir, paths) = setup(&[(Layer::User, user)]);
+        match load_layered(dir.path().join("commands.yaml"), &paths).unwrap_err() {
+            DynamicCliError::Config(ConfigError::DuplicateCommand { name, files, .. }) => {
+                assert_eq!(name, "old");
+                assert_eq!(
+                    files,
+                    vec![dir.path().join("commands.yaml"), paths[0].1.clone()]
+                );
+            }
+            other => panic!("Expected DuplicateCommand, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_locates_errors_in_included_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = format!("{}include:\n  - extra.yaml\n", BASE);
+        std::fs::write(dir.path().join("commands.yaml"), base).unwrap();
+        let extra = "commands:\n  - name: scale\n    description: \"Scale\"\n    implementation: \"scale_handler\"\n    arguments:\n      - name: replicas\n        arg_type: integer\n        required: true\n        description: \"Replicas\"\n        validation:\n          - { min: 10, max: 1 }\n";
+        std::fs::write(dir.path().join("extra.yaml"), extra).unwrap();
+
+        let layered = load_layered(dir.path().join("commands.yaml"), &[]).unwrap();
+        match layered.validate().unwrap_err() {
+            DynamicCliError::Config(ConfigError::Located { error, location }) => {
+                assert!(matches!(*error, ConfigError::Inconsistency { .. }));
+                assert_eq!(location.file, dir.path().join("extra.yaml"));
+                assert_eq!((location.line, location.column), (11, 13));
+                assert_eq!(location.text, "          - { min: 10, max: 1 }");
+            }
+            other => panic!("Expected Located, got {:?}", other),
+        }
+
+        let diagnostics = layered.diagnose();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].location.is_some());
+    }
+
+    #[test]
+    fn test_missing_overlays_are_skipped() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("commands.yaml"), BASE).unwrap();
+        let overlays = vec![(Layer::User, dir.path().join("missing.yaml"))];
+
+        let layered = load_layered(dir.path().join("commands.yaml"), &overlays).unwrap();
+        assert_eq!(layered.config.commands.len(), 3);
+        assert_eq!(layered.overrides().count(), 0);
+    }
+}
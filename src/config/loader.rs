@@ -1,12 +1,41 @@
 //! Configuration file loading
 //!
 //! This module provides functions to load configuration from
-//! YAML or JSON files, with automatic format detection.
+//! YAML, JSON or TOML files, with automatic format detection.
 //!
 //! # Supported Formats
 //!
 //! - YAML (`.yaml`, `.yml`)
 //! - JSON (`.json`)
+//! - TOML (`.toml`)
+//!
+//! # Includes
+//!
+//! A configuration can be split over several files. The main file lists
+//! the others under `include:`; included files hold `commands`,
+//! `global_options` and possibly further `include`s:
+//!
+//! ```yaml
+//! # commands.yaml
+//! metadata:
+//!   version: "1.0.0"
+//!   prompt: "myapp"
+//! commands: []
+//! include:
+//!   - teams/*.yaml
+//! ```
+//!
+//! # Environment Variables
+//!
+//! With [`LoadOptions::interpolate_env`] set, string values may reference
+//! environment variables, expanded before deserialization:
+//!
+//! - `${NAME}` - the value of `NAME`; an error if it is not set
+//! - `${NAME:-fallback}` - `fallback` if `NAME` is unset or empty
+//! - `$$` - a literal `$`
+//!
+//! Only values are expanded, not keys, and the result stays a string.
+//! Without the option, `$` has no special meaning.
 //!
 //! # Example
 //!
@@ -18,20 +47,58 @@
 //! println!("Loaded {} commands", config.commands.len());
 //! ```
 
-use crate::config::schema::CommandsConfig;
+use crate::config::migration::{schema_version, MigrationRegistry};
+use crate::config::schema::{CommandDefinition, CommandsConfig, OptionDefinition, SCHEMA_VERSION};
+use crate::config::spans::SourceMap;
 use crate::error::{ConfigError, DynamicCliError, Result};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Options controlling how configuration files are loaded
+///
+/// # Example
+///
+/// ```no_run
+/// use dynamic_cli::config::loader::{load_config_with, LoadOptions};
+///
+/// let options = LoadOptions {
+///     interpolate_env: true,
+/// };
+/// let config = load_config_with("commands.yaml", options)?;
+/// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LoadOptions {
+    /// Expand `${NAME}` and `${NAME:-fallback}` in string values
+    ///
+    /// Disabled by default, so that existing configurations keep their
+    /// literal `$` strings.
+    pub interpolate_env: bool,
+}
 
 /// Load configuration from a file
 ///
-/// Automatically detects the format (YAML or JSON) based on
+/// Automatically detects the format (YAML, JSON or TOML) based on
 /// the file extension and parses the content accordingly.
 ///
+/// Files listed under `include:` are loaded too, recursively, and their
+/// commands and global options are appended to those of the main file.
+/// Include entries are glob patterns relative to the file that lists
+/// them (`teams/*.yaml`); an entry without wildcards must name an
+/// existing file. A pattern never matches the file that lists it, and a
+/// file reached twice is merged once.
+///
+/// Files written for an older `schema_version` are migrated in memory;
+/// see [`config::migration`](crate::config::migration).
+///
 /// # Supported Extensions
 ///
 /// - `.yaml`, `.yml` → YAML parser
 /// - `.json` → JSON parser
+/// - `.toml` → TOML parser
 ///
 /// # Arguments
 ///
@@ -39,13 +106,24 @@ use std::path::Path;
 ///
 /// # Returns
 ///
-/// Parsed [`CommandsConfig`] on success
+/// Parsed [`CommandsConfig`] on success, with `include` resolved and
+/// emptied. Top-level commands from included files have their
+/// [`source`](crate::config::schema::CommandDefinition::source) set.
 ///
 /// # Errors
 ///
-/// - [`ConfigError::FileNotFound`] if the file doesn't exist
+/// - [`ConfigError::FileNotFound`] if the file (or an included file) doesn't exist
 /// - [`ConfigError::UnsupportedFormat`] if the extension is not recognized
-/// - [`ConfigError::YamlParse`] or [`ConfigError::JsonParse`] if parsing fails
+/// - [`ConfigError::YamlParse`], [`ConfigError::JsonParse`] or
+///   [`ConfigError::TomlParse`] if parsing fails
+/// - [`ConfigError::UnsupportedSchemaVersion`] if a file's `schema_version`
+///   is newer than [`SCHEMA_VERSION`]
+/// - [`ConfigError::DuplicateCommand`] if two files define the same
+///   command name or alias
+/// - [`ConfigError::DuplicateGlobalOption`] if two files define the same
+///   global option
+/// - [`ConfigError::InvalidSchema`] if an included file is malformed or
+///   the includes form a cycle; its `path` starts with the offending file
 ///
 /// # Example
 ///
@@ -57,8 +135,289 @@ use std::path::Path;
 /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 /// ```
 pub fn load_config<P: AsRef<Path>>(path: P) -> Result<CommandsConfig> {
-    let path = path.as_ref();
+    load_config_with(path, LoadOptions::default())
+}
+
+/// Load configuration from a file with the given options
+///
+/// Same as [`load_config`], with the options applied to the main file and
+/// to every included file.
+///
+/// # Errors
+///
+/// Those of [`load_config`], and with
+/// [`interpolate_env`](LoadOptions::interpolate_env):
+///
+/// - [`ConfigError::UndefinedVariable`] if a variable without fallback is
+///   not set
+/// - [`ConfigError::InvalidSchema`] if a `${...}` reference is malformed
+///
+/// # Example
+///
+/// ```no_run
+/// use dynamic_cli::config::loader::{load_config_with, LoadOptions};
+///
+/// let options = LoadOptions {
+///     interpolate_env: true,
+/// };
+/// let config = load_config_with("commands.yaml", options)?;
+/// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+/// ```
+pub fn load_config_with<P: AsRef<Path>>(path: P, options: LoadOptions) -> Result<CommandsConfig> {
+    load_config_sources(path.as_ref(), options).map(|(config, _)| config)
+}
+
+/// Load configuration from a file, keeping the span map of every file read
+///
+/// Backs [`load_config_with`]; the [`SourceMap`] lists the main file first.
+pub(crate) fn load_config_sources(
+    path: &Path,
+    options: LoadOptions,
+) -> Result<(CommandsConfig, SourceMap)> {
+    let (format, content) = read_file(path)?;
+    let mut config = parse_commands_config(format, &content, options)?;
+    let mut merge = Merge {
+        options,
+        ..Merge::default()
+    };
+    merge.sources.add(path, format, &content);
+    if config.include.is_empty() {
+        return Ok((config, merge.sources));
+    }
 
+    let root = path.canonicalize()?;
+    merge.loaded.insert(root.clone());
+    merge.record(path, &config.commands, &config.global_options)?;
+
+    let patterns = std::mem::take(&mut config.include);
+    merge.include(path, &patterns, &mut config, &mut vec![root])?;
+
+    Ok((config, merge.sources))
+}
+
+/// Content of an included file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFragment {
+    /// Checked by [`parse_config`], like that of the main file
+    #[serde(default)]
+    #[allow(dead_code)]
+    schema_version: Option<u32>,
+    #[serde(default)]
+    commands: Vec<CommandDefinition>,
+    #[serde(default)]
+    global_options: Vec<OptionDefinition>,
+    #[serde(default)]
+    include: Vec<String>,
+}
+
+/// State of an `include:` resolution
+#[derive(Default)]
+struct Merge {
+    /// Options applied to every included file
+    options: LoadOptions,
+    /// Canonical paths of the files merged so far
+    loaded: HashSet<PathBuf>,
+    /// File defining each top-level command name and alias
+    commands: HashMap<String, PathBuf>,
+    /// File defining each global option
+    global_options: HashMap<String, PathBuf>,
+    /// Span maps of the files read so far
+    sources: SourceMap,
+}
+
+impl Merge {
+    /// Merge the files matched by the `include` entries of `file`
+    ///
+    /// `chain` holds the canonical paths of the files being included,
+    /// from the main file down to `file`, to detect cycles.
+    fn include(
+        &mut self,
+        file: &Path,
+        patterns: &[String],
+        config: &mut CommandsConfig,
+        chain: &mut Vec<PathBuf>,
+    ) -> Result<()> {
+        for (idx, pattern) in patterns.iter().enumerate() {
+            for included in expand_include(file, idx, pattern)? {
+                let canonical = included.canonicalize()?;
+                if let Some(start) = chain.iter().position(|p| *p == canonical) {
+                    let cycle: Vec<String> = chain[start..]
+                        .iter()
+                        .chain(std::iter::once(&canonical))
+                        .map(|p| p.display().to_string())
+                        .collect();
+                    return Err(ConfigError::InvalidSchema {
+                        reason: format!("Include cycle: {}", cycle.join(" -> ")),
+                        path: Some(format!("{}: include[{}]", file.display(), idx)),
+                        suggestion: Some("Remove one of the includes of the cycle.".to_string()),
+                    }
+                    .into());
+                }
+                if !self.loaded.insert(canonical.clone()) {
+                    continue;
+                }
+
+                let fragment: ConfigFragment = read_file(&included)
+                    .and_then(|(format, content)| {
+                        let fragment = parse_config(
+                            format,
+                            &content,
+                            self.options,
+                            &MigrationRegistry::builtin(),
+                        )?;
+                        self.sources.add(&included, format, &content);
+                        Ok(fragment)
+                    })
+                    .map_err(|e| parse_error_in_file(e, &included))?;
+                self.record(&included, &fragment.commands, &fragment.global_options)?;
+                config
+                    .commands
+                    .extend(fragment.commands.into_iter().map(|mut command| {
+                        command.source = Some(included.clone());
+                        command
+                    }));
+                config.global_options.extend(fragment.global_options);
+
+                chain.push(canonical);
+                self.include(&included, &fragment.include, config, chain)?;
+                chain.pop();
+            }
+        }
+        Ok(())
+    }
+
+    /// Record the names defined by `file`, rejecting those of another file
+    ///
+    /// Duplicates within a single file are left to
+    /// [`validate_config`](crate::config::validator::validate_config).
+    fn record(
+        &mut self,
+        file: &Path,
+        commands: &[CommandDefinition],
+        global_options: &[OptionDefinition],
+    ) -> Result<()> {
+        for name in commands
+            .iter()
+            .flat_map(|command| std::iter::once(&command.name).chain(&command.aliases))
+        {
+            match self.commands.get(name) {
+                Some(other) if other != file => {
+                    return Err(ConfigError::DuplicateCommand {
+                        name: name.clone(),
+                        files: vec![other.clone(), file.to_path_buf()],
+                        suggestion: Some(
+                            "Rename the command or alias in one of the files.".to_string(),
+                        ),
+                    }
+                    .into());
+                }
+                Some(_) => {}
+                None => {
+                    self.commands.insert(name.clone(), file.to_path_buf());
+                }
+            }
+        }
+
+        for option in global_options {
+            match self.global_options.get(&option.name) {
+                Some(other) if other != file => {
+                    return Err(ConfigError::DuplicateGlobalOption {
+                        name: option.name.clone(),
+                        files: vec![other.clone(), file.to_path_buf()],
+                        suggestion: Some("Define each global option in one file.".to_string()),
+                    }
+                    .into());
+                }
+                Some(_) => {}
+                None => {
+                    self.global_options
+                        .insert(option.name.clone(), file.to_path_buf());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// List the files matched by entry `idx` of the `include` list of `file`
+///
+/// Matches are sorted so that the merge order does not depend on the
+/// file system. A pattern such as `*.yaml` next to the main file leaves
+/// `file` itself out rather than reporting it as an include cycle.
+fn expand_include(file: &Path, idx: usize, pattern: &str) -> Result<Vec<PathBuf>> {
+    let base = file.parent().unwrap_or_else(|| Path::new(""));
+    let entry = || format!("{}: include[{}]", file.display(), idx);
+
+    if !pattern.contains(['*', '?', '[']) {
+        let included = base.join(pattern);
+        if !included.is_file() {
+            return Err(ConfigError::FileNotFound {
+                path: included,
+                suggestion: Some(format!("Check the entry at {}.", entry())),
+            }
+            .into());
+        }
+        return Ok(vec![included]);
+    }
+
+    let base = base.to_str().ok_or_else(|| ConfigError::InvalidSchema {
+        reason: format!("Cannot expand '{}': the path is not valid UTF-8", pattern),
+        path: Some(entry()),
+        suggestion: None,
+    })?;
+    let full_pattern = if base.is_empty() {
+        pattern.to_string()
+    } else {
+        format!("{}/{}", glob::Pattern::escape(base), pattern)
+    };
+    let matches = glob::glob(&full_pattern).map_err(|e| ConfigError::InvalidSchema {
+        reason: format!("Invalid include pattern '{}': {}", pattern, e.msg),
+        path: Some(entry()),
+        suggestion: None,
+    })?;
+
+    let itself = file.canonicalize()?;
+    let mut files = Vec::new();
+    for path in matches {
+        let path = path.map_err(|e| DynamicCliError::from(std::io::Error::from(e)))?;
+        if path.is_file() && path.canonicalize()? != itself {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Report a parse error of an included file with the file's name
+pub(crate) fn parse_error_in_file(error: DynamicCliError, file: &Path) -> DynamicCliError {
+    match error {
+        DynamicCliError::Config(
+            error @ (ConfigError::YamlParse { .. }
+            | ConfigError::JsonParse { .. }
+            | ConfigError::TomlParse { .. }),
+        ) => ConfigError::InvalidSchema {
+            reason: error.to_string(),
+            path: Some(file.display().to_string()),
+            suggestion: None,
+        }
+        .into(),
+        DynamicCliError::Config(
+            error @ (ConfigError::InvalidSchema { .. } | ConfigError::UndefinedVariable { .. }),
+        ) => error.in_file(file).into(),
+        other => other,
+    }
+}
+
+/// Read and deserialize a YAML, JSON or TOML file
+pub(crate) fn parse_file<T: DeserializeOwned>(path: &Path, options: LoadOptions) -> Result<T> {
+    let (format, content) = read_file(path)?;
+    format.parse(&content, options)
+}
+
+/// Read a configuration file and detect its format
+pub(crate) fn read_file(path: &Path) -> Result<(Format, String)> {
     // Check if file exists
     if !path.exists() {
         return Err(ConfigError::file_not_found(path.to_path_buf()).into());
@@ -69,23 +428,317 @@ pub fn load_config<P: AsRef<Path>>(path: P) -> Result<CommandsConfig> {
         .extension()
         .and_then(|ext| ext.to_str())
         .ok_or_else(|| ConfigError::unsupported_format("<none>"))?;
+    let format = match extension.to_lowercase().as_str() {
+        "yaml" | "yml" => Format::Yaml,
+        "json" => Format::Json,
+        "toml" => Format::Toml,
+        other => return Err(ConfigError::unsupported_format(other).into()),
+    };
 
     // Read file content
     let content = fs::read_to_string(path).map_err(DynamicCliError::from)?;
+    Ok((format, content))
+}
+
+/// Deserialize a configuration document of any [`SCHEMA_VERSION`]
+///
+/// The content is parsed once into a [`serde_yaml::Value`], upgraded
+/// through `registry` when an older version's migrations rewrite it, and
+/// deserialized from there. A document that fails to deserialize without
+/// having been rewritten is parsed again as text, only to report the
+/// error with its position.
+pub(crate) fn parse_config<T: DeserializeOwned>(
+    format: Format,
+    content: &str,
+    options: LoadOptions,
+    registry: &MigrationRegistry,
+) -> Result<T> {
+    let mut document = format.document(content)?;
+    let from = schema_version(&document)?;
+    let report = if registry.rewrites(from)? {
+        Some(registry.migrate(&mut document)?)
+    } else {
+        None
+    };
+    if options.interpolate_env {
+        interpolate_yaml(&mut document, "", &env_var)?;
+    }
+
+    serde_yaml::from_value(document).map_err(|e| match report {
+        Some(report) => ConfigError::InvalidSchema {
+            reason: format!(
+                "{} (after migrating from schema_version {} to {})",
+                e, report.from, report.to
+            ),
+            path: None,
+            suggestion: Some(
+                "Run migrate_file() on the file and check the migrated document.".to_string(),
+            ),
+        }
+        .into(),
+        None => format
+            .parse::<T>(content, options)
+            .err()
+            .unwrap_or_else(|| ConfigError::yaml_parse_with_location(e).into()),
+    })
+}
+
+/// Deserialize a main configuration document, up to date
+fn parse_commands_config(
+    format: Format,
+    content: &str,
+    options: LoadOptions,
+) -> Result<CommandsConfig> {
+    let mut config: CommandsConfig =
+        parse_config(format, content, options, &MigrationRegistry::builtin())?;
+    // Steps that only bump the version leave the document as written
+    config.schema_version = SCHEMA_VERSION;
+    Ok(config)
+}
+
+/// Format of a configuration file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    Yaml,
+    Json,
+    Toml,
+}
+
+impl Format {
+    /// Deserialize `content`, expanding environment variables if enabled
+    ///
+    /// With [`LoadOptions::interpolate_env`], the content is first parsed
+    /// into the format's generic value, whose strings are expanded with
+    /// [`interpolate`] before deserialization.
+    pub(crate) fn parse<T: DeserializeOwned>(
+        self,
+        content: &str,
+        options: LoadOptions,
+    ) -> Result<T> {
+        match self {
+            Format::Yaml => {
+                let parse_error =
+                    |e| DynamicCliError::from(ConfigError::yaml_parse_with_location(e));
+                if !options.interpolate_env {
+                    return serde_yaml::from_str(content).map_err(parse_error);
+                }
+                let mut value = serde_yaml::from_str(content).map_err(parse_error)?;
+                interpolate_yaml(&mut value, "", &env_var)?;
+                serde_yaml::from_value(value).map_err(parse_error)
+            }
+            Format::Json => {
+                let parse_error =
+                    |e| DynamicCliError::from(ConfigError::json_parse_with_location(e));
+                if !options.interpolate_env {
+                    return serde_json::from_str(content).map_err(parse_error);
+                }
+                let mut value = serde_json::from_str(content).map_err(parse_error)?;
+                interpolate_json(&mut value, "", &env_var)?;
+                serde_json::from_value(value).map_err(parse_error)
+            }
+            Format::Toml => {
+                let parse_error =
+                    |e| DynamicCliError::from(ConfigError::toml_parse_with_location(e, content));
+                if !options.interpolate_env {
+                    return toml::from_str(content).map_err(parse_error);
+                }
+                let mut value = toml::from_str(content).map_err(parse_error)?;
+                interpolate_toml(&mut value, "", &env_var)?;
+                value.try_into().map_err(parse_error)
+            }
+        }
+    }
+
+    /// Parse `content` into a format-independent document
+    ///
+    /// The order of keys is kept, so that the document can be written
+    /// back with [`write`](Self::write).
+    pub(crate) fn document(self, content: &str) -> Result<serde_yaml::Value> {
+        match self {
+            Format::Yaml => serde_yaml::from_str(content)
+                .map_err(|e| ConfigError::yaml_parse_with_location(e).into()),
+            Format::Json => serde_json::from_str(content)
+                .map_err(|e| ConfigError::json_parse_with_location(e).into()),
+            Format::Toml => toml::from_str(content)
+                .map_err(|e| ConfigError::toml_parse_with_location(e, content).into()),
+        }
+    }
 
-    // Parse according to format
-    match extension.to_lowercase().as_str() {
-        "yaml" | "yml" => load_yaml(&content),
-        "json" => load_json(&content),
-        other => Err(ConfigError::unsupported_format(other).into()),
+    /// Serialize a document in this format
+    pub(crate) fn write(self, document: &serde_yaml::Value) -> Result<String> {
+        let written = match self {
+            Format::Yaml => serde_yaml::to_string(document).map_err(|e| e.to_string()),
+            Format::Json => serde_json::to_string_pretty(document)
+                .map(|json| json + "\n")
+                .map_err(|e| e.to_string()),
+            Format::Toml => toml::to_string(document).map_err(|e| e.to_string()),
+        };
+        written.map_err(|reason| {
+            ConfigError::InvalidSchema {
+                reason: format!("Cannot write the configuration: {}", reason),
+                path: None,
+                suggestion: None,
+            }
+            .into()
+        })
+    }
+}
+
+/// Value of an environment variable, for [`interpolate`]
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name).ok()
+}
+
+/// Source of environment variable values
+type Lookup<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// Key of `child` under `parent`, in the `a.b[0].c` notation
+fn child_key(parent: &str, child: &str) -> String {
+    if parent.is_empty() {
+        child.to_string()
+    } else {
+        format!("{}.{}", parent, child)
+    }
+}
+
+/// Expand the strings of a YAML value
+fn interpolate_yaml(value: &mut serde_yaml::Value, key: &str, lookup: Lookup) -> Result<()> {
+    match value {
+        serde_yaml::Value::String(text) => *text = interpolate(text, key, lookup)?,
+        serde_yaml::Value::Sequence(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                interpolate_yaml(item, &format!("{}[{}]", key, idx), lookup)?;
+            }
+        }
+        serde_yaml::Value::Mapping(entries) => {
+            for (name, item) in entries.iter_mut() {
+                let name = name
+                    .as_str()
+                    .map_or_else(|| format!("{:?}", name), String::from);
+                interpolate_yaml(item, &child_key(key, &name), lookup)?;
+            }
+        }
+        serde_yaml::Value::Tagged(tagged) => interpolate_yaml(&mut tagged.value, key, lookup)?,
+        _ => {}
     }
+    Ok(())
+}
+
+/// Expand the strings of a JSON value
+fn interpolate_json(value: &mut serde_json::Value, key: &str, lookup: Lookup) -> Result<()> {
+    match value {
+        serde_json::Value::String(text) => *text = interpolate(text, key, lookup)?,
+        serde_json::Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                interpolate_json(item, &format!("{}[{}]", key, idx), lookup)?;
+            }
+        }
+        serde_json::Value::Object(entries) => {
+            for (name, item) in entries.iter_mut() {
+                interpolate_json(item, &child_key(key, name), lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Expand the strings of a TOML value
+fn interpolate_toml(value: &mut toml::Value, key: &str, lookup: Lookup) -> Result<()> {
+    match value {
+        toml::Value::String(text) => *text = interpolate(text, key, lookup)?,
+        toml::Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                interpolate_toml(item, &format!("{}[{}]", key, idx), lookup)?;
+            }
+        }
+        toml::Value::Table(entries) => {
+            for (name, item) in entries.iter_mut() {
+                interpolate_toml(item, &child_key(key, name), lookup)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Expand the `${...}` references of `text`, the value of `key`
+///
+/// A fallback is used when the variable is unset or empty, as in a POSIX
+/// shell; references cannot be nested.
+fn interpolate(text: &str, key: &str, lookup: Lookup) -> Result<String> {
+    let mut output = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(pos) = rest.find('$') {
+        output.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if let Some(after) = rest.strip_prefix("$$") {
+            output.push('$');
+            rest = after;
+            continue;
+        }
+        let Some(reference) = rest.strip_prefix("${") else {
+            output.push('$');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let end = reference
+            .find('}')
+            .ok_or_else(|| ConfigError::InvalidSchema {
+                reason: format!("Unterminated '${{' in '{}'", text),
+                path: Some(key.to_string()),
+                suggestion: Some(
+                    "Close the reference with '}', or write '$$' for a literal '$'.".to_string(),
+                ),
+            })?;
+        let (name, fallback) = match reference[..end].split_once(":-") {
+            Some((name, fallback)) => (name, Some(fallback)),
+            None => (&reference[..end], None),
+        };
+        rest = &reference[end + 1..];
+
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ConfigError::InvalidSchema {
+                reason: format!("Invalid environment variable name '{}'", name),
+                path: Some(key.to_string()),
+                suggestion: Some(
+                    "Variable names contain only letters, digits and '_'.".to_string(),
+                ),
+            }
+            .into());
+        }
+
+        match (lookup(name), fallback) {
+            (Some(value), Some(fallback)) if value.is_empty() => output.push_str(fallback),
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(fallback)) => output.push_str(fallback),
+            (None, None) => {
+                return Err(ConfigError::UndefinedVariable {
+                    name: name.to_string(),
+                    key: key.to_string(),
+                    suggestion: Some(format!(
+                        "Set {} or give a fallback with '${{{}:-<value>}}'.",
+                        name, name
+                    )),
+                }
+                .into());
+            }
+        }
+    }
+
+    output.push_str(rest);
+    Ok(output)
 }
 
 /// Load configuration from a YAML string
 ///
 /// Parses YAML content and deserializes it into a [`CommandsConfig`].
 /// Provides detailed error messages with line and column information
-/// when parsing fails.
+/// when parsing fails. Documents of an older `schema_version` are
+/// migrated; `include` entries are not resolved, see [`load_config`].
 ///
 /// # Arguments
 ///
@@ -98,6 +751,8 @@ pub fn load_config<P: AsRef<Path>>(path: P) -> Result<CommandsConfig> {
 /// # Errors
 ///
 /// - [`ConfigError::YamlParse`] if the YAML is invalid or doesn't match the schema
+/// - [`ConfigError::UnsupportedSchemaVersion`] if `schema_version` is newer
+///   than [`SCHEMA_VERSION`]
 ///
 /// # Example
 ///
@@ -116,17 +771,15 @@ pub fn load_config<P: AsRef<Path>>(path: P) -> Result<CommandsConfig> {
 /// assert_eq!(config.metadata.version, "1.0.0");
 /// ```
 pub fn load_yaml(content: &str) -> Result<CommandsConfig> {
-    serde_yaml::from_str(content).map_err(|e| {
-        // Extract position information from error
-        ConfigError::yaml_parse_with_location(e).into()
-    })
+    parse_commands_config(Format::Yaml, content, LoadOptions::default())
 }
 
 /// Load configuration from a JSON string
 ///
 /// Parses JSON content and deserializes it into a [`CommandsConfig`].
 /// Provides detailed error messages with line and column information
-/// when parsing fails.
+/// when parsing fails. Documents of an older `schema_version` are
+/// migrated; `include` entries are not resolved, see [`load_config`].
 ///
 /// # Arguments
 ///
@@ -139,6 +792,8 @@ pub fn load_yaml(content: &str) -> Result<CommandsConfig> {
 /// # Errors
 ///
 /// - [`ConfigError::JsonParse`] if the JSON is invalid or doesn't match the schema
+/// - [`ConfigError::UnsupportedSchemaVersion`] if `schema_version` is newer
+///   than [`SCHEMA_VERSION`]
 ///
 /// # Example
 ///
@@ -160,10 +815,48 @@ pub fn load_yaml(content: &str) -> Result<CommandsConfig> {
 /// assert_eq!(config.metadata.version, "1.0.0");
 /// ```
 pub fn load_json(content: &str) -> Result<CommandsConfig> {
-    serde_json::from_str(content).map_err(|e| {
-        // Extract position information from error
-        ConfigError::json_parse_with_location(e).into()
-    })
+    parse_commands_config(Format::Json, content, LoadOptions::default())
+}
+
+/// Load configuration from a TOML string
+///
+/// Parses TOML content and deserializes it into a [`CommandsConfig`].
+/// Provides detailed error messages with line and column information
+/// when parsing fails. Documents of an older `schema_version` are
+/// migrated; `include` entries are not resolved, see [`load_config`].
+///
+/// # Arguments
+///
+/// * `content` - TOML string to parse
+///
+/// # Returns
+///
+/// Parsed [`CommandsConfig`] on success
+///
+/// # Errors
+///
+/// - [`ConfigError::TomlParse`] if the TOML is invalid or doesn't match the schema
+/// - [`ConfigError::UnsupportedSchemaVersion`] if `schema_version` is newer
+///   than [`SCHEMA_VERSION`]
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::loader::load_toml;
+///
+/// let toml = r#"
+/// commands = []
+///
+/// [metadata]
+/// version = "1.0.0"
+/// prompt = "test"
+/// "#;
+///
+/// let config = load_toml(toml).unwrap();
+/// assert_eq!(config.metadata.version, "1.0.0");
+/// ```
+pub fn load_toml(content: &str) -> Result<CommandsConfig> {
+    parse_commands_config(Format::Toml, content, LoadOptions::default())
 }
 
 #[cfg(test)]
@@ -279,6 +972,45 @@ commands: [
         }
     }
 
+    #[test]
+    fn test_load_toml_valid() {
+        let toml = r#"
+[metadata]
+version = "1.0.0"
+prompt = "test"
+prompt_suffix = " > "
+
+[[commands]]
+name = "hello"
+aliases = []
+description = "Say hello"
+required = false
+arguments = []
+options = []
+implementation = "hello_handler"
+        "#;
+
+        let config = load_toml(toml).unwrap();
+
+        assert_eq!(config.metadata.version, "1.0.0");
+        assert_eq!(config.commands.len(), 1);
+        assert_eq!(config.commands[0].name, "hello");
+        assert!(config.global_options.is_empty());
+    }
+
+    #[test]
+    fn test_load_toml_invalid_syntax() {
+        let toml = "commands = []\n\n[metadata]\nversion = 1.0.0\n";
+
+        match load_toml(toml).unwrap_err() {
+            DynamicCliError::Config(ConfigError::TomlParse { line, column, .. }) => {
+                assert_eq!(line, Some(4));
+                assert!(column.is_some());
+            }
+            other => panic!("Expected TomlParse error, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_load_config_yaml_file() {
         let yaml = r#"
@@ -295,6 +1027,28 @@ global_options: []
         assert_eq!(config.metadata.version, "1.0.0");
     }
 
+    #[test]
+    fn test_load_config_hashes_in_flow_values() {
+        let yaml = r#"
+metadata:
+  version: "1.0.0"
+  prompt: "test"
+commands:
+  - name: lang
+    aliases: [c#, f#]
+    description: "Languages"
+    implementation: "lang_handler"
+global_options: []
+"#;
+
+        let file = create_temp_file(yaml, ".yaml");
+        let (config, sources) = load_config_sources(file.path(), LoadOptions::default()).unwrap();
+        assert_eq!(config.commands[0].aliases, vec!["c#", "f#"]);
+
+        let alias = sources.locate(None, "commands[0].aliases[1]").unwrap();
+        assert_eq!((alias.line, alias.column, alias.len), (7, 19, 2));
+    }
+
     #[test]
     fn test_load_config_yml_extension() {
         let yaml = r#"
@@ -330,6 +1084,22 @@ global_options: []
         assert_eq!(config.metadata.version, "1.0.0");
     }
 
+    #[test]
+    fn test_load_config_toml_file() {
+        let toml = r#"
+commands = []
+
+[metadata]
+version = "1.0.0"
+prompt = "test"
+        "#;
+
+        let file = create_temp_file(toml, ".toml");
+        let config = load_config(file.path()).unwrap();
+
+        assert_eq!(config.metadata.version, "1.0.0");
+    }
+
     #[test]
     fn test_load_config_file_not_found() {
         let result = load_config("nonexistent_file.yaml");
@@ -512,4 +1282,353 @@ global_options: []
 
         assert_eq!(config.metadata.version, "1.0.0");
     }
+
+    // ========================================================================
+    // Includes
+    // ========================================================================
+
+    const MAIN: &str = r#"
+metadata:
+  version: "1.0.0"
+  prompt: "test"
+commands:
+  - name: status
+    description: "Show status"
+    implementation: "status_handler"
+include:
+  - teams/*.yaml
+"#;
+
+    /// Write `files` (relative path, content) into a fresh directory
+    fn config_dir(files: &[(&str, &str)]) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for (name, content) in files {
+            let path = dir.path().join(name);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        dir
+    }
+
+    fn command_yaml(name: &str) -> String {
+        format!(
+            "commands:\n  - name: {name}\n    description: \"{name}\"\n    implementation: \"{name}_handler\"\n"
+        )
+    }
+
+    #[test]
+    fn test_load_config_merges_includes_in_order() {
+        let billing = format!(
+            "{}include:\n  - ../shared/common.yaml\n",
+            command_yaml("bill")
+        );
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/deploy.yaml", &command_yaml("deploy")),
+            ("teams/billing.yaml", &billing),
+            ("shared/common.yaml", &command_yaml("common")),
+        ]);
+
+        let config = load_config(dir.path().join("commands.yaml")).unwrap();
+
+        let names: Vec<&str> = config.commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["status", "bill", "common", "deploy"]);
+        assert!(config.include.is_empty());
+        assert_eq!(config.commands[0].source, None);
+        assert_eq!(
+            config.commands[1].source.as_deref(),
+            Some(dir.path().join("teams/billing.yaml").as_path())
+        );
+        assert!(config.commands[2]
+            .source
+            .as_ref()
+            .unwrap()
+            .ends_with("shared/common.yaml"));
+    }
+
+    #[test]
+    fn test_load_config_include_merges_each_file_once() {
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/a.yaml", "include: [b.yaml]\n"),
+            ("teams/b.yaml", &command_yaml("shared")),
+        ]);
+
+        let config = load_config(dir.path().join("commands.yaml")).unwrap();
+        assert_eq!(config.commands.len(), 2);
+    }
+
+    #[test]
+    fn test_load_config_include_duplicate_names_files() {
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/ops.yaml", &command_yaml("status")),
+        ]);
+
+        match load_config(dir.path().join("commands.yaml")).unwrap_err() {
+            DynamicCliError::Config(ConfigError::DuplicateCommand { name, files, .. }) => {
+                assert_eq!(name, "status");
+                assert_eq!(
+                    files,
+                    vec![
+                        dir.path().join("commands.yaml"),
+                        dir.path().join("teams/ops.yaml")
+                    ]
+                );
+            }
+            other => panic!("Expected DuplicateCommand error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_include_global_option_conflict() {
+        let option = "global_options:\n  - name: verbose\n    long: verbose\n    option_type: bool\n    required: false\n    description: \"Verbose\"\n";
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/a.yaml", option),
+            ("teams/b.yaml", option),
+        ]);
+
+        match load_config(dir.path().join("commands.yaml")).unwrap_err() {
+            DynamicCliError::Config(ConfigError::DuplicateGlobalOption { name, files, .. }) => {
+                assert_eq!(name, "verbose");
+                assert_eq!(
+                    files,
+                    vec![
+                        dir.path().join("teams/a.yaml"),
+                        dir.path().join("teams/b.yaml")
+                    ]
+                );
+            }
+            other => panic!("Expected DuplicateGlobalOption error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_include_cycle() {
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/a.yaml", "include: [../commands.yaml]\n"),
+        ]);
+
+        match load_config(dir.path().join("commands.yaml")).unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { reason, path, .. }) => {
+                assert!(reason.starts_with("Include cycle: "));
+                assert!(path.unwrap().ends_with("a.yaml: include[0]"));
+            }
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_include_pattern_skips_the_including_file() {
+        let main = MAIN.replace("teams/*.yaml", "\"*.yaml\"");
+        let dir = config_dir(&[
+            ("commands.yaml", &main),
+            ("deploy.yaml", &command_yaml("deploy")),
+        ]);
+
+        let config = load_config(dir.path().join("commands.yaml")).unwrap();
+        let names: Vec<&str> = config.commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["status", "deploy"]);
+    }
+
+    #[test]
+    fn test_load_config_include_missing_file() {
+        let main = MAIN.replace("teams/*.yaml", "teams/missing.yaml");
+        let dir = config_dir(&[("commands.yaml", &main)]);
+
+        match load_config(dir.path().join("commands.yaml")).unwrap_err() {
+            DynamicCliError::Config(ConfigError::FileNotFound { path, suggestion }) => {
+                assert_eq!(path, dir.path().join("teams/missing.yaml"));
+                assert!(suggestion.unwrap().contains("include[0]"));
+            }
+            other => panic!("Expected FileNotFound error, got {:?}", other),
+        }
+
+        // A pattern may match nothing
+        let dir = config_dir(&[("commands.yaml", MAIN)]);
+        assert_eq!(
+            load_config(dir.path().join("commands.yaml"))
+                .unwrap()
+                .commands
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_load_config_included_file_errors_name_the_file() {
+        // Only commands, global options and includes are allowed
+        let dir = config_dir(&[
+            ("commands.yaml", MAIN),
+            ("teams/bad.yaml", "metadata:\n  version: \"2\"\n"),
+        ]);
+        match load_config(dir.path().join("commands.yaml")).unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { path, .. }) => {
+                assert!(path.unwrap().ends_with("bad.yaml"));
+            }
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+
+        // Validation errors point at the file and the command's index in it
+        let invalid =
+            "commands:\n  - name: broken\n    description: \"\"\n    implementation: \"\"\n";
+        let dir = config_dir(&[("commands.yaml", MAIN), ("teams/bad.yaml", invalid)]);
+        let config = load_config(dir.path().join("commands.yaml")).unwrap();
+        match crate::config::validator::validate_config(&config).unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { path, .. }) => {
+                let expected = format!(
+                    "{}: commands[0].implementation",
+                    dir.path().join("teams/bad.yaml").display()
+                );
+                assert_eq!(path.as_deref(), Some(expected.as_str()));
+            }
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_load_config_checks_included_schema_version() {
+        let current = format!(
+            "schema_version: {}\n{}",
+            SCHEMA_VERSION,
+            command_yaml("sync")
+        );
+        let dir = config_dir(&[("commands.yaml", MAIN), ("teams/sync.yaml", &current)]);
+        let config = load_config(dir.path().join("commands.yaml")).unwrap();
+        assert_eq!(config.schema_version, SCHEMA_VERSION);
+        assert_eq!(config.commands.len(), 2);
+
+        let newer = format!(
+            "schema_version: {}\n{}",
+            SCHEMA_VERSION + 1,
+            command_yaml("sync")
+        );
+        let dir = config_dir(&[("commands.yaml", MAIN), ("teams/sync.yaml", &newer)]);
+        assert!(matches!(
+            load_config(dir.path().join("commands.yaml")).unwrap_err(),
+            DynamicCliError::Config(ConfigError::UnsupportedSchemaVersion { .. })
+        ));
+    }
+
+    // ── Environment interpolation ────────────────────────────
+
+    const INTERPOLATE: LoadOptions = LoadOptions {
+        interpolate_env: true,
+    };
+
+    fn env(name: &str) -> Option<String> {
+        match name {
+            "HOME" => Some("/home/user".to_string()),
+            "EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_interpolate_variables_and_fallbacks() {
+        assert_eq!(
+            interpolate("${HOME}/data", "k", &env).unwrap(),
+            "/home/user/data"
+        );
+        assert_eq!(interpolate("${APP_ENV:-dev}", "k", &env).unwrap(), "dev");
+        assert_eq!(interpolate("${EMPTY:-dev}", "k", &env).unwrap(), "dev");
+        assert_eq!(interpolate("[${EMPTY}]", "k", &env).unwrap(), "[]");
+        assert_eq!(
+            interpolate("${HOME:-x} ${HOME}", "k", &env).unwrap(),
+            "/home/user /home/user"
+        );
+    }
+
+    #[test]
+    fn test_interpolate_literal_dollars() {
+        assert_eq!(
+            interpolate("$$HOME $$${HOME}", "k", &env).unwrap(),
+            "$HOME $/home/user"
+        );
+        assert_eq!(interpolate("costs $5", "k", &env).unwrap(), "costs $5");
+        assert_eq!(interpolate("end $", "k", &env).unwrap(), "end $");
+    }
+
+    #[test]
+    fn test_interpolate_undefined_variable_names_key() {
+        let mut value: serde_yaml::Value =
+            serde_yaml::from_str("commands:\n  - options:\n      - default: \"${DATA_DIR}/x\"\n")
+                .unwrap();
+        match interpolate_yaml(&mut value, "", &env).unwrap_err() {
+            DynamicCliError::Config(ConfigError::UndefinedVariable {
+                name,
+                key,
+                suggestion,
+            }) => {
+                assert_eq!(name, "DATA_DIR");
+                assert_eq!(key, "commands[0].options[0].default");
+                assert!(suggestion.unwrap().contains("${DATA_DIR:-"));
+            }
+            other => panic!("Expected UndefinedVariable error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_interpolate_malformed_references() {
+        for text in ["${HOME", "${}", "${A B}"] {
+            match interpolate(text, "metadata.prompt", &env).unwrap_err() {
+                DynamicCliError::Config(ConfigError::InvalidSchema { path, .. }) => {
+                    assert_eq!(path.as_deref(), Some("metadata.prompt"));
+                }
+                other => panic!("Expected InvalidSchema error for {text}, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_load_config_with_interpolates_each_format() {
+        let files = [
+            (
+                "commands.yaml",
+                "metadata:\n  version: \"1.0.0\"\n  prompt: \"${DYNAMIC_CLI_TEST_UNSET:-dev}\"\ncommands: []\n",
+            ),
+            (
+                "commands.json",
+                r#"{"metadata": {"version": "1.0.0", "prompt": "${DYNAMIC_CLI_TEST_UNSET:-dev}"}, "commands": []}"#,
+            ),
+            (
+                "commands.toml",
+                "commands = []\n\n[metadata]\nversion = \"1.0.0\"\nprompt = \"${DYNAMIC_CLI_TEST_UNSET:-dev}\"\n",
+            ),
+        ];
+        let dir = config_dir(&files);
+
+        for (name, _) in files {
+            let path = dir.path().join(name);
+            let config = load_config_with(&path, INTERPOLATE).unwrap();
+            assert_eq!(config.metadata.prompt, "dev", "{name}");
+
+            // Disabled by default: the string is kept as written
+            let config = load_config(&path).unwrap();
+            assert_eq!(
+                config.metadata.prompt, "${DYNAMIC_CLI_TEST_UNSET:-dev}",
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_load_config_with_undefined_variable_in_include_names_file() {
+        let team = "commands:\n  - name: sync\n    description: \"${DYNAMIC_CLI_TEST_UNSET}\"\n    implementation: \"sync_handler\"\n";
+        let dir = config_dir(&[("commands.yaml", MAIN), ("teams/sync.yaml", team)]);
+
+        match load_config_with(dir.path().join("commands.yaml"), INTERPOLATE).unwrap_err() {
+            DynamicCliError::Config(ConfigError::UndefinedVariable { name, key, .. }) => {
+                assert_eq!(name, "DYNAMIC_CLI_TEST_UNSET");
+                let expected = format!(
+                    "{}: commands[0].description",
+                    dir.path().join("teams/sync.yaml").display()
+                );
+                assert_eq!(key, expected);
+            }
+            other => panic!("Expected UndefinedVariable error, got {:?}", other),
+        }
+    }
 }
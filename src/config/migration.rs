@@ -0,0 +1,648 @@
+//! Configuration format versions and migrations
+//!
+//! The configuration format is versioned by the top-level
+//! `schema_version` key, independent of the application's
+//! `metadata.version`. A document written for an older version is
+//! upgraded one version at a time, by the [`Migration`]s of a
+//! [`MigrationRegistry`], before it is deserialized.
+//!
+//! # Versions
+//!
+//! - 1 - documents without `schema_version` (dynamic-cli 0.3 and earlier)
+//! - 2 - adds `schema_version`; every other key reads as in version 1
+//!
+//! The loaders ([`load_config`](crate::config::loader::load_config),
+//! [`load_yaml`](crate::config::loader::load_yaml), ...) migrate in
+//! memory. [`migrate_file`] writes the upgraded document back to the
+//! file, keeping the original next to it as `<file>.bak`; the rewritten
+//! file loses its comments and key order, so
+//! [`migrate_file_with`] can preview the new text instead. A version
+//! newer than [`SCHEMA_VERSION`] is rejected with
+//! [`ConfigError::UnsupportedSchemaVersion`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use dynamic_cli::config::migration::migrate_file;
+//!
+//! let report = migrate_file("commands.yaml")?;
+//! for step in &report.applied {
+//!     println!("{}", step);
+//! }
+//! println!("schema_version {} -> {}", report.from, report.to);
+//! # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+//! ```
+
+use crate::config::loader::read_file;
+use crate::config::schema::SCHEMA_VERSION;
+use crate::error::{ConfigError, DynamicCliError, Result};
+use serde_yaml::{Mapping, Value};
+use std::ffi::OsString;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Key holding the format version in a document
+const VERSION_KEY: &str = "schema_version";
+
+/// One upgrade step, from version `from` to `from + 1`
+#[derive(Debug, Clone, Copy)]
+pub struct Migration {
+    /// Version the step upgrades from
+    pub from: u32,
+
+    /// What the step changes, listed in [`MigrationReport::applied`]
+    pub description: &'static str,
+
+    /// Rewrite the document in place
+    ///
+    /// `None` for a step that only bumps the version. The registry sets
+    /// `schema_version` after each step.
+    pub apply: Option<fn(&mut Value) -> Result<()>>,
+}
+
+/// Outcome of a migration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationReport {
+    /// Version of the document before the migration
+    pub from: u32,
+
+    /// Version of the document after the migration
+    pub to: u32,
+
+    /// Descriptions of the steps applied, in order
+    pub applied: Vec<&'static str>,
+
+    /// Upgraded text of a migrated file
+    ///
+    /// `None` when the file is up to date. Set by
+    /// [`migrate_file`](MigrationRegistry::migrate_file), also on a dry run.
+    pub content: Option<String>,
+
+    /// Copy of the original file, saved before it was rewritten
+    pub backup: Option<PathBuf>,
+}
+
+impl MigrationReport {
+    /// Whether the document was upgraded
+    pub fn migrated(&self) -> bool {
+        self.from != self.to
+    }
+}
+
+/// Options controlling how [`migrate_file_with`] rewrites a file
+///
+/// # Example
+///
+/// ```no_run
+/// use dynamic_cli::config::migration::{migrate_file_with, MigrateOptions};
+///
+/// let report = migrate_file_with("commands.yaml", MigrateOptions { dry_run: true })?;
+/// if let Some(content) = report.content {
+///     println!("{}", content);
+/// }
+/// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MigrateOptions {
+    /// Compute the upgraded text without touching the file
+    pub dry_run: bool,
+}
+
+/// Ordered upgrade steps, from version 1 to [`latest`](Self::latest)
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::migration::MigrationRegistry;
+///
+/// let mut document: serde_yaml::Value = serde_yaml::from_str("commands: []")?;
+/// let report = MigrationRegistry::builtin().migrate(&mut document)?;
+///
+/// assert_eq!((report.from, report.to), (1, 2));
+/// assert_eq!(document["schema_version"], serde_yaml::Value::from(2));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MigrationRegistry {
+    migrations: Vec<Migration>,
+}
+
+impl MigrationRegistry {
+    /// Create a registry without steps, whose latest version is 1
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The steps of this release, up to [`SCHEMA_VERSION`]
+    pub fn builtin() -> Self {
+        Self {
+            migrations: vec![Migration {
+                from: 1,
+                description: "Add `schema_version`",
+                apply: None,
+            }],
+        }
+    }
+
+    /// Version reached by the last step
+    pub fn latest(&self) -> u32 {
+        self.migrations.len() as u32 + 1
+    }
+
+    /// Upgrade `document` to the latest version
+    ///
+    /// An up-to-date document is left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// - [`ConfigError::UnsupportedSchemaVersion`] if the document is newer
+    ///   than [`latest`](Self::latest)
+    /// - [`ConfigError::InvalidSchema`] if the document is not a mapping or
+    ///   `schema_version` is not a positive integer
+    /// - Any error of a step
+    pub fn migrate(&self, document: &mut Value) -> Result<MigrationReport> {
+        let from = schema_version(document)?;
+        self.check(from)?;
+
+        let mut applied = Vec::new();
+        for migration in &self.migrations[from as usize - 1..] {
+            if let Some(apply) = migration.apply {
+                apply(document)?;
+            }
+            set_schema_version(document, migration.from + 1)?;
+            applied.push(migration.description);
+        }
+
+        Ok(MigrationReport {
+            from,
+            to: self.latest(),
+            applied,
+            content: None,
+            backup: None,
+        })
+    }
+
+    /// Upgrade a configuration file and write it back
+    ///
+    /// Same as [`migrate_file_with`](Self::migrate_file_with) with default
+    /// options.
+    ///
+    /// # Errors
+    ///
+    /// Those of [`migrate_file_with`](Self::migrate_file_with).
+    pub fn migrate_file<P: AsRef<Path>>(&self, path: P) -> Result<MigrationReport> {
+        self.migrate_file_with(path, MigrateOptions::default())
+    }
+
+    /// Upgrade a configuration file with the given options
+    ///
+    /// The file keeps its format and is rewritten whenever it is older than
+    /// [`latest`](Self::latest), if only to set `schema_version`. An
+    /// up-to-date file is left alone.
+    ///
+    /// The new text is serialized from the parsed document, so comments
+    /// are lost and keys may be reordered. The original file is therefore
+    /// copied to `<file>.bak` first, and the new text is returned in
+    /// [`MigrationReport::content`]; with [`MigrateOptions::dry_run`] the
+    /// file is not touched at all. Files listed under `include:` are not
+    /// followed: migrate each of them.
+    ///
+    /// # Errors
+    ///
+    /// - Those of [`load_config`](crate::config::loader::load_config)
+    ///   reading the file
+    /// - Those of [`migrate`](Self::migrate)
+    /// - [`DynamicCliError::Io`] if the backup or the file cannot be written
+    pub fn migrate_file_with<P: AsRef<Path>>(
+        &self,
+        path: P,
+        options: MigrateOptions,
+    ) -> Result<MigrationReport> {
+        let path = path.as_ref();
+        let (format, content) = read_file(path)?;
+        let mut document = format.document(&content)?;
+
+        let mut report = self.migrate(&mut document)?;
+        if !report.migrated() {
+            return Ok(report);
+        }
+
+        let migrated = format.write(&document)?;
+        if !options.dry_run {
+            let backup = backup_path(path);
+            fs::copy(path, &backup).map_err(DynamicCliError::from)?;
+            fs::write(path, &migrated).map_err(DynamicCliError::from)?;
+            report.backup = Some(backup);
+        }
+        report.content = Some(migrated);
+        Ok(report)
+    }
+
+    /// Whether upgrading from `version` changes more than the version
+    ///
+    /// # Errors
+    ///
+    /// [`ConfigError::UnsupportedSchemaVersion`] if `version` is newer than
+    /// [`latest`](Self::latest)
+    pub(crate) fn rewrites(&self, version: u32) -> Result<bool> {
+        self.check(version)?;
+        Ok(self.migrations[version as usize - 1..]
+            .iter()
+            .any(|migration| migration.apply.is_some()))
+    }
+
+    /// Reject versions this registry cannot upgrade
+    fn check(&self, version: u32) -> Result<()> {
+        if version > self.latest() {
+            return Err(ConfigError::UnsupportedSchemaVersion {
+                version,
+                supported: self.latest(),
+                suggestion: Some(
+                    "The file was written for a newer release; upgrade dynamic-cli to read it."
+                        .to_string(),
+                ),
+            }
+            .into());
+        }
+        Ok(())
+    }
+}
+
+/// Upgrade a configuration file to [`SCHEMA_VERSION`] and write it back
+///
+/// Uses [`MigrationRegistry::builtin`]; see
+/// [`MigrationRegistry::migrate_file`].
+///
+/// # Example
+///
+/// ```no_run
+/// use dynamic_cli::config::migration::migrate_file;
+///
+/// let report = migrate_file("commands.yaml")?;
+/// assert_eq!(report.to, dynamic_cli::config::schema::SCHEMA_VERSION);
+/// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+/// ```
+pub fn migrate_file<P: AsRef<Path>>(path: P) -> Result<MigrationReport> {
+    MigrationRegistry::builtin().migrate_file(path)
+}
+
+/// Upgrade a configuration file to [`SCHEMA_VERSION`] with the given options
+///
+/// Uses [`MigrationRegistry::builtin`]; see
+/// [`MigrationRegistry::migrate_file_with`].
+pub fn migrate_file_with<P: AsRef<Path>>(
+    path: P,
+    options: MigrateOptions,
+) -> Result<MigrationReport> {
+    MigrationRegistry::builtin().migrate_file_with(path, options)
+}
+
+/// Path of the copy kept before a file is rewritten: `<file>.bak`
+fn backup_path(path: &Path) -> PathBuf {
+    let mut name = OsString::from(path.as_os_str());
+    name.push(".bak");
+    PathBuf::from(name)
+}
+
+/// Read the `schema_version` of a document, 1 when absent
+///
+/// # Errors
+///
+/// [`ConfigError::InvalidSchema`] if the document is not a mapping or the
+/// version is not a positive integer
+pub fn schema_version(document: &Value) -> Result<u32> {
+    let mapping = as_mapping(document)?;
+    let Some(version) = mapping.get(VERSION_KEY) else {
+        return Ok(1);
+    };
+
+    version
+        .as_u64()
+        .and_then(|version| u32::try_from(version).ok())
+        .filter(|version| *version > 0)
+        .ok_or_else(|| {
+            ConfigError::InvalidSchema {
+                reason: format!(
+                    "schema_version must be a positive integer, found {}",
+                    describe(version)
+                ),
+                path: Some(VERSION_KEY.to_string()),
+                suggestion: Some(format!(
+                    "Remove the key or set it to {} or lower.",
+                    SCHEMA_VERSION
+                )),
+            }
+            .into()
+        })
+}
+
+/// Set `schema_version`, as the first key when it is added
+fn set_schema_version(document: &mut Value, version: u32) -> Result<()> {
+    let Value::Mapping(mapping) = document else {
+        return Err(not_a_mapping(document));
+    };
+
+    if let Some(current) = mapping.get_mut(VERSION_KEY) {
+        *current = version.into();
+    } else {
+        let rest = std::mem::take(mapping);
+        mapping.insert(VERSION_KEY.into(), version.into());
+        mapping.extend(rest);
+    }
+    Ok(())
+}
+
+/// View the root of a document as a mapping
+fn as_mapping(document: &Value) -> Result<&Mapping> {
+    document.as_mapping().ok_or_else(|| not_a_mapping(document))
+}
+
+/// Error for a document whose root is not a mapping
+fn not_a_mapping(document: &Value) -> DynamicCliError {
+    ConfigError::InvalidSchema {
+        reason: format!(
+            "The configuration must be a mapping, found {}",
+            describe(document)
+        ),
+        path: None,
+        suggestion: None,
+    }
+    .into()
+}
+
+/// Short description of a value for error messages
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => format!("'{}'", s),
+        Value::Sequence(_) => "a list".to_string(),
+        Value::Mapping(_) => "a mapping".to_string(),
+        Value::Tagged(tagged) => describe(&tagged.value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::loader::{load_config, load_yaml, parse_config, Format, LoadOptions};
+    use crate::config::schema::CommandsConfig;
+
+    const V1: &str = r#"
+metadata:
+  version: "1.0.0"
+  prompt: "test"
+commands:
+  - name: hello
+    description: "Say hello"
+    implementation: "hello_handler"
+"#;
+
+    /// Registry with a third version renaming `metadata.prompt` to `title`
+    /// and a fourth one restoring it
+    fn registry() -> MigrationRegistry {
+        fn rename(document: &mut Value, from: &str, to: &str) -> Result<()> {
+            let metadata = document["metadata"].as_mapping_mut().unwrap();
+            let value = metadata.remove(from).unwrap();
+            metadata.insert(to.into(), value);
+            Ok(())
+        }
+
+        let mut registry = MigrationRegistry::builtin();
+        registry.migrations.extend([
+            Migration {
+                from: 2,
+                description: "Rename prompt to title",
+                apply: Some(|document| rename(document, "prompt", "title")),
+            },
+            Migration {
+                from: 3,
+                description: "Rename title to prompt",
+                apply: Some(|document| rename(document, "title", "prompt")),
+            },
+        ]);
+        registry
+    }
+
+    fn document(content: &str) -> Value {
+        serde_yaml::from_str(content).unwrap()
+    }
+
+    #[test]
+    fn test_builtin_registry_reaches_schema_version() {
+        assert_eq!(MigrationRegistry::builtin().latest(), SCHEMA_VERSION);
+        assert_eq!(MigrationRegistry::new().latest(), 1);
+    }
+
+    #[test]
+    fn test_migrate_applies_steps_in_order() {
+        let mut doc = document(V1);
+        let report = registry().migrate(&mut doc).unwrap();
+
+        assert_eq!(report.from, 1);
+        assert_eq!(report.to, 4);
+        assert_eq!(
+            report.applied,
+            vec![
+                "Add `schema_version`",
+                "Rename prompt to title",
+                "Rename title to prompt"
+            ]
+        );
+        assert_eq!(doc["metadata"]["prompt"], Value::from("test"));
+        assert_eq!(schema_version(&doc).unwrap(), 4);
+        // Added as the first key
+        let first = doc.as_mapping().unwrap().keys().next().unwrap();
+        assert_eq!(first, &Value::from(VERSION_KEY));
+    }
+
+    #[test]
+    fn test_migrate_starts_from_document_version() {
+        let mut doc = document("schema_version: 3\nmetadata:\n  title: x\n");
+        let report = registry().migrate(&mut doc).unwrap();
+
+        assert_eq!(report.applied, vec!["Rename title to prompt"]);
+        assert_eq!(doc["metadata"]["prompt"], Value::from("x"));
+
+        // Up to date: nothing to do
+        let report = registry().migrate(&mut doc).unwrap();
+        assert!(!report.migrated());
+        assert!(report.applied.is_empty());
+    }
+
+    #[test]
+    fn test_migrate_rejects_newer_version() {
+        let mut doc = document("schema_version: 9\ncommands: []\n");
+        match MigrationRegistry::builtin().migrate(&mut doc).unwrap_err() {
+            DynamicCliError::Config(ConfigError::UnsupportedSchemaVersion {
+                version,
+                supported,
+                suggestion,
+            }) => {
+                assert_eq!(version, 9);
+                assert_eq!(supported, SCHEMA_VERSION);
+                assert!(suggestion.is_some());
+            }
+            other => panic!("Expected UnsupportedSchemaVersion error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_schema_version_must_be_positive_integer() {
+        for content in [
+            "schema_version: 0",
+            "schema_version: \"2\"",
+            "schema_version: 1.5",
+        ] {
+            match schema_version(&document(content)).unwrap_err() {
+                DynamicCliError::Config(ConfigError::InvalidSchema { path, .. }) => {
+                    assert_eq!(path.as_deref(), Some(VERSION_KEY));
+                }
+                other => panic!("Expected InvalidSchema error, got {:?}", other),
+            }
+        }
+        assert!(schema_version(&document("- a\n- b\n")).is_err());
+    }
+
+    /// Registry with a third version renaming `metadata.prompt` to `title`
+    fn renaming() -> MigrationRegistry {
+        let mut registry = MigrationRegistry::builtin();
+        registry.migrations.push(Migration {
+            from: 2,
+            description: "Rename prompt to title",
+            apply: Some(|document| {
+                let metadata = document["metadata"].as_mapping_mut().unwrap();
+                let value = metadata.remove("prompt").unwrap();
+                metadata.insert("title".into(), value);
+                Ok(())
+            }),
+        });
+        registry
+    }
+
+    /// The same version 1 configuration in each format
+    fn v1_files() -> [(&'static str, String); 3] {
+        [
+            ("commands.yaml", format!("# Commands\n{}", V1)),
+            (
+                "commands.json",
+                r#"{"metadata": {"version": "1.0.0", "prompt": "test"}, "commands": []}"#
+                    .to_string(),
+            ),
+            (
+                "commands.toml",
+                "commands = []\n\n[metadata]\nversion = \"1.0.0\"\nprompt = \"test\"\n".to_string(),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_migrate_file_writes_back_in_same_format() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for (name, content) in &v1_files() {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+
+            let report = renaming().migrate_file(&path).unwrap();
+            assert_eq!((report.from, report.to), (1, 3), "{name}");
+
+            let (format, written) = read_file(&path).unwrap();
+            assert_eq!(report.content.as_deref(), Some(written.as_str()), "{name}");
+            let document = format.document(&written).unwrap();
+            assert_eq!(schema_version(&document).unwrap(), 3, "{name}");
+            assert_eq!(document["metadata"]["title"], Value::from("test"), "{name}");
+
+            // The original is kept next to the file
+            let backup = report.backup.unwrap();
+            assert_eq!(backup, dir.path().join(format!("{name}.bak")));
+            assert_eq!(&std::fs::read_to_string(&backup).unwrap(), content);
+
+            // Already up to date: the file is left alone
+            let report = renaming().migrate_file(&path).unwrap();
+            assert!(!report.migrated());
+            assert_eq!(report.content, None);
+            assert_eq!(std::fs::read_to_string(&path).unwrap(), written);
+        }
+    }
+
+    #[test]
+    fn test_migrate_file_writes_version_only_changes() {
+        let dir = tempfile::tempdir().unwrap();
+
+        for (name, content) in &v1_files() {
+            let path = dir.path().join(name);
+            std::fs::write(&path, content).unwrap();
+
+            // The builtin step only adds schema_version
+            let report = migrate_file(&path).unwrap();
+            assert_eq!((report.from, report.to), (1, SCHEMA_VERSION), "{name}");
+            assert!(report.backup.is_some(), "{name}");
+
+            let (format, written) = read_file(&path).unwrap();
+            assert_eq!(report.content.as_deref(), Some(written.as_str()), "{name}");
+            let document = format.document(&written).unwrap();
+            assert_eq!(schema_version(&document).unwrap(), SCHEMA_VERSION, "{name}");
+
+            let config = load_config(&path).unwrap();
+            assert_eq!(config.schema_version, SCHEMA_VERSION, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_migrate_file_dry_run_leaves_file_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("commands.yaml");
+        std::fs::write(&path, V1).unwrap();
+
+        let report = renaming()
+            .migrate_file_with(&path, MigrateOptions { dry_run: true })
+            .unwrap();
+        assert!(report.content.unwrap().contains("title: test"));
+        assert_eq!(report.backup, None);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), V1);
+        assert!(!dir.path().join("commands.yaml.bak").exists());
+    }
+
+    #[test]
+    fn test_load_reports_current_version() {
+        let config = load_yaml(V1).unwrap();
+        assert_eq!(config.schema_version, SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn test_load_rejects_newer_version() {
+        let content = format!("schema_version: {}\n{}", SCHEMA_VERSION + 1, V1);
+        assert!(matches!(
+            load_yaml(&content).unwrap_err(),
+            DynamicCliError::Config(ConfigError::UnsupportedSchemaVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parse_config_migrates_before_deserializing() {
+        // Version 3 documents use `title`, which the schema does not know
+        let content = format!("schema_version: 3\n{}", V1.replace("prompt:", "title:"));
+        let config: CommandsConfig =
+            parse_config(Format::Yaml, &content, LoadOptions::default(), &registry()).unwrap();
+        assert_eq!(config.metadata.prompt, "test");
+        assert_eq!(config.schema_version, 4);
+
+        // Errors in the migrated document name the versions
+        let content = "schema_version: 3\nmetadata:\n  title: x\ncommands: []\n";
+        match parse_config::<CommandsConfig>(
+            Format::Yaml,
+            content,
+            LoadOptions::default(),
+            &registry(),
+        )
+        .unwrap_err()
+        {
+            DynamicCliError::Config(ConfigError::InvalidSchema { reason, .. }) => {
+                assert!(reason.contains("from schema_version 3 to 4"), "{reason}");
+            }
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+    }
+}
@@ -7,6 +7,9 @@
 //!
 //! - [`schema`]: Data structures for configuration
 //! - [`loader`]: Functions to load configuration files
+//! - [`layers`]: Overlay files applied on top of a configuration
+//! - [`json_schema`]: JSON Schema of the format, for editors
+//! - [`migration`]: Format versions and upgrades of older files
 //! - [`validator`]: Configuration validation logic
 //!
 //! # Quick Start
@@ -82,22 +85,29 @@
 //! ```
 
 // Public submodules
+pub mod json_schema;
+pub mod layers;
 pub mod loader;
+pub mod migration;
 pub mod schema;
+pub mod spans;
 pub mod validator;
 
 // Re-export commonly used types and functions for convenience
 #[allow(unused_imports)]
 pub use schema::{
     ArgumentDefinition, ArgumentType, CommandDefinition, CommandsConfig, Metadata,
-    OptionDefinition, ValidationRule,
+    OptionDefinition, ValidationRule, SCHEMA_VERSION,
 };
 
 #[allow(unused_imports)]
-pub use loader::{load_config, load_json, load_yaml};
+pub use loader::{load_config, load_config_with, load_json, load_toml, load_yaml, LoadOptions};
 
 #[allow(unused_imports)]
-pub use validator::{validate_argument_types, validate_command, validate_config};
+pub use validator::{
+    diagnose_config, diagnose_config_in, validate_argument_types, validate_command,
+    validate_config, validate_config_in,
+};
 
 #[cfg(test)]
 mod tests {
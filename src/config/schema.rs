@@ -7,10 +7,13 @@
 //!
 //! - [`CommandsConfig`]: Root configuration structure
 //! - [`CommandDefinition`]: Individual command specification
+//! - [`ArgumentGroup`]: Mutually exclusive arguments and options
 //! - [`ArgumentType`]: Supported argument types
 //! - [`ValidationRule`]: Validation constraints
 
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 /// Complete configuration for CLI/REPL commands
 ///
@@ -32,6 +35,14 @@ use serde::{Deserialize, Serialize};
 /// ```
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub struct CommandsConfig {
+    /// Version of the configuration format
+    ///
+    /// Unrelated to [`Metadata::version`], the application's version.
+    /// Documents without the field are version 1; older documents are
+    /// upgraded when loading, see [`config::migration`](crate::config::migration).
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
+
     /// Metadata about the application interface
     pub metadata: Metadata,
 
@@ -41,6 +52,13 @@ pub struct CommandsConfig {
     /// Global options available to all commands
     #[serde(default)]
     pub global_options: Vec<OptionDefinition>,
+
+    /// Other files to merge into this one
+    ///
+    /// Glob patterns relative to the including file. Resolved (and
+    /// emptied) by [`load_config`](crate::config::loader::load_config).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
 }
 
 /// Metadata for the CLI/REPL interface
@@ -73,6 +91,17 @@ fn default_prompt_suffix() -> String {
     " > ".to_string()
 }
 
+/// Version of the configuration format written by this release
+///
+/// See [`config::migration`](crate::config::migration) for the changes
+/// between versions.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Version of documents written before `schema_version` existed
+fn default_schema_version() -> u32 {
+    1
+}
+
 /// Definition of a single command
 ///
 /// Describes a command with its arguments, options, and validation rules.
@@ -96,7 +125,32 @@ fn default_prompt_suffix() -> String {
 /// options: []
 /// implementation: "simulate_handler"
 /// ```
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+///
+/// Relations between arguments and options are declared with `groups`,
+/// `requires` and `conflicts_with`, using argument and option names:
+///
+/// ```yaml
+/// groups:
+///   - name: source
+///     members: [file, url]
+///     required: true
+/// requires:
+///   format: [output]
+/// conflicts_with:
+///   quiet: [verbose]
+/// ```
+///
+/// A value only counts as given when it comes from the command line or
+/// an environment variable, not from a `default`.
+///
+/// Simple comparisons between values are declared with `constraints`:
+///
+/// ```yaml
+/// constraints:
+///   - check: "end > start"
+///     message: "end must come after start"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 pub struct CommandDefinition {
     /// Command name (used for invocation)
     pub name: String,
@@ -127,6 +181,54 @@ pub struct CommandDefinition {
     /// This string is used to match the command with its
     /// registered handler in the CommandRegistry.
     pub implementation: String,
+
+    /// Nested subcommands (e.g. `remote add`)
+    ///
+    /// A command with subcommands dispatches to the child named by the
+    /// next token. Its own handler is only invoked when no subcommand is
+    /// given; if none is registered, a subcommand is required.
+    #[serde(default)]
+    pub subcommands: Vec<CommandDefinition>,
+
+    /// Groups of mutually exclusive arguments and options
+    #[serde(default)]
+    pub groups: Vec<ArgumentGroup>,
+
+    /// Arguments and options that only make sense together
+    ///
+    /// Maps a name to the names that must also be given when it is
+    /// (e.g. `format: [output]`).
+    #[serde(default)]
+    pub requires: BTreeMap<String, Vec<String>>,
+
+    /// Arguments and options that cannot be used together
+    ///
+    /// Maps a name to the names that must not be given when it is
+    /// (e.g. `quiet: [verbose]`). The relation is symmetric.
+    #[serde(default)]
+    pub conflicts_with: BTreeMap<String, Vec<String>>,
+
+    /// Comparisons between argument and option values
+    ///
+    /// Checked on typed values after parsing, before the handler runs.
+    #[serde(default)]
+    pub constraints: Vec<ConstraintDefinition>,
+
+    /// Leave the command out of listings
+    ///
+    /// A hidden command still resolves and runs, and `--help <command>`
+    /// still describes it, but help, completions, man pages and reference
+    /// documentation do not list it.
+    #[serde(default)]
+    pub hidden: bool,
+
+    /// File the command was read from, if it came from an `include:`
+    ///
+    /// Set by [`load_config`](crate::config::loader::load_config) on
+    /// top-level commands of included files so that configuration errors
+    /// can name the file. `None` for the main file.
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
 }
 
 /// Definition of a positional argument
@@ -161,7 +263,19 @@ pub struct CommandDefinition {
 /// the entire line is silently omitted from the history file. The command
 /// name itself is not filtered — only lines with a secure argument value
 /// are suppressed.
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+///
+/// The last argument can take several values with `multiple: true`,
+/// optionally bounded by `min_values` and `max_values`:
+///
+/// ```yaml
+/// name: files
+/// arg_type: path
+/// required: true
+/// description: "Files to remove"
+/// multiple: true
+/// min_values: 1
+/// ```
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 pub struct ArgumentDefinition {
     /// Argument name (used in error messages and documentation)
     pub name: String,
@@ -185,6 +299,24 @@ pub struct ArgumentDefinition {
     /// argument is not written to the history file. Defaults to `false`.
     #[serde(default)]
     pub secure: bool,
+
+    /// Whether the argument accepts several values
+    ///
+    /// Only the last argument may be variadic: it takes all remaining
+    /// positional tokens, and its values are delivered as a list.
+    #[serde(default)]
+    pub multiple: bool,
+
+    /// Minimum number of values of a variadic argument
+    #[serde(default)]
+    pub min_values: Option<usize>,
+
+    /// Maximum number of values of a variadic argument
+    #[serde(default)]
+    pub max_values: Option<usize>,
+    /// Accepted values of an `enum` argument
+    #[serde(default)]
+    pub variants: Vec<EnumVariant>,
 }
 
 /// Definition of a named option (flag)
@@ -204,7 +336,7 @@ pub struct ArgumentDefinition {
 /// description: "Output file path"
 /// choices: []
 /// ```
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
 pub struct OptionDefinition {
     /// Option name (internal identifier)
     pub name: String,
@@ -233,6 +365,131 @@ pub struct OptionDefinition {
     /// If non-empty, the value must be one of these choices.
     #[serde(default)]
     pub choices: Vec<String>,
+
+    /// Environment variable used when the option is not given
+    ///
+    /// Values are resolved with the precedence command line >
+    /// environment > `default`. An empty variable counts as unset.
+    #[serde(default)]
+    pub env: Option<String>,
+
+    /// Whether the option may be repeated (`-I a -I b`)
+    ///
+    /// The values of every occurrence are delivered as a list.
+    #[serde(default)]
+    pub multiple: bool,
+
+    /// Minimum number of values of a repeatable option
+    #[serde(default)]
+    pub min_values: Option<usize>,
+
+    /// Maximum number of values of a repeatable option
+    #[serde(default)]
+    pub max_values: Option<usize>,
+    /// Accepted values of an `enum` option
+    ///
+    /// Unlike `choices`, variants carry descriptions shown in help and
+    /// may have aliases.
+    #[serde(default)]
+    pub variants: Vec<EnumVariant>,
+}
+
+/// Group of mutually exclusive arguments and options
+///
+/// At most one member of a group may be given. With `required: true`,
+/// exactly one must be given.
+///
+/// # Example
+///
+/// ```yaml
+/// name: source
+/// members: [file, url]
+/// required: true
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ArgumentGroup {
+    /// Group name (used in error messages)
+    pub name: String,
+
+    /// Names of the arguments and options in the group
+    pub members: Vec<String>,
+
+    /// Whether one member must be given
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Cross-field comparison between argument and option values
+///
+/// `check` compares two operands with one of `<`, `<=`, `>`, `>=`, `==`
+/// or `!=`. An operand is the name of an argument or option of the
+/// command, or a literal: a quoted string, or any other text that is not
+/// such a name, read with the type of the other operand (e.g.
+/// `timeout <= 5m`). The check is skipped when a named value is absent.
+///
+/// # Example
+///
+/// ```yaml
+/// check: "end > start"
+/// message: "end must come after start"
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct ConstraintDefinition {
+    /// Comparison to enforce (e.g. `"end > start"`)
+    pub check: String,
+
+    /// Error message shown when the check fails
+    ///
+    /// Defaults to a message naming the check and the values involved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+/// A variant of an `enum` argument or option
+///
+/// # Example
+///
+/// ```yaml
+/// name: fast
+/// description: "Skip consistency checks"
+/// aliases: [f, quick]
+/// ```
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct EnumVariant {
+    /// Canonical name, delivered to handlers
+    pub name: String,
+
+    /// Human-readable description shown in help
+    #[serde(default)]
+    pub description: String,
+
+    /// Alternative names accepted on input
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl EnumVariant {
+    /// Find the variant matching a name or an alias
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::config::schema::EnumVariant;
+    ///
+    /// let variants = vec![EnumVariant {
+    ///     name: "fast".to_string(),
+    ///     description: String::new(),
+    ///     aliases: vec!["f".to_string()],
+    /// }];
+    ///
+    /// assert_eq!(EnumVariant::find(&variants, "f").unwrap().name, "fast");
+    /// assert!(EnumVariant::find(&variants, "slow").is_none());
+    /// ```
+    pub fn find<'a>(variants: &'a [EnumVariant], value: &str) -> Option<&'a EnumVariant> {
+        variants
+            .iter()
+            .find(|variant| variant.name == value || variant.aliases.iter().any(|a| a == value))
+    }
 }
 
 /// Supported argument and option types
@@ -248,10 +505,18 @@ pub struct OptionDefinition {
 /// - `Float` → "float"
 /// - `Bool` → "bool"
 /// - `Path` → "path"
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+/// - `Duration` → "duration"
+/// - `Bytes` → "bytes"
+/// - `Url` → "url"
+/// - `Ip` → "ip"
+/// - `DateTime` → "datetime"
+/// - `Regex` → "regex"
+/// - `Enum` → "enum"
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
 #[serde(rename_all = "lowercase")]
 pub enum ArgumentType {
-    /// UTF-8 string
+    /// UTF-8 string (the default)
+    #[default]
     String,
 
     /// Signed integer (i64)
@@ -268,6 +533,31 @@ pub enum ArgumentType {
     /// Represents a path that may or may not exist,
     /// depending on validation rules.
     Path,
+
+    /// Time span (e.g. `30s`, `1h30m`, `250ms`; a bare number is seconds)
+    Duration,
+
+    /// Byte size (e.g. `512`, `10KB`, `1.5GiB`; units are powers of 1024)
+    Bytes,
+
+    /// Absolute URL with a scheme and a host (e.g. `https://example.com/path`)
+    Url,
+
+    /// IPv4 or IPv6 address
+    Ip,
+
+    /// Date or date and time in ISO 8601 / RFC 3339 form
+    /// (e.g. `2026-01-01`, `2026-01-01T12:30:00Z`)
+    #[serde(rename = "datetime")]
+    DateTime,
+
+    /// Regular expression (checked by compiling it, passed on as text)
+    Regex,
+
+    /// One of the declared [`EnumVariant`]s
+    ///
+    /// Aliases are replaced by the canonical variant name when parsing.
+    Enum,
 }
 
 impl ArgumentType {
@@ -288,8 +578,37 @@ impl ArgumentType {
             ArgumentType::Float => "float",
             ArgumentType::Bool => "bool",
             ArgumentType::Path => "path",
+            ArgumentType::Duration => "duration",
+            ArgumentType::Bytes => "bytes",
+            ArgumentType::Url => "url",
+            ArgumentType::Ip => "ip",
+            ArgumentType::DateTime => "datetime",
+            ArgumentType::Regex => "regex",
+            ArgumentType::Enum => "enum",
         }
     }
+
+    /// Whether values of this type are numbers that `min`/`max` rules apply to
+    ///
+    /// Durations are compared in seconds and byte sizes in bytes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::config::schema::ArgumentType;
+    ///
+    /// assert!(ArgumentType::Duration.is_numeric());
+    /// assert!(!ArgumentType::Url.is_numeric());
+    /// ```
+    pub fn is_numeric(&self) -> bool {
+        matches!(
+            self,
+            ArgumentType::Integer
+                | ArgumentType::Float
+                | ArgumentType::Duration
+                | ArgumentType::Bytes
+        )
+    }
 }
 
 /// Validation rules for arguments and options
@@ -302,6 +621,11 @@ impl ArgumentType {
 /// - `MustExist`: For paths, require that the file/directory exists
 /// - `Extensions`: For paths, restrict to specific file extensions
 /// - `Range`: For numbers, enforce min/max bounds
+/// - `Pattern`: For text, require a match of a regular expression
+/// - `Length`: For text, enforce min/max length in characters
+/// - `NotEmpty`: For text, reject empty or whitespace-only values
+/// - `Format`: For text, require a well-known format (e.g. email)
+/// - `Custom`: Run a validator registered by the application
 ///
 /// # Serialization
 ///
@@ -317,9 +641,33 @@ impl ArgumentType {
 /// # Range
 /// - min: 0.0
 ///   max: 100.0
+///
+/// # Pattern
+/// - pattern: "^[a-z][a-z0-9-]*$"
+///
+/// # Length
+/// - min_length: 3
+///   max_length: 32
+///
+/// # NotEmpty
+/// - not_empty: true
+///
+/// # Format
+/// - format: email
+///
+/// # Custom (params are optional and passed through as-is)
+/// - custom: project_exists
+///   params:
+///     registry: projects.json
 /// ```
+///
+/// Each list entry holds one rule. An entry mixing keys of several rules
+/// (e.g. `must_exist` and `extensions`) keeps the first of them, in the
+/// order of the variants below, as it always did;
+/// [`diagnose_config_in`](crate::config::validator::diagnose_config_in)
+/// warns about the keys it ignores.
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
-#[serde(untagged)]
+#[serde(untagged, try_from = "RawValidationRule")]
 pub enum ValidationRule {
     /// Require that a path exists on the file system
     MustExist { must_exist: bool },
@@ -337,9 +685,191 @@ pub enum ValidationRule {
     /// - `min: None, max: Some(100.0)` → x ≤ 100
     /// - `min: Some(0.0), max: Some(100.0)` → 0 ≤ x ≤ 100
     Range { min: Option<f64>, max: Option<f64> },
+
+    /// Require the value to match a regular expression
+    ///
+    /// The pattern is unanchored, as with [`regex::Regex::is_match`];
+    /// use `^` and `$` to match the complete value.
+    Pattern { pattern: String },
+
+    /// Enforce length constraints, counted in characters
+    ///
+    /// Either or both bounds can be specified, both inclusive.
+    Length {
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+    },
+
+    /// Reject empty or whitespace-only values
+    NotEmpty { not_empty: bool },
+
+    /// Require a well-known text format
+    Format { format: StringFormat },
+
+    /// Run an application-defined validator
+    ///
+    /// `custom` names a validator registered with
+    /// [`CliBuilder::register_validator`](crate::CliBuilder::register_validator);
+    /// `params` is handed to it unchanged (`Null` when omitted).
+    Custom {
+        custom: String,
+        #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
+        params: serde_json::Value,
+    },
+}
+
+/// Text formats understood by [`ValidationRule::Format`]
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StringFormat {
+    /// An email address (`user@example.com`)
+    Email,
+}
+
+impl StringFormat {
+    /// Get the name of this format as written in the configuration
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::config::schema::StringFormat;
+    ///
+    /// assert_eq!(StringFormat::Email.as_str(), "email");
+    /// ```
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StringFormat::Email => "email",
+        }
+    }
+}
+
+/// Keys a validation rule entry may contain
+pub(crate) const VALIDATION_RULE_KEYS: [&str; 11] = [
+    "must_exist",
+    "extensions",
+    "min",
+    "max",
+    "pattern",
+    "min_length",
+    "max_length",
+    "not_empty",
+    "format",
+    "custom",
+    "params",
+];
+
+/// Keys of each validation rule, in the order of the [`ValidationRule`] variants
+pub(crate) const VALIDATION_RULES: [&[&str]; 8] = [
+    &["must_exist"],
+    &["extensions"],
+    &["min", "max"],
+    &["pattern"],
+    &["min_length", "max_length"],
+    &["not_empty"],
+    &["format"],
+    &["custom", "params"],
+];
+
+/// Flat form of a [`ValidationRule`] as written in the configuration
+///
+/// Every key is optional so that one entry can be inspected as a whole;
+/// the conversion then picks the first rule it names. This keeps rules
+/// with optional bounds (`min`/`max`, `min_length`/`max_length`) from
+/// matching entries that hold none of their keys.
+///
+/// Keys outside [`VALIDATION_RULE_KEYS`] are ignored, as they always
+/// were; [`diagnose_config_in`](crate::config::validator::diagnose_config_in)
+/// reports them as warnings.
+#[derive(Deserialize)]
+struct RawValidationRule {
+    must_exist: Option<bool>,
+    extensions: Option<Vec<String>>,
+    min: Option<f64>,
+    max: Option<f64>,
+    pattern: Option<String>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    not_empty: Option<bool>,
+    format: Option<StringFormat>,
+    custom: Option<String>,
+    params: Option<serde_json::Value>,
+}
+
+impl TryFrom<RawValidationRule> for ValidationRule {
+    type Error = String;
+
+    fn try_from(raw: RawValidationRule) -> std::result::Result<Self, Self::Error> {
+        let mut rules = Vec::new();
+        if let Some(must_exist) = raw.must_exist {
+            rules.push(ValidationRule::MustExist { must_exist });
+        }
+        if let Some(extensions) = raw.extensions {
+            rules.push(ValidationRule::Extensions { extensions });
+        }
+        if raw.min.is_some() || raw.max.is_some() {
+            rules.push(ValidationRule::Range {
+                min: raw.min,
+                max: raw.max,
+            });
+        }
+        if let Some(pattern) = raw.pattern {
+            rules.push(ValidationRule::Pattern { pattern });
+        }
+        if raw.min_length.is_some() || raw.max_length.is_some() {
+            rules.push(ValidationRule::Length {
+                min_length: raw.min_length,
+                max_length: raw.max_length,
+            });
+        }
+        if let Some(not_empty) = raw.not_empty {
+            rules.push(ValidationRule::NotEmpty { not_empty });
+        }
+        if let Some(format) = raw.format {
+            rules.push(ValidationRule::Format { format });
+        }
+        match (raw.custom, raw.params) {
+            (Some(custom), params) => rules.push(ValidationRule::Custom {
+                custom,
+                params: params.unwrap_or_default(),
+            }),
+            (None, Some(_)) => {
+                return Err("'params' is only allowed together with 'custom'".to_string())
+            }
+            (None, None) => {}
+        }
+
+        // Entries mixing several rules keep the first one, like the
+        // untagged form of earlier versions
+        rules.into_iter().next().ok_or_else(|| {
+            "a validation rule must contain one of: must_exist, extensions, \
+             min/max, pattern, min_length/max_length, not_empty, format, custom"
+                .to_string()
+        })
+    }
+}
+
+impl CommandDefinition {
+    /// Subcommands listed in help, completions and documentation
+    ///
+    /// Leaves out `hidden` subcommands.
+    pub fn visible_subcommands(&self) -> Vec<&CommandDefinition> {
+        visible(&self.subcommands)
+    }
+}
+
+/// Commands of `commands` that are not `hidden`, in order
+pub(crate) fn visible(commands: &[CommandDefinition]) -> Vec<&CommandDefinition> {
+    commands.iter().filter(|cmd| !cmd.hidden).collect()
 }
 
 impl CommandsConfig {
+    /// Top-level commands listed in help, completions and documentation
+    ///
+    /// Leaves out `hidden` commands.
+    pub fn visible_commands(&self) -> Vec<&CommandDefinition> {
+        visible(&self.commands)
+    }
+
     /// Create a minimal valid configuration for testing
     ///
     /// This is useful for unit tests and examples.
@@ -363,6 +893,8 @@ impl CommandsConfig {
             },
             commands: vec![],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         }
     }
 }
@@ -378,6 +910,165 @@ mod tests {
         assert_eq!(ArgumentType::Float.as_str(), "float");
         assert_eq!(ArgumentType::Bool.as_str(), "bool");
         assert_eq!(ArgumentType::Path.as_str(), "path");
+        assert_eq!(ArgumentType::Duration.as_str(), "duration");
+        assert_eq!(ArgumentType::Bytes.as_str(), "bytes");
+        assert_eq!(ArgumentType::Url.as_str(), "url");
+        assert_eq!(ArgumentType::Ip.as_str(), "ip");
+        assert_eq!(ArgumentType::DateTime.as_str(), "datetime");
+        assert_eq!(ArgumentType::Regex.as_str(), "regex");
+    }
+
+    #[test]
+    fn test_deserialize_new_argument_types() {
+        let types: Vec<ArgumentType> =
+            serde_yaml::from_str("[duration, bytes, url, ip, datetime, regex]").unwrap();
+        let names: Vec<&str> = types.iter().map(ArgumentType::as_str).collect();
+        assert_eq!(
+            names,
+            ["duration", "bytes", "url", "ip", "datetime", "regex"]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_text_validation_rules() {
+        let yaml = r#"
+- pattern: "^[a-z]+$"
+- min_length: 3
+- min_length: 1
+  max_length: 8
+- not_empty: true
+- format: email
+- min: 0
+  max: 10
+"#;
+        let rules: Vec<ValidationRule> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                ValidationRule::Pattern {
+                    pattern: "^[a-z]+$".to_string()
+                },
+                ValidationRule::Length {
+                    min_length: Some(3),
+                    max_length: None
+                },
+                ValidationRule::Length {
+                    min_length: Some(1),
+                    max_length: Some(8)
+                },
+                ValidationRule::NotEmpty { not_empty: true },
+                ValidationRule::Format {
+                    format: StringFormat::Email
+                },
+                ValidationRule::Range {
+                    min: Some(0.0),
+                    max: Some(10.0)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deserialize_validation_rule_rejects_missing_rules() {
+        assert!(serde_yaml::from_str::<ValidationRule>("{minimum: 1}").is_err());
+        assert!(serde_yaml::from_str::<ValidationRule>("{}").is_err());
+        assert!(serde_yaml::from_str::<ValidationRule>("{format: phone}").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_validation_rule_keeps_first_of_mixed_rules() {
+        let rule: ValidationRule =
+            serde_yaml::from_str("{extensions: [yaml], must_exist: true}").unwrap();
+        assert_eq!(rule, ValidationRule::MustExist { must_exist: true });
+
+        let rule: ValidationRule = serde_yaml::from_str("{min_length: 2, min: 1}").unwrap();
+        assert_eq!(
+            rule,
+            ValidationRule::Range {
+                min: Some(1.0),
+                max: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_validation_rule_ignores_unknown_keys() {
+        let rule: ValidationRule =
+            serde_yaml::from_str("{min: 0, max: 100, note: percent}").unwrap();
+        assert_eq!(
+            rule,
+            ValidationRule::Range {
+                min: Some(0.0),
+                max: Some(100.0)
+            }
+        );
+    }
+
+    #[test]
+    fn test_deserialize_custom_validation_rule() {
+        let yaml = r#"
+- custom: project_exists
+  params:
+    registry: projects.json
+- custom: even
+"#;
+        let rules: Vec<ValidationRule> = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(
+            rules,
+            vec![
+                ValidationRule::Custom {
+                    custom: "project_exists".to_string(),
+                    params: serde_json::json!({ "registry": "projects.json" }),
+                },
+                ValidationRule::Custom {
+                    custom: "even".to_string(),
+                    params: serde_json::Value::Null,
+                },
+            ]
+        );
+
+        let err = serde_yaml::from_str::<ValidationRule>("{params: 1}").unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("only allowed together with 'custom'"));
+    }
+
+    #[test]
+    fn test_validation_rule_round_trips_through_json() {
+        let rule = ValidationRule::Length {
+            min_length: None,
+            max_length: Some(4),
+        };
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(serde_json::from_str::<ValidationRule>(&json).unwrap(), rule);
+    }
+
+    #[test]
+    fn test_deserialize_enum_argument() {
+        let yaml = r#"
+name: mode
+arg_type: enum
+required: true
+description: "Mode"
+variants:
+  - name: fast
+    description: "Skip checks"
+    aliases: [f]
+  - name: safe
+"#;
+        let arg: ArgumentDefinition = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(arg.arg_type, ArgumentType::Enum);
+        assert_eq!(arg.variants.len(), 2);
+        assert_eq!(arg.variants[0].aliases, vec!["f"]);
+        assert!(arg.variants[1].description.is_empty());
+        assert_eq!(
+            EnumVariant::find(&arg.variants, "f"),
+            Some(&arg.variants[0])
+        );
+        assert_eq!(
+            EnumVariant::find(&arg.variants, "fast"),
+            Some(&arg.variants[0])
+        );
     }
 
     #[test]
@@ -460,6 +1151,54 @@ mod tests {
         assert_eq!(cmd.description, "A test command");
         assert!(cmd.required);
         assert_eq!(cmd.implementation, "test_handler");
+        assert!(cmd.subcommands.is_empty());
+    }
+
+    #[test]
+    fn test_deserialize_constraints() {
+        let yaml = r#"
+            name: slice
+            description: "Slice"
+            implementation: "slice_handler"
+            constraints:
+              - check: "end > start"
+                message: "end must come after start"
+              - check: "end <= 100"
+        "#;
+
+        let cmd: CommandDefinition = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(cmd.constraints.len(), 2);
+        assert_eq!(cmd.constraints[0].check, "end > start");
+        assert_eq!(
+            cmd.constraints[0].message.as_deref(),
+            Some("end must come after start")
+        );
+        assert_eq!(cmd.constraints[1].message, None);
+    }
+
+    #[test]
+    fn test_deserialize_nested_subcommands() {
+        let yaml = r#"
+            name: remote
+            description: "Manage remotes"
+            implementation: "remote_handler"
+            subcommands:
+              - name: add
+                aliases: [a]
+                description: "Add a remote"
+                implementation: "remote_add_handler"
+                subcommands:
+                  - name: mirror
+                    description: "Add a mirror"
+                    implementation: "remote_add_mirror_handler"
+        "#;
+
+        let cmd: CommandDefinition = serde_yaml::from_str(yaml).unwrap();
+
+        assert_eq!(cmd.subcommands.len(), 1);
+        assert_eq!(cmd.subcommands[0].aliases, vec!["a"]);
+        assert_eq!(cmd.subcommands[0].subcommands[0].name, "mirror");
     }
 
     #[test]
@@ -538,6 +1277,7 @@ mod tests {
             description: "A secret value".to_string(),
             validation: vec![],
             secure: true,
+            ..Default::default()
         };
 
         let yaml = serde_yaml::to_string(&original).unwrap();
@@ -656,8 +1396,11 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "handler1".to_string(),
+                ..Default::default()
             }],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         };
 
         // Serialize to YAML
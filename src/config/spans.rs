@@ -0,0 +1,810 @@
+//! Source positions of configuration values
+//!
+//! Semantic errors found by [`crate::config::validator`] name a logical
+//! path such as `commands[0].options[1].default`. A [`SpanMap`] records
+//! where each such path is written in one file, and a [`SourceMap`] holds
+//! the span maps of the files the loader read, so that these errors can
+//! point at `file:line:column`.
+//!
+//! YAML and JSON span maps are built by a lightweight scanner rather than
+//! by the parser that deserializes the configuration. It follows block
+//! and flow collections, quoted and plain scalars; positions inside
+//! constructs it skips (block scalars, multi-line plain scalars) resolve
+//! to the nearest enclosing key. TOML spans come from the TOML parser.
+//!
+//! A [`SourceMap`] only scans a file the first time one of its positions
+//! is asked for, so loading a configuration never waits on the scanner.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::config::spans::SpanMap;
+//!
+//! let spans = SpanMap::from_yaml("commands:\n  - name: deploy\n    aliases: [d]\n");
+//!
+//! let alias = spans.get("commands[0].aliases[0]").unwrap();
+//! assert_eq!((alias.line, alias.column), (3, 15));
+//!
+//! // Paths that are not written in the file resolve to their parent
+//! let options = spans.locate("commands[0].options[2]").unwrap();
+//! assert_eq!((options.line, options.column), (2, 5));
+//! ```
+
+use crate::config::loader::Format;
+use crate::error::SourceLocation;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Position of a key or of a sequence item in a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Line, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+    /// Length in characters of the key, or of the item's first token
+    pub len: usize,
+}
+
+/// Positions of the keys and sequence items of one file, by path
+#[derive(Debug, Clone, Default)]
+pub struct SpanMap {
+    spans: HashMap<String, Span>,
+}
+
+impl SpanMap {
+    /// Scan a YAML document
+    ///
+    /// Documents that do not parse yield the spans found before the
+    /// first problem.
+    pub fn from_yaml(content: &str) -> Self {
+        let mut map = Self::default();
+        YamlScanner::new(content, &mut map.spans).document();
+        map
+    }
+
+    /// Scan a JSON document
+    ///
+    /// JSON is read as flow-style YAML.
+    pub fn from_json(content: &str) -> Self {
+        Self::from_yaml(content)
+    }
+
+    /// Scan a TOML document
+    ///
+    /// Documents that do not parse yield an empty map.
+    pub fn from_toml(content: &str) -> Self {
+        let mut map = Self::default();
+        if let Ok(table) = toml::de::DeTable::parse(content) {
+            map.toml_table("", table.get_ref(), content);
+        }
+        map
+    }
+
+    /// Scan a document of the given format
+    pub(crate) fn parse(format: Format, content: &str) -> Self {
+        match format {
+            Format::Yaml => Self::from_yaml(content),
+            Format::Json => Self::from_json(content),
+            Format::Toml => Self::from_toml(content),
+        }
+    }
+
+    /// Get the position of a path written in the file
+    pub fn get(&self, path: &str) -> Option<Span> {
+        self.spans.get(path).copied()
+    }
+
+    /// Get the position of a path, or of its nearest written parent
+    ///
+    /// `commands[0].options[1].default` falls back to
+    /// `commands[0].options[1]`, then `commands[0].options`, and so on.
+    pub fn locate(&self, path: &str) -> Option<Span> {
+        let mut path = path;
+        loop {
+            if let Some(span) = self.get(path) {
+                return Some(span);
+            }
+            path = &path[..path.rfind(['.', '['])?];
+        }
+    }
+
+    /// Number of recorded paths
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Whether no path was recorded
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    /// Record a position, keeping the first one of a path
+    fn record(&mut self, path: String, span: Span) {
+        self.spans.entry(path).or_insert(span);
+    }
+
+    fn toml_table(&mut self, path: &str, table: &toml::de::DeTable, content: &str) {
+        for (key, value) in table.iter() {
+            let child = child_path(path, key.get_ref());
+            if let Some(span) = byte_span(content, key.span()) {
+                self.record(child.clone(), span);
+            }
+            self.toml_value(&child, value, content);
+        }
+    }
+
+    fn toml_value(&mut self, path: &str, value: &toml::Spanned<toml::de::DeValue>, content: &str) {
+        match value.get_ref() {
+            toml::de::DeValue::Table(table) => self.toml_table(path, table, content),
+            toml::de::DeValue::Array(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    let child = format!("{}[{}]", path, idx);
+                    if let Some(span) = byte_span(content, item.span()) {
+                        self.record(child.clone(), span);
+                    }
+                    self.toml_value(&child, item, content);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Convert a byte range into a span, limited to its first line
+fn byte_span(content: &str, range: std::ops::Range<usize>) -> Option<Span> {
+    let before = content.get(..range.start)?;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let text = content.get(range)?;
+    Some(Span {
+        line: before.matches('\n').count() + 1,
+        column: before[line_start..].chars().count() + 1,
+        len: text.lines().next().unwrap_or("").chars().count().max(1),
+    })
+}
+
+/// Path of a key below `parent`
+fn child_path(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", parent, key)
+    }
+}
+
+/// Position of the YAML scanner inside flow collections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cursor {
+    line: usize,
+    col: usize,
+}
+
+/// Records the spans of a YAML document
+///
+/// Block structure is read line by line: sequence markers are blanked
+/// once read, so that an item's content parses as if it started its own
+/// line at a deeper indentation. Flow collections and quoted scalars are
+/// read character by character and may span lines.
+struct YamlScanner<'a> {
+    lines: Vec<Vec<char>>,
+    /// Next line to read in block context
+    line: usize,
+    map: &'a mut HashMap<String, Span>,
+}
+
+impl<'a> YamlScanner<'a> {
+    fn new(content: &str, map: &'a mut HashMap<String, Span>) -> Self {
+        Self {
+            lines: content.lines().map(|l| l.chars().collect()).collect(),
+            line: 0,
+            map,
+        }
+    }
+
+    fn record(&mut self, path: String, line: usize, col: usize, len: usize) {
+        self.map.entry(path).or_insert(Span {
+            line: line + 1,
+            column: col + 1,
+            len: len.max(1),
+        });
+    }
+
+    fn document(&mut self) {
+        while self.line < self.lines.len() {
+            let before = self.line;
+            self.node("", 0);
+            if self.line == before {
+                self.line += 1;
+            }
+        }
+    }
+
+    /// Indentation of a line with content, `None` for blank lines,
+    /// comments and document markers
+    fn indent(&self, line: usize) -> Option<usize> {
+        let chars = self.lines.get(line)?;
+        let indent = chars.iter().position(|c| *c != ' ')?;
+        let rest: String = chars[indent..].iter().collect();
+        if rest.starts_with('#') || rest.starts_with("---") || rest.starts_with("...") {
+            return None;
+        }
+        Some(indent)
+    }
+
+    /// Skip blank lines and comments; return the indentation of the next
+    /// line with content
+    fn next_indent(&mut self) -> Option<usize> {
+        while self.line < self.lines.len() {
+            if let Some(indent) = self.indent(self.line) {
+                return Some(indent);
+            }
+            self.line += 1;
+        }
+        None
+    }
+
+    fn is_sequence_item(&self, line: usize, col: usize) -> bool {
+        let chars = &self.lines[line];
+        chars.get(col) == Some(&'-') && chars.get(col + 1).map_or(true, |c| *c == ' ')
+    }
+
+    /// Read a key at `col` of `line`: its text, its length and the column
+    /// after its colon
+    fn key_at(&self, line: usize, col: usize) -> Option<(String, usize, usize)> {
+        let chars = &self.lines[line];
+        let (key, end) = match chars.get(col)? {
+            '"' | '\'' => {
+                let quote = chars[col];
+                let mut key = String::new();
+                let mut i = col + 1;
+                loop {
+                    match *chars.get(i)? {
+                        '\\' if quote == '"' => {
+                            key.push(*chars.get(i + 1)?);
+                            i += 2;
+                        }
+                        '\'' if quote == '\'' && chars.get(i + 1) == Some(&'\'') => {
+                            key.push('\'');
+                            i += 2;
+                        }
+                        c if c == quote => break,
+                        c => {
+                            key.push(c);
+                            i += 1;
+                        }
+                    }
+                }
+                (key, i + 1)
+            }
+            '[' | '{' | '|' | '>' | '&' | '*' | '!' | '#' => return None,
+            _ => {
+                let mut i = col;
+                while i < chars.len() {
+                    let ends_value = chars.get(i + 1).map_or(true, |c| *c == ' ');
+                    if chars[i] == ':' && ends_value {
+                        break;
+                    }
+                    if chars[i] == '#' && chars[i - 1] == ' ' {
+                        return None;
+                    }
+                    i += 1;
+                }
+                let key: String = chars[col..i].iter().collect();
+                (key.trim_end().to_string(), i)
+            }
+        };
+
+        let colon = end + chars[end..].iter().take_while(|c| **c == ' ').count();
+        if chars.get(colon) != Some(&':') || chars.get(colon + 1).is_some_and(|c| *c != ' ') {
+            return None;
+        }
+        Some((key, end - col, colon + 1))
+    }
+
+    /// Read the node starting at the next line with content, if it is
+    /// indented by at least `min_indent`
+    fn node(&mut self, path: &str, min_indent: usize) {
+        let Some(indent) = self.next_indent() else {
+            return;
+        };
+        if indent < min_indent {
+            return;
+        }
+        if self.is_sequence_item(self.line, indent) {
+            self.sequence(path, indent);
+        } else if self.key_at(self.line, indent).is_some() {
+            self.mapping(path, indent);
+        } else {
+            self.inline_value(path, indent);
+        }
+    }
+
+    fn mapping(&mut self, path: &str, indent: usize) {
+        while let Some(current) = self.next_indent() {
+            if current > indent {
+                // Continuation of a multi-line plain scalar
+                self.line += 1;
+                continue;
+            }
+            if current < indent {
+                break;
+            }
+            let Some((key, len, after)) = self.key_at(self.line, indent) else {
+                break;
+            };
+            let child = child_path(path, &key);
+            self.record(child.clone(), self.line, indent, len);
+            self.value(&child, indent, after);
+        }
+    }
+
+    fn sequence(&mut self, path: &str, indent: usize) {
+        let mut idx = 0;
+        while let Some(current) = self.next_indent() {
+            if current > indent {
+                self.line += 1;
+                continue;
+            }
+            if current < indent || !self.is_sequence_item(self.line, indent) {
+                break;
+            }
+            let item = format!("{}[{}]", path, idx);
+            idx += 1;
+
+            // Blank the marker: the item's content reads as indented
+            self.lines[self.line][indent] = ' ';
+            match self.indent(self.line) {
+                Some(col) => {
+                    let len = match self.key_at(self.line, col) {
+                        Some((_, len, _)) => len,
+                        None => self.token_len(self.line, col),
+                    };
+                    self.record(item.clone(), self.line, col, len);
+                }
+                None => self.record(item.clone(), self.line, indent, 1),
+            }
+            self.node(&item, indent + 1);
+        }
+    }
+
+    /// Read what follows the colon of a key at `indent`
+    fn value(&mut self, path: &str, indent: usize, after: usize) {
+        let chars = &self.lines[self.line];
+        let mut col = after;
+        loop {
+            while chars.get(col) == Some(&' ') {
+                col += 1;
+            }
+            // Anchors and tags precede the value
+            match chars.get(col) {
+                Some('&' | '!') => {
+                    while chars.get(col).is_some_and(|c| *c != ' ') {
+                        col += 1;
+                    }
+                }
+                _ => break,
+            }
+        }
+
+        match chars.get(col) {
+            None | Some('#') => {
+                self.line += 1;
+                match self.next_indent() {
+                    Some(next) if next == indent && self.is_sequence_item(self.line, next) => {
+                        self.sequence(path, indent)
+                    }
+                    _ => self.node(path, indent + 1),
+                }
+            }
+            Some('|' | '>') => {
+                // Block scalar: skip its more indented lines
+                self.line += 1;
+                while self.line < self.lines.len() {
+                    let blank = self.lines[self.line].iter().all(|c| *c == ' ');
+                    let nested = self.lines[self.line]
+                        .iter()
+                        .position(|c| *c != ' ')
+                        .is_some_and(|i| i > indent);
+                    if !blank && !nested {
+                        break;
+                    }
+                    self.line += 1;
+                }
+            }
+            Some(_) => {
+                let line = self.line;
+                self.flow(path, line, col);
+            }
+        }
+    }
+
+    /// Read a value that is not a block collection
+    fn inline_value(&mut self, path: &str, col: usize) {
+        let line = self.line;
+        self.flow(path, line, col);
+    }
+
+    /// Length of the plain token at `col`, up to a comment or the end of
+    /// the line
+    fn token_len(&self, line: usize, col: usize) -> usize {
+        let text: String = self.lines[line][col..].iter().collect();
+        let text = text.split(" #").next().unwrap_or("");
+        text.trim_end().chars().count()
+    }
+
+    /// Read a flow value starting at `col` of `line`, then continue block
+    /// reading after its last line
+    fn flow(&mut self, path: &str, line: usize, col: usize) {
+        let mut cursor = Cursor { line, col };
+        self.flow_value(path, &mut cursor);
+        self.line = cursor.line + 1;
+    }
+
+    fn peek(&self, cursor: &Cursor) -> Option<char> {
+        let chars = self.lines.get(cursor.line)?;
+        match chars.get(cursor.col) {
+            Some(c) => Some(*c),
+            None if cursor.line + 1 < self.lines.len() => Some('\n'),
+            None => None,
+        }
+    }
+
+    fn bump(&self, cursor: &mut Cursor) {
+        if cursor.col < self.lines[cursor.line].len() {
+            cursor.col += 1;
+        } else {
+            cursor.line += 1;
+            cursor.col = 0;
+        }
+    }
+
+    fn skip_space(&self, cursor: &mut Cursor) {
+        while let Some(c) = self.peek(cursor) {
+            match c {
+                ' ' | '\t' | '\n' | '\r' => self.bump(cursor),
+                '#' if self.after_space(cursor) => {
+                    cursor.col = self.lines[cursor.line].len();
+                }
+                _ => break,
+            }
+        }
+    }
+
+    fn flow_value(&mut self, path: &str, cursor: &mut Cursor) {
+        self.skip_space(cursor);
+        match self.peek(cursor) {
+            Some('{') => {
+                self.bump(cursor);
+                loop {
+                    self.skip_space(cursor);
+                    match self.peek(cursor) {
+                        None => return,
+                        Some('}') => {
+                            self.bump(cursor);
+                            return;
+                        }
+                        Some(',') => self.bump(cursor),
+                        Some(_) => {
+                            let start = *cursor;
+                            let key = self.flow_scalar(cursor);
+                            if *cursor == start {
+                                // Not a key the scanner understands: skip it
+                                self.bump(cursor);
+                                continue;
+                            }
+                            let child = child_path(path, &key);
+                            let len = self.len_from(&start, cursor);
+                            self.record(child.clone(), start.line, start.col, len);
+                            self.skip_space(cursor);
+                            if self.peek(cursor) == Some(':') {
+                                self.bump(cursor);
+                                self.flow_value(&child, cursor);
+                            }
+                        }
+                    }
+                }
+            }
+            Some('[') => {
+                self.bump(cursor);
+                let mut idx = 0;
+                loop {
+                    self.skip_space(cursor);
+                    match self.peek(cursor) {
+                        None => return,
+                        Some(']') => {
+                            self.bump(cursor);
+                            return;
+                        }
+                        Some(',') => self.bump(cursor),
+                        Some(c) => {
+                            let item = format!("{}[{}]", path, idx);
+                            idx += 1;
+                            let start = *cursor;
+                            self.flow_value(&item, cursor);
+                            if *cursor == start {
+                                self.bump(cursor);
+                                continue;
+                            }
+                            let len = if matches!(c, '{' | '[') {
+                                1
+                            } else {
+                                self.len_from(&start, cursor)
+                            };
+                            self.record(item, start.line, start.col, len);
+                        }
+                    }
+                }
+            }
+            Some(_) => {
+                self.flow_scalar(cursor);
+            }
+            None => {}
+        }
+    }
+
+    /// Length between two cursors on the same line, 1 otherwise
+    fn len_from(&self, start: &Cursor, end: &Cursor) -> usize {
+        if start.line == end.line {
+            end.col - start.col
+        } else {
+            1
+        }
+    }
+
+    /// Whether the character before the cursor is whitespace or a line
+    /// start, so that a `#` there begins a comment
+    fn after_space(&self, cursor: &Cursor) -> bool {
+        cursor.col == 0 || matches!(self.lines[cursor.line][cursor.col - 1], ' ' | '\t')
+    }
+
+    /// Read a quoted or plain scalar, returning its text
+    fn flow_scalar(&self, cursor: &mut Cursor) -> String {
+        let mut text = String::new();
+        match self.peek(cursor) {
+            Some(quote @ ('"' | '\'')) => {
+                self.bump(cursor);
+                while let Some(c) = self.peek(cursor) {
+                    self.bump(cursor);
+                    match c {
+                        '\\' if quote == '"' => {
+                            if let Some(escaped) = self.peek(cursor) {
+                                text.push(escaped);
+                                self.bump(cursor);
+                            }
+                        }
+                        '\'' if quote == '\'' && self.peek(cursor) == Some('\'') => {
+                            text.push('\'');
+                            self.bump(cursor);
+                        }
+                        c if c == quote => break,
+                        c => text.push(c),
+                    }
+                }
+            }
+            _ => {
+                while let Some(c) = self.peek(cursor) {
+                    let next = self.lines[cursor.line].get(cursor.col + 1).copied();
+                    let ends_key = c == ':' && next.map_or(true, |n| " ,]}".contains(n));
+                    let comment = c == '#' && self.after_space(cursor);
+                    if matches!(c, ',' | ']' | '}' | '\n') || ends_key || comment {
+                        break;
+                    }
+                    text.push(c);
+                    self.bump(cursor);
+                }
+            }
+        }
+        text.trim_end().to_string()
+    }
+}
+
+/// A configuration file read by the loader
+#[derive(Debug, Clone)]
+struct SourceFile {
+    path: PathBuf,
+    format: Format,
+    content: String,
+    /// Scanned on first use
+    spans: OnceLock<SpanMap>,
+}
+
+impl SourceFile {
+    fn spans(&self) -> &SpanMap {
+        self.spans
+            .get_or_init(|| SpanMap::parse(self.format, &self.content))
+    }
+}
+
+/// Span maps of the files of a configuration
+///
+/// Filled by the loader with the main file first, then the files it
+/// includes. Obtained from
+/// [`LayeredConfig::sources`](crate::config::layers::LayeredConfig::sources).
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+}
+
+impl SourceMap {
+    /// Record a file read by the loader
+    pub(crate) fn add(&mut self, path: &Path, format: Format, content: &str) {
+        self.files.push(SourceFile {
+            path: path.to_path_buf(),
+            format,
+            content: content.to_string(),
+            spans: OnceLock::new(),
+        });
+    }
+
+    /// Get the span map of a file
+    pub fn spans(&self, file: &Path) -> Option<&SpanMap> {
+        self.file(Some(file)).map(SourceFile::spans)
+    }
+
+    /// Locate a path of a file, or of the main file when `file` is `None`
+    ///
+    /// Falls back to the nearest written parent of the path, like
+    /// [`SpanMap::locate`].
+    pub fn locate(&self, file: Option<&Path>, path: &str) -> Option<SourceLocation> {
+        let source = self.file(file)?;
+        let span = source.spans().locate(path)?;
+        let text = source.content.lines().nth(span.line - 1)?;
+        Some(SourceLocation {
+            file: source.path.clone(),
+            line: span.line,
+            column: span.column,
+            len: span.len,
+            text: text.to_string(),
+        })
+    }
+
+    /// Whether no file was recorded
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Parse the recorded files, main file first
+    ///
+    /// Files that do not parse are skipped.
+    pub(crate) fn documents(&self) -> impl Iterator<Item = (&Path, serde_yaml::Value)> + '_ {
+        self.files.iter().filter_map(|source| {
+            let document = source.format.document(&source.content).ok()?;
+            Some((source.path.as_path(), document))
+        })
+    }
+
+    fn file(&self, file: Option<&Path>) -> Option<&SourceFile> {
+        match file {
+            Some(file) => self.files.iter().find(|source| source.path == file),
+            None => self.files.first(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"# Commands
+metadata:
+  version: "1.0.0"
+  prompt: app
+commands:
+  - name: deploy
+    aliases: [d, "ship"]
+    description: >
+      Deploy: everything
+    options:
+    - name: env
+      short: e
+      choices:
+        - dev
+        - prod   # default
+  - name: status
+    "implementation": status_handler
+"#;
+
+    fn position(map: &SpanMap, path: &str) -> (usize, usize, usize) {
+        let span = map
+            .get(path)
+            .unwrap_or_else(|| panic!("no span for {}", path));
+        (span.line, span.column, span.len)
+    }
+
+    #[test]
+    fn test_yaml_block_and_flow() {
+        let map = SpanMap::from_yaml(YAML);
+        assert_eq!(position(&map, "metadata.prompt"), (4, 3, 6));
+        assert_eq!(position(&map, "commands[0]"), (6, 5, 4));
+        assert_eq!(position(&map, "commands[0].name"), (6, 5, 4));
+        assert_eq!(position(&map, "commands[0].aliases"), (7, 5, 7));
+        assert_eq!(position(&map, "commands[0].aliases[0]"), (7, 15, 1));
+        assert_eq!(position(&map, "commands[0].aliases[1]"), (7, 18, 6));
+        assert_eq!(position(&map, "commands[0].options"), (10, 5, 7));
+        assert_eq!(position(&map, "commands[0].options[0].short"), (12, 7, 5));
+        assert_eq!(
+            position(&map, "commands[0].options[0].choices[1]"),
+            (15, 11, 4)
+        );
+        assert_eq!(position(&map, "commands[1].implementation"), (17, 5, 16));
+        // The block scalar is not read as keys
+        assert_eq!(map.get("commands[0].description.Deploy"), None);
+    }
+
+    #[test]
+    fn test_json() {
+        let json = "{\n  \"commands\": [\n    {\n      \"name\": \"a\",\n      \"aliases\": [\"b\"]\n    }\n  ]\n}\n";
+        let map = SpanMap::from_json(json);
+        assert_eq!(position(&map, "commands"), (2, 3, 10));
+        assert_eq!(position(&map, "commands[0]"), (3, 5, 1));
+        assert_eq!(position(&map, "commands[0].name"), (4, 7, 6));
+        assert_eq!(position(&map, "commands[0].aliases[0]"), (5, 19, 3));
+    }
+
+    #[test]
+    fn test_toml() {
+        let toml = "[metadata]\nversion = \"1\"\n\n[[commands]]\nname = \"a\"\naliases = [\"b\"]\n";
+        let map = SpanMap::from_toml(toml);
+        assert_eq!(position(&map, "metadata.version"), (2, 1, 7));
+        assert_eq!(position(&map, "commands[0].name"), (5, 1, 4));
+        assert_eq!(position(&map, "commands[0].aliases[0]"), (6, 12, 3));
+    }
+
+    #[test]
+    fn test_hash_inside_flow_scalars() {
+        let map = SpanMap::from_yaml("a: [x#y, z]\nb: {k: v#w, l: m}\n");
+        assert_eq!(position(&map, "a[0]"), (1, 5, 3));
+        assert_eq!(position(&map, "a[1]"), (1, 10, 1));
+        assert_eq!(position(&map, "b.l"), (2, 13, 1));
+
+        let map = SpanMap::from_yaml("aliases: [c#, f#] # keys\nnext: 1\n");
+        assert_eq!(position(&map, "aliases[1]"), (1, 15, 2));
+        assert_eq!(position(&map, "next"), (2, 1, 4));
+    }
+
+    #[test]
+    fn test_malformed_flow_collections_terminate() {
+        for yaml in [
+            "a: [#",
+            "a: {#",
+            "a: [#]\n",
+            "a: {#}\n",
+            "a: [: x]\n",
+            "a: {,: }\n",
+        ] {
+            SpanMap::from_yaml(yaml);
+        }
+    }
+
+    #[test]
+    fn test_locate_falls_back_to_parent() {
+        let map = SpanMap::from_yaml(YAML);
+        let span = map.locate("commands[1].arguments[0].name").unwrap();
+        assert_eq!((span.line, span.column), (16, 5));
+        assert!(map.locate("unknown").is_none());
+    }
+
+    #[test]
+    fn test_source_map_locates_files() {
+        let mut sources = SourceMap::default();
+        sources.add(Path::new("main.yaml"), Format::Yaml, YAML);
+        sources.add(
+            Path::new("more.yaml"),
+            Format::Yaml,
+            "commands:\n  - name: x\n",
+        );
+
+        let main = sources.locate(None, "commands[0].aliases[0]").unwrap();
+        assert_eq!(main.file, PathBuf::from("main.yaml"));
+        assert_eq!(main.text, "    aliases: [d, \"ship\"]");
+
+        let more = sources
+            .locate(Some(Path::new("more.yaml")), "commands[0].name")
+            .unwrap();
+        assert_eq!((more.line, more.column, more.len), (2, 5, 4));
+        assert!(sources
+            .locate(Some(Path::new("other.yaml")), "commands")
+            .is_none());
+    }
+}
@@ -12,7 +12,7 @@
 //! # Example
 //!
 //! ```
-//! use dynamic_cli::config::schema::{CommandsConfig, Metadata};
+//! use dynamic_cli::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
 //! use dynamic_cli::config::validator::validate_config;
 //!
 //! # let config = CommandsConfig {
@@ -22,7 +22,9 @@
 //!         prompt_suffix: " >".to_string()
 //!         },
 //!       commands: vec![],
-//!       global_options: vec![]
+//!       global_options: vec![],
+//!       include: vec![],
+//!       schema_version: SCHEMA_VERSION,
 //! };
 //! // After loading configuration
 //! validate_config(&config)?;
@@ -30,21 +32,29 @@
 //! ```
 
 use crate::config::schema::{
-    ArgumentDefinition, ArgumentType, CommandDefinition, CommandsConfig, OptionDefinition,
-    ValidationRule,
+    ArgumentDefinition, ArgumentType, CommandDefinition, CommandsConfig, EnumVariant,
+    OptionDefinition, ValidationRule, VALIDATION_RULES, VALIDATION_RULE_KEYS,
 };
-use crate::error::{ConfigError, Result};
-use std::collections::{HashMap, HashSet};
+use crate::config::spans::SourceMap;
+use crate::error::{ConfigError, Diagnostic, Result, SourceLocation};
+use crate::parser::global_options::GLOBAL_OPTION_PREFIX;
+use crate::validator::constraint_validator::parse_constraint;
+use crate::validator::string_validator::compile_pattern;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Validate the entire configuration
 ///
 /// Performs comprehensive validation of the configuration structure,
 /// checking for:
-/// - Duplicate command names and aliases
+/// - Duplicate command names and aliases (among siblings, at every level)
 /// - Valid argument types
 /// - Consistent validation rules
 /// - Option/argument naming conflicts
 ///
+/// Returns the first problem found; use [`diagnose_config`] to list them
+/// all at once.
+///
 /// # Arguments
 ///
 /// * `config` - The configuration to validate
@@ -58,7 +68,7 @@ use std::collections::{HashMap, HashSet};
 /// # Example
 ///
 /// ```
-/// use dynamic_cli::config::schema::{CommandsConfig, Metadata};
+/// use dynamic_cli::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
 /// use dynamic_cli::config::validator::validate_config;
 ///
 /// # let config = CommandsConfig {
@@ -68,65 +78,447 @@ use std::collections::{HashMap, HashSet};
 ///         prompt_suffix: " >".to_string()
 ///         },
 ///       commands: vec![],
-///       global_options: vec![]
+///       global_options: vec![],
+///       include: vec![],
+///       schema_version: SCHEMA_VERSION,
 /// };
 /// // After loading configuration
 /// validate_config(&config)?;
 /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 /// ```
 pub fn validate_config(config: &CommandsConfig) -> Result<()> {
-    // Track all command names and aliases to detect duplicates
-    let mut seen_names: HashSet<String> = HashSet::new();
+    validate_config_in(config, &SourceMap::default())
+}
 
-    for (idx, command) in config.commands.iter().enumerate() {
-        // Validate the command itself
-        validate_command(command)?;
+/// Validate the entire configuration, locating the first error in its file
+///
+/// Same as [`validate_config`], but an error found in one of the files of
+/// `sources` is wrapped in [`ConfigError::Located`], whose display shows
+/// the offending line. [`LayeredConfig::validate`] calls this with the
+/// files the loader read.
+///
+/// [`LayeredConfig::validate`]: crate::config::layers::LayeredConfig::validate
+///
+/// # Example
+///
+/// ```no_run
+/// use dynamic_cli::config::layers::load_layered;
+/// use dynamic_cli::config::validator::validate_config_in;
+/// use dynamic_cli::error::display_error;
+///
+/// let layered = load_layered("commands.yaml", &[])?;
+/// if let Err(e) = validate_config_in(&layered.config, layered.sources()) {
+///     // Error: Duplicate command name or alias: 'd'
+///     //   --> commands.yaml:12:15
+///     //    |
+///     // 12 |     aliases: [d]
+///     //    |               ^
+///     display_error(&e);
+/// }
+/// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+/// ```
+pub fn validate_config_in(config: &CommandsConfig, sources: &SourceMap) -> Result<()> {
+    let mut report = Report::default();
+    check_config(config, &mut report);
+    report.into_result(sources)
+}
 
-        // Check for duplicate command name
-        if !seen_names.insert(command.name.clone()) {
-            return Err(ConfigError::DuplicateCommand {
-                name: command.name.clone(),
-                suggestion: None,
-            }
-            .into());
+/// Check the entire configuration and list every problem found
+///
+/// Runs the same checks as [`validate_config`] but does not stop at the
+/// first problem. Besides errors, the list contains warnings for
+/// definitions that are accepted but probably not intended, such as an
+/// empty description or a required option with a default value.
+///
+/// Diagnostics are listed in configuration order. A later diagnostic may
+/// be a consequence of an earlier one, e.g. an argument named after a
+/// duplicated option.
+///
+/// # Arguments
+///
+/// * `config` - The configuration to check
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
+/// use dynamic_cli::config::validator::diagnose_config;
+/// use dynamic_cli::error::format_diagnostics;
+///
+/// # let config = CommandsConfig {
+/// #     metadata: Metadata {
+/// #         version: "1.0.0".to_string(),
+/// #         prompt: "test".to_string(),
+/// #         prompt_suffix: " >".to_string(),
+/// #     },
+/// #     commands: vec![],
+/// #     global_options: vec![],
+/// #     include: vec![],
+/// #     schema_version: SCHEMA_VERSION,
+/// # };
+/// // After loading configuration
+/// let diagnostics = diagnose_config(&config);
+/// if diagnostics.iter().any(|d| d.is_error()) {
+///     eprint!("{}", format_diagnostics(&diagnostics));
+/// }
+/// # assert!(diagnostics.is_empty());
+/// ```
+pub fn diagnose_config(config: &CommandsConfig) -> Vec<Diagnostic> {
+    diagnose_config_in(config, &SourceMap::default())
+}
+
+/// Check the entire configuration, locating each problem in its file
+///
+/// Same as [`diagnose_config`], with the
+/// [`location`](Diagnostic::location) of each diagnostic found in one of
+/// the files of `sources`. The files themselves are checked as well:
+/// keys of validation rules that the loader ignored, unknown or belonging
+/// to a second rule of the same entry, are reported as warnings, after
+/// the other diagnostics.
+pub fn diagnose_config_in(config: &CommandsConfig, sources: &SourceMap) -> Vec<Diagnostic> {
+    let mut report = Report::default();
+    check_config(config, &mut report);
+    check_ignored_keys(sources, &mut report);
+    let locations: Vec<Option<SourceLocation>> = (0..report.diagnostics.len())
+        .map(|idx| report.locate(idx, sources))
+        .collect();
+    let mut diagnostics = report.diagnostics;
+    for (diagnostic, location) in diagnostics.iter_mut().zip(locations) {
+        diagnostic.location = location;
+    }
+    diagnostics
+}
+
+/// Problems found while walking a configuration
+///
+/// Checks record every problem instead of returning at the first one. The
+/// fail-fast entry points return the first recorded error unchanged.
+#[derive(Default)]
+struct Report {
+    diagnostics: Vec<Diagnostic>,
+    /// Where each diagnostic is written, for [`SourceMap::locate`]
+    anchors: Vec<Anchor>,
+    /// First error, with the index of its diagnostic
+    first_error: Option<(usize, ConfigError)>,
+}
+
+/// Position of a problem in the configuration files
+///
+/// Checks of a command name their paths after the command (e.g.
+/// `deploy.options[0]`); anchors hold the path in the document instead
+/// (e.g. `commands[2].options[0]`).
+struct Anchor {
+    /// Included file, `None` for the main file
+    file: Option<PathBuf>,
+    path: Option<String>,
+}
+
+impl Report {
+    /// Record an error, located by its own path
+    fn error(&mut self, error: ConfigError) {
+        let diagnostic = Diagnostic::from_error(&error);
+        self.push_error(diagnostic, error);
+    }
+
+    /// Record an error that carries no path of its own, located at `path`
+    fn error_at(&mut self, path: &str, error: ConfigError) {
+        let mut diagnostic = Diagnostic::from_error(&error);
+        diagnostic.path.get_or_insert_with(|| path.to_string());
+        self.push_error(diagnostic, error);
+    }
+
+    fn push_error(&mut self, diagnostic: Diagnostic, error: ConfigError) {
+        if self.first_error.is_none() {
+            self.first_error = Some((self.diagnostics.len(), error));
         }
+        self.push(diagnostic);
+    }
 
-        // Check for duplicate aliases
-        for alias in &command.aliases {
-            if !seen_names.insert(alias.clone()) {
-                return Err(ConfigError::DuplicateCommand {
-                    name: alias.clone(),
-                    suggestion: None,
+    /// Record a warning
+    fn warning(&mut self, diagnostic: Diagnostic) {
+        self.push(diagnostic);
+    }
+
+    fn push(&mut self, diagnostic: Diagnostic) {
+        self.anchors.push(Anchor {
+            file: None,
+            path: diagnostic.path.clone(),
+        });
+        self.diagnostics.push(diagnostic);
+    }
+
+    /// Anchor the paths recorded since `start` under `scope` at `path`
+    ///
+    /// `deploy.options[0]` becomes `commands[2].options[0]` for the scope
+    /// `deploy` of the command at `commands[2]`.
+    fn rescope(&mut self, start: usize, scope: &str, path: &str) {
+        for anchor in &mut self.anchors[start..] {
+            let Some(ref anchored) = anchor.path else {
+                continue;
+            };
+            if let Some(rest) = anchored.strip_prefix(scope) {
+                if rest.is_empty() || rest.starts_with(['.', '[']) {
+                    anchor.path = Some(format!("{}{}", path, rest));
                 }
-                .into());
             }
         }
+    }
 
-        // Validate that command has a non-empty name
-        if command.name.trim().is_empty() {
-            return Err(ConfigError::InvalidSchema {
-                reason: "Command name cannot be empty".to_string(),
-                path: Some(format!("commands[{}].name", idx)),
-                suggestion: None,
-            }
-            .into());
+    /// Locate a diagnostic in `sources`
+    fn locate(&self, idx: usize, sources: &SourceMap) -> Option<SourceLocation> {
+        let anchor = &self.anchors[idx];
+        sources.locate(anchor.file.as_deref(), anchor.path.as_deref()?)
+    }
+
+    /// Attribute the problems recorded since `start` to `file`
+    fn in_file(&mut self, start: usize, file: &Path) {
+        let moved: Vec<Diagnostic> = self
+            .diagnostics
+            .drain(start..)
+            .map(|d| d.in_file(file))
+            .collect();
+        self.diagnostics.extend(moved);
+        for anchor in &mut self.anchors[start..] {
+            anchor.file.get_or_insert_with(|| file.to_path_buf());
         }
 
-        // Validate that implementation is specified
-        if command.implementation.trim().is_empty() {
-            return Err(ConfigError::InvalidSchema {
-                reason: "Command implementation cannot be empty".to_string(),
-                path: Some(format!("commands[{}].implementation", idx)),
-                suggestion: None,
+        if let Some((idx, error)) = self.first_error.take() {
+            let error = if idx >= start {
+                error.in_file(file)
+            } else {
+                error
+            };
+            self.first_error = Some((idx, error));
+        }
+    }
+
+    /// The first error, if any, located in `sources` when found there
+    fn into_result(mut self, sources: &SourceMap) -> Result<()> {
+        let Some((idx, error)) = self.first_error.take() else {
+            return Ok(());
+        };
+        match self.locate(idx, sources) {
+            Some(location) => Err(ConfigError::Located {
+                error: Box::new(error),
+                location,
             }
-            .into());
+            .into()),
+            None => Err(error.into()),
         }
     }
+}
+
+/// Check commands and global options
+fn check_config(config: &CommandsConfig, report: &mut Report) {
+    validate_command_list(&config.commands, "commands", report);
 
     // Validate global options
-    validate_options(&config.global_options, "global_options")?;
+    let start = report.diagnostics.len();
+    validate_options(&config.global_options, "global_options", report);
+    validate_option_flags(&config.global_options, "global_options", report);
+    report.rescope(start, "global_options.options", "global_options");
+}
 
-    Ok(())
+/// Warn about ignored keys in the validation rules of the source files
+fn check_ignored_keys(sources: &SourceMap, report: &mut Report) {
+    for (idx, (file, document)) in sources.documents().enumerate() {
+        let start = report.diagnostics.len();
+        ignored_rule_keys(&document["commands"], "commands", report);
+        // Paths of the main file are not prefixed, like those of the
+        // other checks
+        if idx > 0 {
+            report.in_file(start, file);
+        }
+    }
+}
+
+/// Warn about the keys of validation rules that the loader ignores
+///
+/// These are keys that name no rule, and the keys of every rule but the
+/// first in an entry that mixes several rules.
+///
+/// # Arguments
+///
+/// * `commands` - A list of commands in a parsed document
+/// * `path` - Location of the list in the document
+/// * `report` - Where problems are recorded
+fn ignored_rule_keys(commands: &serde_yaml::Value, path: &str, report: &mut Report) {
+    fn items(value: &serde_yaml::Value) -> &[serde_yaml::Value] {
+        value.as_sequence().map_or(&[], Vec::as_slice)
+    }
+
+    for (idx, command) in items(commands).iter().enumerate() {
+        let command_path = format!("{}[{}]", path, idx);
+        for (arg_idx, argument) in items(&command["arguments"]).iter().enumerate() {
+            for (rule_idx, rule) in items(&argument["validation"]).iter().enumerate() {
+                let Some(rule) = rule.as_mapping() else {
+                    continue;
+                };
+                let rule_path = format!(
+                    "{}.arguments[{}].validation[{}]",
+                    command_path, arg_idx, rule_idx
+                );
+                mixed_rule_keys(rule, &rule_path, report);
+                for key in rule.keys().filter_map(serde_yaml::Value::as_str) {
+                    if VALIDATION_RULE_KEYS.contains(&key) {
+                        continue;
+                    }
+                    report.warning(
+                        Diagnostic::warning(
+                            format!("Unknown key '{}' in validation rule is ignored", key),
+                            format!("{}.{}", rule_path, key),
+                        )
+                        .with_suggestion(format!(
+                            "Check the spelling; rules use the keys {}.",
+                            VALIDATION_RULE_KEYS.join(", ")
+                        )),
+                    );
+                }
+            }
+        }
+        ignored_rule_keys(
+            &command["subcommands"],
+            &format!("{}.subcommands", command_path),
+            report,
+        );
+    }
+}
+
+/// Warn about the keys of the rules that a mixed validation entry drops
+///
+/// The loader keeps the first rule of the entry, in the order of
+/// [`VALIDATION_RULES`].
+fn mixed_rule_keys(rule: &serde_yaml::Mapping, rule_path: &str, report: &mut Report) {
+    let mut present = VALIDATION_RULES
+        .iter()
+        .filter(|keys| keys.iter().any(|key| rule.contains_key(*key)));
+    let Some(kept) = present.next() else {
+        return;
+    };
+    for keys in present {
+        for key in keys.iter().filter(|key| rule.contains_key(**key)) {
+            report.warning(
+                Diagnostic::warning(
+                    format!(
+                        "Key '{}' in validation rule is ignored: the entry already holds a '{}' rule",
+                        key, kept[0]
+                    ),
+                    format!("{}.{}", rule_path, key),
+                )
+                .with_suggestion("Give each rule its own list entry.".to_string()),
+            );
+        }
+    }
+}
+
+/// Validate a list of sibling commands and, recursively, their subcommands
+///
+/// Names and aliases must be unique among siblings only: `remote add` and
+/// `plugin add` do not conflict.
+///
+/// # Arguments
+///
+/// * `commands` - Sibling command definitions
+/// * `path` - Location of the list in the configuration (for errors)
+/// * `report` - Where problems are recorded
+fn validate_command_list(commands: &[CommandDefinition], path: &str, report: &mut Report) {
+    // Track all command names and aliases to detect duplicates
+    let mut seen_names: HashSet<String> = HashSet::new();
+
+    for (idx, command) in commands.iter().enumerate() {
+        // Position in the command's own file: commands of included files
+        // are appended after those of the main file
+        let file_idx = commands[..idx]
+            .iter()
+            .filter(|other| other.source == command.source)
+            .count();
+        let command_path = format!("{}[{}]", path, file_idx);
+        let start = report.diagnostics.len();
+        validate_listed_command(command, &command_path, report);
+
+        // Check for duplicate command names and aliases
+        let names = std::iter::once((&command.name, format!("{}.name", command_path))).chain(
+            (command.aliases.iter().enumerate())
+                .map(|(i, alias)| (alias, format!("{}.aliases[{}]", command_path, i))),
+        );
+        for (name, name_path) in names {
+            if !seen_names.insert(name.clone()) {
+                report.error_at(
+                    &name_path,
+                    ConfigError::DuplicateCommand {
+                        name: name.clone(),
+                        files: vec![],
+                        suggestion: None,
+                    },
+                );
+            }
+        }
+
+        if let Some(ref file) = command.source {
+            report.in_file(start, file);
+        }
+    }
+}
+
+/// Validate one command of a list, and its subcommands
+///
+/// # Arguments
+///
+/// * `command` - The command definition
+/// * `path` - Location of the command in the configuration (for errors)
+/// * `report` - Where problems are recorded
+fn validate_listed_command(command: &CommandDefinition, path: &str, report: &mut Report) {
+    // Validate the command itself
+    let start = report.diagnostics.len();
+    check_command(command, report);
+    report.rescope(start, &command.name, path);
+
+    // Validate that command has a non-empty name
+    if command.name.trim().is_empty() {
+        report.error(ConfigError::InvalidSchema {
+            reason: "Command name cannot be empty".to_string(),
+            path: Some(format!("{}.name", path)),
+            suggestion: None,
+        });
+    }
+
+    // Names are joined with spaces into subcommand paths
+    if let Some(name) = std::iter::once(&command.name)
+        .chain(&command.aliases)
+        .find(|name| name.contains(char::is_whitespace))
+    {
+        report.error(ConfigError::InvalidSchema {
+            reason: format!("Command name '{}' cannot contain whitespace", name),
+            path: Some(format!("{}.name", path)),
+            suggestion: Some("Declare nested commands under 'subcommands' instead.".to_string()),
+        });
+    }
+
+    // Validate that implementation is specified
+    if command.implementation.trim().is_empty() {
+        report.error(ConfigError::InvalidSchema {
+            reason: "Command implementation cannot be empty".to_string(),
+            path: Some(format!("{}.implementation", path)),
+            suggestion: None,
+        });
+    }
+
+    if command.description.trim().is_empty() {
+        report.warning(
+            Diagnostic::warning(
+                format!("Command '{}' has no description", command.name),
+                format!("{}.description", path),
+            )
+            .with_suggestion("Describe the command; the text is shown in help."),
+        );
+    }
+
+    // Validate subcommands
+    validate_command_list(
+        &command.subcommands,
+        &format!("{}.subcommands", path),
+        report,
+    );
 }
 
 /// Validate a single command definition
@@ -136,6 +528,9 @@ pub fn validate_config(config: &CommandsConfig) -> Result<()> {
 /// - No duplicate argument/option names
 /// - Validation rules are consistent with types
 /// - Required arguments come before optional ones
+/// - Only the last argument accepts multiple values
+/// - Groups and relations reference existing names, without `requires` cycles
+/// - No name uses the prefix reserved for global options
 ///
 /// # Arguments
 ///
@@ -162,26 +557,61 @@ pub fn validate_config(config: &CommandsConfig) -> Result<()> {
 ///     arguments: vec![],
 ///     options: vec![],
 ///     implementation: "test_handler".to_string(),
+///     ..Default::default()
 /// };
 ///
 /// validate_command(&cmd)?;
 /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 /// ```
 pub fn validate_command(cmd: &CommandDefinition) -> Result<()> {
+    let mut report = Report::default();
+    check_command(cmd, &mut report);
+    report.into_result(&SourceMap::default())
+}
+
+/// Record the problems of a single command definition
+///
+/// See [`validate_command`] for the checks performed.
+fn check_command(cmd: &CommandDefinition, report: &mut Report) {
     // Validate arguments
-    validate_argument_types(&cmd.arguments)?;
-    validate_argument_ordering(&cmd.arguments, &cmd.name)?;
-    validate_argument_names(&cmd.arguments, &cmd.name)?;
-    validate_argument_validation_rules(&cmd.arguments, &cmd.name)?;
+    check_argument_types(&cmd.arguments, report);
+    validate_argument_ordering(&cmd.arguments, &cmd.name, report);
+    validate_argument_names(&cmd.arguments, &cmd.name, report);
+    validate_argument_validation_rules(&cmd.arguments, &cmd.name, report);
+    validate_variadic_arguments(&cmd.arguments, &cmd.name, report);
+    for (idx, arg) in cmd.arguments.iter().enumerate() {
+        validate_variants(
+            &arg.name,
+            arg.arg_type,
+            &arg.variants,
+            None,
+            &format!("{}.arguments[{}]", cmd.name, idx),
+            report,
+        );
+        if arg.description.trim().is_empty() {
+            report.warning(
+                Diagnostic::warning(
+                    format!("Argument '{}' has no description", arg.name),
+                    format!("{}.arguments[{}].description", cmd.name, idx),
+                )
+                .with_suggestion("Describe the argument; the text is shown in help."),
+            );
+        }
+    }
 
     // Validate options
-    validate_options(&cmd.options, &cmd.name)?;
-    validate_option_flags(&cmd.options, &cmd.name)?;
+    validate_options(&cmd.options, &cmd.name, report);
+    validate_option_flags(&cmd.options, &cmd.name, report);
 
     // Check for name conflicts between arguments and options
-    check_name_conflicts(&cmd.arguments, &cmd.options, &cmd.name)?;
+    check_name_conflicts(&cmd.arguments, &cmd.options, &cmd.name, report);
+    check_reserved_names(&cmd.arguments, &cmd.options, &cmd.name, report);
 
-    Ok(())
+    // Check groups, `requires` and `conflicts_with`
+    validate_relations(cmd, report);
+
+    // Check cross-field constraints
+    validate_constraints(cmd, report);
 }
 
 /// Validate argument types
@@ -209,6 +639,7 @@ pub fn validate_command(cmd: &CommandDefinition) -> Result<()> {
 ///         description: "Count".to_string(),
 ///         validation: vec![],
 ///         secure: false,
+///         ..Default::default()
 ///     }
 /// ];
 ///
@@ -216,6 +647,13 @@ pub fn validate_command(cmd: &CommandDefinition) -> Result<()> {
 /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 /// ```
 pub fn validate_argument_types(args: &[ArgumentDefinition]) -> Result<()> {
+    let mut report = Report::default();
+    check_argument_types(args, &mut report);
+    report.into_result(&SourceMap::default())
+}
+
+/// Record the problems of argument types (none so far)
+fn check_argument_types(args: &[ArgumentDefinition], _report: &mut Report) {
     // Currently all ArgumentType variants are valid
     // This function exists for future extensibility
 
@@ -224,8 +662,6 @@ pub fn validate_argument_types(args: &[ArgumentDefinition]) -> Result<()> {
         // (In the current implementation, all enum variants are valid)
         let _ = arg.arg_type;
     }
-
-    Ok(())
 }
 
 /// Validate that required arguments come before optional ones
@@ -237,64 +673,391 @@ pub fn validate_argument_types(args: &[ArgumentDefinition]) -> Result<()> {
 ///
 /// * `args` - List of argument definitions
 /// * `context` - Context string for error messages (command name)
-fn validate_argument_ordering(args: &[ArgumentDefinition], context: &str) -> Result<()> {
+/// * `report` - Where problems are recorded
+fn validate_argument_ordering(args: &[ArgumentDefinition], context: &str, report: &mut Report) {
     let mut seen_optional = false;
 
     for (idx, arg) in args.iter().enumerate() {
         if !arg.required {
             seen_optional = true;
         } else if seen_optional {
-            return Err(ConfigError::InvalidSchema {
+            report.error(ConfigError::InvalidSchema {
                 reason: format!(
                     "Required argument '{}' cannot come after optional arguments",
                     arg.name
                 ),
                 path: Some(format!("{}.arguments[{}]", context, idx)),
                 suggestion: None,
+            });
+        }
+    }
+}
+
+/// Validate `multiple`, `min_values` and `max_values` on arguments
+///
+/// Only the last argument may be variadic, since it takes all the
+/// remaining positional tokens.
+fn validate_variadic_arguments(args: &[ArgumentDefinition], context: &str, report: &mut Report) {
+    for (idx, arg) in args.iter().enumerate() {
+        let path = format!("{}.arguments[{}]", context, idx);
+        if arg.multiple && idx + 1 != args.len() {
+            report.error(ConfigError::InvalidSchema {
+                reason: format!(
+                    "Argument '{}' accepts multiple values but is not the last argument",
+                    arg.name
+                ),
+                path: Some(path.clone()),
+                suggestion: Some(
+                    "Move the variadic argument to the end, or use a repeatable option."
+                        .to_string(),
+                ),
+            });
+        }
+        validate_value_bounds(
+            &arg.name,
+            arg.multiple,
+            arg.min_values,
+            arg.max_values,
+            &path,
+            report,
+        );
+    }
+}
+
+/// Validate the value count bounds of an argument or option
+fn validate_value_bounds(
+    name: &str,
+    multiple: bool,
+    min: Option<usize>,
+    max: Option<usize>,
+    path: &str,
+    report: &mut Report,
+) {
+    if !multiple && (min.is_some() || max.is_some()) {
+        report.error(ConfigError::InvalidSchema {
+            reason: format!(
+                "'{}' sets min_values/max_values but does not accept multiple values",
+                name
+            ),
+            path: Some(path.to_string()),
+            suggestion: Some("Add 'multiple: true'.".to_string()),
+        });
+        return;
+    }
+
+    if max == Some(0) || matches!((min, max), (Some(min), Some(max)) if min > max) {
+        report.error_at(
+            path,
+            ConfigError::Inconsistency {
+                details: format!(
+                    "Invalid value count for '{}': min_values {} and max_values {}",
+                    name,
+                    min.map_or("-".to_string(), |m| m.to_string()),
+                    max.map_or("-".to_string(), |m| m.to_string())
+                ),
+                suggestion: Some(
+                    "max_values must be at least 1 and not lower than min_values.".to_string(),
+                ),
+            },
+        );
+    }
+}
+
+/// Validate the variants of an `enum` argument or option
+///
+/// Enums must declare at least one variant, and variant names and
+/// aliases must be unique. A default must name a variant.
+fn validate_variants(
+    name: &str,
+    arg_type: ArgumentType,
+    variants: &[EnumVariant],
+    default: Option<&str>,
+    path: &str,
+    report: &mut Report,
+) {
+    if arg_type != ArgumentType::Enum {
+        if !variants.is_empty() {
+            report.error_at(
+                path,
+                ConfigError::Inconsistency {
+                    details: format!(
+                        "Variants can only be used with 'enum' type, but '{}' has type '{}'",
+                        name,
+                        arg_type.as_str()
+                    ),
+                    suggestion: Some("Set the type to 'enum'.".to_string()),
+                },
+            );
+        }
+        return;
+    }
+
+    if variants.is_empty() {
+        report.error(ConfigError::InvalidSchema {
+            reason: format!("Enum '{}' must declare at least one variant", name),
+            path: Some(format!("{}.variants", path)),
+            suggestion: None,
+        });
+        return;
+    }
+
+    let mut seen = HashSet::new();
+    for (idx, variant) in variants.iter().enumerate() {
+        for value in std::iter::once(&variant.name).chain(&variant.aliases) {
+            if value.is_empty() {
+                report.error(ConfigError::InvalidSchema {
+                    reason: format!("Empty variant name or alias in '{}'", name),
+                    path: Some(format!("{}.variants[{}]", path, idx)),
+                    suggestion: None,
+                });
+            } else if !seen.insert(value.as_str()) {
+                report.error(ConfigError::InvalidSchema {
+                    reason: format!("Duplicate variant name or alias '{}' in '{}'", value, name),
+                    path: Some(format!("{}.variants[{}]", path, idx)),
+                    suggestion: None,
+                });
+            }
+        }
+    }
+
+    if let Some(default) = default {
+        if EnumVariant::find(variants, default).is_none() {
+            let names: Vec<&str> = variants.iter().map(|v| v.name.as_str()).collect();
+            report.error_at(
+                path,
+                ConfigError::Inconsistency {
+                    details: format!(
+                        "Default value '{}' for '{}' is not a variant: [{}]",
+                        default,
+                        name,
+                        names.join(", ")
+                    ),
+                    suggestion: None,
+                },
+            );
+        }
+    }
+}
+
+/// Validate groups, `requires` and `conflicts_with` of a command
+///
+/// Every referenced name must be an argument or option of the command.
+/// `requires` must not contain cycles, and must not contradict a conflict
+/// or an exclusive group.
+fn validate_relations(cmd: &CommandDefinition, report: &mut Report) {
+    let context = &cmd.name;
+    let names: Vec<String> = cmd
+        .arguments
+        .iter()
+        .map(|a| a.name.clone())
+        .chain(cmd.options.iter().map(|o| o.name.clone()))
+        .collect();
+    let is_required = |name: &str| {
+        cmd.arguments.iter().any(|a| a.name == name && a.required)
+            || cmd.options.iter().any(|o| o.name == name && o.required)
+    };
+    let check_name = |name: &str, path: String, report: &mut Report| {
+        if names.iter().any(|n| n == name) {
+            return;
+        }
+        let similar = crate::error::find_similar_strings(name, &names, 1);
+        report.error(ConfigError::InvalidSchema {
+            reason: format!("Unknown argument or option '{}'", name),
+            path: Some(path),
+            suggestion: Some(match similar.first() {
+                Some(similar) => format!("Did you mean '{}'?", similar),
+                None => format!("Declare '{}' in the command's arguments or options.", name),
+            }),
+        });
+    };
+
+    let mut group_names: HashSet<&str> = HashSet::new();
+    for (idx, group) in cmd.groups.iter().enumerate() {
+        let path = format!("{}.groups[{}]", context, idx);
+        if group.name.trim().is_empty() || !group_names.insert(&group.name) {
+            report.error(ConfigError::InvalidSchema {
+                reason: format!("Group name '{}' is empty or duplicated", group.name),
+                path: Some(path.clone()),
+                suggestion: None,
+            });
+        }
+        let unique: HashSet<&String> = group.members.iter().collect();
+        if group.members.len() < 2 || unique.len() != group.members.len() {
+            report.error(ConfigError::InvalidSchema {
+                reason: format!("Group '{}' needs at least two distinct members", group.name),
+                path: Some(format!("{}.members", path)),
+                suggestion: None,
+            });
+        }
+        for member in &group.members {
+            check_name(member, format!("{}.members", path), report);
+            if is_required(member) {
+                report.error_at(
+                    &path,
+                    ConfigError::Inconsistency {
+                        details: format!(
+                            "'{}' is required but belongs to the exclusive group '{}'",
+                            member, group.name
+                        ),
+                        suggestion: Some(format!(
+                            "Make '{}' optional and set 'required: true' on the group.",
+                            member
+                        )),
+                    },
+                );
+            }
+        }
+    }
+
+    for (field, relations) in [
+        ("requires", &cmd.requires),
+        ("conflicts_with", &cmd.conflicts_with),
+    ] {
+        for (name, others) in relations {
+            let path = format!("{}.{}.{}", context, field, name);
+            check_name(name, path.clone(), report);
+            for other in others {
+                check_name(other, path.clone(), report);
+                if other == name {
+                    report.error_at(
+                        &path,
+                        ConfigError::Inconsistency {
+                            details: format!("'{}' cannot be listed in its own {}", name, field),
+                            suggestion: None,
+                        },
+                    );
+                }
             }
-            .into());
         }
     }
 
-    Ok(())
+    // A requirement must not contradict a conflict or an exclusive group
+    for (name, required) in &cmd.requires {
+        for other in required {
+            let conflicting = |a: &String, b: &String| {
+                cmd.conflicts_with
+                    .get(a)
+                    .is_some_and(|others| others.contains(b))
+            };
+            let grouped = cmd
+                .groups
+                .iter()
+                .find(|g| g.members.contains(name) && g.members.contains(other));
+            if conflicting(name, other) || conflicting(other, name) || grouped.is_some() {
+                report.error_at(
+                    &format!("{}.requires.{}", context, name),
+                    ConfigError::Inconsistency {
+                        details: format!(
+                            "'{}' requires '{}' but the two cannot be used together",
+                            name, other
+                        ),
+                        suggestion: None,
+                    },
+                );
+            }
+        }
+    }
+
+    if let Some(cycle) = find_requires_cycle(&cmd.requires) {
+        report.error_at(
+            &format!("{}.requires", context),
+            ConfigError::Inconsistency {
+                details: format!("Cyclic requirement: {}", cycle.join(" -> ")),
+                suggestion: Some(
+                    "Declare the requirement in one direction only, or group the names."
+                        .to_string(),
+                ),
+            },
+        );
+    }
+}
+
+/// Check that every constraint of a command can be evaluated
+///
+/// Each check must compare an argument or option with another one of a
+/// compatible type, or with a literal valid for its type.
+fn validate_constraints(cmd: &CommandDefinition, report: &mut Report) {
+    for (idx, constraint) in cmd.constraints.iter().enumerate() {
+        if let Err(reason) = parse_constraint(&constraint.check, cmd) {
+            report.error(ConfigError::InvalidSchema {
+                reason: format!("Invalid constraint '{}': {}", constraint.check, reason),
+                path: Some(format!("{}.constraints[{}]", cmd.name, idx)),
+                suggestion: Some(
+                    "Write checks as '<name> <op> <name or value>', e.g. 'end > start'."
+                        .to_string(),
+                ),
+            });
+        }
+    }
+}
+
+/// Find a cycle in `requires`, returned as the names along the cycle
+fn find_requires_cycle(requires: &BTreeMap<String, Vec<String>>) -> Option<Vec<String>> {
+    fn visit<'a>(
+        name: &'a str,
+        requires: &'a BTreeMap<String, Vec<String>>,
+        stack: &mut Vec<&'a str>,
+        done: &mut HashSet<&'a str>,
+    ) -> Option<Vec<String>> {
+        if let Some(start) = stack.iter().position(|n| *n == name) {
+            let mut cycle: Vec<String> = stack[start..].iter().map(|n| n.to_string()).collect();
+            cycle.push(name.to_string());
+            return Some(cycle);
+        }
+        if !done.insert(name) {
+            return None;
+        }
+        stack.push(name);
+        for next in requires.get(name).into_iter().flatten() {
+            if let Some(cycle) = visit(next, requires, stack, done) {
+                return Some(cycle);
+            }
+        }
+        stack.pop();
+        None
+    }
+
+    let mut done = HashSet::new();
+    requires
+        .keys()
+        .find_map(|name| visit(name, requires, &mut Vec::new(), &mut done))
 }
 
 /// Validate that argument names are unique
-fn validate_argument_names(args: &[ArgumentDefinition], context: &str) -> Result<()> {
+fn validate_argument_names(args: &[ArgumentDefinition], context: &str, report: &mut Report) {
     let mut seen_names: HashSet<String> = HashSet::new();
 
     for (idx, arg) in args.iter().enumerate() {
         if arg.name.trim().is_empty() {
-            return Err(ConfigError::InvalidSchema {
+            report.error(ConfigError::InvalidSchema {
                 reason: "Argument name cannot be empty".to_string(),
                 path: Some(format!("{}.arguments[{}]", context, idx)),
                 suggestion: None,
-            }
-            .into());
-        }
-
-        if !seen_names.insert(arg.name.clone()) {
-            return Err(ConfigError::InvalidSchema {
+            });
+        } else if !seen_names.insert(arg.name.clone()) {
+            report.error(ConfigError::InvalidSchema {
                 reason: format!("Duplicate argument name: '{}'", arg.name),
                 path: Some(format!("{}.arguments", context)),
                 suggestion: None,
-            }
-            .into());
+            });
         }
     }
-
-    Ok(())
 }
 
 /// Validate that validation rules are consistent with argument types
-fn validate_argument_validation_rules(args: &[ArgumentDefinition], _context: &str) -> Result<()> {
-    for arg in args.iter() {
-        for rule in arg.validation.iter() {
+fn validate_argument_validation_rules(
+    args: &[ArgumentDefinition],
+    context: &str,
+    report: &mut Report,
+) {
+    for (idx, arg) in args.iter().enumerate() {
+        for (rule_idx, rule) in arg.validation.iter().enumerate() {
+            let path = format!("{}.arguments[{}].validation[{}]", context, idx, rule_idx);
             match rule {
                 ValidationRule::MustExist { .. } | ValidationRule::Extensions { .. } => {
                     // These rules only make sense for Path arguments
                     if arg.arg_type != ArgumentType::Path {
-                        return Err(ConfigError::Inconsistency {
+                        report.error_at(&path, ConfigError::Inconsistency {
                             details: format!(
                                 "Validation rule 'must_exist' or 'extensions' can only be used with 'path' type, \
                                 but argument '{}' has type '{}'",
@@ -302,114 +1065,308 @@ fn validate_argument_validation_rules(args: &[ArgumentDefinition], _context: &st
                                 arg.arg_type.as_str()
                             ),
                             suggestion: None,
-                        }.into());
+                        });
                     }
                 }
                 ValidationRule::Range { min, max } => {
                     // Range rules only make sense for numeric types
-                    if !matches!(arg.arg_type, ArgumentType::Integer | ArgumentType::Float) {
-                        return Err(ConfigError::Inconsistency {
-                            details: format!(
-                                "Validation rule 'range' can only be used with numeric types, \
-                                but argument '{}' has type '{}'",
-                                arg.name,
-                                arg.arg_type.as_str()
-                            ),
-                            suggestion: None,
-                        }
-                        .into());
+                    if !arg.arg_type.is_numeric() {
+                        report.error_at(&path, range_type_mismatch(&arg.name, arg.arg_type));
                     }
 
                     // Validate that min <= max if both are specified
                     if let (Some(min_val), Some(max_val)) = (min, max) {
                         if min_val > max_val {
-                            return Err(ConfigError::Inconsistency {
+                            report.error_at(
+                                &path,
+                                ConfigError::Inconsistency {
+                                    details: format!(
+                                        "Invalid range for argument '{}': min ({}) > max ({})",
+                                        arg.name, min_val, max_val
+                                    ),
+                                    suggestion: None,
+                                },
+                            );
+                        }
+                    }
+                }
+                ValidationRule::Pattern { .. }
+                | ValidationRule::Length { .. }
+                | ValidationRule::NotEmpty { .. }
+                | ValidationRule::Format { .. } => {
+                    validate_text_rule(arg, rule, &path, report);
+                }
+                ValidationRule::Custom { custom, .. } => {
+                    // Names are resolved against registered validators at build time
+                    if custom.trim().is_empty() {
+                        report.error_at(
+                            &path,
+                            ConfigError::Inconsistency {
                                 details: format!(
-                                    "Invalid range for argument '{}': min ({}) > max ({})",
-                                    arg.name, min_val, max_val
+                                    "Custom validation rule for argument '{}' has an empty name",
+                                    arg.name
                                 ),
-                                suggestion: None,
-                            }
-                            .into());
-                        }
+                                suggestion: Some(
+                                    "Set 'custom' to the name used with register_validator()."
+                                        .to_string(),
+                                ),
+                            },
+                        );
                     }
                 }
             }
         }
     }
+}
+
+/// Error for a `min`/`max` rule on an argument whose type is not numeric
+pub(crate) fn range_type_mismatch(arg_name: &str, arg_type: ArgumentType) -> ConfigError {
+    ConfigError::Inconsistency {
+        details: format!(
+            "Validation rule 'range' can only be used with numeric types, \
+            but argument '{}' has type '{}'",
+            arg_name,
+            arg_type.as_str()
+        ),
+        suggestion: None,
+    }
+}
+
+/// Validate a text rule (`pattern`, `min_length`/`max_length`,
+/// `not_empty`, `format`) against its argument
+///
+/// Text rules make no sense on `bool` or `enum` arguments, whose values
+/// are fixed; `format` is further restricted to `string`. Patterns must
+/// compile and length bounds must be ordered.
+fn validate_text_rule(
+    arg: &ArgumentDefinition,
+    rule: &ValidationRule,
+    path: &str,
+    report: &mut Report,
+) {
+    let rule_name = match rule {
+        ValidationRule::Pattern { .. } => "pattern",
+        ValidationRule::Length { .. } => "min_length/max_length",
+        ValidationRule::NotEmpty { .. } => "not_empty",
+        ValidationRule::Format { .. } => "format",
+        _ => return,
+    };
+    let allowed = match rule {
+        ValidationRule::Format { .. } => arg.arg_type == ArgumentType::String,
+        _ => !matches!(arg.arg_type, ArgumentType::Bool | ArgumentType::Enum),
+    };
+    if !allowed {
+        report.error_at(
+            path,
+            ConfigError::Inconsistency {
+                details: format!(
+                    "Validation rule '{}' cannot be used with type '{}' (argument '{}')",
+                    rule_name,
+                    arg.arg_type.as_str(),
+                    arg.name
+                ),
+                suggestion: Some(if rule_name == "format" {
+                    "Use 'format' on 'string' arguments only.".to_string()
+                } else {
+                    "Text rules apply to free-form values; remove the rule or change the type."
+                        .to_string()
+                }),
+            },
+        );
+        return;
+    }
 
-    Ok(())
+    match rule {
+        ValidationRule::Pattern { pattern } => {
+            if let Err(e) = compile_pattern(pattern) {
+                report.error_at(
+                    path,
+                    ConfigError::Inconsistency {
+                        details: format!("Invalid pattern for argument '{}': {}", arg.name, e),
+                        suggestion: Some(
+                            "Check the regular expression syntax; in YAML, quote the pattern \
+                             and double backslashes inside double quotes."
+                                .to_string(),
+                        ),
+                    },
+                );
+            }
+        }
+        ValidationRule::Length {
+            min_length: Some(min),
+            max_length: Some(max),
+        } if min > max => {
+            report.error_at(
+                path,
+                ConfigError::Inconsistency {
+                    details: format!(
+                        "Invalid length bounds for argument '{}': min_length ({}) > max_length ({})",
+                        arg.name, min, max
+                    ),
+                    suggestion: None,
+                },
+            );
+        }
+        _ => {}
+    }
 }
 
 /// Validate option definitions
-fn validate_options(options: &[OptionDefinition], context: &str) -> Result<()> {
+fn validate_options(options: &[OptionDefinition], context: &str, report: &mut Report) {
     let mut seen_names: HashSet<String> = HashSet::new();
 
     for (idx, opt) in options.iter().enumerate() {
+        let path = format!("{}.options[{}]", context, idx);
+
         // Validate name is not empty
         if opt.name.trim().is_empty() {
-            return Err(ConfigError::InvalidSchema {
+            report.error(ConfigError::InvalidSchema {
                 reason: "Option name cannot be empty".to_string(),
-                path: Some(format!("{}.options[{}]", context, idx)),
+                path: Some(path.clone()),
                 suggestion: None,
-            }
-            .into());
-        }
-
-        // Check for duplicate names
-        if !seen_names.insert(opt.name.clone()) {
-            return Err(ConfigError::InvalidSchema {
+            });
+        } else if !seen_names.insert(opt.name.clone()) {
+            // Check for duplicate names
+            report.error(ConfigError::InvalidSchema {
                 reason: format!("Duplicate option name: '{}'", opt.name),
                 path: Some(format!("{}.options", context)),
                 suggestion: None,
-            }
-            .into());
+            });
         }
 
         // Validate that at least one of short or long is specified
         if opt.short.is_none() && opt.long.is_none() {
-            return Err(ConfigError::InvalidSchema {
+            report.error(ConfigError::InvalidSchema {
                 reason: format!(
                     "Option '{}' must have at least a short or long form",
                     opt.name
                 ),
-                path: Some(format!("{}.options[{}]", context, idx)),
+                path: Some(path.clone()),
                 suggestion: None,
-            }
-            .into());
+            });
         }
 
         // Validate choices are consistent with default
         if let Some(ref default) = opt.default {
             if !opt.choices.is_empty() && !opt.choices.contains(default) {
-                return Err(ConfigError::Inconsistency {
+                report.error_at(
+                    &path,
+                    ConfigError::Inconsistency {
+                        details: format!(
+                            "Default value '{}' for option '{}' is not in choices: [{}]",
+                            default,
+                            opt.name,
+                            opt.choices.join(", ")
+                        ),
+                        suggestion: None,
+                    },
+                );
+            }
+        }
+
+        // Validate that boolean options don't have choices
+        if opt.option_type == ArgumentType::Bool && !opt.choices.is_empty() {
+            report.error_at(
+                &path,
+                ConfigError::Inconsistency {
+                    details: format!("Boolean option '{}' cannot have choices", opt.name),
+                    suggestion: None,
+                },
+            );
+        }
+
+        // Validate enum variants, which replace choices
+        if !opt.variants.is_empty() && !opt.choices.is_empty() {
+            report.error_at(
+                &path,
+                ConfigError::Inconsistency {
+                    details: format!(
+                        "Option '{}' cannot have both choices and variants",
+                        opt.name
+                    ),
+                    suggestion: Some(
+                        "Use 'type: enum' with variants, which can also carry descriptions."
+                            .to_string(),
+                    ),
+                },
+            );
+        }
+        validate_variants(
+            &opt.name,
+            opt.option_type,
+            &opt.variants,
+            opt.default.as_deref(),
+            &path,
+            report,
+        );
+
+        // Validate repeatable options
+        if opt.multiple && opt.option_type == ArgumentType::Bool {
+            report.error_at(
+                &path,
+                ConfigError::Inconsistency {
                     details: format!(
-                        "Default value '{}' for option '{}' is not in choices: [{}]",
-                        default,
-                        opt.name,
-                        opt.choices.join(", ")
+                        "Boolean option '{}' cannot accept multiple values",
+                        opt.name
                     ),
                     suggestion: None,
-                }
-                .into());
+                },
+            );
+        }
+        validate_value_bounds(
+            &opt.name,
+            opt.multiple,
+            opt.min_values,
+            opt.max_values,
+            &path,
+            report,
+        );
+
+        // Validate the environment variable name
+        if let Some(ref env) = opt.env {
+            if env.is_empty() || env.contains(['=', '\0']) || env.chars().any(char::is_whitespace) {
+                report.error(ConfigError::InvalidSchema {
+                    reason: format!(
+                        "Invalid environment variable name '{}' for option '{}'",
+                        env, opt.name
+                    ),
+                    path: Some(format!("{}.env", path)),
+                    suggestion: Some(
+                        "Use a non-empty name without whitespace or '=', e.g. MYAPP_TOKEN."
+                            .to_string(),
+                    ),
+                });
             }
         }
 
-        // Validate that boolean options don't have choices
-        if opt.option_type == ArgumentType::Bool && !opt.choices.is_empty() {
-            return Err(ConfigError::Inconsistency {
-                details: format!("Boolean option '{}' cannot have choices", opt.name),
-                suggestion: None,
-            }
-            .into());
+        // The default fills a missing value, so `required` never fires
+        if opt.required && opt.default.is_some() {
+            report.warning(
+                Diagnostic::warning(
+                    format!(
+                        "Option '{}' is required but has a default value, so it can never be missing",
+                        opt.name
+                    ),
+                    path.clone(),
+                )
+                .with_suggestion("Remove 'required' or the default value."),
+            );
         }
-    }
 
-    Ok(())
+        if opt.description.trim().is_empty() {
+            report.warning(
+                Diagnostic::warning(
+                    format!("Option '{}' has no description", opt.name),
+                    format!("{}.description", path),
+                )
+                .with_suggestion("Describe the option; the text is shown in help."),
+            );
+        }
+    }
 }
 
 /// Validate option flags (short and long forms)
-fn validate_option_flags(options: &[OptionDefinition], context: &str) -> Result<()> {
+fn validate_option_flags(options: &[OptionDefinition], context: &str, report: &mut Report) {
     let mut seen_short: HashMap<String, String> = HashMap::new();
     let mut seen_long: HashMap<String, String> = HashMap::new();
 
@@ -417,56 +1374,46 @@ fn validate_option_flags(options: &[OptionDefinition], context: &str) -> Result<
         // Check short form
         if let Some(ref short) = opt.short {
             if short.len() != 1 {
-                return Err(ConfigError::InvalidSchema {
+                report.error(ConfigError::InvalidSchema {
                     reason: format!(
                         "Short option '{}' for '{}' must be a single character",
                         short, opt.name
                     ),
                     path: Some(format!("{}.options", context)),
                     suggestion: None,
-                }
-                .into());
-            }
-
-            if let Some(existing) = seen_short.insert(short.clone(), opt.name.clone()) {
-                return Err(ConfigError::InvalidSchema {
+                });
+            } else if let Some(existing) = seen_short.insert(short.clone(), opt.name.clone()) {
+                report.error(ConfigError::InvalidSchema {
                     reason: format!(
                         "Short option '-{}' is used by both '{}' and '{}'",
                         short, existing, opt.name
                     ),
                     path: Some(format!("{}.options", context)),
                     suggestion: None,
-                }
-                .into());
+                });
             }
         }
 
         // Check long form
         if let Some(ref long) = opt.long {
             if long.is_empty() {
-                return Err(ConfigError::InvalidSchema {
+                report.error(ConfigError::InvalidSchema {
                     reason: format!("Long option for '{}' cannot be empty", opt.name),
                     path: Some(format!("{}.options", context)),
                     suggestion: None,
-                }
-                .into());
-            }
-
-            if let Some(existing) = seen_long.insert(long.clone(), opt.name.clone()) {
-                return Err(ConfigError::InvalidSchema {
+                });
+            } else if let Some(existing) = seen_long.insert(long.clone(), opt.name.clone()) {
+                report.error(ConfigError::InvalidSchema {
                     reason: format!(
                         "Long option '--{}' is used by both '{}' and '{}'",
                         long, existing, opt.name
                     ),
                     path: Some(format!("{}.options", context)),
                     suggestion: None,
-                }
-                .into());
+                });
             }
         }
     }
-
-    Ok(())
 }
 
 /// Check for name conflicts between arguments and options
@@ -474,21 +1421,48 @@ fn check_name_conflicts(
     args: &[ArgumentDefinition],
     options: &[OptionDefinition],
     context: &str,
-) -> Result<()> {
+    report: &mut Report,
+) {
     let arg_names: HashSet<String> = args.iter().map(|a| a.name.clone()).collect();
 
     for opt in options {
         if arg_names.contains(&opt.name) {
-            return Err(ConfigError::InvalidSchema {
+            report.error(ConfigError::InvalidSchema {
                 reason: format!("Option '{}' has the same name as an argument", opt.name),
                 path: Some(format!("{}.options", context)),
                 suggestion: None,
-            }
-            .into());
+            });
         }
     }
+}
 
-    Ok(())
+/// Check that no argument or option name uses the global option namespace
+///
+/// Global options are delivered to handlers under keys starting with
+/// [`GLOBAL_OPTION_PREFIX`]; command-local names must stay out of it.
+fn check_reserved_names(
+    args: &[ArgumentDefinition],
+    options: &[OptionDefinition],
+    context: &str,
+    report: &mut Report,
+) {
+    let names = args
+        .iter()
+        .map(|a| (&a.name, "arguments"))
+        .chain(options.iter().map(|o| (&o.name, "options")));
+
+    for (name, section) in names {
+        if name.starts_with(GLOBAL_OPTION_PREFIX) {
+            report.error(ConfigError::InvalidSchema {
+                reason: format!(
+                    "Name '{}' uses the prefix '{}' reserved for global options",
+                    name, GLOBAL_OPTION_PREFIX
+                ),
+                path: Some(format!("{}.{}", context, section)),
+                suggestion: Some("Rename the argument or option.".to_string()),
+            });
+        }
+    }
 }
 
 #[cfg(test)]
@@ -496,6 +1470,13 @@ mod tests {
     use super::*;
     use crate::config::schema::CommandsConfig;
 
+    /// Run a check and return its first error, like the fail-fast entry points
+    fn run(check: impl FnOnce(&mut Report)) -> Result<()> {
+        let mut report = Report::default();
+        check(&mut report);
+        report.into_result(&SourceMap::default())
+    }
+
     #[test]
     fn test_validate_config_empty() {
         let config = CommandsConfig::minimal();
@@ -514,6 +1495,7 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "handler1".to_string(),
+                ..Default::default()
             },
             CommandDefinition {
                 name: "test".to_string(), // Duplicate!
@@ -523,6 +1505,7 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "handler2".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -550,6 +1533,7 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "handler1".to_string(),
+                ..Default::default()
             },
             CommandDefinition {
                 name: "cmd2".to_string(),
@@ -559,6 +1543,7 @@ mod tests {
                 arguments: vec![],
                 options: vec![],
                 implementation: "handler2".to_string(),
+                ..Default::default()
             },
         ];
 
@@ -576,6 +1561,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "handler".to_string(),
+            ..Default::default()
         };
 
         let mut config = CommandsConfig::minimal();
@@ -595,6 +1581,7 @@ mod tests {
                 description: "Optional".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             },
             ArgumentDefinition {
                 name: "required".to_string(),
@@ -603,10 +1590,11 @@ mod tests {
                 description: "Required".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             },
         ];
 
-        let result = validate_argument_ordering(&args, "test");
+        let result = run(|r| validate_argument_ordering(&args, "test", r));
         assert!(result.is_err());
     }
 
@@ -620,6 +1608,7 @@ mod tests {
                 description: "Arg 1".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             },
             ArgumentDefinition {
                 name: "arg1".to_string(), // Duplicate!
@@ -628,10 +1617,11 @@ mod tests {
                 description: "Arg 1 again".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             },
         ];
 
-        let result = validate_argument_names(&args, "test");
+        let result = run(|r| validate_argument_names(&args, "test", r));
         assert!(result.is_err());
     }
 
@@ -646,9 +1636,10 @@ mod tests {
                 ValidationRule::MustExist { must_exist: true }, // Wrong for integer!
             ],
             secure: false,
+            ..Default::default()
         }];
 
-        let result = validate_argument_validation_rules(&args, "test");
+        let result = run(|r| validate_argument_validation_rules(&args, "test", r));
         assert!(result.is_err());
     }
 
@@ -664,12 +1655,159 @@ mod tests {
                 max: Some(0.0), // min > max!
             }],
             secure: false,
+            ..Default::default()
         }];
 
-        let result = validate_argument_validation_rules(&args, "test");
+        let result = run(|r| validate_argument_validation_rules(&args, "test", r));
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_validate_validation_rules_range_types() {
+        let mut args = vec![ArgumentDefinition {
+            name: "timeout".to_string(),
+            arg_type: ArgumentType::Duration,
+            required: true,
+            description: "Timeout".to_string(),
+            validation: vec![ValidationRule::Range {
+                min: Some(1.0),
+                max: Some(60.0),
+            }],
+            secure: false,
+            ..Default::default()
+        }];
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_ok());
+
+        args[0].arg_type = ArgumentType::Bytes;
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_ok());
+
+        args[0].arg_type = ArgumentType::DateTime;
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_err());
+    }
+
+    #[test]
+    fn test_validate_validation_rules_text_rules() {
+        use crate::config::schema::StringFormat;
+
+        let mut args = vec![ArgumentDefinition {
+            name: "contact".to_string(),
+            arg_type: ArgumentType::String,
+            required: true,
+            description: "Contact".to_string(),
+            validation: vec![
+                ValidationRule::Pattern {
+                    pattern: r"^\S+$".to_string(),
+                },
+                ValidationRule::Length {
+                    min_length: Some(3),
+                    max_length: Some(64),
+                },
+                ValidationRule::NotEmpty { not_empty: true },
+                ValidationRule::Format {
+                    format: StringFormat::Email,
+                },
+            ],
+            secure: false,
+            ..Default::default()
+        }];
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_ok());
+
+        // `format` is for strings only; other text rules also fit paths
+        args[0].arg_type = ArgumentType::Path;
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_err());
+        args[0].validation.pop();
+        assert!(run(|r| validate_argument_validation_rules(&args, "test", r)).is_ok());
+
+        args[0].arg_type = ArgumentType::Bool;
+        let err = run(|r| validate_argument_validation_rules(&args, "test", r)).unwrap_err();
+        assert!(err.to_string().contains("'pattern'"));
+    }
+
+    #[test]
+    fn test_validate_validation_rules_invalid_pattern_and_length() {
+        let mut args = vec![ArgumentDefinition {
+            name: "name".to_string(),
+            arg_type: ArgumentType::String,
+            required: true,
+            description: "Name".to_string(),
+            validation: vec![ValidationRule::Pattern {
+                pattern: "[a-z".to_string(),
+            }],
+            secure: false,
+            ..Default::default()
+        }];
+        let err = run(|r| validate_argument_validation_rules(&args, "test", r)).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Invalid pattern for argument 'name'"));
+
+        args[0].validation = vec![ValidationRule::Length {
+            min_length: Some(10),
+            max_length: Some(2),
+        }];
+        let err = run(|r| validate_argument_validation_rules(&args, "test", r)).unwrap_err();
+        assert!(err.to_string().contains("min_length (10) > max_length (2)"));
+    }
+
+    #[test]
+    fn test_validate_variants() {
+        let variant = |name: &str, aliases: &[&str]| EnumVariant {
+            name: name.to_string(),
+            description: String::new(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        };
+        let path = "cmd.options[0]";
+        let ok = vec![variant("fast", &["f"]), variant("safe", &[])];
+
+        assert!(
+            run(|r| validate_variants("mode", ArgumentType::Enum, &ok, Some("f"), path, r)).is_ok()
+        );
+        assert!(
+            run(|r| validate_variants("mode", ArgumentType::String, &[], None, path, r)).is_ok()
+        );
+
+        // Enum without variants, variants without enum
+        assert!(
+            run(|r| validate_variants("mode", ArgumentType::Enum, &[], None, path, r)).is_err()
+        );
+        assert!(
+            run(|r| validate_variants("mode", ArgumentType::String, &ok, None, path, r)).is_err()
+        );
+
+        // Duplicate name or alias
+        let clash = vec![variant("fast", &[]), variant("quick", &["fast"])];
+        let err = run(|r| validate_variants("mode", ArgumentType::Enum, &clash, None, path, r))
+            .unwrap_err();
+        assert!(err.to_string().contains("'fast'"));
+
+        // Default must be a variant
+        assert!(
+            run(|r| validate_variants("mode", ArgumentType::Enum, &ok, Some("slow"), path, r))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_validate_options_choices_and_variants() {
+        let option = OptionDefinition {
+            name: "mode".to_string(),
+            short: None,
+            long: Some("mode".to_string()),
+            option_type: ArgumentType::Enum,
+            required: false,
+            default: None,
+            description: "Mode".to_string(),
+            choices: vec!["fast".to_string()],
+            variants: vec![EnumVariant {
+                name: "fast".to_string(),
+                description: String::new(),
+                aliases: vec![],
+            }],
+            ..Default::default()
+        };
+        assert!(run(|r| validate_options(&[option], "test", r)).is_err());
+    }
+
     #[test]
     fn test_validate_options_no_flags() {
         let options = vec![OptionDefinition {
@@ -681,9 +1819,10 @@ mod tests {
             default: None,
             description: "Option".to_string(),
             choices: vec![],
+            ..Default::default()
         }];
 
-        let result = validate_options(&options, "test");
+        let result = run(|r| validate_options(&options, "test", r));
         assert!(result.is_err());
     }
 
@@ -698,9 +1837,10 @@ mod tests {
             default: Some("invalid".to_string()), // Not in choices!
             description: "Mode".to_string(),
             choices: vec!["fast".to_string(), "slow".to_string()],
+            ..Default::default()
         }];
 
-        let result = validate_options(&options, "test");
+        let result = run(|r| validate_options(&options, "test", r));
         assert!(result.is_err());
     }
 
@@ -716,6 +1856,7 @@ mod tests {
                 default: None,
                 description: "Option 1".to_string(),
                 choices: vec![],
+                ..Default::default()
             },
             OptionDefinition {
                 name: "opt2".to_string(),
@@ -726,10 +1867,11 @@ mod tests {
                 default: None,
                 description: "Option 2".to_string(),
                 choices: vec![],
+                ..Default::default()
             },
         ];
 
-        let result = validate_option_flags(&options, "test");
+        let result = run(|r| validate_option_flags(&options, "test", r));
         assert!(result.is_err());
     }
 
@@ -744,9 +1886,10 @@ mod tests {
             default: None,
             description: "Option".to_string(),
             choices: vec![],
+            ..Default::default()
         }];
 
-        let result = validate_option_flags(&options, "test");
+        let result = run(|r| validate_option_flags(&options, "test", r));
         assert!(result.is_err());
     }
 
@@ -759,6 +1902,7 @@ mod tests {
             description: "Output".to_string(),
             validation: vec![],
             secure: false,
+            ..Default::default()
         }];
 
         let options = vec![OptionDefinition {
@@ -770,9 +1914,10 @@ mod tests {
             default: None,
             description: "Output".to_string(),
             choices: vec![],
+            ..Default::default()
         }];
 
-        let result = check_name_conflicts(&args, &options, "test");
+        let result = run(|r| check_name_conflicts(&args, &options, "test", r));
         assert!(result.is_err());
     }
 
@@ -795,6 +1940,7 @@ mod tests {
                     },
                 ],
                 secure: false,
+                ..Default::default()
             }],
             options: vec![OptionDefinition {
                 name: "output".to_string(),
@@ -805,13 +1951,161 @@ mod tests {
                 default: Some("out.csv".to_string()),
                 description: "Output file".to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             implementation: "process_handler".to_string(),
+            ..Default::default()
         };
 
         assert!(validate_command(&cmd).is_ok());
     }
 
+    /// Command with options `file`, `url`, `format` and `output`
+    fn relations_command() -> CommandDefinition {
+        let option = |name: &str| OptionDefinition {
+            name: name.to_string(),
+            short: None,
+            long: Some(name.to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: None,
+            description: name.to_string(),
+            choices: vec![],
+            ..Default::default()
+        };
+        CommandDefinition {
+            name: "fetch".to_string(),
+            aliases: vec![],
+            description: "Fetch data".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![
+                option("file"),
+                option("url"),
+                option("format"),
+                option("output"),
+            ],
+            implementation: "fetch_handler".to_string(),
+            groups: vec![crate::config::schema::ArgumentGroup {
+                name: "source".to_string(),
+                members: vec!["file".to_string(), "url".to_string()],
+                required: true,
+            }],
+            requires: [("format".to_string(), vec!["output".to_string()])].into(),
+            ..Default::default()
+        }
+    }
+
+    fn relation(name: &str, others: &[&str]) -> (String, Vec<String>) {
+        (
+            name.to_string(),
+            others.iter().map(|o| o.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_validate_relations_valid() {
+        assert!(validate_command(&relations_command()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_relations_unknown_name_suggests_similar() {
+        let mut cmd = relations_command();
+        cmd.requires.extend([relation("format", &["outptu"])]);
+
+        match validate_command(&cmd) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::InvalidSchema {
+                path,
+                suggestion,
+                ..
+            })) => {
+                assert_eq!(path.as_deref(), Some("fetch.requires.format"));
+                assert_eq!(suggestion.as_deref(), Some("Did you mean 'output'?"));
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_relations_rejects_requires_cycle() {
+        let mut cmd = relations_command();
+        cmd.requires
+            .extend([relation("output", &["url"]), relation("url", &["format"])]);
+
+        match validate_command(&cmd) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::Inconsistency {
+                details,
+                ..
+            })) => {
+                assert!(details.contains("format -> output -> url -> format"));
+            }
+            other => panic!("Expected Inconsistency, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_relations_rejects_contradictions() {
+        // Requirement between members of an exclusive group
+        let mut cmd = relations_command();
+        cmd.requires.extend([relation("file", &["url"])]);
+        assert!(validate_command(&cmd).is_err());
+
+        // Requirement and conflict on the same pair
+        let mut cmd = relations_command();
+        cmd.conflicts_with.extend([relation("output", &["format"])]);
+        assert!(validate_command(&cmd).is_err());
+
+        // Self-reference
+        let mut cmd = relations_command();
+        cmd.conflicts_with.extend([relation("file", &["file"])]);
+        assert!(validate_command(&cmd).is_err());
+    }
+
+    #[test]
+    fn test_validate_groups() {
+        // Required member of an exclusive group
+        let mut cmd = relations_command();
+        cmd.options[0].required = true;
+        assert!(validate_command(&cmd).is_err());
+
+        // Single-member group
+        let mut cmd = relations_command();
+        cmd.groups[0].members.pop();
+        assert!(validate_command(&cmd).is_err());
+    }
+
+    fn constraint(check: &str) -> crate::config::schema::ConstraintDefinition {
+        crate::config::schema::ConstraintDefinition {
+            check: check.to_string(),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_constraints() {
+        let mut cmd = relations_command();
+        cmd.constraints = vec![constraint("output != file"), constraint("format == 'csv'")];
+        assert!(validate_command(&cmd).is_ok());
+
+        cmd.constraints.push(constraint("output = file"));
+        match validate_command(&cmd) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::InvalidSchema {
+                reason,
+                path,
+                ..
+            })) => {
+                assert!(reason.starts_with("Invalid constraint 'output = file'"));
+                assert_eq!(path.as_deref(), Some("fetch.constraints[2]"));
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+
+        // Both sides literal (misspelled name)
+        let mut cmd = relations_command();
+        cmd.constraints = vec![constraint("outptu != 'x'")];
+        assert!(validate_command(&cmd).is_err());
+    }
+
     #[test]
     fn test_validate_boolean_with_choices() {
         let options = vec![OptionDefinition {
@@ -823,9 +2117,515 @@ mod tests {
             default: None,
             description: "A flag".to_string(),
             choices: vec!["true".to_string(), "false".to_string()], // Boolean can't have choices!
+            ..Default::default()
         }];
 
-        let result = validate_options(&options, "test");
+        let result = run(|r| validate_options(&options, "test", r));
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_validate_variadic_argument_must_be_last() {
+        let argument = |name: &str, multiple: bool| ArgumentDefinition {
+            name: name.to_string(),
+            arg_type: ArgumentType::Path,
+            required: true,
+            description: "File".to_string(),
+            validation: vec![],
+            secure: false,
+            multiple,
+            ..Default::default()
+        };
+
+        let args = vec![argument("dest", false), argument("files", true)];
+        assert!(run(|r| validate_variadic_arguments(&args, "test", r)).is_ok());
+
+        let args = vec![argument("files", true), argument("dest", false)];
+        assert!(matches!(
+            run(|r| validate_variadic_arguments(&args, "test", r)),
+            Err(crate::error::DynamicCliError::Config(
+                ConfigError::InvalidSchema { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_value_bounds() {
+        assert!(run(|r| validate_value_bounds("x", true, Some(1), Some(3), "test", r)).is_ok());
+        assert!(run(|r| validate_value_bounds("x", true, None, None, "test", r)).is_ok());
+        // Bounds without multiple
+        assert!(run(|r| validate_value_bounds("x", false, Some(1), None, "test", r)).is_err());
+        // Inverted or empty range
+        assert!(run(|r| validate_value_bounds("x", true, Some(3), Some(2), "test", r)).is_err());
+        assert!(run(|r| validate_value_bounds("x", true, None, Some(0), "test", r)).is_err());
+    }
+
+    #[test]
+    fn test_validate_options_invalid_env_name() {
+        let mut option = OptionDefinition {
+            name: "token".to_string(),
+            short: None,
+            long: Some("token".to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: None,
+            description: "API token".to_string(),
+            choices: vec![],
+            env: Some("MYAPP_TOKEN".to_string()),
+            ..Default::default()
+        };
+        assert!(run(|r| validate_options(std::slice::from_ref(&option), "test", r)).is_ok());
+
+        option.env = Some("MYAPP TOKEN".to_string());
+        assert!(matches!(
+            run(|r| validate_options(&[option], "test", r)),
+            Err(crate::error::DynamicCliError::Config(
+                ConfigError::InvalidSchema { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_command_rejects_reserved_global_prefix() {
+        let cmd = CommandDefinition {
+            name: "run".to_string(),
+            aliases: vec![],
+            description: "Run".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![OptionDefinition {
+                name: "global.verbose".to_string(),
+                short: None,
+                long: Some("verbose".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Verbose".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+            implementation: "run_handler".to_string(),
+            ..Default::default()
+        };
+
+        let result = validate_command(&cmd);
+        assert!(matches!(
+            result,
+            Err(crate::error::DynamicCliError::Config(
+                ConfigError::InvalidSchema { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_config_subcommand_duplicates_are_per_level() {
+        let command = |name: &str, aliases: Vec<&str>, subcommands| CommandDefinition {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            description: name.to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: format!("{}_handler", name),
+            subcommands,
+            ..Default::default()
+        };
+
+        // The same name under different parents is fine
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![
+            command("remote", vec![], vec![command("add", vec!["a"], vec![])]),
+            command("branch", vec![], vec![command("add", vec!["a"], vec![])]),
+        ];
+        assert!(validate_config(&config).is_ok());
+
+        // Siblings must be unique
+        config.commands[0].subcommands = vec![
+            command("add", vec!["a"], vec![]),
+            command("append", vec!["a"], vec![]),
+        ];
+        match validate_config(&config) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::DuplicateCommand {
+                name,
+                ..
+            })) => assert_eq!(name, "a"),
+            other => panic!("Expected DuplicateCommand error, got {:?}", other),
+        }
+
+        // Nested definitions are validated with their path
+        config.commands[0].subcommands = vec![command("add", vec![], vec![])];
+        config.commands[0].subcommands[0].implementation = String::new();
+        match validate_config(&config) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::InvalidSchema {
+                path, ..
+            })) => assert_eq!(
+                path.as_deref(),
+                Some("commands[0].subcommands[0].implementation")
+            ),
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_config_rejects_whitespace_in_names() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![CommandDefinition {
+            name: "remote add".to_string(),
+            aliases: vec![],
+            description: "Add a remote".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "remote_add".to_string(),
+            ..Default::default()
+        }];
+
+        assert!(matches!(
+            validate_config(&config),
+            Err(crate::error::DynamicCliError::Config(
+                ConfigError::InvalidSchema { .. }
+            ))
+        ));
+    }
+
+    fn plain_command(name: &str) -> CommandDefinition {
+        CommandDefinition {
+            name: name.to_string(),
+            aliases: vec![],
+            description: format!("The {} command", name),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: format!("{}_handler", name),
+            ..Default::default()
+        }
+    }
+
+    fn plain_option(name: &str) -> OptionDefinition {
+        OptionDefinition {
+            name: name.to_string(),
+            short: None,
+            long: Some(name.to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: None,
+            description: "Option".to_string(),
+            choices: vec![],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_diagnose_config_valid_is_empty() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![plain_command("build")];
+        assert!(diagnose_config(&config).is_empty());
+    }
+
+    #[test]
+    fn test_diagnose_config_collects_all_errors() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![plain_command("build"), plain_command("build")];
+        config.commands[0].implementation = String::new();
+        config.commands[0].options = vec![plain_option("out")];
+        config.commands[0].options[0].long = None;
+        config.global_options = vec![plain_option("verbose"), plain_option("verbose")];
+
+        let diagnostics = diagnose_config(&config);
+        let found: Vec<(&str, Option<&str>)> = diagnostics
+            .iter()
+            .map(|d| (d.message.as_str(), d.path.as_deref()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "Option 'out' must have at least a short or long form",
+                    Some("build.options[0]")
+                ),
+                (
+                    "Command implementation cannot be empty",
+                    Some("commands[0].implementation")
+                ),
+                (
+                    "Duplicate command name or alias: 'build'",
+                    Some("commands[1].name")
+                ),
+                (
+                    "Duplicate option name: 'verbose'",
+                    Some("global_options.options")
+                ),
+                (
+                    "Long option '--verbose' is used by both 'verbose' and 'verbose'",
+                    Some("global_options.options")
+                ),
+            ]
+        );
+        assert!(diagnostics.iter().all(|d| d.is_error()));
+
+        // The fail-fast mode reports the first of them
+        match validate_config(&config) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::InvalidSchema {
+                path, ..
+            })) => assert_eq!(path.as_deref(), Some("build.options[0]")),
+            other => panic!("Expected InvalidSchema error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_diagnose_config_locates_inconsistencies() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![plain_command("deploy")];
+        config.commands[0].options = vec![plain_option("mode")];
+        config.commands[0].options[0].default = Some("fast".to_string());
+        config.commands[0].options[0].choices = vec!["slow".to_string()];
+
+        let diagnostics = diagnose_config(&config);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.starts_with("Default value 'fast'"));
+        assert_eq!(diagnostics[0].path.as_deref(), Some("deploy.options[0]"));
+    }
+
+    #[test]
+    fn test_diagnose_config_warnings_do_not_fail_validation() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![plain_command("deploy")];
+        config.commands[0].description = String::new();
+        config.commands[0].options = vec![plain_option("region")];
+        config.commands[0].options[0].required = true;
+        config.commands[0].options[0].default = Some("eu".to_string());
+
+        let diagnostics = diagnose_config(&config);
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics
+            .iter()
+            .all(|d| d.severity == crate::error::Severity::Warning));
+        assert_eq!(diagnostics[0].path.as_deref(), Some("deploy.options[0]"));
+        assert_eq!(
+            diagnostics[1].path.as_deref(),
+            Some("commands[0].description")
+        );
+        assert!(diagnostics[1].suggestion.is_some());
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    fn test_diagnose_config_names_included_files() {
+        let mut config = CommandsConfig::minimal();
+        config.commands = vec![plain_command("build"), plain_command("test")];
+        config.commands[1].source = Some(std::path::PathBuf::from("more.yaml"));
+        config.commands[1].implementation = String::new();
+        config.commands[1].description = String::new();
+
+        let paths: Vec<Option<String>> = diagnose_config(&config)
+            .into_iter()
+            .map(|d| d.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                Some("more.yaml: commands[0].implementation".to_string()),
+                Some("more.yaml: commands[0].description".to_string()),
+            ]
+        );
+    }
+
+    const LOCATED: &str = r#"metadata:
+  version: "1.0.0"
+  prompt: app
+commands:
+  - name: deploy
+    aliases: [d]
+    description: Deploy
+    implementation: deploy_handler
+    arguments:
+      - name: replicas
+        arg_type: integer
+        required: true
+        description: Replica count
+        validation:
+          - { min: 10, max: 1 }
+  - name: diff
+    aliases: [d]
+    description: Diff
+    implementation: diff_handler
+"#;
+
+    fn located_config() -> (CommandsConfig, SourceMap) {
+        let config = crate::config::loader::load_yaml(LOCATED).unwrap();
+        let mut sources = SourceMap::default();
+        sources.add(
+            Path::new("commands.yaml"),
+            crate::config::loader::Format::Yaml,
+            LOCATED,
+        );
+        (config, sources)
+    }
+
+    #[test]
+    fn test_validate_config_in_locates_first_error() {
+        let (config, sources) = located_config();
+
+        match validate_config_in(&config, &sources) {
+            Err(crate::error::DynamicCliError::Config(ConfigError::Located {
+                error,
+                location,
+            })) => {
+                assert!(matches!(*error, ConfigError::Inconsistency { .. }));
+                assert_eq!(location.file, PathBuf::from("commands.yaml"));
+                assert_eq!((location.line, location.column), (15, 13));
+                assert_eq!(location.text, "          - { min: 10, max: 1 }");
+            }
+            other => panic!("Expected Located error, got {:?}", other),
+        }
+
+        // Without sources, the error is unchanged
+        assert!(matches!(
+            validate_config(&config),
+            Err(crate::error::DynamicCliError::Config(
+                ConfigError::Inconsistency { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_diagnose_config_in_locates_each_problem() {
+        let (config, sources) = located_config();
+
+        let located: Vec<_> = diagnose_config_in(&config, &sources)
+            .into_iter()
+            .map(|d| {
+                let location = d.location.expect("every problem is located");
+                (
+                    d.message,
+                    d.path,
+                    (location.line, location.column, location.len),
+                )
+            })
+            .collect();
+        assert_eq!(
+            located,
+            vec![
+                (
+                    "Invalid range for argument 'replicas': min (10) > max (1)".to_string(),
+                    Some("deploy.arguments[0].validation[0]".to_string()),
+                    (15, 13, 19)
+                ),
+                (
+                    "Duplicate command name or alias: 'd'".to_string(),
+                    Some("commands[1].aliases[0]".to_string()),
+                    (17, 15, 1)
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diagnose_config_in_warns_about_unknown_rule_keys() {
+        let yaml = r#"metadata:
+  version: "1.0.0"
+  prompt: app
+commands:
+  - name: scale
+    description: Scale
+    implementation: scale_handler
+    arguments:
+      - name: percent
+        arg_type: integer
+        required: true
+        description: Percentage
+        validation:
+          - { min: 0, max: 100, note: "whole numbers" }
+"#;
+        // Unknown keys do not stop the configuration from loading
+        let config = crate::config::loader::load_yaml(yaml).unwrap();
+        assert!(diagnose_config(&config).is_empty());
+
+        let mut sources = SourceMap::default();
+        sources.add(
+            Path::new("commands.yaml"),
+            crate::config::loader::Format::Yaml,
+            yaml,
+        );
+        let diagnostics = diagnose_config_in(&config, &sources);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].is_error());
+        assert_eq!(
+            diagnostics[0].message,
+            "Unknown key 'note' in validation rule is ignored"
+        );
+        assert_eq!(
+            diagnostics[0].path.as_deref(),
+            Some("commands[0].arguments[0].validation[0].note")
+        );
+        let location = diagnostics[0].location.as_ref().unwrap();
+        assert_eq!((location.line, location.column, location.len), (14, 33, 4));
+    }
+
+    #[test]
+    fn test_diagnose_config_in_warns_about_mixed_rules() {
+        let yaml = r#"metadata:
+  version: "1.0.0"
+  prompt: app
+commands:
+  - name: open
+    description: Open
+    implementation: open_handler
+    arguments:
+      - name: file
+        arg_type: path
+        required: true
+        description: File
+        validation:
+          - must_exist: true
+            extensions: [yaml, yml]
+"#;
+        // The entry keeps loading, with its first rule
+        let config = crate::config::loader::load_yaml(yaml).unwrap();
+        assert_eq!(
+            config.commands[0].arguments[0].validation,
+            vec![ValidationRule::MustExist { must_exist: true }]
+        );
+
+        let mut sources = SourceMap::default();
+        sources.add(
+            Path::new("commands.yaml"),
+            crate::config::loader::Format::Yaml,
+            yaml,
+        );
+        let diagnostics = diagnose_config_in(&config, &sources);
+        assert_eq!(diagnostics.len(), 1);
+        assert!(!diagnostics[0].is_error());
+        assert_eq!(
+            diagnostics[0].message,
+            "Key 'extensions' in validation rule is ignored: the entry already holds a 'must_exist' rule"
+        );
+        assert_eq!(
+            diagnostics[0].path.as_deref(),
+            Some("commands[0].arguments[0].validation[0].extensions")
+        );
+        let location = diagnostics[0].location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (15, 13));
+    }
+
+    #[test]
+    fn test_diagnose_config_in_locates_global_options() {
+        let yaml = "metadata:\n  version: \"1\"\n  prompt: app\ncommands: []\nglobal_options:\n  - name: verbose\n    option_type: bool\n    description: Verbose\n";
+        let config = crate::config::loader::load_yaml(yaml).unwrap();
+        let mut sources = SourceMap::default();
+        sources.add(
+            Path::new("commands.yaml"),
+            crate::config::loader::Format::Yaml,
+            yaml,
+        );
+
+        let diagnostics = diagnose_config_in(&config, &sources);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].path.as_deref(),
+            Some("global_options.options[0]")
+        );
+        let location = diagnostics[0].location.as_ref().unwrap();
+        assert_eq!((location.line, location.column), (6, 5));
+    }
 }
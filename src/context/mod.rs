@@ -205,6 +205,7 @@ mod tests {
     /// Another context type for testing type safety
     #[derive(Default)]
     struct AnotherContext {
+        #[allow(dead_code)]
         value: i32,
     }
 
@@ -308,8 +309,10 @@ mod tests {
             Ok(app_ctx.command_count)
         }
 
-        let mut ctx = TestAppContext::default();
-        ctx.command_count = 42;
+        let ctx = TestAppContext {
+            command_count: 42,
+            ..Default::default()
+        };
 
         let count = read_command_count(&ctx).unwrap();
         assert_eq!(count, 42);
@@ -302,6 +302,7 @@ mod tests {
     /// Alternative context type for testing type mismatch
     #[derive(Default)]
     struct OtherContext {
+        #[allow(dead_code)]
         data: Vec<u8>,
     }
 
@@ -534,7 +535,7 @@ mod tests {
 
         // Verify changes
         assert_eq!(*ctx.counters.get("visits").unwrap(), 1);
-        assert_eq!(ctx.flags[0], false);
+        assert!(!ctx.flags[0]);
         assert!(ctx.optional_data.is_none());
     }
 
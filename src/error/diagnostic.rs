@@ -0,0 +1,274 @@
+//! Configuration diagnostics
+//!
+//! A [`Diagnostic`] describes one problem found while checking a whole
+//! configuration, as opposed to the single [`ConfigError`] returned by the
+//! fail-fast validators. Diagnostics are produced by
+//! [`crate::config::validator::diagnose_config`] and rendered as a batch by
+//! [`crate::error::format_diagnostics`].
+//!
+//! When the configuration was loaded from files, diagnostics and errors
+//! can carry a [`SourceLocation`], rendered as a code frame.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::error::ConfigError;
+
+/// How serious a [`Diagnostic`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// The configuration is rejected
+    Error,
+    /// The configuration loads, but probably not as intended
+    Warning,
+}
+
+impl Severity {
+    /// Lowercase label, as printed in front of each diagnostic
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Where a configuration problem is written
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::error::SourceLocation;
+/// use std::path::PathBuf;
+///
+/// let location = SourceLocation {
+///     file: PathBuf::from("commands.yaml"),
+///     line: 7,
+///     column: 15,
+///     len: 1,
+///     text: "    aliases: [d]".to_string(),
+/// };
+/// assert_eq!(location.to_string(), "commands.yaml:7:15");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// File the problem is in
+    pub file: PathBuf,
+    /// Line, starting at 1
+    pub line: usize,
+    /// Column in characters, starting at 1
+    pub column: usize,
+    /// Length in characters of the offending key or item
+    pub len: usize,
+    /// Text of the line, shown in code frames
+    pub text: String,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file.display(), self.line, self.column)
+    }
+}
+
+/// One problem found in a configuration
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::error::{ConfigError, Diagnostic, Severity};
+///
+/// let diagnostic = Diagnostic::from_error(&ConfigError::InvalidSchema {
+///     reason: "Option name cannot be empty".to_string(),
+///     path: Some("deploy.options[0]".to_string()),
+///     suggestion: None,
+/// });
+///
+/// assert_eq!(diagnostic.severity, Severity::Error);
+/// assert_eq!(diagnostic.message, "Option name cannot be empty");
+/// assert_eq!(diagnostic.path.as_deref(), Some("deploy.options[0]"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Whether the problem rejects the configuration
+    pub severity: Severity,
+    /// Description of the problem
+    pub message: String,
+    /// Location in the configuration, e.g. `deploy.options[1]`
+    pub path: Option<String>,
+    /// Actionable hint surfaced to the user (not part of the Display string)
+    pub suggestion: Option<String>,
+    /// Position in the file, when the configuration was loaded from one
+    pub location: Option<SourceLocation>,
+}
+
+impl Diagnostic {
+    /// Create a warning at `path`
+    pub fn warning(message: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            path: Some(path.into()),
+            suggestion: None,
+            location: None,
+        }
+    }
+
+    /// Attach a suggestion
+    pub fn with_suggestion(mut self, suggestion: impl Into<String>) -> Self {
+        self.suggestion = Some(suggestion.into());
+        self
+    }
+
+    /// Describe a configuration error as an error-level diagnostic
+    ///
+    /// The message omits the category prefix of the error's `Display`
+    /// string when the variant carries a bare description, and the path
+    /// is taken from [`ConfigError::InvalidSchema`]. A
+    /// [`ConfigError::Located`] error gives its location.
+    pub fn from_error(error: &ConfigError) -> Self {
+        let (message, path, suggestion) = match error {
+            ConfigError::Located { error, location } => {
+                return Self {
+                    location: Some(location.clone()),
+                    ..Self::from_error(error)
+                };
+            }
+            ConfigError::InvalidSchema {
+                reason,
+                path,
+                suggestion,
+            } => (reason.clone(), path.clone(), suggestion),
+            ConfigError::Inconsistency {
+                details,
+                suggestion,
+            } => (details.clone(), None, suggestion),
+            ConfigError::UndefinedVariable {
+                key, suggestion, ..
+            } => (error.to_string(), Some(key.clone()), suggestion),
+            ConfigError::FileNotFound { suggestion, .. }
+            | ConfigError::UnsupportedFormat { suggestion, .. }
+            | ConfigError::DuplicateCommand { suggestion, .. }
+            | ConfigError::DuplicateGlobalOption { suggestion, .. }
+            | ConfigError::UnknownType { suggestion, .. }
+            | ConfigError::UnsupportedSchemaVersion { suggestion, .. } => {
+                (error.to_string(), None, suggestion)
+            }
+            ConfigError::YamlParse { .. }
+            | ConfigError::JsonParse { .. }
+            | ConfigError::TomlParse { .. } => (error.to_string(), None, &None),
+        };
+
+        Self {
+            severity: Severity::Error,
+            message,
+            path,
+            suggestion: suggestion.clone(),
+            location: None,
+        }
+    }
+
+    /// Whether this diagnostic rejects the configuration
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Prefix the path with the file the problem comes from
+    ///
+    /// Mirrors [`ConfigError::in_file`].
+    pub fn in_file(mut self, file: &Path) -> Self {
+        self.path = Some(match self.path {
+            Some(path) => format!("{}: {}", file.display(), path),
+            None => file.display().to_string(),
+        });
+        self
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref location) = self.location {
+            write!(f, "{}: ", location)?;
+        }
+        write!(f, "{}: {}", self.severity, self.message)?;
+        if let Some(ref path) = self.path {
+            write!(f, " (at {})", path)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_error_keeps_path_and_suggestion() {
+        let diagnostic = Diagnostic::from_error(&ConfigError::InvalidSchema {
+            reason: "Bad".to_string(),
+            path: Some("commands[0]".to_string()),
+            suggestion: Some("Fix it.".to_string()),
+        });
+
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.message, "Bad");
+        assert_eq!(diagnostic.path.as_deref(), Some("commands[0]"));
+        assert_eq!(diagnostic.suggestion.as_deref(), Some("Fix it."));
+    }
+
+    #[test]
+    fn test_from_error_uses_bare_details() {
+        let diagnostic = Diagnostic::from_error(&ConfigError::Inconsistency {
+            details: "min > max".to_string(),
+            suggestion: None,
+        });
+
+        assert_eq!(diagnostic.message, "min > max");
+        assert_eq!(diagnostic.path, None);
+    }
+
+    #[test]
+    fn test_from_located_error() {
+        let location = SourceLocation {
+            file: PathBuf::from("a.yaml"),
+            line: 3,
+            column: 5,
+            len: 4,
+            text: "  - name: x".to_string(),
+        };
+        let diagnostic = Diagnostic::from_error(&ConfigError::Located {
+            error: Box::new(ConfigError::Inconsistency {
+                details: "min > max".to_string(),
+                suggestion: None,
+            }),
+            location: location.clone(),
+        });
+
+        assert_eq!(diagnostic.message, "min > max");
+        assert_eq!(diagnostic.location, Some(location));
+        assert_eq!(diagnostic.to_string(), "a.yaml:3:5: error: min > max");
+    }
+
+    #[test]
+    fn test_in_file_prefixes_path() {
+        let diagnostic =
+            Diagnostic::warning("Empty description", "commands[0]").in_file(Path::new("a.yaml"));
+
+        assert_eq!(diagnostic.path.as_deref(), Some("a.yaml: commands[0]"));
+    }
+
+    #[test]
+    fn test_display() {
+        let diagnostic = Diagnostic::warning("Empty description", "commands[0]");
+
+        assert_eq!(
+            diagnostic.to_string(),
+            "warning: Empty description (at commands[0])"
+        );
+    }
+}
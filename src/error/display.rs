@@ -27,7 +27,8 @@
 use colored::Colorize;
 
 use crate::error::{
-    ConfigError, DynamicCliError, ExecutionError, ParseError, RegistryError, ValidationError,
+    ConfigError, Diagnostic, DynamicCliError, ExecutionError, ParseError, RegistryError, Severity,
+    SourceLocation, ValidationError,
 };
 
 // ═══════════════════════════════════════════════════════════
@@ -39,6 +40,11 @@ fn color_error(s: &str) -> String {
     s.red().bold().to_string()
 }
 
+/// Render a bold yellow warning label (used for "warning:")
+fn color_warning(s: &str) -> String {
+    s.yellow().bold().to_string()
+}
+
 /// Render a question mark prompt (used before "Did you mean:")
 fn color_question(s: &str) -> String {
     s.yellow().bold().to_string()
@@ -112,8 +118,8 @@ pub fn display_error(error: &DynamicCliError) {
 ///   ℹ  <actionable suggestion>
 /// ```
 ///
-/// For parse errors with Levenshtein suggestions, a "Did you mean:" block
-/// is appended instead of the `ℹ` line.
+/// For parse errors and invalid choices with Levenshtein suggestions, a
+/// "Did you mean:" block is appended instead of the `ℹ` line.
 ///
 /// # Arguments
 ///
@@ -151,6 +157,76 @@ pub fn format_error(error: &DynamicCliError) -> String {
     output
 }
 
+/// Format a batch of configuration diagnostics as a colored report
+///
+/// Each diagnostic is rendered like a configuration error, labelled with
+/// its severity, and the report ends with a count of errors and warnings:
+///
+/// ```text
+/// error: Option name cannot be empty
+///   in deploy.options[0]
+/// warning: Command 'status' has no description
+///   in commands[1].description
+///   ℹ  Describe the command; the text is shown in help.
+///
+/// 1 error, 1 warning
+/// ```
+///
+/// An empty batch renders as an empty string.
+///
+/// # Arguments
+///
+/// * `diagnostics` - The diagnostics to format, in report order
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::error::{format_diagnostics, Diagnostic};
+///
+/// let diagnostics = vec![Diagnostic::warning("Empty description", "commands[0]")];
+///
+/// let formatted = format_diagnostics(&diagnostics);
+/// assert!(formatted.contains("Empty description"));
+/// assert!(formatted.contains("commands[0]"));
+/// assert!(formatted.contains("0 errors, 1 warning"));
+/// ```
+pub fn format_diagnostics(diagnostics: &[Diagnostic]) -> String {
+    if diagnostics.is_empty() {
+        return String::new();
+    }
+
+    let mut output = String::new();
+    for diagnostic in diagnostics {
+        let label = format!("{}:", diagnostic.severity);
+        let label = match diagnostic.severity {
+            Severity::Error => color_error(&label),
+            Severity::Warning => color_warning(&label),
+        };
+        output.push_str(&format!("{} {}\n", label, diagnostic.message));
+        if let Some(ref location) = diagnostic.location {
+            append_code_frame(&mut output, location);
+        }
+        if let Some(ref path) = diagnostic.path {
+            output.push_str(&format!(
+                "  {} {}\n",
+                color_dimmed("in"),
+                color_type_name(path)
+            ));
+        }
+        append_suggestion(&mut output, diagnostic.suggestion.as_deref());
+    }
+
+    let errors = diagnostics.iter().filter(|d| d.is_error()).count();
+    let warnings = diagnostics.len() - errors;
+    output.push_str(&format!(
+        "\n{}, {}\n",
+        plural(errors, "error"),
+        plural(warnings, "warning")
+    ));
+
+    output
+}
+
 // ═══════════════════════════════════════════════════════════
 // CATEGORY FORMATTERS
 // ═══════════════════════════════════════════════════════════
@@ -199,7 +275,11 @@ fn format_parse_error(output: &mut String, error: &ParseError) {
 
         ParseError::MissingArgument { suggestion, .. }
         | ParseError::MissingOption { suggestion, .. }
-        | ParseError::TooManyArguments { suggestion, .. } => {
+        | ParseError::TooManyArguments { suggestion, .. }
+        | ParseError::WrongValueCount { suggestion, .. }
+        | ParseError::ConflictingArguments { suggestion, .. }
+        | ParseError::MissingRequirement { suggestion, .. }
+        | ParseError::MissingGroupMember { suggestion, .. } => {
             append_suggestion(output, suggestion.as_deref());
         }
 
@@ -226,18 +306,37 @@ fn format_config_error(output: &mut String, error: &ConfigError) {
             }
         }
 
+        ConfigError::TomlParse {
+            source,
+            line,
+            column,
+        } => {
+            output.push_str(&format!("{}\n", source.message()));
+            if let (Some(l), Some(c)) = (line, column) {
+                output.push_str(&format!(
+                    "  {} line {}, column {}\n",
+                    color_dimmed("at"),
+                    color_arg_name(&l.to_string()),
+                    color_arg_name(&c.to_string())
+                ));
+            }
+        }
+
         ConfigError::JsonParse {
             source,
             line,
             column,
         } => {
             output.push_str(&format!("{}\n", source));
-            output.push_str(&format!(
-                "  {} line {}, column {}\n",
-                color_dimmed("at"),
-                color_arg_name(&line.to_string()),
-                color_arg_name(&column.to_string())
-            ));
+            // serde_json reports line 0 when the error has no position
+            if *line > 0 {
+                output.push_str(&format!(
+                    "  {} line {}, column {}\n",
+                    color_dimmed("at"),
+                    color_arg_name(&line.to_string()),
+                    color_arg_name(&column.to_string())
+                ));
+            }
         }
 
         ConfigError::InvalidSchema {
@@ -256,11 +355,25 @@ fn format_config_error(output: &mut String, error: &ConfigError) {
             append_suggestion(output, suggestion.as_deref());
         }
 
+        ConfigError::Located { error, location } => {
+            let mut inner = String::new();
+            format_config_error(&mut inner, error);
+            // The code frame goes right under the message
+            let (message, details) = inner.split_once('\n').unwrap_or((&inner, ""));
+            output.push_str(message);
+            output.push('\n');
+            append_code_frame(output, location);
+            output.push_str(details);
+        }
+
         ConfigError::FileNotFound { suggestion, .. }
         | ConfigError::UnsupportedFormat { suggestion, .. }
         | ConfigError::DuplicateCommand { suggestion, .. }
+        | ConfigError::DuplicateGlobalOption { suggestion, .. }
         | ConfigError::UnknownType { suggestion, .. }
-        | ConfigError::Inconsistency { suggestion, .. } => {
+        | ConfigError::Inconsistency { suggestion, .. }
+        | ConfigError::UnsupportedSchemaVersion { suggestion, .. }
+        | ConfigError::UndefinedVariable { suggestion, .. } => {
             output.push_str(&format!("{}\n", error));
             append_suggestion(output, suggestion.as_deref());
         }
@@ -271,14 +384,34 @@ fn format_config_error(output: &mut String, error: &ConfigError) {
 fn format_validation_error(output: &mut String, error: &ValidationError) {
     output.push_str(&format!("{}\n", error));
 
+    if let ValidationError::InvalidChoice { suggestions, .. } = error {
+        if !suggestions.is_empty() {
+            output.push_str(&format!("\n{} Did you mean:\n", color_question("?")));
+            for s in suggestions {
+                output.push_str(&format!(
+                    "  {} {}\n",
+                    color_bullet("•"),
+                    color_suggestion(s)
+                ));
+            }
+        }
+    }
+
     let suggestion = match error {
         ValidationError::FileNotFound { suggestion, .. } => suggestion.as_deref(),
         ValidationError::OutOfRange { suggestion, .. } => suggestion.as_deref(),
         ValidationError::CustomConstraint { suggestion, .. } => suggestion.as_deref(),
         ValidationError::MissingDependency { suggestion, .. } => suggestion.as_deref(),
         ValidationError::MutuallyExclusive { suggestion, .. } => suggestion.as_deref(),
+        ValidationError::InvalidField { suggestion, .. } => suggestion.as_deref(),
+        ValidationError::PatternMismatch { suggestion, .. } => suggestion.as_deref(),
+        ValidationError::InvalidLength { suggestion, .. } => suggestion.as_deref(),
+        ValidationError::EmptyValue { suggestion, .. } => suggestion.as_deref(),
+        ValidationError::InvalidFormat { suggestion, .. } => suggestion.as_deref(),
         // InvalidExtension already lists the expected extensions in the message
         ValidationError::InvalidExtension { .. } => None,
+        // InvalidChoice renders its suggestions as a "Did you mean" block
+        ValidationError::InvalidChoice { .. } => None,
     };
 
     append_suggestion(output, suggestion);
@@ -307,6 +440,7 @@ fn format_registry_error(output: &mut String, error: &RegistryError) {
         RegistryError::DuplicateRegistration { suggestion, .. } => suggestion.as_deref(),
         RegistryError::DuplicateAlias { suggestion, .. } => suggestion.as_deref(),
         RegistryError::MissingHandler { suggestion, .. } => suggestion.as_deref(),
+        RegistryError::UnknownParent { suggestion, .. } => suggestion.as_deref(),
     };
 
     append_suggestion(output, suggestion);
@@ -331,6 +465,56 @@ fn append_suggestion(output: &mut String, suggestion: Option<&str>) {
     }
 }
 
+/// Append a rustc-style code frame with a caret under the location
+///
+/// ```text
+///   --> commands.yaml:7:15
+///    |
+///  7 |     aliases: [d]
+///    |               ^
+/// ```
+fn append_code_frame(output: &mut String, location: &SourceLocation) {
+    let number = location.line.to_string();
+    let gutter = " ".repeat(number.len());
+    // Keep tabs so that the caret lines up with the text
+    let indent: String = location
+        .text
+        .chars()
+        .take(location.column.saturating_sub(1))
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+
+    output.push_str(&format!(
+        "  {}{} {}\n",
+        gutter,
+        color_bullet("-->"),
+        color_type_name(&location.to_string())
+    ));
+    output.push_str(&format!("  {} {}\n", gutter, color_bullet("|")));
+    output.push_str(&format!(
+        "  {} {} {}\n",
+        color_bullet(&number),
+        color_bullet("|"),
+        location.text
+    ));
+    output.push_str(&format!(
+        "  {} {} {}{}\n",
+        gutter,
+        color_bullet("|"),
+        indent,
+        color_error(&"^".repeat(location.len.max(1)))
+    ));
+}
+
+/// Format a count with its noun, e.g. "1 error" or "3 warnings"
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════
@@ -383,13 +567,13 @@ mod tests {
     #[test]
     fn test_format_config_unsupported_format_with_suggestion() {
         let error: DynamicCliError = ConfigError::UnsupportedFormat {
-            extension: ".toml".to_string(),
+            extension: ".ini".to_string(),
             suggestion: Some("Use .yaml instead.".to_string()),
         }
         .into();
 
         let formatted = format_error(&error);
-        assert!(formatted.contains(".toml"));
+        assert!(formatted.contains(".ini"));
         assert!(formatted.contains("Use .yaml instead."));
     }
 
@@ -404,6 +588,20 @@ mod tests {
         assert!(formatted.contains("Error:"));
     }
 
+    #[test]
+    fn test_format_config_toml_parse_contains_location() {
+        let content = "[metadata]\nversion = \"1.0.0\"\nprompt =\n";
+        let toml_error = toml::from_str::<toml::Table>(content).unwrap_err();
+
+        let error: DynamicCliError =
+            ConfigError::toml_parse_with_location(toml_error, content).into();
+        let formatted = format_error(&error);
+        assert!(formatted.contains("line"));
+        assert!(formatted.contains("column"));
+        // The message is shown without the TOML crate's own code frame
+        assert!(!formatted.contains(" | "));
+    }
+
     #[test]
     fn test_format_config_invalid_schema_with_path_and_suggestion() {
         let error: DynamicCliError = ConfigError::InvalidSchema {
@@ -492,6 +690,30 @@ mod tests {
         assert!(formatted.contains("Run --help run"));
     }
 
+    #[test]
+    fn test_format_parse_conflicting_arguments_with_suggestion() {
+        let error: DynamicCliError = ParseError::ConflictingArguments {
+            first: "--file".to_string(),
+            second: "--url".to_string(),
+            suggestion: Some("Use either --file or --url, not both.".to_string()),
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("--file cannot be used with --url"));
+        assert!(formatted.contains("Use either --file or --url"));
+    }
+
+    #[test]
+    fn test_format_parse_wrong_value_count_with_suggestion() {
+        let error: DynamicCliError =
+            ParseError::wrong_value_count("files", "merge", Some(2), None, 1).into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("expected at least 2, got 1"));
+        assert!(formatted.contains("Run --help merge"));
+    }
+
     #[test]
     fn test_format_parse_type_parse_error_shows_info_block() {
         let error: DynamicCliError = ParseError::TypeParseError {
@@ -583,6 +805,66 @@ mod tests {
         assert!(!formatted.contains('ℹ'));
     }
 
+    #[test]
+    fn test_format_validation_invalid_choice_with_suggestions() {
+        let error: DynamicCliError = ValidationError::InvalidChoice {
+            arg_name: "priority".to_string(),
+            value: "urgnt".to_string(),
+            choices: vec!["low".to_string(), "urgent".to_string()],
+            suggestions: vec!["urgent".to_string()],
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("urgnt"));
+        assert!(formatted.contains("Did you mean"));
+        assert!(formatted.contains("urgent"));
+    }
+
+    #[test]
+    fn test_format_validation_invalid_choice_no_suggestions() {
+        let error: DynamicCliError = ValidationError::InvalidChoice {
+            arg_name: "priority".to_string(),
+            value: "xyz".to_string(),
+            choices: vec!["low".to_string(), "high".to_string()],
+            suggestions: vec![],
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("low, high"));
+        assert!(!formatted.contains("Did you mean"));
+    }
+
+    #[test]
+    fn test_format_validation_invalid_field_with_suggestion() {
+        let error: DynamicCliError = ValidationError::InvalidField {
+            field: "count".to_string(),
+            reason: "no value was provided".to_string(),
+            suggestion: Some("Make the field optional.".to_string()),
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("Invalid argument 'count'"));
+        assert!(formatted.contains("Make the field optional."));
+    }
+
+    #[test]
+    fn test_format_validation_invalid_length_with_suggestion() {
+        let error: DynamicCliError = ValidationError::InvalidLength {
+            arg_name: "username".to_string(),
+            expected: "at least 3".to_string(),
+            got: 1,
+            suggestion: Some("Add at least 2 more characters.".to_string()),
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("username must be at least 3 characters long"));
+        assert!(formatted.contains("Add at least 2 more characters."));
+    }
+
     // ── format_error — Execution ─────────────────────────────
 
     #[test]
@@ -651,6 +933,20 @@ mod tests {
         assert!(formatted.contains("Command names must be unique."));
     }
 
+    #[test]
+    fn test_format_registry_unknown_parent_with_suggestion() {
+        let error: DynamicCliError = RegistryError::UnknownParent {
+            parent: "remote".to_string(),
+            subcommand: "add".to_string(),
+            suggestion: Some("Register 'remote' before its subcommands.".to_string()),
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("'add'"));
+        assert!(formatted.contains("Register 'remote' before its subcommands."));
+    }
+
     #[test]
     fn test_format_registry_duplicate_alias_with_suggestion() {
         let error: DynamicCliError = RegistryError::DuplicateAlias {
@@ -665,6 +961,69 @@ mod tests {
         assert!(formatted.contains("Choose a different alias."));
     }
 
+    #[test]
+    fn test_format_config_located_shows_code_frame() {
+        let error: DynamicCliError = ConfigError::Located {
+            error: Box::new(ConfigError::Inconsistency {
+                details: "Duplicate command name or alias: 'd'".to_string(),
+                suggestion: Some("Rename one of them.".to_string()),
+            }),
+            location: SourceLocation {
+                file: PathBuf::from("commands.yaml"),
+                line: 7,
+                column: 15,
+                len: 1,
+                text: "    aliases: [d]".to_string(),
+            },
+        }
+        .into();
+
+        let formatted = format_error(&error);
+        assert!(formatted.contains("-->"));
+        assert!(formatted.contains("commands.yaml:7:15"));
+        assert!(formatted.contains("    aliases: [d]"));
+        assert!(formatted.contains('^'));
+        assert!(formatted.contains("Rename one of them."));
+        let frame = formatted.find("-->").unwrap();
+        assert!(formatted.find("Duplicate").unwrap() < frame);
+        assert!(frame < formatted.find("Rename").unwrap());
+    }
+
+    // ── format_diagnostics ───────────────────────────────────
+
+    #[test]
+    fn test_format_diagnostics_lists_each_with_summary() {
+        let diagnostics = vec![
+            Diagnostic::from_error(&ConfigError::InvalidSchema {
+                reason: "Option name cannot be empty".to_string(),
+                path: Some("deploy.options[0]".to_string()),
+                suggestion: None,
+            }),
+            Diagnostic::from_error(&ConfigError::Inconsistency {
+                details: "Invalid range".to_string(),
+                suggestion: None,
+            }),
+            Diagnostic::warning("No description", "commands[1].description")
+                .with_suggestion("Describe the command."),
+        ];
+
+        let formatted = format_diagnostics(&diagnostics);
+        assert!(formatted.contains("Option name cannot be empty"));
+        assert!(formatted.contains("deploy.options[0]"));
+        assert!(formatted.contains("Invalid range"));
+        assert!(formatted.contains("warning:"));
+        assert!(formatted.contains("Describe the command."));
+        assert!(formatted.contains("2 errors, 1 warning"));
+        let first = formatted.find("Option name").unwrap();
+        let last = formatted.find("No description").unwrap();
+        assert!(first < last);
+    }
+
+    #[test]
+    fn test_format_diagnostics_empty() {
+        assert_eq!(format_diagnostics(&[]), "");
+    }
+
     // ── display_error ────────────────────────────────────────
 
     #[test]
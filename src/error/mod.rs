@@ -11,6 +11,7 @@
 //! - [`ValidationError`] : Validation errors
 //! - [`ExecutionError`] : Execution errors
 //! - [`RegistryError`] : Registry errors
+//! - [`Diagnostic`] : One problem found when checking a whole configuration
 //!
 //! ## Example
 //!
@@ -25,12 +26,14 @@
 //! }
 //! ```
 
+mod diagnostic;
 mod display;
 mod suggestions;
 mod types;
 
 // Public re-exports
-pub use display::{display_error, format_error};
+pub use diagnostic::{Diagnostic, Severity, SourceLocation};
+pub use display::{display_error, format_diagnostics, format_error};
 pub use suggestions::find_similar_strings;
 pub use types::*;
 
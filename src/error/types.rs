@@ -7,7 +7,8 @@
 //! [`crate::error::display::format_error`] and are never part of the
 //! `Display` string itself, keeping machine-readable messages stable.
 
-use std::path::PathBuf;
+use crate::error::SourceLocation;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 
 /// Main error for the dynamic-cli framework
@@ -75,7 +76,7 @@ pub enum ConfigError {
 
     /// Unsupported file extension
     ///
-    /// Only `.yaml`, `.yml` and `.json` are supported.
+    /// Only `.yaml`, `.yml`, `.json` and `.toml` are supported.
     ///
     /// # Example
     ///
@@ -83,13 +84,13 @@ pub enum ConfigError {
     /// use dynamic_cli::error::ConfigError;
     ///
     /// let error = ConfigError::UnsupportedFormat {
-    ///     extension: ".toml".to_string(),
-    ///     suggestion: Some("Rename the file with a .yaml, .yml or .json extension.".to_string()),
+    ///     extension: ".ini".to_string(),
+    ///     suggestion: Some("Rename the file with a .yaml, .yml, .json or .toml extension.".to_string()),
     /// };
     /// let msg = format!("{}", error);
-    /// assert!(msg.contains(".toml"));
+    /// assert!(msg.contains(".ini"));
     /// ```
-    #[error("Unsupported file format: '{extension}'. Supported: .yaml, .yml, .json")]
+    #[error("Unsupported file format: '{extension}'. Supported: .yaml, .yml, .json, .toml")]
     UnsupportedFormat {
         extension: String,
         /// Actionable hint surfaced to the user (not part of the Display string)
@@ -116,6 +117,17 @@ pub enum ConfigError {
         column: usize,
     },
 
+    /// TOML parsing error
+    #[error("Failed to parse TOML configuration at line {line:?}, column {column:?}: {}",
+        .source.message())]
+    TomlParse {
+        #[source]
+        source: toml::de::Error,
+        /// Position in the file (if available)
+        line: Option<usize>,
+        column: Option<usize>,
+    },
+
     /// Invalid configuration schema
     ///
     /// The file structure doesn't match the expected format.
@@ -148,17 +160,48 @@ pub enum ConfigError {
     ///
     /// ```
     /// use dynamic_cli::error::ConfigError;
+    /// use std::path::PathBuf;
     ///
     /// let error = ConfigError::DuplicateCommand {
     ///     name: "run".to_string(),
+    ///     files: vec![PathBuf::from("commands.yaml"), PathBuf::from("teams/ops.yaml")],
     ///     suggestion: Some("Rename one of the conflicting commands or aliases.".to_string()),
     /// };
     /// let msg = format!("{}", error);
     /// assert!(msg.contains("run"));
+    /// assert!(msg.contains("teams/ops.yaml"));
     /// ```
-    #[error("Duplicate command name or alias: '{name}'")]
+    #[error("Duplicate command name or alias: '{name}'{}", defined_in(.files))]
     DuplicateCommand {
         name: String,
+        /// Files of the conflicting definitions (empty for a single file)
+        files: Vec<PathBuf>,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Global option defined in several files
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    /// use std::path::PathBuf;
+    ///
+    /// let error = ConfigError::DuplicateGlobalOption {
+    ///     name: "verbose".to_string(),
+    ///     files: vec![PathBuf::from("commands.yaml"), PathBuf::from("teams/ops.yaml")],
+    ///     suggestion: Some("Define each global option in one file.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("verbose"));
+    /// assert!(msg.contains("teams/ops.yaml"));
+    /// ```
+    #[error("Duplicate global option: '{name}'{}", defined_in(.files))]
+    DuplicateGlobalOption {
+        name: String,
+        /// Files of the conflicting definitions
+        files: Vec<PathBuf>,
         /// Actionable hint surfaced to the user (not part of the Display string)
         suggestion: Option<String>,
     },
@@ -210,6 +253,92 @@ pub enum ConfigError {
         /// Actionable hint surfaced to the user (not part of the Display string)
         suggestion: Option<String>,
     },
+
+    /// Configuration written for a newer format than this release reads
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    ///
+    /// let error = ConfigError::UnsupportedSchemaVersion {
+    ///     version: 7,
+    ///     supported: 2,
+    ///     suggestion: Some("Upgrade dynamic-cli to read this file.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("schema_version 7"));
+    /// ```
+    #[error("Unsupported schema_version {version} (supported up to {supported})")]
+    UnsupportedSchemaVersion {
+        version: u32,
+        /// Latest version this release can read
+        supported: u32,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Environment variable referenced with `${...}` is not set
+    ///
+    /// Raised when interpolation is enabled and a variable without a
+    /// `:-` fallback is missing from the environment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    ///
+    /// let error = ConfigError::UndefinedVariable {
+    ///     name: "DATA_DIR".to_string(),
+    ///     key: "commands[0].options[1].default".to_string(),
+    ///     suggestion: Some("Set DATA_DIR or write '${DATA_DIR:-<default>}'.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("DATA_DIR"));
+    /// assert!(msg.contains("commands[0].options[1].default"));
+    /// ```
+    #[error("Environment variable '{name}' is not set (referenced by {key})")]
+    UndefinedVariable {
+        name: String,
+        /// Key whose value references the variable (e.g., "metadata.prompt")
+        key: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Another configuration error, with its position in the file
+    ///
+    /// Produced by [`crate::config::validator::validate_config_in`] when
+    /// the configuration was loaded from files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::{ConfigError, SourceLocation};
+    /// use std::path::PathBuf;
+    ///
+    /// let error = ConfigError::Located {
+    ///     error: Box::new(ConfigError::DuplicateCommand {
+    ///         name: "d".to_string(),
+    ///         files: vec![],
+    ///         suggestion: None,
+    ///     }),
+    ///     location: SourceLocation {
+    ///         file: PathBuf::from("commands.yaml"),
+    ///         line: 7,
+    ///         column: 15,
+    ///         len: 1,
+    ///         text: "    aliases: [d]".to_string(),
+    ///     },
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.starts_with("commands.yaml:7:15: Duplicate command"));
+    /// ```
+    #[error("{location}: {error}")]
+    Located {
+        error: Box<ConfigError>,
+        location: SourceLocation,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════
@@ -304,6 +433,101 @@ pub enum ParseError {
         suggestion: Option<String>,
     },
 
+    /// Two arguments or options that cannot be used together were given
+    ///
+    /// Raised for `conflicts_with` relations and for several members of
+    /// the same exclusive group.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ParseError;
+    ///
+    /// let error = ParseError::ConflictingArguments {
+    ///     first: "--file".to_string(),
+    ///     second: "--url".to_string(),
+    ///     suggestion: Some("Use either --file or --url, not both.".to_string()),
+    /// };
+    /// assert_eq!(error.to_string(), "--file cannot be used with --url");
+    /// ```
+    #[error("{first} cannot be used with {second}")]
+    ConflictingArguments {
+        first: String,
+        second: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// An argument or option was given without one it requires
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ParseError;
+    ///
+    /// let error = ParseError::MissingRequirement {
+    ///     argument: "--format".to_string(),
+    ///     required: "--output".to_string(),
+    ///     suggestion: Some("Add --output, or remove --format.".to_string()),
+    /// };
+    /// assert_eq!(error.to_string(), "--format requires --output");
+    /// ```
+    #[error("{argument} requires {required}")]
+    MissingRequirement {
+        argument: String,
+        required: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// No member of a required group was given
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ParseError;
+    ///
+    /// let error = ParseError::MissingGroupMember {
+    ///     group: "source".to_string(),
+    ///     members: vec!["--file".to_string(), "--url".to_string()],
+    ///     suggestion: None,
+    /// };
+    /// assert_eq!(error.to_string(), "One of --file, --url is required (group 'source')");
+    /// ```
+    #[error("One of {} is required (group '{group}')", .members.join(", "))]
+    MissingGroupMember {
+        group: String,
+        members: Vec<String>,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Wrong number of values for a variadic argument or repeatable option
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ParseError;
+    ///
+    /// let error = ParseError::WrongValueCount {
+    ///     arg_name: "files".to_string(),
+    ///     expected: "at least 2".to_string(),
+    ///     got: 1,
+    ///     suggestion: Some("Run --help merge for the expected usage.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("at least 2"));
+    /// ```
+    #[error("Wrong number of values for {arg_name}: expected {expected}, got {got}")]
+    WrongValueCount {
+        arg_name: String,
+        /// Accepted count (e.g. "at least 2", "between 1 and 3")
+        expected: String,
+        got: usize,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
     /// Unknown option
     ///
     /// Includes similar option suggestions.
@@ -490,6 +714,162 @@ pub enum ValidationError {
         /// Actionable hint surfaced to the user (not part of the Display string)
         suggestion: Option<String>,
     },
+
+    /// Value not among the allowed choices
+    ///
+    /// Raised when an option declares `choices` and the user supplies
+    /// a value outside that list. Includes similar choice suggestions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::InvalidChoice {
+    ///     arg_name: "priority".to_string(),
+    ///     value: "hgh".to_string(),
+    ///     choices: vec!["low".to_string(), "medium".to_string(), "high".to_string()],
+    ///     suggestions: vec!["high".to_string()],
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("hgh"));
+    /// assert!(msg.contains("low, medium, high"));
+    /// ```
+    #[error("Invalid value for {arg_name}: '{value}'. Must be one of: {}",
+        .choices.join(", "))]
+    InvalidChoice {
+        arg_name: String,
+        value: String,
+        choices: Vec<String>,
+        /// Similar choice suggestions (from Levenshtein distance)
+        suggestions: Vec<String>,
+    },
+
+    /// Value does not match the configured regular expression
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::PatternMismatch {
+    ///     arg_name: "name".to_string(),
+    ///     value: "My Project".to_string(),
+    ///     pattern: "^[a-z][a-z0-9-]*$".to_string(),
+    ///     suggestion: Some("Provide a value matching ^[a-z][a-z0-9-]*$.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("My Project"));
+    /// assert!(msg.contains("^[a-z][a-z0-9-]*$"));
+    /// ```
+    #[error("{arg_name} does not match the pattern {pattern}: '{value}'")]
+    PatternMismatch {
+        arg_name: String,
+        value: String,
+        pattern: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Value is shorter or longer than allowed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::InvalidLength {
+    ///     arg_name: "username".to_string(),
+    ///     expected: "between 3 and 16".to_string(),
+    ///     got: 2,
+    ///     suggestion: Some("Add at least 1 more character.".to_string()),
+    /// };
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "username must be between 3 and 16 characters long, got 2"
+    /// );
+    /// ```
+    #[error("{arg_name} must be {expected} characters long, got {got}")]
+    InvalidLength {
+        arg_name: String,
+        /// Accepted length (e.g. "at least 3", "between 3 and 16")
+        expected: String,
+        got: usize,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Value is empty or only whitespace
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::EmptyValue {
+    ///     arg_name: "title".to_string(),
+    ///     suggestion: Some("Provide a non-blank value for title.".to_string()),
+    /// };
+    /// assert_eq!(error.to_string(), "title cannot be empty");
+    /// ```
+    #[error("{arg_name} cannot be empty")]
+    EmptyValue {
+        arg_name: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Value is not in the configured text format
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::InvalidFormat {
+    ///     arg_name: "contact".to_string(),
+    ///     value: "alice.example.com".to_string(),
+    ///     format: "email".to_string(),
+    ///     suggestion: Some("Provide an address like user@example.com.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("not a valid email"));
+    /// ```
+    #[error("{arg_name} is not a valid {format}: '{value}'")]
+    InvalidFormat {
+        arg_name: String,
+        value: String,
+        format: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
+
+    /// Parsed arguments do not fit a handler's argument struct
+    ///
+    /// Raised by [`ParsedArgs::deserialize`](crate::parser::ParsedArgs::deserialize)
+    /// when a struct field has no value or a value of the wrong type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let error = ValidationError::InvalidField {
+    ///     field: "priority".to_string(),
+    ///     reason: "unknown variant `urgent`, expected `low` or `high`".to_string(),
+    ///     suggestion: None,
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("priority"));
+    /// assert!(msg.contains("urgent"));
+    /// ```
+    #[error("Invalid argument '{field}': {reason}")]
+    InvalidField {
+        field: String,
+        reason: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════
@@ -669,6 +1049,31 @@ pub enum RegistryError {
         /// Actionable hint surfaced to the user (not part of the Display string)
         suggestion: Option<String>,
     },
+
+    /// Subcommand registered under a command that does not exist
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::RegistryError;
+    ///
+    /// let error = RegistryError::UnknownParent {
+    ///     parent: "remote".to_string(),
+    ///     subcommand: "add".to_string(),
+    ///     suggestion: Some("Register 'remote' before its subcommands.".to_string()),
+    /// };
+    /// let msg = format!("{}", error);
+    /// assert!(msg.contains("remote"));
+    /// ```
+    #[error(
+        "Cannot register subcommand '{subcommand}': parent command '{parent}' is not registered"
+    )]
+    UnknownParent {
+        parent: String,
+        subcommand: String,
+        /// Actionable hint surfaced to the user (not part of the Display string)
+        suggestion: Option<String>,
+    },
 }
 
 // ═══════════════════════════════════════════════════════════
@@ -809,6 +1214,50 @@ impl ParseError {
             suggestion: Some(format!("Run --help {command} for the expected usage.")),
         }
     }
+
+    /// Create a wrong-value-count error from the accepted bounds
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ParseError;
+    ///
+    /// let error = ParseError::wrong_value_count("tags", "tag add", Some(1), Some(3), 4);
+    /// assert_eq!(
+    ///     error.to_string(),
+    ///     "Wrong number of values for tags: expected between 1 and 3, got 4"
+    /// );
+    /// ```
+    pub fn wrong_value_count(
+        arg_name: &str,
+        command: &str,
+        min: Option<usize>,
+        max: Option<usize>,
+        got: usize,
+    ) -> Self {
+        let expected = match (min, max) {
+            (Some(min), Some(max)) if min == max => format!("exactly {min}"),
+            (Some(min), Some(max)) => format!("between {min} and {max}"),
+            (Some(min), None) => format!("at least {min}"),
+            (None, Some(max)) => format!("at most {max}"),
+            (None, None) => "any number".to_string(),
+        };
+        Self::WrongValueCount {
+            arg_name: arg_name.to_string(),
+            expected,
+            got,
+            suggestion: Some(format!("Run --help {command} for the expected usage.")),
+        }
+    }
+}
+
+/// Display suffix naming the files of duplicate definitions
+fn defined_in(files: &[PathBuf]) -> String {
+    if files.is_empty() {
+        return String::new();
+    }
+    let files: Vec<String> = files.iter().map(|f| f.display().to_string()).collect();
+    format!(" (defined in {})", files.join(" and "))
 }
 
 impl ConfigError {
@@ -842,7 +1291,7 @@ impl ConfigError {
     /// ```
     /// use dynamic_cli::error::ConfigError;
     ///
-    /// let error = ConfigError::unsupported_format(".toml");
+    /// let error = ConfigError::unsupported_format(".ini");
     /// match error {
     ///     ConfigError::UnsupportedFormat { suggestion, .. } => {
     ///         assert!(suggestion.is_some());
@@ -853,7 +1302,111 @@ impl ConfigError {
     pub fn unsupported_format(extension: &str) -> Self {
         Self::UnsupportedFormat {
             extension: extension.to_string(),
-            suggestion: Some("Rename the file with a .yaml, .yml or .json extension.".to_string()),
+            suggestion: Some(
+                "Rename the file with a .yaml, .yml, .json or .toml extension.".to_string(),
+            ),
+        }
+    }
+
+    /// Create an error for a `custom:` rule naming an unregistered validator
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The validator name from the configuration
+    /// * `arg_name` - The argument carrying the rule
+    /// * `path` - Location of the rule in the configuration, if known
+    /// * `registered` - Names of the registered validators
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    ///
+    /// let registered = vec!["project_exists".to_string()];
+    /// let error = ConfigError::unknown_validator("project_exist", "project", None, &registered);
+    /// match error {
+    ///     ConfigError::InvalidSchema { reason, suggestion, .. } => {
+    ///         assert!(reason.contains("project_exist"));
+    ///         assert!(suggestion.unwrap().contains("project_exists"));
+    ///     }
+    ///     _ => panic!("wrong variant"),
+    /// }
+    /// ```
+    pub fn unknown_validator(
+        name: &str,
+        arg_name: &str,
+        path: Option<String>,
+        registered: &[String],
+    ) -> Self {
+        let similar = crate::error::find_similar_strings(name, registered, 1);
+        let suggestion = match similar.first() {
+            Some(candidate) => format!("Did you mean '{}'?", candidate),
+            None => "Register it with CliBuilder::register_validator().".to_string(),
+        };
+        Self::InvalidSchema {
+            reason: format!(
+                "Unknown custom validator '{}' for argument '{}'",
+                name, arg_name
+            ),
+            path,
+            suggestion: Some(suggestion),
+        }
+    }
+
+    /// Attach the file an error comes from
+    ///
+    /// Used for configurations split with `include:`: the `path` of an
+    /// [`InvalidSchema`](Self::InvalidSchema) error and the `key` of an
+    /// [`UndefinedVariable`](Self::UndefinedVariable) error are prefixed
+    /// with the file. Other variants are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    /// use std::path::Path;
+    ///
+    /// let error = ConfigError::InvalidSchema {
+    ///     reason: "Command name cannot be empty".to_string(),
+    ///     path: Some("commands[0].name".to_string()),
+    ///     suggestion: None,
+    /// }
+    /// .in_file(Path::new("teams/ops.yaml"));
+    /// match error {
+    ///     ConfigError::InvalidSchema { path, .. } => {
+    ///         assert_eq!(path.as_deref(), Some("teams/ops.yaml: commands[0].name"));
+    ///     }
+    ///     _ => panic!("wrong variant"),
+    /// }
+    /// ```
+    pub fn in_file(self, file: &Path) -> Self {
+        match self {
+            Self::InvalidSchema {
+                reason,
+                path,
+                suggestion,
+            } => Self::InvalidSchema {
+                reason,
+                path: Some(match path {
+                    Some(path) => format!("{}: {}", file.display(), path),
+                    None => file.display().to_string(),
+                }),
+                suggestion,
+            },
+            Self::UndefinedVariable {
+                name,
+                key,
+                suggestion,
+            } => Self::UndefinedVariable {
+                name,
+                key: format!("{}: {}", file.display(), key),
+                suggestion,
+            },
+            Self::Located { error, location } => Self::Located {
+                error: Box::new(error.in_file(file)),
+                location,
+            },
+            other => other,
         }
     }
 
@@ -875,6 +1428,81 @@ impl ConfigError {
             source,
         }
     }
+
+    /// Create a TOML parse error with position computed from the error span
+    ///
+    /// TOML errors report a byte range; `content` is the parsed text, used
+    /// to turn its start into a 1-based line and column.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ConfigError;
+    ///
+    /// let content = "[metadata]\nversion = 1.0.0\n";
+    /// let source = toml::from_str::<toml::Table>(content).unwrap_err();
+    /// match ConfigError::toml_parse_with_location(source, content) {
+    ///     ConfigError::TomlParse { line, column, .. } => {
+    ///         assert_eq!(line, Some(2));
+    ///         assert!(column.is_some());
+    ///     }
+    ///     _ => panic!("wrong variant"),
+    /// }
+    /// ```
+    pub fn toml_parse_with_location(source: toml::de::Error, content: &str) -> Self {
+        let position = source.span().and_then(|span| {
+            let before = content.get(..span.start)?;
+            let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+            let line = before.matches('\n').count() + 1;
+            let column = before[line_start..].chars().count() + 1;
+            Some((line, column))
+        });
+        Self::TomlParse {
+            source,
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+        }
+    }
+}
+
+impl ValidationError {
+    /// Create an invalid choice error with Levenshtein suggestions
+    ///
+    /// Automatically computes the allowed values closest to `value`.
+    ///
+    /// # Arguments
+    ///
+    /// * `arg_name` - Name of the option or argument
+    /// * `value` - The value typed by the user
+    /// * `choices` - The allowed values
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::error::ValidationError;
+    ///
+    /// let choices = vec!["low".to_string(), "medium".to_string(), "high".to_string()];
+    /// let error = ValidationError::invalid_choice_with_suggestions("priority", "hihg", &choices);
+    /// match error {
+    ///     ValidationError::InvalidChoice { suggestions, .. } => {
+    ///         assert!(suggestions.contains(&"high".to_string()));
+    ///     }
+    ///     _ => panic!("wrong variant"),
+    /// }
+    /// ```
+    pub fn invalid_choice_with_suggestions(
+        arg_name: &str,
+        value: &str,
+        choices: &[String],
+    ) -> Self {
+        let suggestions = crate::error::find_similar_strings(value, choices, 3);
+        Self::InvalidChoice {
+            arg_name: arg_name.to_string(),
+            value: value.to_string(),
+            choices: choices.to_vec(),
+            suggestions,
+        }
+    }
 }
 
 impl ExecutionError {
@@ -966,14 +1594,14 @@ mod tests {
 
     #[test]
     fn test_config_unsupported_format_helper_has_suggestion() {
-        let error = ConfigError::unsupported_format(".toml");
+        let error = ConfigError::unsupported_format(".ini");
         match error {
             ConfigError::UnsupportedFormat {
                 suggestion,
                 extension,
                 ..
             } => {
-                assert_eq!(extension, ".toml");
+                assert_eq!(extension, ".ini");
                 assert!(suggestion.is_some());
             }
             _ => panic!("wrong variant"),
@@ -984,14 +1612,41 @@ mod tests {
     fn test_config_duplicate_command_display() {
         let error = ConfigError::DuplicateCommand {
             name: "run".to_string(),
+            files: vec![],
             suggestion: Some("Rename one of the conflicting commands.".to_string()),
         };
         let msg = format!("{}", error);
-        assert!(msg.contains("run"));
+        assert_eq!(msg, "Duplicate command name or alias: 'run'");
         // suggestion must NOT appear in Display (it's rendered separately)
         assert!(!msg.contains("Rename"));
     }
 
+    #[test]
+    fn test_config_duplicate_command_display_with_files() {
+        let error = ConfigError::DuplicateCommand {
+            name: "run".to_string(),
+            files: vec![PathBuf::from("a.yaml"), PathBuf::from("b.yaml")],
+            suggestion: None,
+        };
+        assert_eq!(
+            error.to_string(),
+            "Duplicate command name or alias: 'run' (defined in a.yaml and b.yaml)"
+        );
+    }
+
+    #[test]
+    fn test_config_duplicate_global_option_display() {
+        let error = ConfigError::DuplicateGlobalOption {
+            name: "verbose".to_string(),
+            files: vec![PathBuf::from("a.yaml"), PathBuf::from("b.yaml")],
+            suggestion: Some("Define each global option in one file.".to_string()),
+        };
+        assert_eq!(
+            error.to_string(),
+            "Duplicate global option: 'verbose' (defined in a.yaml and b.yaml)"
+        );
+    }
+
     #[test]
     fn test_config_unknown_type_display() {
         let error = ConfigError::UnknownType {
@@ -1025,6 +1680,19 @@ mod tests {
         assert!(msg.contains("missing field"));
     }
 
+    #[test]
+    fn test_config_undefined_variable_in_file_prefixes_key() {
+        let error = ConfigError::UndefinedVariable {
+            name: "HOME".to_string(),
+            key: "metadata.prompt".to_string(),
+            suggestion: None,
+        }
+        .in_file(Path::new("teams/ops.yaml"));
+        let msg = format!("{}", error);
+        assert!(msg.contains("'HOME'"));
+        assert!(msg.contains("teams/ops.yaml: metadata.prompt"));
+    }
+
     // ── ParseError ───────────────────────────────────────────
 
     #[test]
@@ -1094,6 +1762,18 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_wrong_value_count_describes_bounds() {
+        let describe = |min, max| match ParseError::wrong_value_count("x", "cmd", min, max, 0) {
+            ParseError::WrongValueCount { expected, .. } => expected,
+            _ => panic!("wrong variant"),
+        };
+        assert_eq!(describe(Some(2), Some(2)), "exactly 2");
+        assert_eq!(describe(Some(1), Some(3)), "between 1 and 3");
+        assert_eq!(describe(Some(2), None), "at least 2");
+        assert_eq!(describe(None, Some(4)), "at most 4");
+    }
+
     #[test]
     fn test_parse_missing_argument_suggestion_none_by_default() {
         // Direct construction without helper: suggestion is caller's responsibility
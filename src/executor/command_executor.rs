@@ -0,0 +1,139 @@
+//! Command dispatch
+//!
+//! The interfaces run every command through [`execute_command`], so that
+//! a handler's [`validate`](CommandHandler::validate) hook is always
+//! called before its [`execute_parsed`](CommandHandler::execute_parsed).
+
+use crate::context::ExecutionContext;
+use crate::executor::CommandHandler;
+use crate::parser::ParsedArgs;
+use crate::Result;
+
+/// Validate the arguments with the handler, then execute it
+///
+/// `args` have already been through parsing and the configured
+/// validation (rules and constraints); this adds the handler's own
+/// checks. The handler does not run if [`CommandHandler::validate`]
+/// fails.
+///
+/// # Errors
+///
+/// The error returned by `validate` or by the handler.
+///
+/// # Example
+///
+/// ```
+/// use std::collections::HashMap;
+/// use dynamic_cli::context::ExecutionContext;
+/// use dynamic_cli::error::ExecutionError;
+/// use dynamic_cli::executor::{execute_command, CommandHandler};
+/// use dynamic_cli::parser::ParsedArgs;
+/// use dynamic_cli::Result;
+///
+/// # #[derive(Default)]
+/// # struct AppContext;
+/// # impl ExecutionContext for AppContext {
+/// #     fn as_any(&self) -> &dyn std::any::Any { self }
+/// #     fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+/// # }
+/// struct DeployCommand;
+///
+/// impl CommandHandler for DeployCommand {
+///     fn execute(&self, _: &mut dyn ExecutionContext, _: &HashMap<String, String>) -> Result<()> {
+///         Ok(())
+///     }
+///
+///     fn validate(&self, args: &HashMap<String, String>) -> Result<()> {
+///         if args.get("env").map(String::as_str) == Some("prod") {
+///             return Err(ExecutionError::CommandFailed(anyhow::anyhow!(
+///                 "deploying to prod needs approval"
+///             ))
+///             .into());
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mut context = AppContext::default();
+/// let mut args = HashMap::new();
+/// args.insert("env".to_string(), "prod".to_string());
+///
+/// let args = ParsedArgs::from(args);
+/// assert!(execute_command(&DeployCommand, &mut context, &args).is_err());
+/// ```
+pub fn execute_command(
+    handler: &dyn CommandHandler,
+    context: &mut dyn ExecutionContext,
+    args: &ParsedArgs,
+) -> Result<()> {
+    handler.validate(args.as_map())?;
+    handler.execute_parsed(context, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ExecutionError;
+    use std::any::Any;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct TestContext {
+        executed: bool,
+    }
+
+    impl ExecutionContext for TestContext {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+    }
+
+    /// Rejects a `count` above 10
+    struct LimitedCommand;
+
+    impl CommandHandler for LimitedCommand {
+        fn execute(
+            &self,
+            context: &mut dyn ExecutionContext,
+            _args: &HashMap<String, String>,
+        ) -> Result<()> {
+            crate::context::downcast_mut::<TestContext>(context)
+                .unwrap()
+                .executed = true;
+            Ok(())
+        }
+
+        fn validate(&self, args: &HashMap<String, String>) -> Result<()> {
+            let count: i64 = args.get("count").and_then(|c| c.parse().ok()).unwrap_or(0);
+            if count > 10 {
+                return Err(
+                    ExecutionError::CommandFailed(anyhow::anyhow!("count too large")).into(),
+                );
+            }
+            Ok(())
+        }
+    }
+
+    fn args(count: &str) -> ParsedArgs {
+        ParsedArgs::from(HashMap::from([("count".to_string(), count.to_string())]))
+    }
+
+    #[test]
+    fn test_execute_command_runs_valid_command() {
+        let mut context = TestContext::default();
+        execute_command(&LimitedCommand, &mut context, &args("3")).unwrap();
+        assert!(context.executed);
+    }
+
+    #[test]
+    fn test_execute_command_skips_handler_when_validate_fails() {
+        let mut context = TestContext::default();
+        let err = execute_command(&LimitedCommand, &mut context, &args("11")).unwrap_err();
+        assert!(err.to_string().contains("count too large"));
+        assert!(!context.executed);
+    }
+}
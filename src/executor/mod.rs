@@ -6,8 +6,8 @@
 //!
 //! # Module Organization
 //!
-//! - [`traits`]: Core trait definitions (`CommandHandler`)
-//! - `command_executor` (future): Executor logic for running commands
+//! - [`traits`]: Core trait definitions (`CommandHandler`, `TypedCommandHandler`)
+//! - [`command_executor`]: Dispatch, calling `validate` before `execute_parsed`
 //!
 //! # Architecture
 //!
@@ -272,10 +272,12 @@
 //! ```
 
 // Public submodules
+pub mod command_executor;
 pub mod traits;
 
 // Public re-exports for convenience
-pub use traits::CommandHandler;
+pub use command_executor::execute_command;
+pub use traits::{CommandHandler, TypedCommandHandler};
 
 #[cfg(test)]
 mod tests {
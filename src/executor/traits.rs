@@ -24,6 +24,11 @@
 //! - Provides flexibility in argument types
 //! - Delegates type parsing to the parser module
 //!
+//! Handlers that prefer typed values override `execute_parsed`, which
+//! receives a [`ParsedArgs`] with accessors such as `get_i64` and `get_bool`.
+//! Handlers that only read typed values implement [`TypedCommandHandler`]
+//! instead.
+//!
 //! ## Thread Safety
 //!
 //! All handlers must be `Send + Sync` to support:
@@ -57,6 +62,7 @@
 
 use crate::context::ExecutionContext;
 use crate::error::Result;
+use crate::parser::parsed_args::ParsedArgs;
 use std::collections::HashMap;
 
 /// Trait for command implementations
@@ -83,7 +89,8 @@ use std::collections::HashMap;
 /// 1. Parser converts user input to `HashMap<String, String>`
 /// 2. Validator checks argument constraints
 /// 3. `validate()` is called for custom validation (optional)
-/// 4. `execute()` is called with validated arguments
+/// 4. `execute_parsed()` is called with validated, typed arguments;
+///    by default it forwards the string values to `execute()`
 ///
 /// # Example
 ///
@@ -211,11 +218,68 @@ pub trait CommandHandler: Send + Sync {
         args: &HashMap<String, String>,
     ) -> Result<()>;
 
+    /// Execute the command with typed arguments
+    ///
+    /// This is the method invoked by the CLI and REPL interfaces. The
+    /// arguments have already been converted to their declared types, so
+    /// handlers can use [`ParsedArgs::get_i64`], [`ParsedArgs::get_bool`],
+    /// etc. instead of parsing strings again.
+    ///
+    /// # Default Implementation
+    ///
+    /// Delegates to [`execute`](Self::execute) with the string values, so
+    /// existing map-based handlers keep working unchanged.
+    ///
+    /// A handler that overrides this method still has to provide
+    /// `execute`; it can delegate with [`ParsedArgs::from`], whose
+    /// accessors parse the strings on access. Handlers without a use for
+    /// the string values implement [`TypedCommandHandler`] instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use dynamic_cli::executor::CommandHandler;
+    /// # use dynamic_cli::context::ExecutionContext;
+    /// # use dynamic_cli::parser::ParsedArgs;
+    /// # use dynamic_cli::Result;
+    /// #
+    /// struct RepeatCommand;
+    ///
+    /// impl CommandHandler for RepeatCommand {
+    ///     fn execute(
+    ///         &self,
+    ///         context: &mut dyn ExecutionContext,
+    ///         args: &HashMap<String, String>,
+    ///     ) -> Result<()> {
+    ///         self.execute_parsed(context, &ParsedArgs::from(args.clone()))
+    ///     }
+    ///
+    ///     fn execute_parsed(
+    ///         &self,
+    ///         _context: &mut dyn ExecutionContext,
+    ///         args: &ParsedArgs,
+    ///     ) -> Result<()> {
+    ///         let times = args.get_i64("times").unwrap_or(1);
+    ///         let loud = args.get_bool("loud").unwrap_or(false);
+    ///         for _ in 0..times {
+    ///             println!("{}", if loud { "HELLO" } else { "hello" });
+    ///         }
+    ///         Ok(())
+    ///     }
+    /// }
+    /// ```
+    fn execute_parsed(&self, context: &mut dyn ExecutionContext, args: &ParsedArgs) -> Result<()> {
+        self.execute(context, args.as_map())
+    }
+
     /// Optional custom validation for arguments
     ///
-    /// This method is called after the standard validation (type checking,
-    /// required arguments, etc.) but before execution. It allows commands
-    /// to implement custom validation logic.
+    /// The CLI and REPL interfaces call this method after the standard
+    /// validation (type checking, required arguments, configured rules and
+    /// constraints) and before execution; see
+    /// [`execute_command`](crate::executor::execute_command). If it fails,
+    /// the command is not executed.
     ///
     /// # Default Implementation
     ///
@@ -275,12 +339,80 @@ pub trait CommandHandler: Send + Sync {
     }
 }
 
+/// Trait for command implementations that only read typed arguments
+///
+/// Every `TypedCommandHandler` is a [`CommandHandler`]: its `execute`
+/// wraps the string values with [`ParsedArgs::from`] and its
+/// `execute_parsed` calls [`execute_typed`](Self::execute_typed). Register
+/// it like any other handler.
+///
+/// A type implements either this trait or [`CommandHandler`], not both.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::executor::{CommandHandler, TypedCommandHandler};
+/// use dynamic_cli::context::ExecutionContext;
+/// use dynamic_cli::parser::ParsedArgs;
+/// use dynamic_cli::Result;
+///
+/// struct RepeatCommand;
+///
+/// impl TypedCommandHandler for RepeatCommand {
+///     fn execute_typed(
+///         &self,
+///         _context: &mut dyn ExecutionContext,
+///         args: &ParsedArgs,
+///     ) -> Result<()> {
+///         let times = args.get_i64("times").unwrap_or(1);
+///         for _ in 0..times {
+///             println!("hello");
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let handler: Box<dyn CommandHandler> = Box::new(RepeatCommand);
+/// ```
+pub trait TypedCommandHandler: Send + Sync {
+    /// Execute the command with typed arguments
+    ///
+    /// See [`CommandHandler::execute_parsed`].
+    fn execute_typed(&self, context: &mut dyn ExecutionContext, args: &ParsedArgs) -> Result<()>;
+
+    /// Optional custom validation for arguments
+    ///
+    /// See [`CommandHandler::validate`]. The default implementation accepts
+    /// all arguments.
+    fn validate(&self, _args: &HashMap<String, String>) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<T: TypedCommandHandler> CommandHandler for T {
+    fn execute(
+        &self,
+        context: &mut dyn ExecutionContext,
+        args: &HashMap<String, String>,
+    ) -> Result<()> {
+        self.execute_typed(context, &ParsedArgs::from(args.clone()))
+    }
+
+    fn execute_parsed(&self, context: &mut dyn ExecutionContext, args: &ParsedArgs) -> Result<()> {
+        self.execute_typed(context, args)
+    }
+
+    fn validate(&self, args: &HashMap<String, String>) -> Result<()> {
+        TypedCommandHandler::validate(self, args)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::error::ExecutionError;
     use std::any::Any;
-    use std::sync::{Arc, Mutex};
+    use std::sync::Arc;
 
     // ============================================================================
     // TEST FIXTURES
@@ -527,7 +659,7 @@ mod tests {
         }
 
         let handler = HelloCommand;
-        let mut wrong_context = WrongContext::default();
+        let mut wrong_context = WrongContext;
         let args = HashMap::new();
 
         let result = handler.execute(&mut wrong_context, &args);
@@ -544,8 +676,9 @@ mod tests {
     #[test]
     fn test_context_state_modification() {
         let handler = StatefulCommand;
-        let mut context = TestContext::default();
-        context.state = "initial".to_string();
+        let mut context = TestContext {
+            state: "initial".to_string(),
+        };
         let mut args = HashMap::new();
         args.insert("value".to_string(), "_modified".to_string());
 
@@ -573,6 +706,58 @@ mod tests {
         assert_eq!(context.state, "first_second");
     }
 
+    // ============================================================================
+    // TYPED ARGUMENT TESTS
+    // ============================================================================
+
+    /// Command that only reads typed values
+    struct TypedCommand;
+
+    impl TypedCommandHandler for TypedCommand {
+        fn execute_typed(
+            &self,
+            context: &mut dyn ExecutionContext,
+            args: &ParsedArgs,
+        ) -> Result<()> {
+            let ctx = crate::context::downcast_mut::<TestContext>(context).ok_or_else(|| {
+                ExecutionError::CommandFailed(anyhow::anyhow!("Wrong context type"))
+            })?;
+
+            let count = args.get_i64("count").unwrap_or(0);
+            ctx.state = format!("count={}", count * 2);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_default_execute_parsed_delegates_to_execute() {
+        let handler = HelloCommand;
+        let mut context = TestContext::default();
+        let mut args = HashMap::new();
+        args.insert("name".to_string(), "Typed".to_string());
+
+        let result = handler.execute_parsed(&mut context, &ParsedArgs::from(args));
+
+        assert!(result.is_ok());
+        assert_eq!(context.state, "Hello, Typed!");
+    }
+
+    #[test]
+    fn test_typed_handler_reads_typed_values() {
+        let handler: Box<dyn CommandHandler> = Box::new(TypedCommand);
+        let mut context = TestContext::default();
+        let mut args = HashMap::new();
+        args.insert("count".to_string(), "21".to_string());
+
+        handler.execute(&mut context, &args).unwrap();
+        assert_eq!(context.state, "count=42");
+
+        handler
+            .execute_parsed(&mut context, &ParsedArgs::from(args))
+            .unwrap();
+        assert_eq!(context.state, "count=42");
+    }
+
     // ============================================================================
     // TRAIT OBJECT TESTS
     // ============================================================================
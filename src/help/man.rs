@@ -0,0 +1,588 @@
+//! Man page generation
+//!
+//! [`ManPageGenerator`] renders a [`CommandsConfig`] as roff man pages: one
+//! page for the application (`man myapp`) and one per command and
+//! subcommand (`man myapp-deploy`, `man myapp-remote-add`).
+//!
+//! Pages contain the NAME, SYNOPSIS, DESCRIPTION, SUBCOMMANDS, ARGUMENTS,
+//! OPTIONS, ALIASES and SEE ALSO sections; empty sections are omitted.
+//! The synopsis uses the same usage line as `--help`.
+//!
+//! The output only depends on the configuration: no date is written unless
+//! one is set with [`ManPageGenerator::date`], so pages can be
+//! snapshot-tested and shipped in packages.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::help::ManPageGenerator;
+//! use dynamic_cli::config::schema::{CommandDefinition, CommandsConfig, Metadata, SCHEMA_VERSION};
+//!
+//! let config = CommandsConfig {
+//!     metadata: Metadata {
+//!         version: "1.0.0".to_string(),
+//!         prompt: "mytool".to_string(),
+//!         prompt_suffix: " > ".to_string(),
+//!     },
+//!     commands: vec![CommandDefinition {
+//!         name: "deploy".to_string(),
+//!         aliases: vec![],
+//!         description: "Deploy the application".to_string(),
+//!         required: false,
+//!         arguments: vec![],
+//!         options: vec![],
+//!         implementation: "deploy_handler".to_string(),
+//!         ..Default::default()
+//!     }],
+//!     global_options: vec![],
+//!     include: vec![],
+//!     schema_version: SCHEMA_VERSION,
+//! };
+//!
+//! let pages = ManPageGenerator::new().render_all(&config);
+//! assert_eq!(pages[0].file_name(), "mytool.1");
+//! assert_eq!(pages[1].file_name(), "mytool-deploy.1");
+//! assert!(pages[1].content.contains(".SH NAME\nmytool\\-deploy \\- Deploy the application"));
+//! ```
+
+use super::DefaultHelpFormatter;
+use crate::config::schema::{visible, CommandDefinition, CommandsConfig, OptionDefinition};
+
+// ============================================================================
+// ManPage
+// ============================================================================
+
+/// A rendered man page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManPage {
+    /// Page name (`myapp`, `myapp-deploy`)
+    pub name: String,
+
+    /// Manual section (`1` by default)
+    pub section: String,
+
+    /// roff source of the page
+    pub content: String,
+}
+
+impl ManPage {
+    /// File name of the page, e.g. `myapp-deploy.1`
+    pub fn file_name(&self) -> String {
+        format!("{}.{}", self.name, self.section)
+    }
+}
+
+// ============================================================================
+// ManPageGenerator
+// ============================================================================
+
+/// Renders a [`CommandsConfig`] as roff man pages
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::help::ManPageGenerator;
+///
+/// let generator = ManPageGenerator::new().section("8").date("2024-01-31");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ManPageGenerator {
+    /// Manual section
+    section: String,
+
+    /// Date shown in the page footer (none by default)
+    date: Option<String>,
+}
+
+impl Default for ManPageGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManPageGenerator {
+    /// Create a generator for section 1 (user commands), without a date
+    pub fn new() -> Self {
+        Self {
+            section: "1".to_string(),
+            date: None,
+        }
+    }
+
+    /// Set the manual section (e.g. `"8"` for administration tools)
+    pub fn section(mut self, section: impl Into<String>) -> Self {
+        self.section = section.into();
+        self
+    }
+
+    /// Set the date shown in the page footer
+    ///
+    /// Leave it unset to keep the output independent of the build time.
+    pub fn date(mut self, date: impl Into<String>) -> Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Render the application page and one page per command
+    ///
+    /// Pages are returned in configuration order: the application page
+    /// first, then each command followed by its subcommands.
+    pub fn render_all(&self, config: &CommandsConfig) -> Vec<ManPage> {
+        let mut pages = vec![self.page(&config.metadata.prompt, self.render_app(config))];
+        self.collect_pages(config, &config.commands, "", &mut pages);
+        pages
+    }
+
+    /// Render the application page (`man myapp`)
+    pub fn render_app(&self, config: &CommandsConfig) -> String {
+        let program = &config.metadata.prompt;
+        let mut out = self.header(config, program);
+
+        out.push_str(&format!(
+            ".SH NAME\n{} \\- manual page for {} {}\n",
+            escape(program),
+            escape(program),
+            escape(&config.metadata.version)
+        ));
+
+        // Same usage line as `--help`
+        let usage = if config.global_options.is_empty() {
+            "<command> [arguments] [options]"
+        } else {
+            "[global options] <command> [arguments] [options]"
+        };
+        out.push_str(&format!(
+            ".SH SYNOPSIS\n.B {}\n{}\n",
+            escape(program),
+            usage
+        ));
+
+        out.push_str(&format!(
+            ".SH DESCRIPTION\n\\fB{}\\fR provides the commands listed below.\n\
+             Run \\fB{} \\-\\-help\\fR \\fIcommand\\fR for a summary of a command.\n",
+            escape(program),
+            escape(program)
+        ));
+
+        let commands = config.visible_commands();
+        out.push_str(&command_list("COMMANDS", &commands));
+        out.push_str(&option_list("OPTIONS", &config.global_options));
+
+        let see_also: Vec<String> = commands
+            .iter()
+            .map(|cmd| self.reference(&page_name(program, &cmd.name)))
+            .collect();
+        out.push_str(&see_also_section(&see_also));
+
+        out
+    }
+
+    /// Render the page of a command, addressed by name, alias or path
+    /// (`"remote add"`)
+    ///
+    /// Returns `None` if the command does not exist.
+    pub fn render_command(&self, config: &CommandsConfig, command: &str) -> Option<String> {
+        let (path, cmd) = DefaultHelpFormatter::find_command(config, command).ok()?;
+        Some(self.render_definition(config, &path, cmd))
+    }
+
+    // ------------------------------------------------------------------------
+    // Private helpers
+    // ------------------------------------------------------------------------
+
+    /// Render the page of a resolved command
+    fn render_definition(
+        &self,
+        config: &CommandsConfig,
+        path: &str,
+        cmd: &CommandDefinition,
+    ) -> String {
+        let program = &config.metadata.prompt;
+        let name = page_name(program, path);
+        let invocation = format!("{} {}", program, path);
+        let mut out = self.header(config, &name);
+
+        out.push_str(&format!(
+            ".SH NAME\n{} \\- {}\n",
+            escape(&name),
+            escape(&cmd.description)
+        ));
+
+        // Same usage lines as `--help <command>`
+        out.push_str(".SH SYNOPSIS\n");
+        let mut synopsis = Vec::new();
+        if !cmd.subcommands.is_empty() {
+            synopsis.push("<subcommand> [arguments] [options]".to_string());
+        }
+        if cmd.subcommands.is_empty() || !cmd.arguments.is_empty() || !cmd.options.is_empty() {
+            synopsis.push(DefaultHelpFormatter::usage_args(cmd));
+        }
+        let lines: Vec<String> = synopsis
+            .iter()
+            .map(|usage| {
+                let mut line = format!(".B {}\n", escape(&invocation));
+                if !usage.is_empty() {
+                    line.push_str(&format!("{}\n", escape(usage)));
+                }
+                line
+            })
+            .collect();
+        out.push_str(&lines.join(".br\n"));
+
+        out.push_str(&format!(".SH DESCRIPTION\n{}\n", escape(&cmd.description)));
+
+        let subcommands = cmd.visible_subcommands();
+        out.push_str(&command_list("SUBCOMMANDS", &subcommands));
+
+        if !cmd.arguments.is_empty() {
+            out.push_str(".SH ARGUMENTS\n");
+            for arg in &cmd.arguments {
+                let req = if arg.required { "required" } else { "optional" };
+                out.push_str(&format!(
+                    ".TP\n\\fI{}\\fR ({}, {})\n{}\n",
+                    escape(&arg.name),
+                    arg.arg_type.as_str(),
+                    req,
+                    escape(&arg.description)
+                ));
+            }
+        }
+
+        out.push_str(&option_list("OPTIONS", &cmd.options));
+
+        if !cmd.aliases.is_empty() {
+            out.push_str(&format!(
+                ".SH ALIASES\n{}\n",
+                escape(&cmd.aliases.join(", "))
+            ));
+        }
+
+        let parent = match path.rsplit_once(' ') {
+            Some((parent, _)) => page_name(program, parent),
+            None => program.clone(),
+        };
+        let mut see_also = vec![self.reference(&parent)];
+        see_also.extend(
+            subcommands
+                .iter()
+                .map(|sub| self.reference(&page_name(program, &format!("{} {}", path, sub.name)))),
+        );
+        out.push_str(&see_also_section(&see_also));
+
+        out
+    }
+
+    /// Add the pages of `commands` and their subcommands, depth first
+    fn collect_pages(
+        &self,
+        config: &CommandsConfig,
+        commands: &[CommandDefinition],
+        parent: &str,
+        pages: &mut Vec<ManPage>,
+    ) {
+        for cmd in visible(commands) {
+            let path = if parent.is_empty() {
+                cmd.name.clone()
+            } else {
+                format!("{} {}", parent, cmd.name)
+            };
+            let content = self.render_definition(config, &path, cmd);
+            pages.push(self.page(&page_name(&config.metadata.prompt, &path), content));
+            self.collect_pages(config, &cmd.subcommands, &path, pages);
+        }
+    }
+
+    /// Wrap rendered content into a [`ManPage`]
+    fn page(&self, name: &str, content: String) -> ManPage {
+        ManPage {
+            name: name.to_string(),
+            section: self.section.clone(),
+            content,
+        }
+    }
+
+    /// `.TH` title line
+    fn header(&self, config: &CommandsConfig, name: &str) -> String {
+        format!(
+            ".TH \"{}\" \"{}\" \"{}\" \"{} {}\" \"User Commands\"\n",
+            escape(&name.to_uppercase()),
+            escape(&self.section),
+            escape(self.date.as_deref().unwrap_or("")),
+            escape(&config.metadata.prompt),
+            escape(&config.metadata.version)
+        )
+    }
+
+    /// Reference to another page, e.g. `\fBmyapp\-deploy\fR(1)`
+    fn reference(&self, name: &str) -> String {
+        format!("\\fB{}\\fR({})", escape(name), escape(&self.section))
+    }
+}
+
+/// Page name of a command path (`myapp-remote-add`)
+fn page_name(program: &str, path: &str) -> String {
+    std::iter::once(program)
+        .chain(path.split_whitespace())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// A section listing commands with their descriptions
+fn command_list(title: &str, commands: &[&CommandDefinition]) -> String {
+    if commands.is_empty() {
+        return String::new();
+    }
+    let mut out = format!(".SH {}\n", title);
+    for cmd in commands {
+        out.push_str(&format!(
+            ".TP\n\\fB{}\\fR\n{}\n",
+            escape(&cmd.name),
+            escape(&cmd.description)
+        ));
+    }
+    out
+}
+
+/// A section listing options with type, environment variable, default and choices
+fn option_list(title: &str, options: &[OptionDefinition]) -> String {
+    if options.is_empty() {
+        return String::new();
+    }
+    let mut out = format!(".SH {}\n", title);
+    for opt in options {
+        let mut description = opt.description.clone();
+        if opt.multiple {
+            description.push_str(" [repeatable]");
+        }
+        if let Some(env) = &opt.env {
+            description.push_str(&format!(" [env: {}]", env));
+        }
+        if let Some(default) = &opt.default {
+            description.push_str(&format!(" [default: {}]", default));
+        }
+        if !opt.choices.is_empty() {
+            description.push_str(&format!(" [possible values: {}]", opt.choices.join(", ")));
+        }
+        out.push_str(&format!(
+            ".TP\n\\fB{}\\fR ({})\n{}\n",
+            escape(&DefaultHelpFormatter::flag_label(opt)),
+            opt.option_type.as_str(),
+            escape(&description)
+        ));
+    }
+    out
+}
+
+/// SEE ALSO section from page references
+fn see_also_section(references: &[String]) -> String {
+    if references.is_empty() {
+        return String::new();
+    }
+    format!(".SH SEE ALSO\n{}\n", references.join(", "))
+}
+
+/// Escape text for roff
+///
+/// Backslashes and hyphens are escaped, and lines starting with a control
+/// character (`.` or `'`) are protected with a zero-width `\&`.
+fn escape(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let escaped = line.replace('\\', "\\e").replace('-', "\\-");
+            if escaped.starts_with('.') || escaped.starts_with('\'') {
+                format!("\\&{}", escaped)
+            } else {
+                escaped
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{ArgumentDefinition, ArgumentType, Metadata, SCHEMA_VERSION};
+
+    fn make_config() -> CommandsConfig {
+        let add = CommandDefinition {
+            name: "add".to_string(),
+            aliases: vec!["a".to_string()],
+            description: "Add a remote".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "url".to_string(),
+                arg_type: ArgumentType::String,
+                required: true,
+                description: "Remote URL".to_string(),
+                validation: vec![],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![OptionDefinition {
+                name: "mode".to_string(),
+                short: Some("m".to_string()),
+                long: Some("mode".to_string()),
+                option_type: ArgumentType::String,
+                required: false,
+                default: Some("fetch".to_string()),
+                description: "Access mode".to_string(),
+                choices: vec!["fetch".to_string(), "push".to_string()],
+                env: Some("REMOTE_MODE".to_string()),
+                ..Default::default()
+            }],
+            implementation: "add_handler".to_string(),
+            ..Default::default()
+        };
+
+        CommandsConfig {
+            metadata: Metadata {
+                version: "2.1.0".to_string(),
+                prompt: "mytool".to_string(),
+                prompt_suffix: " > ".to_string(),
+            },
+            commands: vec![CommandDefinition {
+                name: "remote".to_string(),
+                aliases: vec![],
+                description: "Manage remotes".to_string(),
+                required: false,
+                arguments: vec![],
+                options: vec![],
+                implementation: String::new(),
+                subcommands: vec![add],
+                ..Default::default()
+            }],
+            global_options: vec![OptionDefinition {
+                name: "verbose".to_string(),
+                short: Some("v".to_string()),
+                long: Some("verbose".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Verbose output".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+
+    #[test]
+    fn test_render_app_snapshot() {
+        let page = ManPageGenerator::new().render_app(&make_config());
+
+        assert_eq!(
+            page,
+            r#".TH "MYTOOL" "1" "" "mytool 2.1.0" "User Commands"
+.SH NAME
+mytool \- manual page for mytool 2.1.0
+.SH SYNOPSIS
+.B mytool
+[global options] <command> [arguments] [options]
+.SH DESCRIPTION
+\fBmytool\fR provides the commands listed below.
+Run \fBmytool \-\-help\fR \fIcommand\fR for a summary of a command.
+.SH COMMANDS
+.TP
+\fBremote\fR
+Manage remotes
+.SH OPTIONS
+.TP
+\fB\-v, \-\-verbose\fR (bool)
+Verbose output
+.SH SEE ALSO
+\fBmytool\-remote\fR(1)
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_command_snapshot() {
+        let page = ManPageGenerator::new()
+            .render_command(&make_config(), "remote add")
+            .unwrap();
+
+        assert_eq!(
+            page,
+            r#".TH "MYTOOL\-REMOTE\-ADD" "1" "" "mytool 2.1.0" "User Commands"
+.SH NAME
+mytool\-remote\-add \- Add a remote
+.SH SYNOPSIS
+.B mytool remote add
+<url> [options]
+.SH DESCRIPTION
+Add a remote
+.SH ARGUMENTS
+.TP
+\fIurl\fR (string, required)
+Remote URL
+.SH OPTIONS
+.TP
+\fB\-m, \-\-mode\fR (string)
+Access mode [env: REMOTE_MODE] [default: fetch] [possible values: fetch, push]
+.SH ALIASES
+a
+.SH SEE ALSO
+\fBmytool\-remote\fR(1)
+"#
+        );
+    }
+
+    #[test]
+    fn test_render_command_with_subcommands() {
+        let page = ManPageGenerator::new()
+            .render_command(&make_config(), "remote")
+            .unwrap();
+
+        assert!(page.contains(".B mytool remote\n<subcommand> [arguments] [options]\n"));
+        assert!(page.contains(".SH SUBCOMMANDS\n.TP\n\\fBadd\\fR\nAdd a remote\n"));
+        assert!(page.contains("\\fBmytool\\fR(1), \\fBmytool\\-remote\\-add\\fR(1)"));
+        assert!(!page.contains(".SH ARGUMENTS"));
+    }
+
+    #[test]
+    fn test_render_command_by_alias_and_unknown() {
+        let config = make_config();
+        let generator = ManPageGenerator::new();
+
+        assert_eq!(
+            generator.render_command(&config, "remote a"),
+            generator.render_command(&config, "remote add")
+        );
+        assert!(generator.render_command(&config, "deploy").is_none());
+    }
+
+    #[test]
+    fn test_render_all_pages() {
+        let pages = ManPageGenerator::new()
+            .section("8")
+            .date("2024-01-31")
+            .render_all(&make_config());
+
+        let names: Vec<String> = pages.iter().map(ManPage::file_name).collect();
+        assert_eq!(
+            names,
+            vec!["mytool.8", "mytool-remote.8", "mytool-remote-add.8"]
+        );
+        assert!(pages[2]
+            .content
+            .starts_with(".TH \"MYTOOL\\-REMOTE\\-ADD\" \"8\" \"2024\\-01\\-31\""));
+
+        // Deterministic output
+        assert_eq!(
+            pages,
+            ManPageGenerator::new()
+                .section("8")
+                .date("2024-01-31")
+                .render_all(&make_config())
+        );
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("a-b"), "a\\-b");
+        assert_eq!(escape("C:\\path"), "C:\\epath");
+        assert_eq!(escape(".hidden\n'quoted"), "\\&.hidden\n\\&'quoted");
+    }
+}
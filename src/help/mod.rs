@@ -2,7 +2,9 @@
 //!
 //! This module provides the [`HelpFormatter`] trait and its default
 //! implementation [`DefaultHelpFormatter`], which generate help text
-//! from a [`CommandsConfig`] at runtime.
+//! from a [`CommandsConfig`] at runtime. The [`man`] submodule renders the
+//! same information as roff man pages, and [`reference`] as Markdown or
+//! HTML reference documentation.
 //!
 //! # Design
 //!
@@ -34,9 +36,17 @@
 //! }
 //! ```
 
-use crate::config::schema::{ArgumentType, CommandDefinition, CommandsConfig};
+use crate::config::schema::{
+    visible, ArgumentType, CommandDefinition, CommandsConfig, EnumVariant, OptionDefinition,
+};
 use colored::Colorize;
 
+pub mod man;
+pub mod reference;
+
+pub use man::{ManPage, ManPageGenerator};
+pub use reference::{HtmlFormatter, MarkdownFormatter};
+
 // ============================================================================
 // Public trait
 // ============================================================================
@@ -55,7 +65,7 @@ use colored::Colorize;
 ///
 /// ```
 /// use dynamic_cli::help::{HelpFormatter, DefaultHelpFormatter};
-/// use dynamic_cli::config::schema::{CommandsConfig, Metadata};
+/// use dynamic_cli::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
 ///
 /// let config = CommandsConfig {
 ///     metadata: Metadata {
@@ -65,6 +75,8 @@ use colored::Colorize;
 ///     },
 ///     commands: vec![],
 ///     global_options: vec![],
+///     include: vec![],
+///     schema_version: SCHEMA_VERSION,
 /// };
 ///
 /// let formatter = DefaultHelpFormatter::new();
@@ -81,8 +93,9 @@ pub trait HelpFormatter {
     /// Generate help text for a single command.
     ///
     /// Looks up `command` by name **or alias** and prints its arguments,
-    /// options, and aliases. If the command is not found, returns an
-    /// informative error string (never panics).
+    /// options, and aliases. Subcommands are addressed by their
+    /// whitespace-separated path (`"remote add"`). If the command is not
+    /// found, returns an informative error string (never panics).
     fn format_command(&self, config: &CommandsConfig, command: &str) -> String;
 }
 
@@ -133,12 +146,58 @@ impl DefaultHelpFormatter {
         format!("{:<width$}", s, width = width)
     }
 
-    /// Resolve a command by name or alias. Returns `None` if not found.
-    fn find_command<'a>(config: &'a CommandsConfig, name: &str) -> Option<&'a CommandDefinition> {
-        config
-            .commands
-            .iter()
-            .find(|cmd| cmd.name == name || cmd.aliases.iter().any(|a| a == name))
+    /// Resolve a command path by name or alias at each level.
+    ///
+    /// Returns the canonical path and the definition, or — if a level is
+    /// not found — the canonical path resolved so far and the candidates
+    /// available at the failing level.
+    fn find_command<'a>(
+        config: &'a CommandsConfig,
+        name: &str,
+    ) -> std::result::Result<(String, &'a CommandDefinition), (String, &'a [CommandDefinition])>
+    {
+        let mut path = String::new();
+        let mut candidates = config.commands.as_slice();
+        let mut found = None;
+
+        for segment in name.split_whitespace() {
+            let Some(cmd) = candidates
+                .iter()
+                .find(|cmd| cmd.name == segment || cmd.aliases.iter().any(|a| a == segment))
+            else {
+                return Err((path, candidates));
+            };
+            if !path.is_empty() {
+                path.push(' ');
+            }
+            path.push_str(&cmd.name);
+            candidates = &cmd.subcommands;
+            found = Some(cmd);
+        }
+
+        found
+            .map(|cmd| (path, cmd))
+            .ok_or((String::new(), candidates))
+    }
+
+    /// Format the SUBCOMMANDS section of a command.
+    fn format_subcommands(cmd: &CommandDefinition) -> String {
+        let subcommands = cmd.visible_subcommands();
+        if subcommands.is_empty() {
+            return String::new();
+        }
+
+        let col_width = subcommands.iter().map(|c| c.name.len()).max().unwrap_or(0) + 4;
+
+        let mut out = format!("\n{}\n", "SUBCOMMANDS:".bold());
+        for sub in subcommands {
+            out.push_str(&format!(
+                "    {}  {}\n",
+                Self::pad(&sub.name, col_width).green(),
+                sub.description
+            ));
+        }
+        out
     }
 
     /// Format the ARGUMENTS section of a command.
@@ -166,61 +225,102 @@ impl DefaultHelpFormatter {
                 label.dimmed(),
                 arg.description
             ));
+            out.push_str(&Self::format_variants(&arg.variants));
         }
         out
     }
 
-    /// Format the OPTIONS section of a command.
-    fn format_options(cmd: &CommandDefinition) -> String {
-        if cmd.options.is_empty() {
+    /// Format an options section (command OPTIONS or GLOBAL OPTIONS).
+    fn format_options(title: &str, options: &[OptionDefinition]) -> String {
+        if options.is_empty() {
             return String::new();
         }
 
         // Build the flag string for each option, e.g. "-v, --verbose".
-        let flags: Vec<String> = cmd
-            .options
-            .iter()
-            .map(|opt| {
-                let short = opt
-                    .short
-                    .as_deref()
-                    .map(|s| format!("-{s}"))
-                    .unwrap_or_default();
-                let long = opt
-                    .long
-                    .as_deref()
-                    .map(|l| format!("--{l}"))
-                    .unwrap_or_default();
-                match (short.is_empty(), long.is_empty()) {
-                    (false, false) => format!("{short}, {long}"),
-                    (false, true) => short,
-                    (true, false) => long,
-                    (true, true) => opt.name.clone(),
-                }
-            })
-            .collect();
+        let flags: Vec<String> = options.iter().map(Self::flag_label).collect();
 
         let col_width = flags.iter().map(|f| f.len()).max().unwrap_or(0) + 4;
 
-        let mut out = format!("\n{}\n", "OPTIONS:".bold());
-        for (opt, flag) in cmd.options.iter().zip(flags.iter()) {
+        let mut out = format!("\n{}\n", title.bold());
+        for (opt, flag) in options.iter().zip(flags.iter()) {
             let type_label = format!("({})", Self::type_label(opt.option_type));
+            let repeat_note = if opt.multiple { " [repeatable]" } else { "" };
+            let env_note = opt
+                .env
+                .as_deref()
+                .map(|e| format!(" [env: {e}]"))
+                .unwrap_or_default();
             let default_note = opt
                 .default
                 .as_deref()
                 .map(|d| format!(" [default: {d}]"))
                 .unwrap_or_default();
             out.push_str(&format!(
-                "    {}  {}  {}{}\n",
+                "    {}  {}  {}{}{}{}\n",
                 Self::pad(flag, col_width).yellow(),
                 type_label.dimmed(),
                 opt.description,
+                repeat_note.dimmed(),
+                env_note.dimmed(),
                 default_note.dimmed()
             ));
+            out.push_str(&Self::format_variants(&opt.variants));
         }
         out
     }
 
+    /// Format the variants of an `enum` argument or option, one per line
+    /// below the entry (e.g. `fast (f)  Skip checks`).
+    fn format_variants(variants: &[EnumVariant]) -> String {
+        let labels: Vec<String> = variants
+            .iter()
+            .map(|v| {
+                if v.aliases.is_empty() {
+                    v.name.clone()
+                } else {
+                    format!("{} ({})", v.name, v.aliases.join(", "))
+                }
+            })
+            .collect();
+        let col_width = labels.iter().map(|l| l.len()).max().unwrap_or(0) + 2;
+
+        let mut out = String::new();
+        for (variant, label) in variants.iter().zip(&labels) {
+            if variant.description.is_empty() {
+                out.push_str(&format!("        {}\n", label.cyan()));
+            } else {
+                out.push_str(&format!(
+                    "        {}{}\n",
+                    Self::pad(label, col_width).cyan(),
+                    variant.description
+                ));
+            }
+        }
+        out
+    }
+
+    /// Build the flag label of an option (e.g. `-v, --verbose`).
+    ///
+    /// Shared with the man page generator.
+    fn flag_label(opt: &OptionDefinition) -> String {
+        let short = opt
+            .short
+            .as_deref()
+            .map(|s| format!("-{s}"))
+            .unwrap_or_default();
+        let long = opt
+            .long
+            .as_deref()
+            .map(|l| format!("--{l}"))
+            .unwrap_or_default();
+        match (short.is_empty(), long.is_empty()) {
+            (false, false) => format!("{short}, {long}"),
+            (false, true) => short,
+            (true, false) => long,
+            (true, true) => opt.name.clone(),
+        }
+    }
+
     /// Format the ALIASES section of a command.
     fn format_aliases(cmd: &CommandDefinition) -> String {
         if cmd.aliases.is_empty() {
@@ -234,15 +334,18 @@ impl DefaultHelpFormatter {
     }
 
     /// Build the inline usage token for a command (e.g. `<input> [output]`).
+    ///
+    /// Shared with the man page generator so both show the same synopsis.
     fn usage_args(cmd: &CommandDefinition) -> String {
         let args: String = cmd
             .arguments
             .iter()
             .map(|a| {
+                let dots = if a.multiple { "..." } else { "" };
                 if a.required {
-                    format!("<{}>", a.name)
+                    format!("<{}>{dots}", a.name)
                 } else {
-                    format!("[{}]", a.name)
+                    format!("[{}]{dots}", a.name)
                 }
             })
             .collect::<Vec<_>>()
@@ -267,12 +370,15 @@ impl HelpFormatter for DefaultHelpFormatter {
     /// myapp 1.0.0
     ///
     /// USAGE:
-    ///     myapp <command> [arguments] [options]
+    ///     myapp [global options] <command> [arguments] [options]
     ///
     /// COMMANDS:
     ///     hello      Say hello to someone
     ///     process    Process data files
     ///
+    /// GLOBAL OPTIONS:
+    ///     -v, --verbose    (bool)  Verbose output
+    ///
     /// Run 'myapp --help <command>' for more information on a command.
     /// ```
     fn format_app(&self, config: &CommandsConfig) -> String {
@@ -288,26 +394,27 @@ impl HelpFormatter for DefaultHelpFormatter {
         // USAGE
         out.push('\n');
         out.push_str(&format!("{}\n", "USAGE:".bold()));
+        let global_usage = if config.global_options.is_empty() {
+            ""
+        } else {
+            " [global options]"
+        };
         out.push_str(&format!(
-            "    {} {} [arguments] [options]\n",
+            "    {}{} {} [arguments] [options]\n",
             config.metadata.prompt,
+            global_usage,
             "<command>".green()
         ));
 
         // COMMANDS
-        if !config.commands.is_empty() {
+        let commands = config.visible_commands();
+        if !commands.is_empty() {
             out.push('\n');
             out.push_str(&format!("{}\n", "COMMANDS:".bold()));
 
-            let col_width = config
-                .commands
-                .iter()
-                .map(|c| c.name.len())
-                .max()
-                .unwrap_or(0)
-                + 4;
+            let col_width = commands.iter().map(|c| c.name.len()).max().unwrap_or(0) + 4;
 
-            for cmd in &config.commands {
+            for cmd in commands {
                 out.push_str(&format!(
                     "    {}  {}\n",
                     Self::pad(&cmd.name, col_width).green(),
@@ -316,6 +423,12 @@ impl HelpFormatter for DefaultHelpFormatter {
             }
         }
 
+        // GLOBAL OPTIONS (accepted before or after the command name)
+        out.push_str(&Self::format_options(
+            "GLOBAL OPTIONS:",
+            &config.global_options,
+        ));
+
         // Footer hint
         out.push('\n');
         out.push_str(&format!(
@@ -348,47 +461,71 @@ impl HelpFormatter for DefaultHelpFormatter {
     ///     hi
     /// ```
     ///
+    /// Commands with subcommands also get a SUBCOMMANDS section listing
+    /// their children. `command` may be a path such as `"remote add"`.
+    ///
     /// If the command is not found, returns a message listing available commands.
     fn format_command(&self, config: &CommandsConfig, command: &str) -> String {
-        let Some(cmd) = Self::find_command(config, command) else {
-            // Unknown command — list available names to guide the user.
-            let available = config
-                .commands
-                .iter()
-                .map(|c| c.name.as_str())
-                .collect::<Vec<_>>()
-                .join(", ");
-            return format!(
-                "{} '{}'\n\nAvailable commands: {}\n",
-                "Unknown command:".red().bold(),
-                command,
-                available
-            );
+        let (path, cmd) = match Self::find_command(config, command) {
+            Ok(found) => found,
+            Err((parent, candidates)) => {
+                // Unknown command — list available names to guide the user.
+                let available = visible(candidates)
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let label = if parent.is_empty() {
+                    "Available commands:".to_string()
+                } else {
+                    format!("Available subcommands of '{}':", parent)
+                };
+                return format!(
+                    "{} '{}'\n\n{} {}\n",
+                    "Unknown command:".red().bold(),
+                    command,
+                    label,
+                    available
+                );
+            }
         };
 
         let mut out = String::new();
 
-        // Header: "name — description"
-        out.push_str(&format!(
-            "{} — {}\n",
-            cmd.name.bold().cyan(),
-            cmd.description
-        ));
+        // Header: "name — description" (full path for subcommands)
+        out.push_str(&format!("{} — {}\n", path.bold().cyan(), cmd.description));
 
         // USAGE
         out.push('\n');
         out.push_str(&format!("{}\n", "USAGE:".bold()));
-        out.push_str(&format!(
-            "    {} {}\n",
-            cmd.name.green(),
-            Self::usage_args(cmd)
-        ));
+        if !cmd.subcommands.is_empty() {
+            out.push_str(&format!(
+                "    {} {} [arguments] [options]\n",
+                path.green(),
+                "<subcommand>".green()
+            ));
+        }
+        if cmd.subcommands.is_empty() || !cmd.arguments.is_empty() || !cmd.options.is_empty() {
+            out.push_str(&format!("    {} {}\n", path.green(), Self::usage_args(cmd)));
+        }
 
-        // ARGUMENTS, OPTIONS, ALIASES (empty sections are omitted)
+        // SUBCOMMANDS, ARGUMENTS, OPTIONS, ALIASES (empty sections are omitted)
+        out.push_str(&Self::format_subcommands(cmd));
         out.push_str(&Self::format_arguments(cmd));
-        out.push_str(&Self::format_options(cmd));
+        out.push_str(&Self::format_options("OPTIONS:", &cmd.options));
         out.push_str(&Self::format_aliases(cmd));
 
+        // Footer hint for drilling down into subcommands
+        if !cmd.subcommands.is_empty() {
+            out.push('\n');
+            out.push_str(&format!(
+                "{} '{}' {}\n",
+                "Run".dimmed(),
+                format!("{} --help {} <subcommand>", config.metadata.prompt, path).italic(),
+                "for more information on a subcommand.".dimmed()
+            ));
+        }
+
         out
     }
 }
@@ -402,6 +539,7 @@ mod tests {
     use super::*;
     use crate::config::schema::{
         ArgumentDefinition, ArgumentType, CommandDefinition, Metadata, OptionDefinition,
+        SCHEMA_VERSION,
     };
 
     // Disable ANSI codes in tests so assertions work on plain text.
@@ -433,6 +571,7 @@ mod tests {
                         description: "Name to greet".to_string(),
                         validation: vec![],
                         secure: false,
+                        ..Default::default()
                     }],
                     options: vec![OptionDefinition {
                         name: "loud".to_string(),
@@ -443,8 +582,10 @@ mod tests {
                         default: None,
                         description: "Use uppercase".to_string(),
                         choices: vec![],
+                        ..Default::default()
                     }],
                     implementation: "hello_handler".to_string(),
+                    ..Default::default()
                 },
                 CommandDefinition {
                     name: "process".to_string(),
@@ -454,9 +595,12 @@ mod tests {
                     arguments: vec![],
                     options: vec![],
                     implementation: "process_handler".to_string(),
+                    ..Default::default()
                 },
             ],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         }
     }
 
@@ -469,6 +613,7 @@ mod tests {
     // -----------------------------------------------------------------------
 
     #[test]
+    #[allow(clippy::default_constructed_unit_structs)] // exercises `Default`
     fn test_new_and_default_are_equivalent() {
         // Both construction paths compile and produce the same type.
         let _a = DefaultHelpFormatter::new();
@@ -503,6 +648,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_format_app_omits_hidden_commands() {
+        no_color();
+        let mut config = make_config();
+        config.commands[1].hidden = true;
+        let formatter = make_formatter();
+
+        let out = formatter.format_app(&config);
+        assert!(out.contains("hello"));
+        assert!(
+            !out.contains("process"),
+            "hidden command must not be listed"
+        );
+
+        // A hidden command still has its own help page
+        let out = formatter.format_command(&config, "process");
+        assert!(out.contains("Process data files"));
+    }
+
     #[test]
     fn test_format_app_contains_usage_and_footer() {
         no_color();
@@ -529,6 +693,37 @@ mod tests {
         assert!(!out.contains("COMMANDS:"));
     }
 
+    #[test]
+    fn test_format_app_shows_global_options() {
+        no_color();
+        let mut config = make_config();
+        config.global_options.push(OptionDefinition {
+            name: "verbose".to_string(),
+            short: Some("v".to_string()),
+            long: Some("verbose".to_string()),
+            option_type: ArgumentType::Bool,
+            required: false,
+            default: None,
+            description: "Verbose output".to_string(),
+            choices: vec![],
+            ..Default::default()
+        });
+        let out = make_formatter().format_app(&config);
+
+        assert!(out.contains("[global options] <command>"));
+        assert!(out.contains("GLOBAL OPTIONS:"));
+        assert!(out.contains("-v, --verbose"));
+        assert!(out.contains("Verbose output"));
+    }
+
+    #[test]
+    fn test_format_app_no_global_options_section_when_empty() {
+        no_color();
+        let out = make_formatter().format_app(&make_config());
+        assert!(!out.contains("GLOBAL OPTIONS:"));
+        assert!(!out.contains("[global options]"));
+    }
+
     // -----------------------------------------------------------------------
     // format_command — known command
     // -----------------------------------------------------------------------
@@ -662,6 +857,61 @@ mod tests {
         );
     }
 
+    // -----------------------------------------------------------------------
+    // format_command — subcommands
+    // -----------------------------------------------------------------------
+
+    /// `make_config()` with `process` turned into a group of two subcommands.
+    fn make_nested_config() -> CommandsConfig {
+        let mut config = make_config();
+        let mut csv = config.commands[0].clone();
+        csv.name = "csv".to_string();
+        csv.aliases = vec!["c".to_string()];
+        csv.description = "Process CSV files".to_string();
+        let mut json = config.commands[1].clone();
+        json.name = "json".to_string();
+        json.description = "Process JSON files".to_string();
+        config.commands[1].subcommands = vec![csv, json];
+        config
+    }
+
+    #[test]
+    fn test_format_command_lists_subcommands() {
+        no_color();
+        let config = make_nested_config();
+        let out = make_formatter().format_command(&config, "process");
+
+        assert!(
+            out.contains("SUBCOMMANDS:"),
+            "should have SUBCOMMANDS section"
+        );
+        assert!(out.contains("csv"));
+        assert!(out.contains("Process JSON files"));
+        assert!(out.contains("process <subcommand>"));
+        assert!(out.contains("myapp --help process <subcommand>"));
+    }
+
+    #[test]
+    fn test_format_command_by_subcommand_path() {
+        no_color();
+        let config = make_nested_config();
+        // Aliases are accepted at every level
+        let out = make_formatter().format_command(&config, "process c");
+
+        assert!(out.contains("process csv — Process CSV files"));
+        assert!(out.contains("process csv <name> [options]"));
+        assert!(!out.contains("SUBCOMMANDS:"));
+    }
+
+    #[test]
+    fn test_format_command_unknown_subcommand_lists_siblings() {
+        no_color();
+        let config = make_nested_config();
+        let out = make_formatter().format_command(&config, "process xml");
+
+        assert!(out.contains("Available subcommands of 'process': csv, json"));
+    }
+
     // -----------------------------------------------------------------------
     // HelpFormatter trait — object safety check
     // -----------------------------------------------------------------------
@@ -690,6 +940,61 @@ mod tests {
         assert!(out.contains("false"), "should show default value");
     }
 
+    #[test]
+    fn test_format_command_marks_multiple_values() {
+        no_color();
+        let mut config = make_config();
+        config.commands[0].arguments[0].multiple = true;
+        config.commands[0].options[0].multiple = true;
+        let out = make_formatter().format_command(&config, "hello");
+
+        assert!(out.contains("<name>..."));
+        assert!(out.contains("[repeatable]"));
+    }
+
+    #[test]
+    fn test_format_command_shows_env_variable() {
+        no_color();
+        let mut config = make_config();
+        config.commands[0].options[0].env = Some("HELLO_LOUD".to_string());
+        config.commands[0].options[0].default = Some("false".to_string());
+        let out = make_formatter().format_command(&config, "hello");
+
+        assert!(
+            out.contains("[env: HELLO_LOUD] [default: false]"),
+            "should show the environment variable before the default"
+        );
+    }
+
+    #[test]
+    fn test_format_command_lists_enum_variants() {
+        no_color();
+        let mut config = make_config();
+        let arg = &mut config.commands[0].arguments[0];
+        arg.arg_type = ArgumentType::Enum;
+        arg.variants = vec![
+            EnumVariant {
+                name: "world".to_string(),
+                description: "Greet everyone".to_string(),
+                aliases: vec!["all".to_string()],
+            },
+            EnumVariant {
+                name: "me".to_string(),
+                description: String::new(),
+                aliases: vec![],
+            },
+        ];
+        let out = make_formatter().format_command(&config, "hello");
+
+        assert!(out.contains("(enum, required)"));
+        assert!(
+            out.contains("        world (all)  Greet everyone\n"),
+            "{}",
+            out
+        );
+        assert!(out.contains("        me\n"), "{}", out);
+    }
+
     // -----------------------------------------------------------------------
     // Custom HelpFormatter implementation (framework extensibility)
     // -----------------------------------------------------------------------
@@ -0,0 +1,749 @@
+//! Reference documentation in Markdown and HTML
+//!
+//! [`MarkdownFormatter`] and [`HtmlFormatter`] render a [`CommandsConfig`]
+//! as a complete reference document, so the documentation is generated
+//! from the same configuration (and the same usage lines) as `--help`
+//! instead of being maintained by hand.
+//!
+//! Both implement [`HelpFormatter`]:
+//! - [`format_app`](HelpFormatter::format_app) renders the whole document:
+//!   usage, a table of contents, the global options and one section per
+//!   command and subcommand, each with an anchor.
+//! - [`format_command`](HelpFormatter::format_command) renders the section
+//!   of a single command.
+//!
+//! Arguments and options are listed in tables with their type, default
+//! value and choices; an option's environment variable is noted after its
+//! description.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::help::{HelpFormatter, MarkdownFormatter};
+//! use dynamic_cli::config::schema::{CommandDefinition, CommandsConfig, Metadata, SCHEMA_VERSION};
+//!
+//! let config = CommandsConfig {
+//!     metadata: Metadata {
+//!         version: "1.0.0".to_string(),
+//!         prompt: "mytool".to_string(),
+//!         prompt_suffix: " > ".to_string(),
+//!     },
+//!     commands: vec![CommandDefinition {
+//!         name: "deploy".to_string(),
+//!         aliases: vec![],
+//!         description: "Deploy the application".to_string(),
+//!         required: false,
+//!         arguments: vec![],
+//!         options: vec![],
+//!         implementation: "deploy_handler".to_string(),
+//!         ..Default::default()
+//!     }],
+//!     global_options: vec![],
+//!     include: vec![],
+//!     schema_version: SCHEMA_VERSION,
+//! };
+//!
+//! let markdown = MarkdownFormatter::new().format_app(&config);
+//! assert!(markdown.contains("- [deploy](#command-deploy) — Deploy the application"));
+//! assert!(markdown.contains("<a id=\"command-deploy\"></a>"));
+//! ```
+
+use super::{DefaultHelpFormatter, HelpFormatter};
+use crate::config::schema::{visible, CommandDefinition, CommandsConfig, OptionDefinition};
+
+// ============================================================================
+// Markdown
+// ============================================================================
+
+/// Renders the configuration as a Markdown reference document
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::help::MarkdownFormatter;
+///
+/// let fmt = MarkdownFormatter::new();
+/// ```
+#[derive(Debug, Default)]
+pub struct MarkdownFormatter;
+
+impl MarkdownFormatter {
+    /// Create a new `MarkdownFormatter`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render the section of one command.
+    fn command_section(config: &CommandsConfig, path: &str, cmd: &CommandDefinition) -> String {
+        let mut out = format!(
+            "<a id=\"{}\"></a>\n\n### {}\n\n{}\n\n",
+            anchor(path),
+            path,
+            md_text(&cmd.description)
+        );
+
+        out.push_str("```text\n");
+        for usage in usage_lines(config, path, cmd) {
+            out.push_str(&format!("{}\n", usage));
+        }
+        out.push_str("```\n");
+
+        if !cmd.aliases.is_empty() {
+            let aliases: Vec<String> = cmd.aliases.iter().map(|a| format!("`{}`", a)).collect();
+            out.push_str(&format!("\n**Aliases:** {}\n", aliases.join(", ")));
+        }
+
+        let subcommands = cmd.visible_subcommands();
+        if !subcommands.is_empty() {
+            out.push_str("\n#### Subcommands\n\n");
+            for sub in subcommands {
+                let sub_path = format!("{} {}", path, sub.name);
+                out.push_str(&format!(
+                    "- [{}](#{}) — {}\n",
+                    sub_path,
+                    anchor(&sub_path),
+                    md_text(&sub.description)
+                ));
+            }
+        }
+
+        if !cmd.arguments.is_empty() {
+            out.push_str("\n#### Arguments\n\n");
+            out.push_str("| Name | Type | Required | Description |\n");
+            out.push_str("|------|------|----------|-------------|\n");
+            for arg in &cmd.arguments {
+                out.push_str(&format!(
+                    "| `{}` | {} | {} | {} |\n",
+                    arg.name,
+                    arg.arg_type.as_str(),
+                    yes_no(arg.required),
+                    md_cell(&arg.description)
+                ));
+            }
+        }
+
+        if !cmd.options.is_empty() {
+            out.push_str("\n#### Options\n\n");
+            out.push_str(&Self::options_table(&cmd.options));
+        }
+
+        out
+    }
+
+    /// Render a table of options.
+    fn options_table(options: &[OptionDefinition]) -> String {
+        let mut out = String::from(
+            "| Option | Type | Required | Default | Choices | Description |\n\
+             |--------|------|----------|---------|---------|-------------|\n",
+        );
+        for opt in options {
+            let flags: Vec<String> = DefaultHelpFormatter::flag_label(opt)
+                .split(", ")
+                .map(|f| format!("`{}`", f))
+                .collect();
+            let default = opt
+                .default
+                .as_deref()
+                .map(|d| format!("`{}`", md_cell(d)))
+                .unwrap_or_default();
+            let choices: Vec<String> = opt
+                .choices
+                .iter()
+                .map(|c| format!("`{}`", md_cell(c)))
+                .collect();
+            out.push_str(&format!(
+                "| {} | {} | {} | {} | {} | {}{} |\n",
+                flags.join(", "),
+                opt.option_type.as_str(),
+                yes_no(opt.required),
+                default,
+                choices.join(", "),
+                md_cell(&opt.description),
+                opt.env
+                    .as_deref()
+                    .map(|e| format!(" (env: `{}`)", e))
+                    .unwrap_or_default()
+            ));
+        }
+        out
+    }
+}
+
+impl HelpFormatter for MarkdownFormatter {
+    /// Render the complete reference document.
+    ///
+    /// # Output structure
+    ///
+    /// ```text
+    /// # myapp
+    ///
+    /// Version 1.0.0
+    ///
+    /// ## Usage            (fenced usage line)
+    /// ## Contents         (links to every command, nested by level)
+    /// ## Global options   (table, omitted when empty)
+    /// ## Commands         (one anchored section per command)
+    /// ```
+    fn format_app(&self, config: &CommandsConfig) -> String {
+        let program = &config.metadata.prompt;
+        let mut out = format!(
+            "# {}\n\nVersion {}\n\n## Usage\n\n```text\n{}\n```\n",
+            program,
+            config.metadata.version,
+            app_usage(config)
+        );
+
+        let commands = all_commands(config);
+        if !commands.is_empty() {
+            out.push_str("\n## Contents\n\n");
+            for (path, depth, cmd) in &commands {
+                out.push_str(&format!(
+                    "{}- [{}](#{}) — {}\n",
+                    "  ".repeat(*depth),
+                    path,
+                    anchor(path),
+                    md_text(&cmd.description)
+                ));
+            }
+        }
+
+        if !config.global_options.is_empty() {
+            out.push_str("\n## Global options\n\n");
+            out.push_str("Global options are accepted before or after the command.\n\n");
+            out.push_str(&Self::options_table(&config.global_options));
+        }
+
+        if !commands.is_empty() {
+            out.push_str("\n## Commands\n");
+            for (path, _, cmd) in &commands {
+                out.push('\n');
+                out.push_str(&Self::command_section(config, path, cmd));
+            }
+        }
+
+        out
+    }
+
+    /// Render the section of a single command, looked up by name, alias
+    /// or path.
+    fn format_command(&self, config: &CommandsConfig, command: &str) -> String {
+        match DefaultHelpFormatter::find_command(config, command) {
+            Ok((path, cmd)) => Self::command_section(config, &path, cmd),
+            Err((_, candidates)) => format!(
+                "**Unknown command:** `{}`\n\nAvailable commands: {}\n",
+                command,
+                names(candidates)
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// HTML
+// ============================================================================
+
+/// Renders the configuration as an HTML reference document
+///
+/// [`format_app`](HelpFormatter::format_app) produces a standalone page
+/// without styling; [`format_command`](HelpFormatter::format_command)
+/// produces a `<section>` fragment.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::help::HtmlFormatter;
+///
+/// let fmt = HtmlFormatter::new();
+/// ```
+#[derive(Debug, Default)]
+pub struct HtmlFormatter;
+
+impl HtmlFormatter {
+    /// Create a new `HtmlFormatter`.
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Render the section of one command.
+    fn command_section(config: &CommandsConfig, path: &str, cmd: &CommandDefinition) -> String {
+        let mut out = format!(
+            "<section id=\"{}\">\n<h3>{}</h3>\n<p>{}</p>\n<pre>{}</pre>\n",
+            anchor(path),
+            html(path),
+            html(&cmd.description),
+            usage_lines(config, path, cmd)
+                .iter()
+                .map(|u| html(u))
+                .collect::<Vec<_>>()
+                .join("\n")
+        );
+
+        if !cmd.aliases.is_empty() {
+            let aliases: Vec<String> = cmd
+                .aliases
+                .iter()
+                .map(|a| format!("<code>{}</code>", html(a)))
+                .collect();
+            out.push_str(&format!(
+                "<p><strong>Aliases:</strong> {}</p>\n",
+                aliases.join(", ")
+            ));
+        }
+
+        let subcommands = cmd.visible_subcommands();
+        if !subcommands.is_empty() {
+            out.push_str("<h4>Subcommands</h4>\n<ul>\n");
+            for sub in subcommands {
+                let sub_path = format!("{} {}", path, sub.name);
+                out.push_str(&format!(
+                    "<li><a href=\"#{}\">{}</a> — {}</li>\n",
+                    anchor(&sub_path),
+                    html(&sub_path),
+                    html(&sub.description)
+                ));
+            }
+            out.push_str("</ul>\n");
+        }
+
+        if !cmd.arguments.is_empty() {
+            out.push_str(
+                "<h4>Arguments</h4>\n<table>\n\
+                 <tr><th>Name</th><th>Type</th><th>Required</th><th>Description</th></tr>\n",
+            );
+            for arg in &cmd.arguments {
+                out.push_str(&format!(
+                    "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
+                    html(&arg.name),
+                    arg.arg_type.as_str(),
+                    yes_no(arg.required),
+                    html(&arg.description)
+                ));
+            }
+            out.push_str("</table>\n");
+        }
+
+        if !cmd.options.is_empty() {
+            out.push_str("<h4>Options</h4>\n");
+            out.push_str(&Self::options_table(&cmd.options));
+        }
+
+        out.push_str("</section>\n");
+        out
+    }
+
+    /// Render a table of options.
+    fn options_table(options: &[OptionDefinition]) -> String {
+        let mut out = String::from(
+            "<table>\n<tr><th>Option</th><th>Type</th><th>Required</th>\
+             <th>Default</th><th>Choices</th><th>Description</th></tr>\n",
+        );
+        for opt in options {
+            let flags: Vec<String> = DefaultHelpFormatter::flag_label(opt)
+                .split(", ")
+                .map(|f| format!("<code>{}</code>", html(f)))
+                .collect();
+            let default = opt
+                .default
+                .as_deref()
+                .map(|d| format!("<code>{}</code>", html(d)))
+                .unwrap_or_default();
+            let choices: Vec<String> = opt
+                .choices
+                .iter()
+                .map(|c| format!("<code>{}</code>", html(c)))
+                .collect();
+            out.push_str(&format!(
+                "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}{}</td></tr>\n",
+                flags.join(", "),
+                opt.option_type.as_str(),
+                yes_no(opt.required),
+                default,
+                choices.join(", "),
+                html(&opt.description),
+                opt.env
+                    .as_deref()
+                    .map(|e| format!(" (env: <code>{}</code>)", html(e)))
+                    .unwrap_or_default()
+            ));
+        }
+        out.push_str("</table>\n");
+        out
+    }
+}
+
+impl HelpFormatter for HtmlFormatter {
+    /// Render the complete reference as a standalone HTML page.
+    ///
+    /// The page has the same structure as the Markdown document: usage,
+    /// nested table of contents, global options and command sections.
+    fn format_app(&self, config: &CommandsConfig) -> String {
+        let program = html(&config.metadata.prompt);
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{program} reference</title>\n</head>\n<body>\n\
+             <h1>{program}</h1>\n<p>Version {}</p>\n\
+             <h2>Usage</h2>\n<pre>{}</pre>\n",
+            html(&config.metadata.version),
+            html(&app_usage(config)),
+        );
+
+        let commands = all_commands(config);
+        if !commands.is_empty() {
+            out.push_str("<h2>Contents</h2>\n<nav>\n");
+            let mut depth = 0;
+            out.push_str("<ul>\n");
+            for (index, (path, level, cmd)) in commands.iter().enumerate() {
+                // Open or close nested lists when the level changes
+                while depth < *level {
+                    out.push_str("<ul>\n");
+                    depth += 1;
+                }
+                while depth > *level {
+                    out.push_str("</ul></li>\n");
+                    depth -= 1;
+                }
+                out.push_str(&format!(
+                    "<li><a href=\"#{}\">{}</a> — {}",
+                    anchor(path),
+                    html(path),
+                    html(&cmd.description)
+                ));
+                let has_children = commands
+                    .get(index + 1)
+                    .is_some_and(|(_, next, _)| next > level);
+                if !has_children {
+                    out.push_str("</li>\n");
+                } else {
+                    out.push('\n');
+                }
+            }
+            while depth > 0 {
+                out.push_str("</ul></li>\n");
+                depth -= 1;
+            }
+            out.push_str("</ul>\n</nav>\n");
+        }
+
+        if !config.global_options.is_empty() {
+            out.push_str(
+                "<h2>Global options</h2>\n\
+                 <p>Global options are accepted before or after the command.</p>\n",
+            );
+            out.push_str(&Self::options_table(&config.global_options));
+        }
+
+        if !commands.is_empty() {
+            out.push_str("<h2>Commands</h2>\n");
+            for (path, _, cmd) in &commands {
+                out.push_str(&Self::command_section(config, path, cmd));
+            }
+        }
+
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+
+    /// Render the `<section>` of a single command, looked up by name,
+    /// alias or path.
+    fn format_command(&self, config: &CommandsConfig, command: &str) -> String {
+        match DefaultHelpFormatter::find_command(config, command) {
+            Ok((path, cmd)) => Self::command_section(config, &path, cmd),
+            Err((_, candidates)) => format!(
+                "<p><strong>Unknown command:</strong> <code>{}</code></p>\n\
+                 <p>Available commands: {}</p>\n",
+                html(command),
+                html(&names(candidates))
+            ),
+        }
+    }
+}
+
+// ============================================================================
+// Shared helpers
+// ============================================================================
+
+/// Every visible command as `(path, depth, definition)`, depth first
+fn all_commands(config: &CommandsConfig) -> Vec<(String, usize, &CommandDefinition)> {
+    fn walk<'a>(
+        commands: &'a [CommandDefinition],
+        parent: &str,
+        depth: usize,
+        out: &mut Vec<(String, usize, &'a CommandDefinition)>,
+    ) {
+        for cmd in visible(commands) {
+            let path = if parent.is_empty() {
+                cmd.name.clone()
+            } else {
+                format!("{} {}", parent, cmd.name)
+            };
+            out.push((path.clone(), depth, cmd));
+            walk(&cmd.subcommands, &path, depth + 1, out);
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(&config.commands, "", 0, &mut out);
+    out
+}
+
+/// Application usage line, as printed by `--help`
+fn app_usage(config: &CommandsConfig) -> String {
+    let global_usage = if config.global_options.is_empty() {
+        ""
+    } else {
+        " [global options]"
+    };
+    format!(
+        "{}{} <command> [arguments] [options]",
+        config.metadata.prompt, global_usage
+    )
+}
+
+/// Usage lines of a command, as printed by `--help <command>`
+fn usage_lines(config: &CommandsConfig, path: &str, cmd: &CommandDefinition) -> Vec<String> {
+    let invocation = format!("{} {}", config.metadata.prompt, path);
+    let mut lines = Vec::new();
+    if !cmd.subcommands.is_empty() {
+        lines.push(format!("{} <subcommand> [arguments] [options]", invocation));
+    }
+    if cmd.subcommands.is_empty() || !cmd.arguments.is_empty() || !cmd.options.is_empty() {
+        let args = DefaultHelpFormatter::usage_args(cmd);
+        if args.is_empty() {
+            lines.push(invocation);
+        } else {
+            lines.push(format!("{} {}", invocation, args));
+        }
+    }
+    lines
+}
+
+/// Anchor of a command section (`command-remote-add`)
+fn anchor(path: &str) -> String {
+    let slug: String = path
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("command-{}", slug)
+}
+
+/// Comma-separated names of the visible commands
+fn names(commands: &[CommandDefinition]) -> String {
+    visible(commands)
+        .iter()
+        .map(|c| c.name.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value {
+        "yes"
+    } else {
+        "no"
+    }
+}
+
+/// Markdown text on a single line
+fn md_text(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Markdown table cell content (pipes escaped)
+fn md_cell(text: &str) -> String {
+    md_text(text).replace('|', "\\|")
+}
+
+/// HTML-escaped text
+fn html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{ArgumentDefinition, ArgumentType, Metadata, SCHEMA_VERSION};
+
+    fn make_config() -> CommandsConfig {
+        let add = CommandDefinition {
+            name: "add".to_string(),
+            aliases: vec!["a".to_string()],
+            description: "Add a <remote>".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "url".to_string(),
+                arg_type: ArgumentType::String,
+                required: true,
+                description: "Remote URL".to_string(),
+                validation: vec![],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![OptionDefinition {
+                name: "mode".to_string(),
+                short: Some("m".to_string()),
+                long: Some("mode".to_string()),
+                option_type: ArgumentType::String,
+                required: false,
+                default: Some("fetch".to_string()),
+                description: "Access mode | direction".to_string(),
+                choices: vec!["fetch".to_string(), "push".to_string()],
+                env: Some("REMOTE_MODE".to_string()),
+                ..Default::default()
+            }],
+            implementation: "add_handler".to_string(),
+            ..Default::default()
+        };
+        let status = CommandDefinition {
+            name: "status".to_string(),
+            aliases: vec![],
+            description: "Show status".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "status_handler".to_string(),
+            ..Default::default()
+        };
+
+        CommandsConfig {
+            metadata: Metadata {
+                version: "2.1.0".to_string(),
+                prompt: "mytool".to_string(),
+                prompt_suffix: " > ".to_string(),
+            },
+            commands: vec![
+                CommandDefinition {
+                    name: "remote".to_string(),
+                    aliases: vec![],
+                    description: "Manage remotes".to_string(),
+                    required: false,
+                    arguments: vec![],
+                    options: vec![],
+                    implementation: String::new(),
+                    subcommands: vec![add],
+                    ..Default::default()
+                },
+                status,
+            ],
+            global_options: vec![OptionDefinition {
+                name: "verbose".to_string(),
+                short: Some("v".to_string()),
+                long: Some("verbose".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Verbose output".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
+        }
+    }
+
+    // -----------------------------------------------------------------------
+    // Markdown
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_markdown_document_structure() {
+        let doc = MarkdownFormatter::new().format_app(&make_config());
+
+        assert!(doc.starts_with("# mytool\n\nVersion 2.1.0\n"));
+        assert!(
+            doc.contains("```text\nmytool [global options] <command> [arguments] [options]\n```")
+        );
+        // Nested table of contents with anchors
+        assert!(doc.contains(
+            "- [remote](#command-remote) — Manage remotes\n  \
+             - [remote add](#command-remote-add) — Add a <remote>\n\
+             - [status](#command-status) — Show status\n"
+        ));
+        assert!(doc.contains("<a id=\"command-remote-add\"></a>\n\n### remote add\n"));
+        assert!(doc.contains("| `-v`, `--verbose` | bool | no |  |  | Verbose output |"));
+        // Sections follow the configuration order
+        let remote = doc.find("### remote\n").unwrap();
+        let add = doc.find("### remote add").unwrap();
+        let status = doc.find("### status").unwrap();
+        assert!(remote < add && add < status);
+    }
+
+    #[test]
+    fn test_markdown_command_section() {
+        let section = MarkdownFormatter::new().format_command(&make_config(), "remote a");
+
+        assert!(section.contains("```text\nmytool remote add <url> [options]\n```"));
+        assert!(section.contains("**Aliases:** `a`"));
+        assert!(section.contains("| `url` | string | yes | Remote URL |"));
+        assert!(section.contains(
+            "| `-m`, `--mode` | string | no | `fetch` | `fetch`, `push` | Access mode \\| direction (env: `REMOTE_MODE`) |"
+        ));
+    }
+
+    #[test]
+    fn test_markdown_group_lists_subcommands() {
+        let section = MarkdownFormatter::new().format_command(&make_config(), "remote");
+
+        assert!(section.contains("mytool remote <subcommand> [arguments] [options]"));
+        assert!(section.contains("#### Subcommands\n\n- [remote add](#command-remote-add)"));
+        assert!(!section.contains("#### Arguments"));
+    }
+
+    #[test]
+    fn test_markdown_unknown_command() {
+        let out = MarkdownFormatter::new().format_command(&make_config(), "deploy");
+        assert!(out.contains("`deploy`"));
+        assert!(out.contains("Available commands: remote, status"));
+    }
+
+    // -----------------------------------------------------------------------
+    // HTML
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn test_html_document_structure() {
+        let doc = HtmlFormatter::new().format_app(&make_config());
+
+        assert!(doc.starts_with("<!DOCTYPE html>\n"));
+        assert!(doc.ends_with("</body>\n</html>\n"));
+        assert!(doc.contains("<title>mytool reference</title>"));
+        assert!(doc.contains(
+            "<li><a href=\"#command-remote\">remote</a> — Manage remotes\n<ul>\n\
+             <li><a href=\"#command-remote-add\">remote add</a> — Add a &lt;remote&gt;</li>\n\
+             </ul></li>\n<li><a href=\"#command-status\">status</a> — Show status</li>\n</ul>\n"
+        ));
+        assert!(doc.contains("<section id=\"command-status\">"));
+        assert_eq!(doc.matches("<ul>").count(), doc.matches("</ul>").count());
+    }
+
+    #[test]
+    fn test_html_command_section() {
+        let section = HtmlFormatter::new().format_command(&make_config(), "remote add");
+
+        assert!(section.starts_with("<section id=\"command-remote-add\">"));
+        assert!(section.contains("<pre>mytool remote add &lt;url&gt; [options]</pre>"));
+        assert!(section
+            .contains("<td><code>fetch</code></td><td><code>fetch</code>, <code>push</code></td>"));
+        assert!(section.contains("(env: <code>REMOTE_MODE</code>)</td>"));
+    }
+
+    #[test]
+    fn test_html_unknown_command_is_escaped() {
+        let out = HtmlFormatter::new().format_command(&make_config(), "<script>");
+        assert!(out.contains("<code>&lt;script&gt;</code>"));
+    }
+
+    #[test]
+    fn test_anchor() {
+        assert_eq!(anchor("remote add"), "command-remote-add");
+        assert_eq!(anchor("Build.All"), "command-build-all");
+    }
+}
@@ -27,7 +27,8 @@
 
 use crate::context::ExecutionContext;
 use crate::error::{display_error, DynamicCliError, Result};
-use crate::parser::CliParser;
+use crate::executor::execute_command;
+use crate::parser::{CliParser, ParsedArgs};
 use crate::registry::CommandRegistry;
 use std::process;
 
@@ -145,30 +146,37 @@ impl CliInterface {
             ));
         }
 
-        // First argument is the command name
-        let command_name = &args[0];
-
-        // Resolve command name (handles aliases)
-        let resolved_name = self.registry.resolve_name(command_name).ok_or_else(|| {
-            crate::error::ParseError::unknown_command_with_suggestions(
-                command_name,
-                &self
-                    .registry
-                    .list_commands()
-                    .iter()
-                    .map(|cmd| cmd.name.clone())
-                    .collect::<Vec<_>>(),
-            )
-        })?;
+        // Global options may precede the command name
+        let position =
+            crate::parser::global_options::command_position(self.registry.global_options(), &args)?;
+        if position >= args.len() {
+            return Err(DynamicCliError::Parse(
+                crate::error::ParseError::InvalidSyntax {
+                    details: "No command specified".to_string(),
+                    hint: Some("Try 'help' to see available commands".to_string()),
+                },
+            ));
+        }
+
+        // First non-global arguments name the command (and subcommands)
+        let (resolved_name, consumed) = self.registry.resolve_command(&args[position..])?;
+        let resolved_name = resolved_name.as_str();
 
         // Get command definition
         let definition = self.registry.get_definition(resolved_name).ok_or_else(|| {
             DynamicCliError::Registry(crate::error::RegistryError::missing_handler(resolved_name))
         })?;
 
-        // Parse arguments using CLI parser
-        let parser = CliParser::new(definition);
-        let parsed_args = parser.parse(&args[1..])?;
+        // Parse arguments using CLI parser (leading global options included)
+        let parser = CliParser::new(definition)
+            .with_global_options(self.registry.global_options())
+            .with_custom_validators(self.registry.custom_validators(&*self.context));
+        let parser = match self.registry.constraints(resolved_name) {
+            Some(constraints) => parser.with_constraints(constraints),
+            None => parser,
+        };
+        let parsed_args =
+            parser.parse_after_globals(&args[..position], &args[position + consumed..])?;
 
         // Get handler and execute command
         let handler = self.registry.get_handler(resolved_name).ok_or_else(|| {
@@ -178,7 +186,8 @@ impl CliInterface {
             ))
         })?;
 
-        handler.execute(&mut *self.context, &parsed_args)?;
+        let typed_args = ParsedArgs::new(definition, self.registry.global_options(), parsed_args)?;
+        execute_command(handler.as_ref(), &mut *self.context, &typed_args)?;
 
         Ok(())
     }
@@ -289,6 +298,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "test_handler".to_string(),
+            ..Default::default()
         };
 
         let handler = Box::new(TestHandler {
@@ -378,9 +388,11 @@ mod tests {
                 description: "Name to greet".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             }],
             options: vec![],
             implementation: "greet_handler".to_string(),
+            ..Default::default()
         };
 
         struct GreetHandler;
@@ -403,4 +415,292 @@ mod tests {
         let result = cli.run(vec!["greet".to_string(), "Alice".to_string()]);
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_cli_passes_typed_arguments() {
+        use crate::config::schema::OptionDefinition;
+        use crate::parser::ParsedArgs;
+
+        let mut registry = CommandRegistry::new();
+
+        let cmd_def = CommandDefinition {
+            name: "repeat".to_string(),
+            aliases: vec![],
+            description: "Repeat a word".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "times".to_string(),
+                arg_type: ArgumentType::Integer,
+                required: true,
+                description: "Repetitions".to_string(),
+                validation: vec![],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![OptionDefinition {
+                name: "loud".to_string(),
+                short: Some("l".to_string()),
+                long: Some("loud".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: Some("false".to_string()),
+                description: "Shout".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+            implementation: "repeat_handler".to_string(),
+            ..Default::default()
+        };
+
+        struct RepeatHandler;
+        impl crate::executor::TypedCommandHandler for RepeatHandler {
+            fn execute_typed(
+                &self,
+                context: &mut dyn ExecutionContext,
+                args: &ParsedArgs,
+            ) -> Result<()> {
+                assert_eq!(args.get_i64("times"), Some(3));
+                assert_eq!(args.get_bool("loud"), Some(true));
+                let ctx = crate::context::downcast_mut::<TestContext>(context).unwrap();
+                ctx.executed_command = Some("repeat".to_string());
+                Ok(())
+            }
+        }
+
+        registry.register(cmd_def, Box::new(RepeatHandler)).unwrap();
+
+        let context = Box::new(TestContext::default());
+        let cli = CliInterface::new(registry, context);
+
+        let result = cli.run(vec![
+            "repeat".to_string(),
+            "3".to_string(),
+            "--loud".to_string(),
+        ]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_cli_validation_rule_blocks_handler() {
+        use crate::config::schema::ValidationRule;
+
+        let mut registry = CommandRegistry::new();
+
+        let cmd_def = CommandDefinition {
+            name: "load".to_string(),
+            aliases: vec![],
+            description: "Load a file".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "file".to_string(),
+                arg_type: ArgumentType::Path,
+                required: true,
+                description: "File to load".to_string(),
+                validation: vec![ValidationRule::MustExist { must_exist: true }],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![],
+            implementation: "load_handler".to_string(),
+            ..Default::default()
+        };
+
+        struct LoadHandler;
+        impl crate::executor::CommandHandler for LoadHandler {
+            fn execute(
+                &self,
+                _context: &mut dyn ExecutionContext,
+                _args: &HashMap<String, String>,
+            ) -> Result<()> {
+                panic!("Handler must not run when validation fails");
+            }
+        }
+
+        registry.register(cmd_def, Box::new(LoadHandler)).unwrap();
+
+        let context = Box::new(TestContext::default());
+        let cli = CliInterface::new(registry, context);
+
+        let result = cli.run(vec![
+            "load".to_string(),
+            "/nonexistent/data.csv".to_string(),
+        ]);
+
+        match result.unwrap_err() {
+            DynamicCliError::Validation(crate::error::ValidationError::FileNotFound { .. }) => {}
+            other => panic!("Expected FileNotFound error, got: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_cli_handler_validate_blocks_execute() {
+        let mut registry = CommandRegistry::new();
+
+        let cmd_def = CommandDefinition {
+            name: "deploy".to_string(),
+            aliases: vec![],
+            description: "Deploy".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "env".to_string(),
+                arg_type: ArgumentType::String,
+                required: true,
+                description: "Target environment".to_string(),
+                validation: vec![],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![],
+            implementation: "deploy_handler".to_string(),
+            ..Default::default()
+        };
+
+        struct DeployHandler;
+        impl crate::executor::CommandHandler for DeployHandler {
+            fn execute(
+                &self,
+                _context: &mut dyn ExecutionContext,
+                _args: &HashMap<String, String>,
+            ) -> Result<()> {
+                panic!("Handler must not run when validate fails");
+            }
+
+            fn validate(&self, args: &HashMap<String, String>) -> Result<()> {
+                Err(crate::error::ExecutionError::CommandFailed(anyhow::anyhow!(
+                    "cannot deploy to {}",
+                    args["env"]
+                ))
+                .into())
+            }
+        }
+
+        registry.register(cmd_def, Box::new(DeployHandler)).unwrap();
+
+        let context = Box::new(TestContext::default());
+        let cli = CliInterface::new(registry, context);
+
+        let err = cli
+            .run(vec!["deploy".to_string(), "prod".to_string()])
+            .unwrap_err();
+        assert!(err.to_string().contains("cannot deploy to prod"));
+    }
+
+    #[test]
+    fn test_cli_global_options_before_and_after_command() {
+        use crate::config::schema::OptionDefinition;
+        use crate::parser::global_options::get_global_option;
+
+        let mut registry = CommandRegistry::new();
+        registry.set_global_options(vec![OptionDefinition {
+            name: "config".to_string(),
+            short: Some("c".to_string()),
+            long: Some("config".to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: None,
+            description: "Configuration file".to_string(),
+            choices: vec![],
+            ..Default::default()
+        }]);
+
+        struct ConfigHandler;
+        impl crate::executor::CommandHandler for ConfigHandler {
+            fn execute(
+                &self,
+                _context: &mut dyn ExecutionContext,
+                args: &HashMap<String, String>,
+            ) -> Result<()> {
+                assert_eq!(
+                    get_global_option(args, "config"),
+                    Some(&"app.yaml".to_string())
+                );
+                Ok(())
+            }
+        }
+
+        let cmd_def = CommandDefinition {
+            name: "show".to_string(),
+            aliases: vec![],
+            description: "Show configuration".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "show_handler".to_string(),
+            ..Default::default()
+        };
+        registry.register(cmd_def, Box::new(ConfigHandler)).unwrap();
+
+        let cli = CliInterface::new(registry, Box::new(TestContext::default()));
+        let result = cli.run(vec![
+            "--config".to_string(),
+            "app.yaml".to_string(),
+            "show".to_string(),
+        ]);
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_cli_dispatches_to_subcommand() {
+        struct AddHandler;
+        impl crate::executor::CommandHandler for AddHandler {
+            fn execute(
+                &self,
+                _context: &mut dyn ExecutionContext,
+                args: &HashMap<String, String>,
+            ) -> Result<()> {
+                assert_eq!(args.get("name"), Some(&"origin".to_string()));
+                Ok(())
+            }
+        }
+
+        let add_def = CommandDefinition {
+            name: "add".to_string(),
+            aliases: vec!["a".to_string()],
+            description: "Add a remote".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "name".to_string(),
+                arg_type: ArgumentType::String,
+                required: true,
+                description: "Remote name".to_string(),
+                validation: vec![],
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![],
+            implementation: "remote_add".to_string(),
+            ..Default::default()
+        };
+        let remote_def = CommandDefinition {
+            name: "remote".to_string(),
+            aliases: vec!["r".to_string()],
+            description: "Manage remotes".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "remote".to_string(),
+            subcommands: vec![add_def.clone()],
+            ..Default::default()
+        };
+
+        let make_cli = || {
+            let mut registry = CommandRegistry::new();
+            registry.register_group(remote_def.clone()).unwrap();
+            registry
+                .register_subcommand("remote", add_def.clone(), Box::new(AddHandler))
+                .unwrap();
+            CliInterface::new(registry, Box::new(TestContext::default()))
+        };
+
+        let args = |tokens: &[&str]| tokens.iter().map(|s| s.to_string()).collect();
+        assert!(make_cli().run(args(&["remote", "add", "origin"])).is_ok());
+        assert!(make_cli().run(args(&["r", "a", "origin"])).is_ok());
+
+        match make_cli().run(args(&["remote"])).unwrap_err() {
+            DynamicCliError::Parse(crate::error::ParseError::InvalidSyntax { details, .. }) => {
+                assert!(details.contains("requires a subcommand"));
+            }
+            other => panic!("Expected InvalidSyntax error, got: {:?}", other),
+        }
+    }
 }
@@ -277,11 +277,12 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "test".to_string(),
+            ..Default::default()
         };
 
         registry.register(cmd_def, Box::new(TestHandler)).unwrap();
 
-        let context = Box::new(TestContext::default());
+        let context = Box::new(TestContext);
         let _cli = CliInterface::new(registry, context);
     }
 
@@ -297,11 +298,12 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "test".to_string(),
+            ..Default::default()
         };
 
         registry.register(cmd_def, Box::new(TestHandler)).unwrap();
 
-        let context = Box::new(TestContext::default());
+        let context = Box::new(TestContext);
         let _repl = ReplInterface::new(registry, context, "test".to_string(), None, None);
     }
 }
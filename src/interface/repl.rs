@@ -38,11 +38,12 @@ use rustyline::hint::Hinter;
 use rustyline::validate::Validator;
 use rustyline::{CompletionType, Config, Context, Editor, Helper};
 
-use crate::config::schema::CommandsConfig;
+use crate::config::schema::{ArgumentType, CommandDefinition, CommandsConfig, OptionDefinition};
 use crate::context::ExecutionContext;
 use crate::error::{display_error, DynamicCliError, ExecutionError, Result};
+use crate::executor::execute_command;
 use crate::help::HelpFormatter;
-use crate::parser::ReplParser;
+use crate::parser::{ParsedArgs, ReplParser};
 use crate::registry::CommandRegistry;
 
 // ============================================================================
@@ -57,11 +58,16 @@ use crate::registry::CommandRegistry;
 /// |--------------------------|------------------------------------------|
 /// | `<Tab>`                  | all command names + aliases              |
 /// | `he<Tab>`                | command names/aliases starting with `he` |
+/// | `remote <Tab>`           | subcommands and flags of `remote`        |
+/// | `remote a<Tab>`          | subcommands of `remote` starting with `a`|
 /// | `hello <Tab>`            | long and short option flags of `hello`   |
 /// | `hello --<Tab>`          | long flags of `hello`                    |
 /// | `hello -<Tab>`           | short flags of `hello`                   |
+/// | `run --mode <Tab>`       | variants (or choices) of `--mode`        |
+/// | `run --mode=f<Tab>`      | `--mode=` followed by matching variants  |
+/// | `run <Tab>`              | variants of an `enum` first argument     |
 ///
-/// Positional argument values are not completed (open-ended strings).
+/// Other positional argument values are not completed (open-ended strings).
 ///
 /// The completer holds `Arc` references so it shares the same data as
 /// `ReplInterface` without duplication or unsafe aliasing.
@@ -79,23 +85,30 @@ impl DcliCompleter {
         Self { registry, config }
     }
 
-    /// Collect all flag completions for a given canonical command name.
+    /// Find the configured definition of a canonical command path
+    /// (`"remote add"` walks `remote` → `add`).
+    fn definition_for(&self, command_path: &str) -> Option<&CommandDefinition> {
+        let mut segments = command_path.split(' ');
+        let root = segments.next()?;
+        let commands = &self.config.as_ref()?.commands;
+
+        segments.try_fold(commands.iter().find(|c| c.name == root)?, |def, name| {
+            def.subcommands.iter().find(|c| c.name == name)
+        })
+    }
+
+    /// Collect all flag completions for a given canonical command path.
     ///
     /// Returns both long forms (`--flag`) and short forms (`-f`) for every
-    /// option defined on the command.
-    fn flags_for(&self, command_name: &str) -> Vec<String> {
-        let config = match &self.config {
-            Some(c) => c,
-            None => return vec![],
-        };
-
-        let cmd_def = match config.commands.iter().find(|c| c.name == command_name) {
-            Some(d) => d,
-            None => return vec![],
-        };
+    /// option defined on the command, followed by the global options.
+    fn flags_for(&self, command_path: &str) -> Vec<String> {
+        let command_options = self
+            .definition_for(command_path)
+            .map(|d| d.options.as_slice())
+            .unwrap_or(&[]);
 
         let mut flags = Vec::new();
-        for opt in &cmd_def.options {
+        for opt in command_options.iter().chain(self.registry.global_options()) {
             if let Some(long) = &opt.long {
                 flags.push(format!("--{}", long));
             }
@@ -105,6 +118,68 @@ impl DcliCompleter {
         }
         flags
     }
+
+    /// Find an option of a command (or a global option) by its flag
+    /// (`--long` or `-s`).
+    fn option_for(&self, command_path: &str, flag: &str) -> Option<&OptionDefinition> {
+        let command_options = self
+            .definition_for(command_path)
+            .map(|d| d.options.as_slice())
+            .unwrap_or(&[]);
+
+        command_options
+            .iter()
+            .chain(self.registry.global_options())
+            .find(|opt| match flag.strip_prefix("--") {
+                Some(long) => opt.long.as_deref() == Some(long),
+                None => flag.strip_prefix('-').is_some() && opt.short.as_deref() == flag.get(1..),
+            })
+    }
+
+    /// Values offered for an option: `enum` variants or declared choices.
+    fn option_values(opt: &OptionDefinition) -> Vec<String> {
+        if opt.option_type == ArgumentType::Enum {
+            opt.variants.iter().map(|v| v.name.clone()).collect()
+        } else {
+            opt.choices.clone()
+        }
+    }
+
+    /// Variants offered for the positional argument at the cursor, given
+    /// the argument tokens already typed after the command path.
+    fn argument_values(&self, command_path: &str, typed: &[&str]) -> Vec<String> {
+        let Some(def) = self.definition_for(command_path) else {
+            return Vec::new();
+        };
+
+        // Count the positional tokens, skipping options and their values
+        let mut index = 0;
+        let mut tokens = typed.iter();
+        while let Some(token) = tokens.next() {
+            if token.len() > 1 && token.starts_with('-') {
+                let takes_value = !token.contains('=')
+                    && self
+                        .option_for(command_path, token)
+                        .is_some_and(|opt| opt.option_type != ArgumentType::Bool);
+                if takes_value {
+                    tokens.next();
+                }
+            } else {
+                index += 1;
+            }
+        }
+
+        let argument = def
+            .arguments
+            .get(index)
+            .or_else(|| def.arguments.last().filter(|arg| arg.multiple));
+        match argument {
+            Some(arg) if arg.arg_type == ArgumentType::Enum => {
+                arg.variants.iter().map(|v| v.name.clone()).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl Completer for DcliCompleter {
@@ -133,6 +208,7 @@ impl Completer for DcliCompleter {
                 .registry
                 .list_commands()
                 .into_iter()
+                .filter(|def| !def.hidden)
                 .flat_map(|def| {
                     let mut names = vec![def.name.clone()];
                     names.extend(def.aliases.clone());
@@ -149,40 +225,105 @@ impl Completer for DcliCompleter {
             return Ok((start, candidates));
         }
 
-        // ── Level 2: first token is a complete command, completing flags ──────
-        // Resolve the command name (handles aliases).
-        let command_token = tokens[0];
-        let canonical = match self.registry.resolve_name(command_token) {
-            Some(name) => name.to_string(),
-            None => return Ok((pos, vec![])),
-        };
-
-        // The word being completed (may be empty if cursor follows a space).
+        // ── Level 2: first token is a complete command ───────────────────────
+        // Resolve the command path (handles aliases at every level).
         let current_word = if line.ends_with(' ') {
             ""
         } else {
             tokens.last().copied().unwrap_or("")
         };
+        let completed = if line.ends_with(' ') {
+            &tokens[..]
+        } else {
+            &tokens[..tokens.len() - 1]
+        };
 
-        // Only offer flag completions when the current word looks like a flag
-        // or when the user pressed Tab on an empty position after the command.
-        let is_flag_context = current_word.is_empty() || current_word.starts_with('-');
+        let mut canonical = match self.registry.resolve_name(completed[0]) {
+            Some(name) => name.to_string(),
+            None => return Ok((pos, vec![])),
+        };
 
-        if !is_flag_context {
-            return Ok((pos, vec![]));
+        // Descend into subcommands until the first argument token
+        let mut in_arguments = false;
+        let mut path_len = 1;
+        for token in &completed[1..] {
+            match self
+                .registry
+                .resolve_name(&format!("{} {}", canonical, token))
+            {
+                Some(child) => {
+                    canonical = child.to_string();
+                    path_len += 1;
+                }
+                None => {
+                    in_arguments = true;
+                    break;
+                }
+            }
         }
 
         let start = pos - current_word.len();
-        let mut candidates: Vec<Pair> = self
-            .flags_for(&canonical)
+        let mut candidates: Vec<String> = Vec::new();
+
+        // ── Option values: `--flag <Tab>` and `--flag=<Tab>` ─────────────────
+        let pending_option = completed[path_len..]
+            .last()
+            .and_then(|flag| self.option_for(&canonical, flag))
+            .filter(|opt| opt.option_type != ArgumentType::Bool);
+        let inline_option = current_word
+            .split_once('=')
+            .filter(|(flag, _)| flag.starts_with("--"))
+            .and_then(|(flag, _)| Some((flag, self.option_for(&canonical, flag)?)));
+
+        if let Some(opt) = pending_option {
+            candidates.extend(Self::option_values(opt));
+        } else if let Some((flag, opt)) = inline_option {
+            candidates.extend(
+                Self::option_values(opt)
+                    .into_iter()
+                    .map(|value| format!("{}={}", flag, value)),
+            );
+        } else {
+            // ── Level 3: subcommand names and aliases of the resolved command ─
+            if !in_arguments && !current_word.starts_with('-') {
+                candidates.extend(
+                    self.registry
+                        .subcommands_of(&canonical)
+                        .into_iter()
+                        .filter(|def| !def.hidden)
+                        .flat_map(|def| {
+                            std::iter::once(def.name.clone()).chain(def.aliases.clone())
+                        }),
+                );
+            }
+
+            // Variants of an `enum` positional argument
+            if !current_word.starts_with('-') {
+                candidates.extend(self.argument_values(&canonical, &completed[path_len..]));
+            }
+
+            // Only offer flag completions when the current word looks like a flag
+            // or when the user pressed Tab on an empty position after the command.
+            let is_flag_context = current_word.is_empty() || current_word.starts_with('-');
+
+            if is_flag_context {
+                candidates.extend(self.flags_for(&canonical));
+            }
+        }
+
+        let mut candidates: Vec<Pair> = candidates
             .into_iter()
-            .filter(|flag| flag.starts_with(current_word))
-            .map(|flag| Pair {
-                display: flag.clone(),
-                replacement: flag,
+            .filter(|candidate| candidate.starts_with(current_word))
+            .map(|candidate| Pair {
+                display: candidate.clone(),
+                replacement: candidate,
             })
             .collect();
 
+        if candidates.is_empty() {
+            return Ok((pos, vec![]));
+        }
+
         candidates.sort_by(|a, b| a.display.cmp(&b.display));
         Ok((start, candidates))
     }
@@ -395,6 +536,8 @@ impl ReplInterface {
     /// | `-h <command>`     | Per-command help          |
     /// | `<command> --help` | Per-command help          |
     /// | `<command> -h`     | Per-command help          |
+    ///
+    /// `<command>` may be a subcommand path such as `remote add`.
     fn try_handle_help(&self, line: &str) -> Option<String> {
         let config = self.config.as_deref()?;
         let formatter = self.help_formatter.as_deref()?;
@@ -419,7 +562,16 @@ impl ReplInterface {
         if parts.len() >= 2 {
             let last = *parts.last().unwrap();
             if last == "--help" || last == "-h" {
-                return Some(formatter.format_command(config, parts[0]));
+                // Leading words that name subcommands select the help page
+                let mut path = parts[0].to_string();
+                for part in &parts[1..parts.len() - 1] {
+                    let candidate = format!("{} {}", path, part);
+                    match self.registry.resolve_name(&candidate) {
+                        Some(_) => path = candidate,
+                        None => break,
+                    }
+                }
+                return Some(formatter.format_command(config, &path));
             }
         }
 
@@ -579,7 +731,7 @@ impl ReplInterface {
             return Ok(());
         }
 
-        let parser = ReplParser::new(&self.registry);
+        let parser = ReplParser::new(&self.registry).with_context(&*self.context);
         let parsed = parser.parse_line(line)?;
 
         // Write to history only on successful parse and when no secure
@@ -598,7 +750,17 @@ impl ReplInterface {
                 ))
             })?;
 
-        handler.execute(&mut *self.context, &parsed.arguments)?;
+        let definition = self
+            .registry
+            .get_definition(&parsed.command_name)
+            .ok_or_else(|| {
+                DynamicCliError::Registry(crate::error::RegistryError::missing_handler(
+                    &parsed.command_name,
+                ))
+            })?;
+        let typed_args =
+            ParsedArgs::new(definition, self.registry.global_options(), parsed.arguments)?;
+        execute_command(handler.as_ref(), &mut *self.context, &typed_args)?;
 
         Ok(())
     }
@@ -664,6 +826,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "test_handler".to_string(),
+            ..Default::default()
         };
         registry
             .register(
@@ -677,7 +840,7 @@ mod tests {
     }
 
     fn make_help_config() -> CommandsConfig {
-        use crate::config::schema::{CommandsConfig, Metadata};
+        use crate::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
         CommandsConfig {
             metadata: Metadata {
                 version: "1.0.0".to_string(),
@@ -699,13 +862,73 @@ mod tests {
                     default: Some("false".to_string()),
                     description: "Loud greeting".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 }],
                 implementation: "hello_handler".to_string(),
+                ..Default::default()
             }],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         }
     }
 
+    /// Config and registry with a `remote` group holding `add` and `remove`.
+    fn make_remote_fixture() -> (CommandsConfig, CommandRegistry) {
+        let subcommand = |name: &str, aliases: Vec<&str>, options| CommandDefinition {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+            description: format!("{} a remote", name),
+            required: false,
+            arguments: vec![],
+            options,
+            implementation: format!("remote_{}", name),
+            ..Default::default()
+        };
+        let add = subcommand(
+            "add",
+            vec![],
+            vec![OptionDefinition {
+                name: "force".to_string(),
+                short: None,
+                long: Some("force".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Overwrite".to_string(),
+                choices: vec![],
+                ..Default::default()
+            }],
+        );
+        let remove = subcommand("remove", vec!["rm"], vec![]);
+
+        let mut config = make_help_config();
+        config.commands.push(CommandDefinition {
+            name: "remote".to_string(),
+            aliases: vec!["r".to_string()],
+            description: "Manage remotes".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "remote".to_string(),
+            subcommands: vec![add.clone(), remove],
+            ..Default::default()
+        });
+
+        let mut registry = CommandRegistry::new();
+        registry.register_group(config.commands[1].clone()).unwrap();
+        registry
+            .register_subcommand(
+                "remote",
+                add,
+                Box::new(TestHandler {
+                    name: "remote add".to_string(),
+                }),
+            )
+            .unwrap();
+        (config, registry)
+    }
+
     // ── Construction ──────────────────────────────────────────────────────────
 
     #[test]
@@ -786,9 +1009,11 @@ mod tests {
                 description: "Name".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             }],
             options: vec![],
             implementation: "greet_handler".to_string(),
+            ..Default::default()
         };
 
         struct GreetHandler;
@@ -1067,6 +1292,77 @@ mod tests {
         assert!(!names.contains(&"-l"));
     }
 
+    #[test]
+    fn test_completer_enum_variants() {
+        use crate::config::schema::{ArgumentDefinition, EnumVariant};
+        let variants = |names: &[&str]| -> Vec<EnumVariant> {
+            names
+                .iter()
+                .map(|name| EnumVariant {
+                    name: name.to_string(),
+                    description: String::new(),
+                    aliases: vec![format!("{}-alias", name)],
+                })
+                .collect()
+        };
+        let mut config = make_help_config();
+        let hello = &mut config.commands[0];
+        hello.arguments.push(ArgumentDefinition {
+            name: "mood".to_string(),
+            arg_type: ArgumentType::Enum,
+            required: false,
+            description: "Mood".to_string(),
+            validation: vec![],
+            secure: false,
+            variants: variants(&["happy", "grumpy"]),
+            ..Default::default()
+        });
+        let mut color = hello.options[0].clone();
+        color.name = "color".to_string();
+        color.short = Some("c".to_string());
+        color.long = Some("color".to_string());
+        color.option_type = ArgumentType::Enum;
+        color.default = None;
+        color.variants = variants(&["red", "green"]);
+        hello.options.push(color);
+
+        let mut registry = CommandRegistry::new();
+        struct DummyHandler;
+        impl crate::executor::CommandHandler for DummyHandler {
+            fn execute(
+                &self,
+                _: &mut dyn ExecutionContext,
+                _: &HashMap<String, String>,
+            ) -> Result<()> {
+                Ok(())
+            }
+        }
+        registry
+            .register(config.commands[0].clone(), Box::new(DummyHandler))
+            .unwrap();
+        let completer = DcliCompleter::new(Arc::new(registry), Some(Arc::new(config)));
+        let history = rustyline::history::DefaultHistory::new();
+        let ctx = rustyline::Context::new(&history);
+        let complete = |line: &str| -> Vec<String> {
+            let (_, candidates) = completer.complete(line, line.len(), &ctx).unwrap();
+            candidates.into_iter().map(|p| p.replacement).collect()
+        };
+
+        // Option values only, canonical names only
+        assert_eq!(complete("hello --color "), vec!["green", "red"]);
+        assert_eq!(complete("hello -c r"), vec!["red"]);
+        assert_eq!(complete("hello --color=g"), vec!["--color=green"]);
+
+        // Positional argument variants, alongside the flags
+        let names = complete("hello ");
+        assert!(names.contains(&"happy".to_string()), "{:?}", names);
+        assert!(names.contains(&"--color".to_string()), "{:?}", names);
+        assert_eq!(complete("hello --loud -c red g"), vec!["grumpy"]);
+
+        // No variants once the argument is given
+        assert!(complete("hello happy g").is_empty());
+    }
+
     #[test]
     fn test_completer_no_flags_for_unknown_command() {
         let config = Arc::new(make_help_config());
@@ -1079,11 +1375,147 @@ mod tests {
         assert!(candidates.is_empty());
     }
 
+    #[test]
+    fn test_completer_subcommands_after_group() {
+        let (config, registry) = make_remote_fixture();
+        let completer = DcliCompleter::new(Arc::new(registry), Some(Arc::new(config)));
+        let history = rustyline::history::DefaultHistory::new();
+        let ctx = rustyline::Context::new(&history);
+
+        let (_, candidates) = completer.complete("remote ", 7, &ctx).unwrap();
+        let names: Vec<&str> = candidates.iter().map(|p| p.display.as_str()).collect();
+        assert_eq!(names, vec!["add", "remove", "rm"]);
+
+        let (start, candidates) = completer.complete("r re", 4, &ctx).unwrap();
+        let names: Vec<&str> = candidates.iter().map(|p| p.display.as_str()).collect();
+        assert_eq!(start, 2);
+        assert_eq!(names, vec!["remove"]);
+    }
+
+    #[test]
+    fn test_completer_flags_of_subcommand() {
+        let (config, registry) = make_remote_fixture();
+        let completer = DcliCompleter::new(Arc::new(registry), Some(Arc::new(config)));
+        let history = rustyline::history::DefaultHistory::new();
+        let ctx = rustyline::Context::new(&history);
+
+        let (_, candidates) = completer.complete("remote add ", 11, &ctx).unwrap();
+        let names: Vec<&str> = candidates.iter().map(|p| p.display.as_str()).collect();
+        assert_eq!(names, vec!["--force"]);
+    }
+
+    #[test]
+    fn test_repl_execute_subcommand() {
+        let (_, registry) = make_remote_fixture();
+        let context = Box::new(TestContext::default());
+        let mut repl =
+            ReplInterface::new(registry, context, "test".to_string(), None, None).unwrap();
+
+        assert!(repl.execute_line("r add --force").is_ok());
+        assert!(repl.execute_line("remote").is_err());
+        let ctx = crate::context::downcast_ref::<TestContext>(&*repl.context).unwrap();
+        assert_eq!(ctx.executed_commands, vec!["remote add".to_string()]);
+    }
+
+    #[test]
+    fn test_repl_validate_and_constraints_run_before_handler() {
+        use crate::config::schema::ConstraintDefinition;
+
+        /// Rejects a zero `start` in its `validate` hook
+        struct RangeHandler;
+        impl crate::executor::CommandHandler for RangeHandler {
+            fn execute(
+                &self,
+                context: &mut dyn ExecutionContext,
+                _args: &HashMap<String, String>,
+            ) -> Result<()> {
+                let ctx = crate::context::downcast_mut::<TestContext>(context).unwrap();
+                ctx.executed_commands.push("range".to_string());
+                Ok(())
+            }
+
+            fn validate(&self, args: &HashMap<String, String>) -> Result<()> {
+                if args.get("start").map(String::as_str) == Some("0") {
+                    return Err(ExecutionError::CommandFailed(anyhow::anyhow!(
+                        "start must not be zero"
+                    ))
+                    .into());
+                }
+                Ok(())
+            }
+        }
+
+        let argument = |name: &str| ArgumentDefinition {
+            name: name.to_string(),
+            arg_type: ArgumentType::Integer,
+            required: true,
+            description: name.to_string(),
+            validation: vec![],
+            secure: false,
+            ..Default::default()
+        };
+        let mut registry = CommandRegistry::new();
+        registry
+            .register(
+                CommandDefinition {
+                    name: "range".to_string(),
+                    aliases: vec![],
+                    description: "Print a range".to_string(),
+                    required: false,
+                    arguments: vec![argument("start"), argument("end")],
+                    options: vec![],
+                    implementation: "range_handler".to_string(),
+                    constraints: vec![ConstraintDefinition {
+                        check: "end > start".to_string(),
+                        message: None,
+                    }],
+                    ..Default::default()
+                },
+                Box::new(RangeHandler),
+            )
+            .unwrap();
+        let context = Box::new(TestContext::default());
+        let mut repl =
+            ReplInterface::new(registry, context, "test".to_string(), None, None).unwrap();
+
+        assert!(matches!(
+            repl.execute_line("range 9 3"),
+            Err(DynamicCliError::Validation(
+                crate::error::ValidationError::CustomConstraint { .. }
+            ))
+        ));
+        assert!(repl.execute_line("range 0 3").is_err());
+        assert!(repl.execute_line("range 2 10").is_ok());
+
+        let ctx = crate::context::downcast_ref::<TestContext>(&*repl.context).unwrap();
+        assert_eq!(ctx.executed_commands, vec!["range".to_string()]);
+    }
+
+    #[test]
+    fn test_try_handle_help_for_subcommand() {
+        use crate::help::DefaultHelpFormatter;
+        colored::control::set_override(false);
+        let (config, registry) = make_remote_fixture();
+        let context = Box::new(TestContext::default());
+        let repl = ReplInterface::new(
+            registry,
+            context,
+            "test".to_string(),
+            Some(config),
+            Some(Box::new(DefaultHelpFormatter::new())),
+        )
+        .unwrap();
+
+        let out = repl.try_handle_help("remote add --help").unwrap();
+        assert!(out.contains("remote add"));
+        assert!(out.contains("--force"));
+    }
+
     // ── has_secure_arg ────────────────────────────────────────────────────────
 
     /// Build a registry + config with one command that has a `secure` argument.
     fn make_secure_registry_and_config() -> (CommandRegistry, CommandsConfig) {
-        use crate::config::schema::{CommandsConfig, Metadata};
+        use crate::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
 
         let cmd_def = CommandDefinition {
             name: "login".to_string(),
@@ -1098,6 +1530,7 @@ mod tests {
                     description: "Username".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
                 ArgumentDefinition {
                     name: "password".to_string(),
@@ -1106,10 +1539,12 @@ mod tests {
                     description: "Password".to_string(),
                     validation: vec![],
                     secure: true,
+                    ..Default::default()
                 },
             ],
             options: vec![],
             implementation: "login_handler".to_string(),
+            ..Default::default()
         };
 
         struct LoginHandler;
@@ -1136,6 +1571,8 @@ mod tests {
             },
             commands: vec![cmd_def],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         };
 
         (registry, config)
@@ -74,6 +74,7 @@
 //! - [`registry`]: Command and handler registry
 //! - [`parser`]: CLI and REPL argument parsing
 //! - [`validator`]: Argument validation
+//! - [`completion`]: Shell completion scripts for CLI mode
 //!
 //! ## Module Status
 //!
@@ -89,6 +90,7 @@
 // ============================================================================
 
 pub mod builder;
+pub mod completion;
 pub mod config;
 pub mod context;
 pub mod error;
@@ -123,7 +125,9 @@ pub use registry::CommandRegistry;
 pub use parser::{CliParser, ParsedCommand, ReplParser};
 
 // Validator functions
-pub use validator::{validate_file_exists, validate_file_extension, validate_range};
+pub use validator::{
+    validate_file_exists, validate_file_extension, validate_range, ArgumentValidator,
+};
 
 // Interface types
 pub use interface::{CliInterface, ReplInterface};
@@ -132,7 +136,12 @@ pub use interface::{CliInterface, ReplInterface};
 pub use builder::{CliApp, CliBuilder};
 
 // Helper system
-pub use help::{DefaultHelpFormatter, HelpFormatter};
+pub use help::{
+    DefaultHelpFormatter, HelpFormatter, HtmlFormatter, ManPageGenerator, MarkdownFormatter,
+};
+
+// Shell completion
+pub use completion::Shell;
 
 // Utility functions
 pub use utils::{
@@ -160,7 +169,7 @@ pub use utils::{
 /// // - DynamicCliError, Result
 /// // - CommandRegistry
 /// // - ParsedCommand, CliParser, ReplParser
-/// // - validate_file_exists, validate_file_extension, validate_range
+/// // - validate_file_exists, validate_file_extension, validate_range, ArgumentValidator
 /// // - Common config types (ArgumentType, CommandsConfig)
 /// // - CliBuilder, CliApp
 /// // - Utility functions (parse_int, parse_bool, is_blank, etc.)
@@ -182,10 +191,12 @@ pub mod prelude {
     pub use crate::registry::CommandRegistry;
 
     // Parsing
-    pub use crate::parser::{CliParser, ParsedCommand, ReplParser};
+    pub use crate::parser::{CliParser, ParsedArgs, ParsedCommand, ReplParser, Value};
 
     // Validation
-    pub use crate::validator::{validate_file_exists, validate_file_extension, validate_range};
+    pub use crate::validator::{
+        validate_file_exists, validate_file_extension, validate_range, ArgumentValidator,
+    };
 
     // Interface
     pub use crate::interface::{CliInterface, ReplInterface};
@@ -248,7 +259,7 @@ mod tests {
     /// Verify that help types are accessible from the prelude
     #[test]
     fn test_help_prelude_imports() {
-        use crate::config::schema::{CommandsConfig, Metadata};
+        use crate::config::schema::{CommandsConfig, Metadata, SCHEMA_VERSION};
         use crate::prelude::*;
 
         let config = CommandsConfig {
@@ -259,6 +270,8 @@ mod tests {
             },
             commands: vec![],
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         };
 
         // DefaultHelpFormatter accessible from prelude
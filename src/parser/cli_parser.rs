@@ -7,6 +7,14 @@
 //! - Long options (`--verbose`)
 //! - Options with values (`-o file.txt`, `--output=file.txt`)
 //! - Type conversion and validation
+//! - Enforcement of the argument [`ValidationRule`]s (file existence,
+//!   extensions, numeric ranges)
+//! - Enforcement of option `choices` and `enum` variants (aliases are
+//!   replaced by the canonical variant name)
+//! - Variadic last arguments and repeatable options (see [`multiple_values`])
+//! - Environment-variable fallback for options declaring `env`
+//! - Exclusive groups, `requires` and `conflicts_with` relations
+//! - Global options shared by all commands (see [`global_options`])
 //!
 //! # Example
 //!
@@ -27,10 +35,12 @@
 //!             description: "Input file".to_string(),
 //!             validation: vec![],
 //!             secure: false,
+//!             ..Default::default()
 //!         }
 //!     ],
 //!     options: vec![],
 //!     implementation: "handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! let parser = CliParser::new(&definition);
@@ -39,12 +49,24 @@
 //!
 //! assert_eq!(parsed.get("input"), Some(&"file.txt".to_string()));
 //! ```
+//!
+//! [`ValidationRule`]: crate::config::schema::ValidationRule
+//! [`global_options`]: crate::parser::global_options
+//! [`multiple_values`]: crate::parser::multiple_values
 
 #[allow(unused_imports)]
-use crate::config::schema::{ArgumentDefinition, CommandDefinition, OptionDefinition};
-use crate::error::{ParseError, Result};
+use crate::config::schema::{
+    ArgumentDefinition, ArgumentType, CommandDefinition, EnumVariant, OptionDefinition,
+};
+use crate::error::{DynamicCliError, ParseError, Result, ValidationError};
+use crate::parser::global_options::global_option_key;
+use crate::parser::multiple_values;
 use crate::parser::type_parser;
-use std::collections::HashMap;
+use crate::validator::constraint_validator::{self, Constraint};
+use crate::validator::custom_validator::CustomValidators;
+use crate::validator::rule_validator;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 
 /// CLI argument parser
 ///
@@ -81,9 +103,11 @@ use std::collections::HashMap;
 ///             default: Some("false".to_string()),
 ///             description: "Verbose output".to_string(),
 ///             choices: vec![],
+///             ..Default::default()
 ///         }
 ///     ],
 ///     implementation: "handler".to_string(),
+///     ..Default::default()
 /// };
 ///
 /// let parser = CliParser::new(&definition);
@@ -95,6 +119,18 @@ use std::collections::HashMap;
 pub struct CliParser<'a> {
     /// The command definition that specifies expected arguments and options
     definition: &'a CommandDefinition,
+
+    /// Global options accepted in addition to the command's own options
+    global_options: &'a [OptionDefinition],
+
+    /// When set, only global options are recognized (tokens before the command name)
+    leading_only: bool,
+
+    /// Validators for `custom:` rules, with the context they run against
+    custom_validators: Option<CustomValidators<'a>>,
+
+    /// Constraints of the command, parsed beforehand
+    constraints: Option<&'a [Constraint]>,
 }
 
 impl<'a> CliParser<'a> {
@@ -118,11 +154,118 @@ impl<'a> CliParser<'a> {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// let parser = CliParser::new(&definition);
     /// ```
     pub fn new(definition: &'a CommandDefinition) -> Self {
-        Self { definition }
+        Self {
+            definition,
+            global_options: &[],
+            leading_only: false,
+            custom_validators: None,
+            constraints: None,
+        }
+    }
+
+    /// Accept the given global options in addition to the command's own
+    ///
+    /// Global option values are stored under a reserved key built with
+    /// [`global_option_key`] (e.g. `global.verbose`), so they never collide
+    /// with command-local names. When a flag is declared both globally and
+    /// by the command, the command-local option wins.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::parser::cli_parser::CliParser;
+    /// use dynamic_cli::parser::global_options::get_global_option;
+    /// use dynamic_cli::config::schema::{ArgumentType, CommandDefinition, OptionDefinition};
+    ///
+    /// # let definition = CommandDefinition {
+    /// #     name: "run".to_string(),
+    /// #     aliases: vec![],
+    /// #     description: "".to_string(),
+    /// #     required: false,
+    /// #     arguments: vec![],
+    /// #     options: vec![],
+    /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
+    /// # };
+    /// let globals = vec![OptionDefinition {
+    ///     name: "verbose".to_string(),
+    ///     short: Some("v".to_string()),
+    ///     long: Some("verbose".to_string()),
+    ///     option_type: ArgumentType::Bool,
+    ///     required: false,
+    ///     default: None,
+    ///     description: "Verbose output".to_string(),
+    ///     choices: vec![],
+    ///     ..Default::default()
+    /// }];
+    ///
+    /// let parser = CliParser::new(&definition).with_global_options(&globals);
+    /// let parsed = parser.parse(&["--verbose".to_string()]).unwrap();
+    ///
+    /// assert_eq!(get_global_option(&parsed, "verbose"), Some(&"true".to_string()));
+    /// ```
+    pub fn with_global_options(mut self, global_options: &'a [OptionDefinition]) -> Self {
+        self.global_options = global_options;
+        self
+    }
+
+    /// Run `custom:` validation rules through the given validators
+    ///
+    /// Without this, `custom:` rules are skipped, since they cannot be
+    /// checked.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::parser::cli_parser::CliParser;
+    /// use dynamic_cli::config::schema::CommandDefinition;
+    /// use dynamic_cli::context::ExecutionContext;
+    /// use dynamic_cli::validator::{ArgumentValidator, CustomValidators};
+    /// use std::collections::HashMap;
+    ///
+    /// # struct MyContext;
+    /// # impl ExecutionContext for MyContext {
+    /// #     fn as_any(&self) -> &dyn std::any::Any { self }
+    /// #     fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+    /// # }
+    /// # let definition = CommandDefinition {
+    /// #     name: "test".to_string(),
+    /// #     aliases: vec![],
+    /// #     description: "".to_string(),
+    /// #     required: false,
+    /// #     arguments: vec![],
+    /// #     options: vec![],
+    /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
+    /// # };
+    /// let validators: HashMap<String, Box<dyn ArgumentValidator>> = HashMap::new();
+    /// let context = MyContext;
+    ///
+    /// let parser = CliParser::new(&definition)
+    ///     .with_custom_validators(CustomValidators::new(&validators, &context));
+    /// assert!(parser.parse(&[]).is_ok());
+    /// ```
+    pub fn with_custom_validators(mut self, validators: CustomValidators<'a>) -> Self {
+        self.custom_validators = Some(validators);
+        self
+    }
+
+    /// Check `constraints` with checks parsed beforehand
+    ///
+    /// `constraints` come from
+    /// [`parse_constraints`](constraint_validator::parse_constraints) for
+    /// the same definition, usually through
+    /// [`CommandRegistry::constraints`](crate::registry::CommandRegistry::constraints).
+    /// Without this, the checks are parsed on each call to
+    /// [`parse`](Self::parse).
+    pub fn with_constraints(mut self, constraints: &'a [Constraint]) -> Self {
+        self.constraints = Some(constraints);
+        self
     }
 
     /// Parse command-line arguments into a HashMap
@@ -147,6 +290,15 @@ impl<'a> CliParser<'a> {
     /// - [`ParseError::UnknownOption`] if an unrecognized option is provided
     /// - [`ParseError::TypeParseError`] if a value cannot be converted to its expected type
     /// - [`ParseError::TooManyArguments`] if more positional arguments than expected
+    /// - [`ParseError::WrongValueCount`] if a multiple-value argument is outside
+    ///   its `min_values`/`max_values`
+    /// - [`ParseError::ConflictingArguments`], [`ParseError::MissingRequirement`]
+    ///   and [`ParseError::MissingGroupMember`] if the command's `groups`,
+    ///   `conflicts_with` or `requires` relations are violated
+    /// - [`ValidationError::InvalidChoice`] if an option value is not among its
+    ///   `choices`, or an `enum` value matches no variant name or alias
+    /// - [`ValidationError`] if a value violates one of the argument's
+    ///   validation rules (`must_exist`, `extensions`, `min`/`max`)
     ///
     /// # Example
     ///
@@ -169,10 +321,12 @@ impl<'a> CliParser<'a> {
     ///             description: "Name".to_string(),
     ///             validation: vec![],
     ///             secure: false,
+    ///             ..Default::default()
     ///         }
     ///     ],
     ///     options: vec![],
     ///     implementation: "handler".to_string(),
+    ///     ..Default::default()
     /// };
     ///
     /// let parser = CliParser::new(&definition);
@@ -180,7 +334,115 @@ impl<'a> CliParser<'a> {
     /// assert_eq!(result.get("name"), Some(&"Alice".to_string()));
     /// ```
     pub fn parse(&self, args: &[String]) -> Result<HashMap<String, String>> {
+        self.parse_after_globals(&[], args)
+    }
+
+    /// Parse arguments preceded by global options given before the command name
+    ///
+    /// `leading` holds the tokens that appeared before the command name;
+    /// they may only contain global options, and are matched against the
+    /// global options even when the command declares the same flag.
+    /// `args` is parsed exactly as by [`parse`](Self::parse). When a global
+    /// option appears in both, the value after the command name wins.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`parse`](Self::parse); an unknown flag in `leading` yields
+    /// [`ParseError::UnknownOption`] with global option suggestions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::parser::cli_parser::CliParser;
+    /// use dynamic_cli::config::schema::{ArgumentType, CommandDefinition, OptionDefinition};
+    ///
+    /// let verbose = OptionDefinition {
+    ///     name: "verbose".to_string(),
+    ///     short: None,
+    ///     long: Some("verbose".to_string()),
+    ///     option_type: ArgumentType::Bool,
+    ///     required: false,
+    ///     default: None,
+    ///     description: "Verbose output".to_string(),
+    ///     choices: vec![],
+    ///     ..Default::default()
+    /// };
+    /// let definition = CommandDefinition {
+    ///     name: "run".to_string(),
+    ///     aliases: vec![],
+    ///     description: "".to_string(),
+    ///     required: false,
+    ///     arguments: vec![],
+    ///     options: vec![verbose.clone()],
+    ///     implementation: "".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let globals = vec![verbose];
+    ///
+    /// let parser = CliParser::new(&definition).with_global_options(&globals);
+    /// let parsed = parser
+    ///     .parse_after_globals(&["--verbose".to_string()], &[])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(parsed.get("global.verbose"), Some(&"true".to_string()));
+    /// assert_eq!(parsed.get("verbose"), None);
+    /// ```
+    pub fn parse_after_globals(
+        &self,
+        leading: &[String],
+        args: &[String],
+    ) -> Result<HashMap<String, String>> {
         let mut result = HashMap::new();
+
+        // Tokens before the command name only see the global options
+        let leading_parser = CliParser {
+            leading_only: true,
+            ..*self
+        };
+        leading_parser.parse_tokens(leading, &mut result)?;
+
+        self.parse_tokens(args, &mut result)?;
+
+        // Apply defaults for missing optional options
+        let defaulted = self.apply_defaults(&mut result)?;
+
+        // Validate all required arguments are present
+        self.validate_required_arguments(&result)?;
+        self.validate_required_options(&result)?;
+
+        // Enforce groups, `conflicts_with` and `requires`
+        self.validate_relations(&result, &defaulted)?;
+
+        // Enforce min_values / max_values of multiple-value arguments
+        self.validate_value_counts(&result)?;
+
+        // Replace enum aliases by their variant, reject unknown variants
+        self.normalize_variants(&mut result)?;
+
+        // Reject option values outside their declared choices
+        self.validate_choices(&result)?;
+
+        // Enforce validation rules declared in the configuration
+        match self.custom_validators {
+            Some(custom) => {
+                rule_validator::validate_arguments_with(self.definition, &result, custom)?
+            }
+            None => rule_validator::validate_arguments(self.definition, &result)?,
+        }
+
+        // Enforce cross-field constraints such as `end > start`
+        match self.constraints {
+            Some(constraints) => {
+                constraint_validator::check_constraints(self.definition, constraints, &result)?
+            }
+            None => constraint_validator::validate_constraints(self.definition, &result)?,
+        }
+
+        Ok(result)
+    }
+
+    /// Parse a token list into `result` (options and positionals)
+    fn parse_tokens(&self, args: &[String], result: &mut HashMap<String, String>) -> Result<()> {
         let mut positional_index = 0;
         let mut i = 0;
 
@@ -190,7 +452,7 @@ impl<'a> CliParser<'a> {
 
             if arg.starts_with("--") {
                 // Long option
-                self.parse_long_option(arg, args, &mut i, &mut result)?;
+                self.parse_long_option(arg, args, &mut i, result)?;
             } else if arg.starts_with('-') && arg.len() > 1 {
                 // Short option (ensure it's not just a negative number)
                 if arg
@@ -200,28 +462,21 @@ impl<'a> CliParser<'a> {
                     .unwrap_or(false)
                 {
                     // This is a negative number, treat as positional
-                    self.parse_positional_argument(arg, positional_index, &mut result)?;
+                    self.parse_positional_argument(arg, positional_index, result)?;
                     positional_index += 1;
                 } else {
-                    self.parse_short_option(arg, args, &mut i, &mut result)?;
+                    self.parse_short_option(arg, args, &mut i, result)?;
                 }
             } else {
                 // Positional argument
-                self.parse_positional_argument(arg, positional_index, &mut result)?;
+                self.parse_positional_argument(arg, positional_index, result)?;
                 positional_index += 1;
             }
 
             i += 1;
         }
 
-        // Apply defaults for missing optional options
-        self.apply_defaults(&mut result)?;
-
-        // Validate all required arguments are present
-        self.validate_required_arguments(&result)?;
-        self.validate_required_options(&result)?;
-
-        Ok(result)
+        Ok(())
     }
 
     /// Parse a long option (--option or --option=value)
@@ -239,19 +494,19 @@ impl<'a> CliParser<'a> {
             let option_name = &arg_without_dashes[..eq_pos];
             let value = &arg_without_dashes[eq_pos + 1..];
 
-            let option = self.find_option_by_long(option_name)?;
+            let (option, key) = self.find_option_by_long(option_name)?;
             let parsed_value = type_parser::parse_value(value, option.option_type)?;
-            result.insert(option.name.clone(), parsed_value);
+            Self::store_option_value(option, key, parsed_value, result)?;
         } else {
             // --option format (value might be next arg)
-            let option = self.find_option_by_long(arg_without_dashes)?;
+            let (option, key) = self.find_option_by_long(arg_without_dashes)?;
 
             // For boolean options, presence means true
             if matches!(
                 option.option_type,
                 crate::config::schema::ArgumentType::Bool
             ) {
-                result.insert(option.name.clone(), "true".to_string());
+                result.insert(key, "true".to_string());
             } else {
                 // Non-boolean: expect value in next argument
                 *index += 1;
@@ -272,7 +527,7 @@ impl<'a> CliParser<'a> {
 
                 let value = &args[*index];
                 let parsed_value = type_parser::parse_value(value, option.option_type)?;
-                result.insert(option.name.clone(), parsed_value);
+                Self::store_option_value(option, key, parsed_value, result)?;
             }
         }
 
@@ -287,21 +542,21 @@ impl<'a> CliParser<'a> {
         index: &mut usize,
         result: &mut HashMap<String, String>,
     ) -> Result<()> {
-        let short_flag = &arg[1..2];
-        let option = self.find_option_by_short(short_flag)?;
+        let rest = &arg[1..];
+        let (short_flag, attached) = rest.split_at(rest.chars().next().map_or(0, char::len_utf8));
+        let (option, key) = self.find_option_by_short(short_flag)?;
 
         // For boolean options, presence means true
         if matches!(
             option.option_type,
             crate::config::schema::ArgumentType::Bool
         ) {
-            result.insert(option.name.clone(), "true".to_string());
+            result.insert(key, "true".to_string());
         } else {
             // Check if value is attached (e.g., -ovalue)
-            if arg.len() > 2 {
-                let value = &arg[2..];
-                let parsed_value = type_parser::parse_value(value, option.option_type)?;
-                result.insert(option.name.clone(), parsed_value);
+            if !attached.is_empty() {
+                let parsed_value = type_parser::parse_value(attached, option.option_type)?;
+                Self::store_option_value(option, key, parsed_value, result)?;
             } else {
                 // Value is next argument
                 *index += 1;
@@ -318,48 +573,123 @@ impl<'a> CliParser<'a> {
 
                 let value = &args[*index];
                 let parsed_value = type_parser::parse_value(value, option.option_type)?;
-                result.insert(option.name.clone(), parsed_value);
+                Self::store_option_value(option, key, parsed_value, result)?;
             }
         }
 
         Ok(())
     }
 
+    /// Store an option value, appending to the list of a repeatable option
+    fn store_option_value(
+        option: &OptionDefinition,
+        key: String,
+        value: String,
+        result: &mut HashMap<String, String>,
+    ) -> Result<()> {
+        if option.multiple {
+            multiple_values::push_value(result, key, value)?;
+        } else {
+            result.insert(key, value);
+        }
+        Ok(())
+    }
+
     /// Parse a positional argument
+    ///
+    /// Tokens beyond the declared arguments go to the last argument if it
+    /// is variadic.
     fn parse_positional_argument(
         &self,
         value: &str,
         index: usize,
         result: &mut HashMap<String, String>,
     ) -> Result<()> {
-        if index >= self.definition.arguments.len() {
-            return Err(ParseError::too_many_arguments(
-                &self.definition.name,
-                self.definition.arguments.len(),
-                index + 1,
-            )
-            .into());
-        }
+        let arg_def = match self.definition.arguments.get(index) {
+            Some(arg_def) => arg_def,
+            None => match self.definition.arguments.last() {
+                Some(last) if last.multiple => last,
+                _ => {
+                    return Err(ParseError::too_many_arguments(
+                        &self.definition.name,
+                        self.definition.arguments.len(),
+                        index + 1,
+                    )
+                    .into())
+                }
+            },
+        };
 
-        let arg_def = &self.definition.arguments[index];
         let parsed_value = type_parser::parse_value(value, arg_def.arg_type)?;
-        result.insert(arg_def.name.clone(), parsed_value);
+        if arg_def.multiple {
+            multiple_values::push_value(result, arg_def.name.clone(), parsed_value)?;
+        } else {
+            result.insert(arg_def.name.clone(), parsed_value);
+        }
 
         Ok(())
     }
 
-    /// Apply default values for options not provided
-    fn apply_defaults(&self, result: &mut HashMap<String, String>) -> Result<()> {
-        for option in &self.definition.options {
-            if !result.contains_key(&option.name) {
-                if let Some(ref default) = option.default {
-                    // Validate the default value
-                    let parsed_default = type_parser::parse_value(default, option.option_type)?;
-                    result.insert(option.name.clone(), parsed_default);
-                }
+    /// Fill in options not given on the command line
+    ///
+    /// The value comes from the option's environment variable if it is set
+    /// (and not empty), otherwise from its default value. The variable of a
+    /// repeatable option holds comma-separated values. Returns the keys
+    /// filled from a default value.
+    fn apply_defaults(&self, result: &mut HashMap<String, String>) -> Result<HashSet<String>> {
+        let mut defaulted = HashSet::new();
+        for (option, key) in self.all_options() {
+            let Entry::Vacant(entry) = result.entry(key) else {
+                continue;
+            };
+
+            let from_env = option.env.as_deref().and_then(|var| {
+                std::env::var(var)
+                    .ok()
+                    .filter(|value| !value.is_empty())
+                    .map(|value| (var, value))
+            });
+
+            if let Some((var, value)) = from_env {
+                // Report the variable rather than a generic "value"
+                let parse = |value: &str| {
+                    type_parser::parse_value(value, option.option_type).map_err(|error| match error
+                    {
+                        DynamicCliError::Parse(ParseError::TypeParseError {
+                            expected_type,
+                            value,
+                            details,
+                            ..
+                        }) => ParseError::TypeParseError {
+                            arg_name: format!(
+                                "option '{}' (from environment variable {})",
+                                option.name, var
+                            ),
+                            expected_type,
+                            value,
+                            details,
+                        }
+                        .into(),
+                        other => other,
+                    })
+                };
+                let parsed = if option.multiple {
+                    let values = multiple_values::split_env_values(&value)
+                        .map(parse)
+                        .collect::<Result<Vec<_>>>()?;
+                    multiple_values::join_values(values)
+                } else {
+                    parse(&value)?
+                };
+                entry.insert(parsed);
+            } else if let Some(default) = &option.default {
+                // Validate the default value
+                let parsed_default = type_parser::parse_value(default, option.option_type)?;
+                defaulted.insert(entry.key().clone());
+                entry.insert(parsed_default);
             }
         }
-        Ok(())
+        Ok(defaulted)
     }
 
     /// Validate that all required arguments are present
@@ -374,8 +704,8 @@ impl<'a> CliParser<'a> {
 
     /// Validate that all required options are present
     fn validate_required_options(&self, result: &HashMap<String, String>) -> Result<()> {
-        for option in &self.definition.options {
-            if option.required && !result.contains_key(&option.name) {
+        for (option, key) in self.all_options() {
+            if option.required && !result.contains_key(&key) {
                 return Err(ParseError::missing_option(
                     &option
                         .long
@@ -390,18 +720,245 @@ impl<'a> CliParser<'a> {
         Ok(())
     }
 
-    /// Find an option by its long form
-    fn find_option_by_long(&self, long: &str) -> Result<&OptionDefinition> {
-        self.definition
-            .options
+    /// Validate exclusive groups, `conflicts_with` and `requires` relations
+    ///
+    /// Values filled in from a default do not count as given.
+    fn validate_relations(
+        &self,
+        result: &HashMap<String, String>,
+        defaulted: &HashSet<String>,
+    ) -> Result<()> {
+        let definition = self.definition;
+        let given = |name: &str| result.contains_key(name) && !defaulted.contains(name);
+
+        for group in &definition.groups {
+            let present: Vec<&String> = group.members.iter().filter(|m| given(m)).collect();
+            let labels: Vec<String> = group.members.iter().map(|m| self.label(m)).collect();
+
+            if let [first, second, ..] = present.as_slice() {
+                return Err(ParseError::ConflictingArguments {
+                    first: self.label(first),
+                    second: self.label(second),
+                    suggestion: Some(format!(
+                        "Only one of {} can be given (group '{}').",
+                        labels.join(", "),
+                        group.name
+                    )),
+                }
+                .into());
+            }
+            if group.required && present.is_empty() {
+                return Err(ParseError::MissingGroupMember {
+                    group: group.name.clone(),
+                    suggestion: Some(format!(
+                        "Add one of {}. Run --help {} for the expected usage.",
+                        labels.join(", "),
+                        definition.name
+                    )),
+                    members: labels,
+                }
+                .into());
+            }
+        }
+
+        for (name, others) in &definition.conflicts_with {
+            if !given(name) {
+                continue;
+            }
+            if let Some(other) = others.iter().find(|other| given(other)) {
+                let (first, second) = (self.label(name), self.label(other));
+                return Err(ParseError::ConflictingArguments {
+                    suggestion: Some(format!("Remove either {} or {}.", first, second)),
+                    first,
+                    second,
+                }
+                .into());
+            }
+        }
+
+        for (name, required) in &definition.requires {
+            if !given(name) {
+                continue;
+            }
+            if let Some(missing) = required.iter().find(|other| !given(other)) {
+                let (argument, required) = (self.label(name), self.label(missing));
+                return Err(ParseError::MissingRequirement {
+                    suggestion: Some(format!("Add {}, or remove {}.", required, argument)),
+                    argument,
+                    required,
+                }
+                .into());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// How an argument or option is shown in error messages
+    /// (`<input>`, `--output`, `-o`)
+    fn label(&self, name: &str) -> String {
+        if let Some(option) = self.definition.options.iter().find(|o| o.name == name) {
+            if let Some(long) = &option.long {
+                return format!("--{}", long);
+            }
+            if let Some(short) = &option.short {
+                return format!("-{}", short);
+            }
+        }
+        format!("<{}>", name)
+    }
+
+    /// Replace `enum` values given by alias with the variant name
+    ///
+    /// Each value of a multiple-value argument or option is resolved.
+    fn normalize_variants(&self, result: &mut HashMap<String, String>) -> Result<()> {
+        let arguments = self
+            .definition
+            .arguments
+            .iter()
+            .filter(|arg| arg.arg_type == ArgumentType::Enum)
+            .map(|arg| {
+                (
+                    arg.name.clone(),
+                    arg.name.as_str(),
+                    arg.multiple,
+                    &arg.variants,
+                )
+            });
+        let options = self
+            .all_options()
+            .filter(|(option, _)| option.option_type == ArgumentType::Enum)
+            .map(|(option, key)| (key, option.name.as_str(), option.multiple, &option.variants));
+
+        for (key, name, multiple, variants) in arguments.chain(options) {
+            let Some(raw) = result.get(&key) else {
+                continue;
+            };
+            let values: Vec<&str> = if multiple {
+                multiple_values::split_values(raw).collect()
+            } else {
+                vec![raw]
+            };
+            let mut canonical = Vec::with_capacity(values.len());
+            for value in values {
+                match EnumVariant::find(variants, value) {
+                    Some(variant) => canonical.push(variant.name.as_str()),
+                    None => {
+                        let names: Vec<String> = variants.iter().map(|v| v.name.clone()).collect();
+                        return Err(ValidationError::invalid_choice_with_suggestions(
+                            name, value, &names,
+                        )
+                        .into());
+                    }
+                }
+            }
+            let normalized = multiple_values::join_values(canonical);
+            result.insert(key, normalized);
+        }
+        Ok(())
+    }
+
+    /// Validate that option values belong to their declared choices
+    ///
+    /// Each value of a repeatable option is checked.
+    fn validate_choices(&self, result: &HashMap<String, String>) -> Result<()> {
+        for (option, key) in self.all_options() {
+            if option.choices.is_empty() {
+                continue;
+            }
+            let Some(raw) = result.get(&key) else {
+                continue;
+            };
+            let values: Vec<&str> = if option.multiple {
+                multiple_values::split_values(raw).collect()
+            } else {
+                vec![raw]
+            };
+            for value in values {
+                if !option.choices.iter().any(|choice| choice == value) {
+                    return Err(ValidationError::invalid_choice_with_suggestions(
+                        &option.name,
+                        value,
+                        &option.choices,
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the number of values of variadic arguments and repeatable options
+    fn validate_value_counts(&self, result: &HashMap<String, String>) -> Result<()> {
+        let arguments = self
+            .definition
+            .arguments
+            .iter()
+            .filter(|arg| arg.multiple)
+            .map(|arg| {
+                (
+                    arg.name.clone(),
+                    arg.name.as_str(),
+                    arg.min_values,
+                    arg.max_values,
+                )
+            });
+        let options = self
+            .all_options()
+            .filter(|(option, _)| option.multiple)
+            .map(|(option, key)| {
+                (
+                    key,
+                    option.name.as_str(),
+                    option.min_values,
+                    option.max_values,
+                )
+            });
+
+        for (key, name, min, max) in arguments.chain(options) {
+            let Some(raw) = result.get(&key) else {
+                continue;
+            };
+            let count = multiple_values::split_values(raw).count();
+            if min.is_some_and(|min| count < min) || max.is_some_and(|max| count > max) {
+                return Err(ParseError::wrong_value_count(
+                    name,
+                    &self.definition.name,
+                    min,
+                    max,
+                    count,
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Iterate over command-local and global options with their result keys
+    fn all_options(&self) -> impl Iterator<Item = (&'a OptionDefinition, String)> {
+        let local: &'a [OptionDefinition] = if self.leading_only {
+            &[]
+        } else {
+            &self.definition.options
+        };
+        let local = local.iter().map(|opt| (opt, opt.name.clone()));
+        let global = self
+            .global_options
             .iter()
-            .find(|opt| opt.long.as_deref() == Some(long))
+            .map(|opt| (opt, global_option_key(&opt.name)));
+        local.chain(global)
+    }
+
+    /// Find an option by its long form
+    ///
+    /// Returns the option and the key its value is stored under.
+    fn find_option_by_long(&self, long: &str) -> Result<(&'a OptionDefinition, String)> {
+        self.all_options()
+            .find(|(opt, _)| opt.long.as_deref() == Some(long))
             .ok_or_else(|| {
                 let available: Vec<String> = self
-                    .definition
-                    .options
-                    .iter()
-                    .filter_map(|o| o.long.clone())
+                    .all_options()
+                    .filter_map(|(o, _)| o.long.clone())
                     .collect();
                 ParseError::unknown_option_with_suggestions(
                     &format!("--{}", long),
@@ -413,17 +970,15 @@ impl<'a> CliParser<'a> {
     }
 
     /// Find an option by its short form
-    fn find_option_by_short(&self, short: &str) -> Result<&OptionDefinition> {
-        self.definition
-            .options
-            .iter()
-            .find(|opt| opt.short.as_deref() == Some(short))
+    ///
+    /// Returns the option and the key its value is stored under.
+    fn find_option_by_short(&self, short: &str) -> Result<(&'a OptionDefinition, String)> {
+        self.all_options()
+            .find(|(opt, _)| opt.short.as_deref() == Some(short))
             .ok_or_else(|| {
                 let available: Vec<String> = self
-                    .definition
-                    .options
-                    .iter()
-                    .filter_map(|o| o.short.clone())
+                    .all_options()
+                    .filter_map(|(o, _)| o.short.clone())
                     .collect();
                 ParseError::unknown_option_with_suggestions(
                     &format!("-{}", short),
@@ -438,7 +993,7 @@ impl<'a> CliParser<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::schema::{ArgumentType, OptionDefinition};
+    use crate::config::schema::{OptionDefinition, ValidationRule};
 
     /// Helper to create a test command definition
     fn create_test_definition() -> CommandDefinition {
@@ -455,6 +1010,7 @@ mod tests {
                     description: "Input file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
                 ArgumentDefinition {
                     name: "output".to_string(),
@@ -463,6 +1019,7 @@ mod tests {
                     description: "Output file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
             ],
             options: vec![
@@ -475,6 +1032,7 @@ mod tests {
                     default: Some("false".to_string()),
                     description: "Verbose output".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
                 OptionDefinition {
                     name: "count".to_string(),
@@ -485,9 +1043,11 @@ mod tests {
                     default: Some("10".to_string()),
                     description: "Count".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
             ],
             implementation: "handler".to_string(),
+            ..Default::default()
         }
     }
 
@@ -649,6 +1209,25 @@ mod tests {
         assert_eq!(result.get("count"), Some(&"42".to_string()));
     }
 
+    #[test]
+    fn test_parse_non_ascii_short_option() {
+        let mut definition = create_test_definition();
+        definition.options[1].short = Some("é".to_string());
+        let parser = CliParser::new(&definition);
+
+        let args = vec!["input.txt".to_string(), "-é7".to_string()];
+        let result = parser.parse(&args).unwrap();
+        assert_eq!(result.get("count"), Some(&"7".to_string()));
+
+        let args = vec!["input.txt".to_string(), "-ü".to_string()];
+        match parser.parse(&args).unwrap_err() {
+            crate::error::DynamicCliError::Parse(ParseError::UnknownOption { flag, .. }) => {
+                assert_eq!(flag, "-ü");
+            }
+            other => panic!("Expected UnknownOption, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_parse_negative_number_as_positional() {
         let definition = create_test_definition();
@@ -696,6 +1275,419 @@ mod tests {
         assert_eq!(result.get("count"), Some(&"5".to_string()));
     }
 
+    // ========================================================================
+    // Multiple values tests
+    // ========================================================================
+
+    fn tokens(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    /// Definition whose `output` argument is variadic and `count` repeatable
+    fn multiple_definition() -> CommandDefinition {
+        let mut definition = create_test_definition();
+        definition.arguments[1].multiple = true;
+        definition.options[1].multiple = true;
+        definition.options[1].default = None;
+        definition
+    }
+
+    #[test]
+    fn test_variadic_argument_takes_remaining_tokens() {
+        let definition = multiple_definition();
+        let parser = CliParser::new(&definition);
+
+        let result = parser
+            .parse(&tokens(&["in.txt", "a.txt", "-v", "b.txt", "c.txt"]))
+            .unwrap();
+
+        assert_eq!(result.get("input"), Some(&"in.txt".to_string()));
+        assert_eq!(
+            multiple_values::get_values(&result, "output"),
+            Some(vec!["a.txt", "b.txt", "c.txt"])
+        );
+        assert_eq!(result.get("verbose"), Some(&"true".to_string()));
+    }
+
+    #[test]
+    fn test_repeatable_option_collects_every_occurrence() {
+        let definition = multiple_definition();
+        let parser = CliParser::new(&definition);
+
+        let result = parser
+            .parse(&tokens(&["in.txt", "-c", "1", "--count=2", "-c3"]))
+            .unwrap();
+
+        assert_eq!(
+            multiple_values::get_values(&result, "count"),
+            Some(vec!["1", "2", "3"])
+        );
+    }
+
+    #[test]
+    fn test_multiple_values_are_type_checked_individually() {
+        let definition = multiple_definition();
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&tokens(&["in.txt", "-c", "1", "-c", "two"]));
+
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Parse(ParseError::TypeParseError { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_value_count_bounds() {
+        let mut definition = multiple_definition();
+        definition.arguments[1].min_values = Some(2);
+        definition.arguments[1].max_values = Some(3);
+        let parser = CliParser::new(&definition);
+
+        assert!(parser.parse(&tokens(&["in.txt", "a", "b"])).is_ok());
+        // An absent optional argument is not counted
+        assert!(parser.parse(&tokens(&["in.txt"])).is_ok());
+
+        for args in [&["in.txt", "a"][..], &["in.txt", "a", "b", "c", "d"][..]] {
+            match parser.parse(&tokens(args)).unwrap_err() {
+                DynamicCliError::Parse(ParseError::WrongValueCount {
+                    arg_name, expected, ..
+                }) => {
+                    assert_eq!(arg_name, "output");
+                    assert_eq!(expected, "between 2 and 3");
+                }
+                other => panic!("Expected WrongValueCount, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_repeatable_option_max_values() {
+        let mut definition = multiple_definition();
+        definition.options[1].max_values = Some(1);
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&tokens(&["in.txt", "-c", "1", "-c", "2"]));
+
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Parse(ParseError::WrongValueCount {
+                got: 2,
+                ..
+            }))
+        ));
+    }
+
+    #[test]
+    fn test_repeatable_option_choices_checked_per_value() {
+        let mut definition = multiple_definition();
+        definition.options.push(OptionDefinition {
+            name: "tag".to_string(),
+            short: Some("t".to_string()),
+            long: Some("tag".to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: None,
+            description: "Tag".to_string(),
+            choices: vec!["red".to_string(), "blue".to_string()],
+            multiple: true,
+            ..Default::default()
+        });
+        let parser = CliParser::new(&definition);
+
+        assert!(parser
+            .parse(&tokens(&["in.txt", "-t", "red", "-t", "blue"]))
+            .is_ok());
+        match parser
+            .parse(&tokens(&["in.txt", "-t", "red", "-t", "green"]))
+            .unwrap_err()
+        {
+            DynamicCliError::Validation(ValidationError::InvalidChoice { value, .. }) => {
+                assert_eq!(value, "green");
+            }
+            other => panic!("Expected InvalidChoice, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_non_variadic_last_argument_still_rejects_extra_tokens() {
+        let definition = create_test_definition();
+        let parser = CliParser::new(&definition);
+
+        assert!(matches!(
+            parser.parse(&tokens(&["a", "b", "c"])),
+            Err(DynamicCliError::Parse(ParseError::TooManyArguments { .. }))
+        ));
+    }
+
+    // ========================================================================
+    // Relation tests
+    // ========================================================================
+
+    /// Command with `--file`/`--url` (exclusive) and `--format` requiring `--output`
+    fn relations_definition() -> CommandDefinition {
+        let option = |name: &str, default: Option<&str>| OptionDefinition {
+            name: name.to_string(),
+            short: None,
+            long: Some(name.to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: default.map(str::to_string),
+            description: name.to_string(),
+            choices: vec![],
+            ..Default::default()
+        };
+        let mut definition = create_test_definition();
+        definition.arguments[0].required = false;
+        definition.options = vec![
+            option("file", None),
+            option("url", None),
+            option("format", Some("text")),
+            option("output", Some("out.txt")),
+            option("quiet", None),
+        ];
+        definition.groups = vec![crate::config::schema::ArgumentGroup {
+            name: "source".to_string(),
+            members: vec!["file".to_string(), "url".to_string()],
+            required: true,
+        }];
+        definition
+            .requires
+            .insert("format".to_string(), vec!["output".to_string()]);
+        definition
+            .conflicts_with
+            .insert("quiet".to_string(), vec!["input".to_string()]);
+        definition
+    }
+
+    #[test]
+    fn test_exclusive_group_accepts_one_member() {
+        let definition = relations_definition();
+        let parser = CliParser::new(&definition);
+
+        assert!(parser.parse(&tokens(&["--file", "a.csv"])).is_ok());
+        assert!(parser.parse(&tokens(&["--url", "http://x"])).is_ok());
+    }
+
+    #[test]
+    fn test_exclusive_group_rejects_two_members() {
+        let definition = relations_definition();
+        let parser = CliParser::new(&definition);
+
+        match parser
+            .parse(&tokens(&["--file", "a.csv", "--url", "http://x"]))
+            .unwrap_err()
+        {
+            DynamicCliError::Parse(ParseError::ConflictingArguments {
+                first,
+                second,
+                suggestion,
+            }) => {
+                assert_eq!(first, "--file");
+                assert_eq!(second, "--url");
+                assert!(suggestion.unwrap().contains("group 'source'"));
+            }
+            other => panic!("Expected ConflictingArguments, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_required_group_needs_a_member() {
+        let definition = relations_definition();
+        let parser = CliParser::new(&definition);
+
+        match parser.parse(&[]).unwrap_err() {
+            DynamicCliError::Parse(ParseError::MissingGroupMember { group, members, .. }) => {
+                assert_eq!(group, "source");
+                assert_eq!(members, vec!["--file", "--url"]);
+            }
+            other => panic!("Expected MissingGroupMember, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_requires_ignores_default_values() {
+        let definition = relations_definition();
+        let parser = CliParser::new(&definition);
+
+        // `--format` defaults to "text" without requiring `--output`
+        assert!(parser.parse(&tokens(&["--file", "a"])).is_ok());
+        assert!(parser
+            .parse(&tokens(&[
+                "--file", "a", "--format", "csv", "--output", "o.csv"
+            ]))
+            .is_ok());
+
+        match parser
+            .parse(&tokens(&["--file", "a", "--format", "csv"]))
+            .unwrap_err()
+        {
+            DynamicCliError::Parse(ParseError::MissingRequirement {
+                argument, required, ..
+            }) => {
+                assert_eq!(argument, "--format");
+                assert_eq!(required, "--output");
+            }
+            other => panic!("Expected MissingRequirement, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_conflicts_with_positional_argument() {
+        let definition = relations_definition();
+        let parser = CliParser::new(&definition);
+
+        assert!(parser
+            .parse(&tokens(&["--file", "a", "--quiet", "y"]))
+            .is_ok());
+        match parser
+            .parse(&tokens(&["in.txt", "--file", "a", "--quiet", "y"]))
+            .unwrap_err()
+        {
+            DynamicCliError::Parse(ParseError::ConflictingArguments { first, second, .. }) => {
+                assert_eq!(first, "--quiet");
+                assert_eq!(second, "<input>");
+            }
+            other => panic!("Expected ConflictingArguments, got {:?}", other),
+        }
+    }
+
+    // ========================================================================
+    // Environment variable tests
+    // ========================================================================
+
+    /// Definition whose `count` option falls back to the given variable
+    fn env_definition(var: &str) -> CommandDefinition {
+        let mut definition = create_test_definition();
+        definition.options[1].env = Some(var.to_string());
+        definition
+    }
+
+    #[test]
+    fn test_env_overrides_default() {
+        std::env::set_var("DYNCLI_TEST_ENV_OVER_DEFAULT", "42");
+        let definition = env_definition("DYNCLI_TEST_ENV_OVER_DEFAULT");
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&["input.txt".to_string()]).unwrap();
+
+        assert_eq!(result.get("count"), Some(&"42".to_string()));
+    }
+
+    #[test]
+    fn test_command_line_overrides_env() {
+        std::env::set_var("DYNCLI_TEST_CLI_OVER_ENV", "42");
+        let definition = env_definition("DYNCLI_TEST_CLI_OVER_ENV");
+        let parser = CliParser::new(&definition);
+
+        let args = vec![
+            "input.txt".to_string(),
+            "--count".to_string(),
+            "7".to_string(),
+        ];
+        let result = parser.parse(&args).unwrap();
+
+        assert_eq!(result.get("count"), Some(&"7".to_string()));
+    }
+
+    #[test]
+    fn test_unset_or_empty_env_falls_back_to_default() {
+        std::env::set_var("DYNCLI_TEST_EMPTY_ENV", "");
+        for var in ["DYNCLI_TEST_EMPTY_ENV", "DYNCLI_TEST_UNSET_ENV"] {
+            let definition = env_definition(var);
+            let parser = CliParser::new(&definition);
+
+            let result = parser.parse(&["input.txt".to_string()]).unwrap();
+
+            assert_eq!(result.get("count"), Some(&"10".to_string()));
+        }
+    }
+
+    #[test]
+    fn test_invalid_env_value_names_variable() {
+        std::env::set_var("DYNCLI_TEST_INVALID_ENV", "many");
+        let definition = env_definition("DYNCLI_TEST_INVALID_ENV");
+        let parser = CliParser::new(&definition);
+
+        let error = parser.parse(&["input.txt".to_string()]).unwrap_err();
+
+        match error {
+            DynamicCliError::Parse(ParseError::TypeParseError {
+                arg_name, value, ..
+            }) => {
+                assert!(arg_name.contains("'count'"));
+                assert!(arg_name.contains("DYNCLI_TEST_INVALID_ENV"));
+                assert_eq!(value, "many");
+            }
+            other => panic!("Expected TypeParseError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_env_satisfies_required_option() {
+        std::env::set_var("DYNCLI_TEST_REQUIRED_ENV", "3");
+        let mut definition = env_definition("DYNCLI_TEST_REQUIRED_ENV");
+        definition.options[1].required = true;
+        definition.options[1].default = None;
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&["input.txt".to_string()]).unwrap();
+
+        assert_eq!(result.get("count"), Some(&"3".to_string()));
+    }
+
+    #[test]
+    fn test_env_applies_to_global_options() {
+        std::env::set_var("DYNCLI_TEST_GLOBAL_ENV", "staging");
+        let definition = create_test_definition();
+        let globals = vec![OptionDefinition {
+            name: "profile".to_string(),
+            short: None,
+            long: Some("profile".to_string()),
+            option_type: ArgumentType::String,
+            required: false,
+            default: Some("default".to_string()),
+            description: "Profile".to_string(),
+            choices: vec![],
+            env: Some("DYNCLI_TEST_GLOBAL_ENV".to_string()),
+            ..Default::default()
+        }];
+        let parser = CliParser::new(&definition).with_global_options(&globals);
+
+        let result = parser.parse(&["input.txt".to_string()]).unwrap();
+
+        assert_eq!(result.get("global.profile"), Some(&"staging".to_string()));
+    }
+
+    #[test]
+    fn test_env_of_repeatable_option_is_split() {
+        std::env::set_var("DYNCLI_TEST_REPEATABLE_ENV", "1, 2,,3");
+        let mut definition = env_definition("DYNCLI_TEST_REPEATABLE_ENV");
+        definition.options[1].multiple = true;
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&tokens(&["input.txt"])).unwrap();
+
+        assert_eq!(
+            multiple_values::get_values(&result, "count"),
+            Some(vec!["1", "2", "3"])
+        );
+    }
+
+    #[test]
+    fn test_value_containing_list_separator_is_rejected() {
+        let mut definition = create_test_definition();
+        definition.options[1].option_type = ArgumentType::String;
+        definition.options[1].multiple = true;
+        let parser = CliParser::new(&definition);
+        let value = multiple_values::join_values(["a", "b"]);
+
+        assert!(matches!(
+            parser.parse(&tokens(&["input.txt", "--count", &value])),
+            Err(DynamicCliError::Parse(ParseError::InvalidSyntax { .. }))
+        ));
+    }
+
     // ========================================================================
     // Type conversion tests
     // ========================================================================
@@ -759,4 +1751,229 @@ mod tests {
         assert_eq!(result.get("verbose"), Some(&"true".to_string()));
         assert_eq!(result.get("count"), Some(&"50".to_string()));
     }
+
+    // ========================================================================
+    // Validation rule enforcement tests
+    // ========================================================================
+
+    /// Helper to create a definition with a single validated argument
+    fn create_validated_definition(
+        arg_type: ArgumentType,
+        validation: Vec<ValidationRule>,
+    ) -> CommandDefinition {
+        CommandDefinition {
+            name: "check".to_string(),
+            aliases: vec![],
+            description: "Validated command".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "value".to_string(),
+                arg_type,
+                required: true,
+                description: "Validated value".to_string(),
+                validation,
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![],
+            implementation: "handler".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_enforces_must_exist() {
+        let definition = create_validated_definition(
+            ArgumentType::Path,
+            vec![ValidationRule::MustExist { must_exist: true }],
+        );
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&["/nonexistent/input.dat".to_string()]);
+        assert!(matches!(
+            result,
+            Err(DynamicCliError::Validation(
+                ValidationError::FileNotFound { .. }
+            ))
+        ));
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let path = file.path().to_str().unwrap().to_string();
+        assert!(parser.parse(&[path]).is_ok());
+    }
+
+    #[test]
+    fn test_parse_enforces_extensions() {
+        let definition = create_validated_definition(
+            ArgumentType::Path,
+            vec![ValidationRule::Extensions {
+                extensions: vec!["csv".to_string()],
+            }],
+        );
+        let parser = CliParser::new(&definition);
+
+        assert!(parser.parse(&["data.csv".to_string()]).is_ok());
+        assert!(matches!(
+            parser.parse(&["data.txt".to_string()]),
+            Err(DynamicCliError::Validation(
+                ValidationError::InvalidExtension { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_enforces_range() {
+        let definition = create_validated_definition(
+            ArgumentType::Integer,
+            vec![ValidationRule::Range {
+                min: Some(1.0),
+                max: Some(10.0),
+            }],
+        );
+        let parser = CliParser::new(&definition);
+
+        assert!(parser.parse(&["5".to_string()]).is_ok());
+        assert!(matches!(
+            parser.parse(&["42".to_string()]),
+            Err(DynamicCliError::Validation(
+                ValidationError::OutOfRange { .. }
+            ))
+        ));
+        assert!(matches!(
+            parser.parse(&["-3".to_string()]),
+            Err(DynamicCliError::Validation(
+                ValidationError::OutOfRange { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_parse_rejects_value_outside_choices() {
+        let definition = CommandDefinition {
+            name: "add".to_string(),
+            aliases: vec![],
+            description: "Add a task".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![OptionDefinition {
+                name: "priority".to_string(),
+                short: Some("p".to_string()),
+                long: Some("priority".to_string()),
+                option_type: ArgumentType::String,
+                required: false,
+                default: Some("medium".to_string()),
+                description: "Task priority".to_string(),
+                choices: vec!["low".to_string(), "medium".to_string(), "high".to_string()],
+                ..Default::default()
+            }],
+            implementation: "handler".to_string(),
+            ..Default::default()
+        };
+        let parser = CliParser::new(&definition);
+
+        let result = parser.parse(&["--priority".to_string(), "high".to_string()]);
+        assert_eq!(result.unwrap().get("priority"), Some(&"high".to_string()));
+
+        let result = parser.parse(&["-p".to_string(), "hihg".to_string()]);
+        match result.unwrap_err() {
+            DynamicCliError::Validation(ValidationError::InvalidChoice {
+                arg_name,
+                value,
+                suggestions,
+                ..
+            }) => {
+                assert_eq!(arg_name, "priority");
+                assert_eq!(value, "hihg");
+                assert!(suggestions.contains(&"high".to_string()));
+            }
+            other => panic!("Expected InvalidChoice, got {:?}", other),
+        }
+    }
+
+    fn enum_definition() -> CommandDefinition {
+        let variant = |name: &str, aliases: &[&str]| EnumVariant {
+            name: name.to_string(),
+            description: String::new(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        };
+        CommandDefinition {
+            name: "build".to_string(),
+            aliases: vec![],
+            description: "Build".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "profile".to_string(),
+                arg_type: ArgumentType::Enum,
+                required: false,
+                description: "Build profile".to_string(),
+                validation: vec![],
+                secure: false,
+                variants: vec![variant("debug", &["dev"]), variant("release", &["prod"])],
+                ..Default::default()
+            }],
+            options: vec![OptionDefinition {
+                name: "target".to_string(),
+                short: Some("t".to_string()),
+                long: Some("target".to_string()),
+                option_type: ArgumentType::Enum,
+                required: false,
+                default: Some("x86".to_string()),
+                description: "Target".to_string(),
+                choices: vec![],
+                multiple: true,
+                variants: vec![
+                    variant("x86_64", &["x86", "amd64"]),
+                    variant("aarch64", &["arm64"]),
+                ],
+                ..Default::default()
+            }],
+            implementation: "handler".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_enum_aliases_are_normalized() {
+        let definition = enum_definition();
+        let parser = CliParser::new(&definition);
+
+        let result = parser
+            .parse(&[
+                "prod".to_string(),
+                "-t".to_string(),
+                "arm64".to_string(),
+                "--target=x86_64".to_string(),
+            ])
+            .unwrap();
+        assert_eq!(result.get("profile"), Some(&"release".to_string()));
+        assert_eq!(
+            multiple_values::get_values(&result, "target"),
+            Some(vec!["aarch64", "x86_64"])
+        );
+
+        // Defaults given by alias are normalized too
+        let result = parser.parse(&["debug".to_string()]).unwrap();
+        assert_eq!(result.get("profile"), Some(&"debug".to_string()));
+        assert_eq!(result.get("target"), Some(&"x86_64".to_string()));
+    }
+
+    #[test]
+    fn test_enum_rejects_unknown_variant() {
+        let definition = enum_definition();
+        let parser = CliParser::new(&definition);
+
+        match parser.parse(&["relase".to_string()]).unwrap_err() {
+            DynamicCliError::Validation(ValidationError::InvalidChoice {
+                arg_name,
+                choices,
+                suggestions,
+                ..
+            }) => {
+                assert_eq!(arg_name, "profile");
+                assert_eq!(choices, vec!["debug", "release"]);
+                assert_eq!(suggestions, vec!["release"]);
+            }
+            other => panic!("Expected InvalidChoice, got {:?}", other),
+        }
+    }
 }
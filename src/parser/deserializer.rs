@@ -0,0 +1,396 @@
+//! Serde deserializer over parsed arguments
+//!
+//! Backs [`ParsedArgs::deserialize`]: the arguments are presented to serde
+//! as a map from argument name to [`Value`], so any
+//! `#[derive(Deserialize)]` struct whose fields are named after the
+//! command's arguments and options can be filled directly.
+//!
+//! Values are converted leniently, like the [`Value`] accessors: a string
+//! value is parsed when the struct field asks for a number or a boolean,
+//! and any scalar is accepted by a `String` field. Unit enum variants are
+//! matched against the value's text.
+//!
+//! Errors remember the argument they occurred in, so they can be reported
+//! as a [`ValidationError::InvalidField`] naming the field.
+
+use crate::error::{DynamicCliError, ValidationError};
+use crate::parser::parsed_args::{ParsedArgs, Value};
+use crate::parser::type_parser;
+use serde::de::value::SeqDeserializer;
+use serde::de::{
+    self, DeserializeSeed, IntoDeserializer, MapAccess, SeqAccess, Unexpected, Visitor,
+};
+use serde::forward_to_deserialize_any;
+use std::collections::hash_map;
+use std::fmt;
+use std::time::UNIX_EPOCH;
+
+// ═══════════════════════════════════════════════════════════
+// ERROR
+// ═══════════════════════════════════════════════════════════
+
+/// Error raised while deserializing arguments
+#[derive(Debug)]
+pub(crate) struct DeserializeError {
+    /// Argument (struct field) the error occurred in, if known
+    field: Option<String>,
+    /// Description of the problem
+    message: String,
+    /// Whether the field had no value at all
+    missing: bool,
+}
+
+impl DeserializeError {
+    /// Attach the name of the argument being deserialized
+    fn in_field(mut self, field: &str) -> Self {
+        if self.field.is_none() {
+            self.field = Some(field.to_string());
+        }
+        self
+    }
+
+    /// Convert into a validation error naming the field
+    ///
+    /// `type_name` is used as the field name for errors that are not tied
+    /// to a single argument (e.g. the target is not a struct).
+    pub(crate) fn into_validation_error(self, type_name: &str) -> DynamicCliError {
+        let field = self.field.unwrap_or_else(|| type_name.to_string());
+        let (reason, suggestion) = if self.missing {
+            (
+                "no value was provided".to_string(),
+                format!(
+                    "Declare '{}' as a required argument or give it a default, \
+                     or make the field optional (Option<T> or #[serde(default)]).",
+                    field
+                ),
+            )
+        } else {
+            (
+                self.message,
+                format!(
+                    "Check that the type declared for '{}' in the configuration \
+                     matches the struct field.",
+                    field
+                ),
+            )
+        };
+
+        ValidationError::InvalidField {
+            field,
+            reason,
+            suggestion: Some(suggestion),
+        }
+        .into()
+    }
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.field {
+            Some(field) => write!(f, "{}: {}", field, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for DeserializeError {}
+
+impl de::Error for DeserializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self {
+            field: None,
+            message: msg.to_string(),
+            missing: false,
+        }
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            message: "missing field".to_string(),
+            missing: true,
+        }
+    }
+
+    fn unknown_field(field: &str, expected: &'static [&'static str]) -> Self {
+        Self {
+            field: Some(field.to_string()),
+            message: format!(
+                "unexpected argument, the struct only accepts: {}",
+                expected.join(", ")
+            ),
+            missing: false,
+        }
+    }
+}
+
+type Result<T> = std::result::Result<T, DeserializeError>;
+
+// ═══════════════════════════════════════════════════════════
+// ARGUMENT MAP
+// ═══════════════════════════════════════════════════════════
+
+/// Deserializer presenting all arguments as a map
+pub(crate) struct ArgsDeserializer<'a> {
+    args: &'a ParsedArgs,
+}
+
+impl<'a> ArgsDeserializer<'a> {
+    pub(crate) fn new(args: &'a ParsedArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ArgsDeserializer<'_> {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_map(ArgsMapAccess {
+            entries: self.args.values_iter(),
+            current: None,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Map access over the arguments, remembering the current key
+struct ArgsMapAccess<'a> {
+    entries: hash_map::Iter<'a, String, Value>,
+    current: Option<(&'a String, &'a Value)>,
+}
+
+impl<'de> MapAccess<'de> for ArgsMapAccess<'_> {
+    type Error = DeserializeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>> {
+        match self.entries.next() {
+            Some((name, value)) => {
+                self.current = Some((name, value));
+                seed.deserialize(name.as_str().into_deserializer())
+                    .map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value> {
+        let (name, value) = self
+            .current
+            .take()
+            .ok_or_else(|| de::Error::custom("value requested before its key"))?;
+        seed.deserialize(ValueDeserializer(value))
+            .map_err(|e| e.in_field(name))
+    }
+}
+
+// ═══════════════════════════════════════════════════════════
+// SINGLE VALUE
+// ═══════════════════════════════════════════════════════════
+
+/// Deserializer for one [`Value`]
+struct ValueDeserializer<'a>(&'a Value);
+
+impl ValueDeserializer<'_> {
+    /// Text form of a scalar value
+    fn text(&self) -> Option<String> {
+        match self.0 {
+            Value::String(s) => Some(s.clone()),
+            Value::Integer(i) => Some(i.to_string()),
+            Value::Float(f) => Some(f.to_string()),
+            Value::Bool(b) => Some(b.to_string()),
+            Value::Path(p) => Some(p.to_string_lossy().into_owned()),
+            Value::Duration(d) => Some(format!("{}s", d.as_secs_f64())),
+            Value::Bytes(b) => Some(b.to_string()),
+            Value::Url(s) | Value::Regex(s) => Some(s.clone()),
+            Value::Ip(ip) => Some(ip.to_string()),
+            Value::DateTime(t) => Some(type_parser::format_datetime(*t)),
+            Value::List(_) => None,
+        }
+    }
+
+    /// Describe the value for "invalid type" errors
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self.0 {
+            Value::String(s) => Unexpected::Str(s),
+            Value::Integer(i) => Unexpected::Signed(*i),
+            Value::Float(f) => Unexpected::Float(*f),
+            Value::Bool(b) => Unexpected::Bool(*b),
+            Value::Path(_) => Unexpected::Other("path"),
+            Value::Duration(_) => Unexpected::Other("duration"),
+            Value::Bytes(b) => Unexpected::Unsigned(*b),
+            Value::Url(s) | Value::Regex(s) => Unexpected::Str(s),
+            Value::Ip(_) => Unexpected::Other("IP address"),
+            Value::DateTime(_) => Unexpected::Other("date/time"),
+            Value::List(_) => Unexpected::Seq,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'_> {
+    type Error = DeserializeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0 {
+            Value::String(s) => visitor.visit_str(s),
+            Value::Integer(i) => visitor.visit_i64(*i),
+            Value::Float(f) => visitor.visit_f64(*f),
+            Value::Bool(b) => visitor.visit_bool(*b),
+            Value::Path(p) => visitor.visit_str(&p.to_string_lossy()),
+            // Seconds and nanoseconds, the form serde uses for
+            // `std::time::Duration` and `SystemTime`
+            Value::Duration(d) => visitor.visit_seq(SeqDeserializer::new(
+                [d.as_secs(), u64::from(d.subsec_nanos())].into_iter(),
+            )),
+            Value::DateTime(t) => match t.duration_since(UNIX_EPOCH) {
+                Ok(d) => visitor.visit_seq(SeqDeserializer::new(
+                    [d.as_secs(), u64::from(d.subsec_nanos())].into_iter(),
+                )),
+                Err(_) => visitor.visit_string(type_parser::format_datetime(*t)),
+            },
+            Value::Bytes(b) => visitor.visit_u64(*b),
+            Value::Url(s) | Value::Regex(s) => visitor.visit_str(s),
+            Value::Ip(ip) => visitor.visit_string(ip.to_string()),
+            Value::List(values) => visitor.visit_seq(ListAccess {
+                values: values.iter(),
+            }),
+        }
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0.as_bool() {
+            Some(b) => visitor.visit_bool(b),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_i64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        if let Value::Bytes(b) = self.0 {
+            return visitor.visit_u64(*b);
+        }
+        match self.0.as_i64() {
+            Some(i) => visitor.visit_i64(i),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.0.as_f64() {
+            Some(f) => visitor.visit_f64(f),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        match self.text() {
+            Some(text) => visitor.visit_string(text),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        visitor.visit_seq(ListAccess {
+            values: self.0.as_list().iter(),
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value> {
+        match self.text() {
+            Some(text) => visitor.visit_enum(text.into_deserializer()),
+            None => Err(de::Error::invalid_type(self.unexpected(), &visitor)),
+        }
+    }
+
+    fn deserialize_i8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_i32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u8<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u16<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_i64(visitor)
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_char<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value> {
+        self.deserialize_string(visitor)
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value> {
+        self.deserialize_seq(visitor)
+    }
+
+    forward_to_deserialize_any! {
+        i128 u128 bytes byte_buf unit unit_struct tuple_struct map struct ignored_any
+    }
+}
+
+/// Sequence access over the values of a list argument
+struct ListAccess<'a> {
+    values: std::slice::Iter<'a, Value>,
+}
+
+impl<'de> SeqAccess<'de> for ListAccess<'_> {
+    type Error = DeserializeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>> {
+        match self.values.next() {
+            Some(value) => seed.deserialize(ValueDeserializer(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.values.len())
+    }
+}
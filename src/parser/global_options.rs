@@ -0,0 +1,305 @@
+//! Global option support
+//!
+//! Global options are declared once under `global_options` in the
+//! configuration and are accepted by every command, either before the
+//! command name (`myapp --verbose run`) or after it (`myapp run --verbose`).
+//!
+//! # Delivery to Handlers
+//!
+//! Parsed global options are delivered in the same argument map as the
+//! command's own values, but under a reserved namespace: the option
+//! `verbose` is stored under the key `global.verbose`. Command-local
+//! argument and option names may not start with [`GLOBAL_OPTION_PREFIX`]
+//! (this is enforced by [`validate_config`]), so the two can never collide.
+//!
+//! Handlers should not build keys by hand; use [`get_global_option`] or
+//! [`global_options`] instead:
+//!
+//! ```
+//! use dynamic_cli::parser::global_options::{get_global_option, global_option_key};
+//! use std::collections::HashMap;
+//!
+//! let mut args = HashMap::new();
+//! args.insert("verbose".to_string(), "false".to_string()); // command-local
+//! args.insert(global_option_key("verbose"), "true".to_string()); // global
+//!
+//! assert_eq!(args.get("verbose"), Some(&"false".to_string()));
+//! assert_eq!(get_global_option(&args, "verbose"), Some(&"true".to_string()));
+//! ```
+//!
+//! [`validate_config`]: crate::config::validator::validate_config
+
+use crate::config::schema::{ArgumentType, OptionDefinition};
+use crate::error::{ParseError, Result};
+use std::collections::HashMap;
+
+/// Key prefix reserved for global options in parsed argument maps
+pub const GLOBAL_OPTION_PREFIX: &str = "global.";
+
+/// Build the argument-map key under which a global option is stored
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::global_options::global_option_key;
+///
+/// assert_eq!(global_option_key("verbose"), "global.verbose");
+/// ```
+pub fn global_option_key(name: &str) -> String {
+    format!("{}{}", GLOBAL_OPTION_PREFIX, name)
+}
+
+/// Get the value of a global option from a parsed argument map
+///
+/// # Arguments
+///
+/// * `args` - Parsed arguments as received by a handler
+/// * `name` - Name of the global option (without prefix)
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::global_options::get_global_option;
+/// use std::collections::HashMap;
+///
+/// let mut args = HashMap::new();
+/// args.insert("global.config".to_string(), "app.yaml".to_string());
+///
+/// assert_eq!(get_global_option(&args, "config"), Some(&"app.yaml".to_string()));
+/// assert_eq!(get_global_option(&args, "verbose"), None);
+/// ```
+pub fn get_global_option<'m>(args: &'m HashMap<String, String>, name: &str) -> Option<&'m String> {
+    args.get(&global_option_key(name))
+}
+
+/// Extract all global options from a parsed argument map
+///
+/// Returns a new map keyed by the global option names (prefix removed).
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::global_options::global_options;
+/// use std::collections::HashMap;
+///
+/// let mut args = HashMap::new();
+/// args.insert("input".to_string(), "data.csv".to_string());
+/// args.insert("global.verbose".to_string(), "true".to_string());
+///
+/// let globals = global_options(&args);
+/// assert_eq!(globals.len(), 1);
+/// assert_eq!(globals.get("verbose"), Some(&"true".to_string()));
+/// ```
+pub fn global_options(args: &HashMap<String, String>) -> HashMap<String, String> {
+    args.iter()
+        .filter_map(|(key, value)| {
+            key.strip_prefix(GLOBAL_OPTION_PREFIX)
+                .map(|name| (name.to_string(), value.clone()))
+        })
+        .collect()
+}
+
+/// Find the position of the command name in a token list
+///
+/// Skips the global options that precede the command name, consuming
+/// the value token of non-boolean options. Values are not type-checked
+/// here: the skipped tokens are handed to
+/// [`CliParser::parse_after_globals`] afterwards.
+///
+/// Returns `args.len()` when the tokens contain only global options.
+///
+/// # Errors
+///
+/// - [`ParseError::UnknownOption`] if a leading flag is not a global option
+/// - [`ParseError::InvalidSyntax`] if the last token is a global option
+///   that requires a value
+///
+/// [`CliParser::parse_after_globals`]: crate::parser::cli_parser::CliParser::parse_after_globals
+pub(crate) fn command_position(
+    global_options: &[OptionDefinition],
+    args: &[String],
+) -> Result<usize> {
+    let mut index = 0;
+
+    while index < args.len() {
+        let arg = &args[index];
+        if !arg.starts_with('-') || arg.len() == 1 {
+            break;
+        }
+
+        let (option, value_attached) = if let Some(long) = arg.strip_prefix("--") {
+            let name = long.split('=').next().unwrap_or(long);
+            let option = global_options
+                .iter()
+                .find(|opt| opt.long.as_deref() == Some(name));
+            (option, long.contains('='))
+        } else {
+            let rest = &arg[1..];
+            let (short, attached) = rest.split_at(rest.chars().next().map_or(0, char::len_utf8));
+            let option = global_options
+                .iter()
+                .find(|opt| opt.short.as_deref() == Some(short));
+            (option, !attached.is_empty())
+        };
+
+        let option = option.ok_or_else(|| {
+            let available: Vec<String> = global_options
+                .iter()
+                .flat_map(|opt| {
+                    opt.long
+                        .iter()
+                        .map(|l| format!("--{}", l))
+                        .chain(opt.short.iter().map(|s| format!("-{}", s)))
+                })
+                .collect();
+            let flag = arg.split('=').next().unwrap_or(arg);
+            ParseError::unknown_option_with_suggestions(flag, "global options", &available)
+        })?;
+
+        if option.option_type == ArgumentType::Bool || value_attached {
+            index += 1;
+            continue;
+        }
+        if index + 1 >= args.len() {
+            let usage = if arg.starts_with("--") {
+                format!("{arg}=<value> or {arg} <value>")
+            } else {
+                format!("{arg}<value> or {arg} <value>")
+            };
+            return Err(ParseError::InvalidSyntax {
+                details: format!("Option {} requires a value", arg),
+                hint: Some(format!("Usage: {}", usage)),
+            }
+            .into());
+        }
+        index += 2;
+    }
+
+    Ok(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_global_options() -> Vec<OptionDefinition> {
+        vec![
+            OptionDefinition {
+                name: "verbose".to_string(),
+                short: Some("v".to_string()),
+                long: Some("verbose".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Verbose output".to_string(),
+                choices: vec![],
+                ..Default::default()
+            },
+            OptionDefinition {
+                name: "config".to_string(),
+                short: Some("c".to_string()),
+                long: Some("config".to_string()),
+                option_type: ArgumentType::Path,
+                required: false,
+                default: None,
+                description: "Configuration file".to_string(),
+                choices: vec![],
+                ..Default::default()
+            },
+        ]
+    }
+
+    fn tokens(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_global_option_key_roundtrip() {
+        let mut args = HashMap::new();
+        args.insert(global_option_key("verbose"), "true".to_string());
+        args.insert("count".to_string(), "3".to_string());
+
+        assert_eq!(
+            get_global_option(&args, "verbose"),
+            Some(&"true".to_string())
+        );
+        assert_eq!(get_global_option(&args, "count"), None);
+        assert_eq!(global_options(&args).len(), 1);
+    }
+
+    #[test]
+    fn test_command_position_without_globals() {
+        let globals = create_global_options();
+        assert_eq!(
+            command_position(&globals, &tokens(&["run", "-v"])).unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_command_position_skips_leading_globals() {
+        let globals = create_global_options();
+
+        let args = tokens(&["-v", "--config", "app.yaml", "run"]);
+        assert_eq!(command_position(&globals, &args).unwrap(), 3);
+
+        let args = tokens(&["--config=app.yaml", "-capp.yaml", "--verbose", "run"]);
+        assert_eq!(command_position(&globals, &args).unwrap(), 3);
+    }
+
+    #[test]
+    fn test_command_position_only_globals() {
+        let globals = create_global_options();
+        let args = tokens(&["-v"]);
+        assert_eq!(command_position(&globals, &args).unwrap(), 1);
+    }
+
+    #[test]
+    fn test_command_position_missing_global_value() {
+        let globals = create_global_options();
+
+        for (arg, hint) in [
+            ("--config", "Usage: --config=<value> or --config <value>"),
+            ("-c", "Usage: -c<value> or -c <value>"),
+        ] {
+            match command_position(&globals, &tokens(&["-v", arg])).unwrap_err() {
+                crate::error::DynamicCliError::Parse(ParseError::InvalidSyntax {
+                    details,
+                    hint: Some(usage),
+                }) => {
+                    assert_eq!(details, format!("Option {} requires a value", arg));
+                    assert_eq!(usage, hint);
+                }
+                other => panic!("Expected InvalidSyntax, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_command_position_unknown_leading_option() {
+        let globals = create_global_options();
+        let result = command_position(&globals, &tokens(&["--verbos", "run"]));
+
+        match result.unwrap_err() {
+            crate::error::DynamicCliError::Parse(ParseError::UnknownOption {
+                suggestions, ..
+            }) => {
+                assert!(suggestions.contains(&"--verbose".to_string()));
+            }
+            other => panic!("Expected UnknownOption, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_command_position_non_ascii_short_flag() {
+        let globals = create_global_options();
+        let result = command_position(&globals, &tokens(&["-é", "run"]));
+
+        match result.unwrap_err() {
+            crate::error::DynamicCliError::Parse(ParseError::UnknownOption { flag, .. }) => {
+                assert_eq!(flag, "-é");
+            }
+            other => panic!("Expected UnknownOption, got {:?}", other),
+        }
+    }
+}
@@ -5,11 +5,14 @@
 //!
 //! # Module Structure
 //!
-//! The parser module consists of three main components:
+//! The parser module consists of six main components:
 //!
 //! - [`type_parser`]: Type conversion functions (string → typed values)
 //! - [`cli_parser`]: CLI argument parser (Unix-style options)
 //! - [`repl_parser`]: REPL line parser (interactive mode)
+//! - [`global_options`]: Delivery of options shared by all commands
+//! - [`multiple_values`]: Variadic arguments and repeatable options
+//! - [`parsed_args`]: Typed view of parsed values for handlers
 //!
 //! # Architecture
 //!
@@ -48,6 +51,13 @@
 //! │   HashMap<String, String>               │
 //! │   {"input": "file.txt",                 │
 //! │    "verbose": "true"}                   │
+//! └──────────────┬──────────────────────────┘
+//!                │
+//!                ▼
+//! ┌─────────────────────────────────────────┐
+//! │   ParsedArgs (typed values)             │
+//! │   {"input": Path("file.txt"),           │
+//! │    "verbose": Bool(true)}               │
 //! └─────────────────────────────────────────┘
 //! ```
 //!
@@ -95,10 +105,12 @@
 //!             description: "Input file".to_string(),
 //!             validation: vec![],
 //!             secure: false,
+//!             ..Default::default()
 //!         }
 //!     ],
 //!     options: vec![],
 //!     implementation: "handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! let parser = CliParser::new(&definition);
@@ -190,11 +202,18 @@ use crate::error::Result;
 
 // Public submodules
 pub mod cli_parser;
+mod deserializer;
+pub mod global_options;
+pub mod multiple_values;
+pub mod parsed_args;
 pub mod repl_parser;
 pub mod type_parser;
 
 // Re-export commonly used types
 pub use cli_parser::CliParser;
+pub use global_options::{get_global_option, global_option_key, GLOBAL_OPTION_PREFIX};
+pub use multiple_values::{get_values, LIST_SEPARATOR};
+pub use parsed_args::{ParsedArgs, Value};
 pub use repl_parser::{ParsedCommand, ReplParser};
 
 #[cfg(test)]
@@ -236,6 +255,7 @@ mod tests {
                     description: "Input data file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
                 ArgumentDefinition {
                     name: "output".to_string(),
@@ -244,6 +264,7 @@ mod tests {
                     description: "Output report file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
             ],
             options: vec![
@@ -256,6 +277,7 @@ mod tests {
                     default: Some("false".to_string()),
                     description: "Enable verbose output".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
                 OptionDefinition {
                     name: "iterations".to_string(),
@@ -266,6 +288,7 @@ mod tests {
                     default: Some("100".to_string()),
                     description: "Number of iterations".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
                 OptionDefinition {
                     name: "threshold".to_string(),
@@ -276,9 +299,11 @@ mod tests {
                     default: Some("0.5".to_string()),
                     description: "Analysis threshold".to_string(),
                     choices: vec![],
+                    ..Default::default()
                 },
             ],
             implementation: "analyze_handler".to_string(),
+            ..Default::default()
         }
     }
 
@@ -0,0 +1,154 @@
+//! Multiple-value support
+//!
+//! A variadic argument (`multiple: true` on the last argument) takes all
+//! remaining positional tokens, and a repeatable option (`multiple: true`
+//! on an option) collects the value of every occurrence (`-I a -I b`).
+//!
+//! # Delivery to Handlers
+//!
+//! [`ParsedArgs`](crate::parser::ParsedArgs) delivers these values as a
+//! [`Value::List`](crate::parser::Value::List), each element converted to
+//! the declared type. In the string argument map, the values are joined
+//! with [`LIST_SEPARATOR`], a control character the parsers reject inside
+//! values; map-based handlers should read them with
+//! [`get_values`] rather than splitting by hand:
+//!
+//! ```
+//! use dynamic_cli::parser::multiple_values::{get_values, join_values};
+//! use std::collections::HashMap;
+//!
+//! let mut args = HashMap::new();
+//! args.insert("files".to_string(), join_values(["a.txt", "b.txt"]));
+//!
+//! assert_eq!(get_values(&args, "files"), Some(vec!["a.txt", "b.txt"]));
+//! assert_eq!(get_values(&args, "missing"), None);
+//! ```
+//!
+//! # Environment Variables
+//!
+//! The environment variable of a repeatable option (`env:`) holds its
+//! values separated by commas: `MYAPP_INCLUDE=src,vendor` gives the same
+//! values as `-I src -I vendor` (see [`split_env_values`]).
+
+use crate::error::{ParseError, Result};
+use std::collections::HashMap;
+
+/// Separator between the values of a list in parsed argument maps
+pub const LIST_SEPARATOR: char = '\u{1f}';
+
+/// Join values into the string form used in parsed argument maps
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::multiple_values::{join_values, split_values};
+///
+/// let joined = join_values(["x", "y"]);
+/// assert_eq!(split_values(&joined).collect::<Vec<_>>(), vec!["x", "y"]);
+/// ```
+pub fn join_values<I, S>(values: I) -> String
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut joined = String::new();
+    for (i, value) in values.into_iter().enumerate() {
+        if i > 0 {
+            joined.push(LIST_SEPARATOR);
+        }
+        joined.push_str(value.as_ref());
+    }
+    joined
+}
+
+/// Split the string form of a list into its values
+///
+/// A value without separator is a one-element list.
+pub fn split_values(raw: &str) -> std::str::Split<'_, char> {
+    raw.split(LIST_SEPARATOR)
+}
+
+/// Get the values of a variadic argument or repeatable option
+///
+/// # Arguments
+///
+/// * `args` - Parsed arguments as received by a handler
+/// * `name` - Name of the argument or option
+pub fn get_values<'m>(args: &'m HashMap<String, String>, name: &str) -> Option<Vec<&'m str>> {
+    args.get(name).map(|raw| split_values(raw).collect())
+}
+
+/// Split the environment variable value of a repeatable option
+///
+/// The values are separated by commas; whitespace around each value is
+/// trimmed and empty values are skipped.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::multiple_values::split_env_values;
+///
+/// let values: Vec<&str> = split_env_values("src, vendor/include,").collect();
+/// assert_eq!(values, vec!["src", "vendor/include"]);
+/// ```
+pub fn split_env_values(raw: &str) -> impl Iterator<Item = &str> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// Append a value to the list stored under `key`
+///
+/// Fails if the value contains [`LIST_SEPARATOR`], which would split it
+/// in two.
+pub(crate) fn push_value(
+    result: &mut HashMap<String, String>,
+    key: String,
+    value: String,
+) -> Result<()> {
+    if value.contains(LIST_SEPARATOR) {
+        return Err(ParseError::InvalidSyntax {
+            details: format!("value of '{}' contains a control character", key),
+            hint: None,
+        }
+        .into());
+    }
+
+    result
+        .entry(key)
+        .and_modify(|existing| {
+            existing.push(LIST_SEPARATOR);
+            existing.push_str(&value);
+        })
+        .or_insert(value);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_value_appends() {
+        let mut result = HashMap::new();
+        push_value(&mut result, "tags".to_string(), "a".to_string()).unwrap();
+        push_value(&mut result, "tags".to_string(), "b,c".to_string()).unwrap();
+
+        assert_eq!(get_values(&result, "tags"), Some(vec!["a", "b,c"]));
+    }
+
+    #[test]
+    fn test_push_value_rejects_separator() {
+        let mut result = HashMap::new();
+        let value = join_values(["a", "b"]);
+
+        assert!(push_value(&mut result, "tags".to_string(), value).is_err());
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    fn test_single_value_is_one_element_list() {
+        assert_eq!(split_values("only").collect::<Vec<_>>(), vec!["only"]);
+        assert_eq!(join_values(Vec::<String>::new()), "");
+    }
+}
@@ -0,0 +1,789 @@
+//! Typed argument values
+//!
+//! The parsers validate every value against its declared [`ArgumentType`]
+//! and store the result as a string. [`ParsedArgs`] keeps those strings
+//! (so map-based handlers keep working) and adds the converted, typed
+//! [`Value`]s, so handlers do not have to parse integers, floats, booleans
+//! and paths a second time.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::parser::parsed_args::ParsedArgs;
+//! use dynamic_cli::config::schema::{ArgumentDefinition, ArgumentType, CommandDefinition};
+//! use std::collections::HashMap;
+//!
+//! let definition = CommandDefinition {
+//!     name: "repeat".to_string(),
+//!     aliases: vec![],
+//!     description: "Repeat a message".to_string(),
+//!     required: false,
+//!     arguments: vec![ArgumentDefinition {
+//!         name: "times".to_string(),
+//!         arg_type: ArgumentType::Integer,
+//!         required: true,
+//!         description: "Repetitions".to_string(),
+//!         validation: vec![],
+//!         secure: false,
+//!         ..Default::default()
+//!     }],
+//!     options: vec![],
+//!     implementation: "repeat_handler".to_string(),
+//!     ..Default::default()
+//! };
+//!
+//! let mut raw = HashMap::new();
+//! raw.insert("times".to_string(), "3".to_string());
+//!
+//! let args = ParsedArgs::new(&definition, &[], raw)?;
+//! assert_eq!(args.get_i64("times"), Some(3));
+//! assert_eq!(args.as_map().get("times"), Some(&"3".to_string()));
+//! # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+//! ```
+
+use crate::config::schema::{ArgumentType, CommandDefinition, OptionDefinition};
+use crate::error::Result;
+use crate::parser::deserializer::ArgsDeserializer;
+use crate::parser::global_options::global_option_key;
+use crate::parser::multiple_values;
+use crate::parser::type_parser;
+use serde::de::DeserializeOwned;
+use std::collections::{hash_map, HashMap};
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// A typed argument or option value
+///
+/// Each variant corresponds to an [`ArgumentType`]; `List` holds the
+/// values of arguments that accept several values.
+///
+/// The accessors are lenient with `String` values: `as_i64` on
+/// `Value::String("42")` parses the string with the same rules as the
+/// parser. This keeps [`ParsedArgs`] built from a plain string map
+/// usable with the typed accessors.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::parsed_args::Value;
+///
+/// assert_eq!(Value::Integer(3).as_f64(), Some(3.0));
+/// assert_eq!(Value::String("yes".to_string()).as_bool(), Some(true));
+/// assert_eq!(Value::Bool(true).as_i64(), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Text value (also used for values of unknown type)
+    String(String),
+    /// Signed 64-bit integer
+    Integer(i64),
+    /// 64-bit floating point number
+    Float(f64),
+    /// Boolean flag or value
+    Bool(bool),
+    /// File system path
+    Path(PathBuf),
+    /// Length of time
+    Duration(Duration),
+    /// Size in bytes
+    Bytes(u64),
+    /// URL (checked for shape, kept as written)
+    Url(String),
+    /// IPv4 or IPv6 address
+    Ip(IpAddr),
+    /// Point in time
+    DateTime(SystemTime),
+    /// Regular expression (checked for syntax, kept as written)
+    Regex(String),
+    /// Several values for the same argument
+    List(Vec<Value>),
+}
+
+impl Value {
+    /// Get the value as a string slice
+    ///
+    /// Only `String`, `Url`, `Regex` and `Path` values (with valid UTF-8)
+    /// have a string form.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) | Value::Url(s) | Value::Regex(s) => Some(s),
+            Value::Path(p) => p.to_str(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as an integer
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Integer(i) => Some(*i),
+            Value::String(s) => type_parser::parse_integer(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a float (integers are converted)
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Float(f) => Some(*f),
+            Value::Integer(i) => Some(*i as f64),
+            Value::String(s) => type_parser::parse_float(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a boolean
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            Value::String(s) => type_parser::parse_bool(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a path
+    pub fn as_path(&self) -> Option<&Path> {
+        match self {
+            Value::Path(p) => Some(p),
+            Value::String(s) => Some(Path::new(s)),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a duration
+    pub fn as_duration(&self) -> Option<Duration> {
+        match self {
+            Value::Duration(d) => Some(*d),
+            Value::String(s) => type_parser::parse_duration(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a size in bytes
+    pub fn as_bytes(&self) -> Option<u64> {
+        match self {
+            Value::Bytes(b) => Some(*b),
+            Value::String(s) => type_parser::parse_bytes(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as an IP address
+    pub fn as_ip(&self) -> Option<IpAddr> {
+        match self {
+            Value::Ip(ip) => Some(*ip),
+            Value::String(s) => type_parser::parse_ip(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a point in time
+    pub fn as_datetime(&self) -> Option<SystemTime> {
+        match self {
+            Value::DateTime(t) => Some(*t),
+            Value::String(s) => type_parser::parse_datetime(s).ok(),
+            _ => None,
+        }
+    }
+
+    /// Get the value as a list
+    ///
+    /// A single value is returned as a one-element list.
+    pub fn as_list(&self) -> &[Value] {
+        match self {
+            Value::List(values) => values,
+            other => std::slice::from_ref(other),
+        }
+    }
+}
+
+/// Parsed arguments of a command, with typed accessors
+///
+/// Values are keyed by argument or option name; global options use their
+/// reserved key (see [`global_options`](crate::parser::global_options)).
+/// The accessors return `None` when the value is absent or does not have
+/// the requested type.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::parsed_args::ParsedArgs;
+/// use std::collections::HashMap;
+///
+/// // Untyped arguments (e.g. in tests) are converted on access
+/// let mut raw = HashMap::new();
+/// raw.insert("count".to_string(), "10".to_string());
+/// let args = ParsedArgs::from(raw);
+///
+/// assert_eq!(args.get_i64("count"), Some(10));
+/// assert_eq!(args.get_i64("missing"), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ParsedArgs {
+    /// Typed values keyed by name
+    values: HashMap<String, Value>,
+
+    /// Validated string values, as produced by the parsers
+    raw: HashMap<String, String>,
+}
+
+impl ParsedArgs {
+    /// Convert parsed string values using the types declared for a command
+    ///
+    /// Values whose name matches neither an argument, an option nor a
+    /// global option are kept as [`Value::String`]. Values of variadic
+    /// arguments and repeatable options become a [`Value::List`].
+    ///
+    /// # Arguments
+    ///
+    /// * `definition` - The command the values were parsed for
+    /// * `global_options` - Global options of the application
+    /// * `raw` - Parsed values, as returned by [`CliParser`](crate::parser::CliParser)
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::TypeParseError`](crate::error::ParseError::TypeParseError)
+    ///   if a value does not match its declared type (values produced by
+    ///   the parsers are already validated)
+    pub fn new(
+        definition: &CommandDefinition,
+        global_options: &[OptionDefinition],
+        raw: HashMap<String, String>,
+    ) -> Result<Self> {
+        // Declared type of each value, and whether it holds several values
+        let mut types: HashMap<String, (ArgumentType, bool)> = HashMap::new();
+        for arg in &definition.arguments {
+            types.insert(arg.name.clone(), (arg.arg_type, arg.multiple));
+        }
+        for opt in &definition.options {
+            types.insert(opt.name.clone(), (opt.option_type, opt.multiple));
+        }
+        for opt in global_options {
+            types.insert(
+                global_option_key(&opt.name),
+                (opt.option_type, opt.multiple),
+            );
+        }
+
+        let mut values = HashMap::with_capacity(raw.len());
+        for (name, value) in &raw {
+            let typed = match types.get(name) {
+                Some((arg_type, true)) => Value::List(
+                    multiple_values::split_values(value)
+                        .map(|item| type_parser::parse_typed_value(item, *arg_type))
+                        .collect::<Result<_>>()?,
+                ),
+                Some((arg_type, false)) => type_parser::parse_typed_value(value, *arg_type)?,
+                None => Value::String(value.clone()),
+            };
+            values.insert(name.clone(), typed);
+        }
+
+        Ok(Self { values, raw })
+    }
+
+    /// Get the typed value of an argument or option
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.values.get(name)
+    }
+
+    /// Check whether a value is present
+    pub fn contains(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /// Get a value as it was given on the command line
+    ///
+    /// The values of a list are joined with
+    /// [`LIST_SEPARATOR`](crate::parser::multiple_values::LIST_SEPARATOR);
+    /// use [`get_list`](Self::get_list) to read them.
+    pub fn get_str(&self, name: &str) -> Option<&str> {
+        self.raw.get(name).map(String::as_str)
+    }
+
+    /// Get an integer value
+    pub fn get_i64(&self, name: &str) -> Option<i64> {
+        self.get(name).and_then(Value::as_i64)
+    }
+
+    /// Get a float value (integer values are converted)
+    pub fn get_f64(&self, name: &str) -> Option<f64> {
+        self.get(name).and_then(Value::as_f64)
+    }
+
+    /// Get a boolean value
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        self.get(name).and_then(Value::as_bool)
+    }
+
+    /// Get a path value
+    pub fn get_path(&self, name: &str) -> Option<&Path> {
+        self.get(name).and_then(Value::as_path)
+    }
+
+    /// Get a duration value
+    pub fn get_duration(&self, name: &str) -> Option<Duration> {
+        self.get(name).and_then(Value::as_duration)
+    }
+
+    /// Get a byte size value
+    pub fn get_bytes(&self, name: &str) -> Option<u64> {
+        self.get(name).and_then(Value::as_bytes)
+    }
+
+    /// Get an IP address value
+    pub fn get_ip(&self, name: &str) -> Option<IpAddr> {
+        self.get(name).and_then(Value::as_ip)
+    }
+
+    /// Get a date/time value
+    pub fn get_datetime(&self, name: &str) -> Option<SystemTime> {
+        self.get(name).and_then(Value::as_datetime)
+    }
+
+    /// Get all values of an argument (a single value is a one-element list)
+    pub fn get_list(&self, name: &str) -> Option<&[Value]> {
+        self.get(name).map(Value::as_list)
+    }
+
+    /// Get the string values, as received by [`CommandHandler::execute`]
+    ///
+    /// [`CommandHandler::execute`]: crate::executor::CommandHandler::execute
+    pub fn as_map(&self) -> &HashMap<String, String> {
+        &self.raw
+    }
+
+    /// Iterate over the typed values (in arbitrary order)
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.values
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    /// Fill a `serde::Deserialize` struct from the arguments
+    ///
+    /// Struct fields are matched by argument or option name. Values are
+    /// converted leniently (a string is parsed when the field is numeric,
+    /// any scalar fits a `String` field) and unit enum variants are matched
+    /// by name, so `#[serde(rename_all = "lowercase")]` pairs well with
+    /// options declaring `choices`. Optional arguments map to `Option<T>`
+    /// or `#[serde(default)]` fields; extra arguments are ignored unless
+    /// the struct uses `#[serde(deny_unknown_fields)]`.
+    ///
+    /// # Errors
+    ///
+    /// - [`ValidationError::InvalidField`](crate::error::ValidationError::InvalidField)
+    ///   naming the field that has no value or a value of the wrong type
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::parser::parsed_args::ParsedArgs;
+    /// use serde::Deserialize;
+    /// use std::collections::HashMap;
+    ///
+    /// #[derive(Deserialize)]
+    /// #[serde(rename_all = "lowercase")]
+    /// enum Priority {
+    ///     Low,
+    ///     High,
+    /// }
+    ///
+    /// #[derive(Deserialize)]
+    /// struct AddArgs {
+    ///     description: String,
+    ///     priority: Priority,
+    ///     count: Option<u32>,
+    /// }
+    ///
+    /// let mut raw = HashMap::new();
+    /// raw.insert("description".to_string(), "Write docs".to_string());
+    /// raw.insert("priority".to_string(), "high".to_string());
+    ///
+    /// let args: AddArgs = ParsedArgs::from(raw).deserialize()?;
+    /// assert_eq!(args.description, "Write docs");
+    /// assert!(matches!(args.priority, Priority::High));
+    /// assert_eq!(args.count, None);
+    /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+    /// ```
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T> {
+        T::deserialize(ArgsDeserializer::new(self))
+            .map_err(|e| e.into_validation_error(std::any::type_name::<T>()))
+    }
+
+    /// Iterate over the typed values with their owned keys
+    pub(crate) fn values_iter(&self) -> hash_map::Iter<'_, String, Value> {
+        self.values.iter()
+    }
+
+    /// Number of values
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Check whether there are no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl From<HashMap<String, String>> for ParsedArgs {
+    /// Wrap untyped values; the typed accessors parse them on access
+    ///
+    /// Values joined with
+    /// [`LIST_SEPARATOR`](crate::parser::multiple_values::LIST_SEPARATOR)
+    /// become a [`Value::List`] of strings.
+    fn from(raw: HashMap<String, String>) -> Self {
+        let values = raw
+            .iter()
+            .map(|(name, value)| {
+                let value = if value.contains(multiple_values::LIST_SEPARATOR) {
+                    Value::List(
+                        multiple_values::split_values(value)
+                            .map(|item| Value::String(item.to_string()))
+                            .collect(),
+                    )
+                } else {
+                    Value::String(value.clone())
+                };
+                (name.clone(), value)
+            })
+            .collect();
+        Self { values, raw }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::ArgumentDefinition;
+
+    fn create_definition() -> CommandDefinition {
+        let argument = |name: &str, arg_type| ArgumentDefinition {
+            name: name.to_string(),
+            arg_type,
+            required: false,
+            description: name.to_string(),
+            validation: vec![],
+            secure: false,
+            ..Default::default()
+        };
+        CommandDefinition {
+            name: "convert".to_string(),
+            aliases: vec![],
+            description: "Convert".to_string(),
+            required: false,
+            arguments: vec![
+                argument("input", ArgumentType::Path),
+                argument("count", ArgumentType::Integer),
+            ],
+            options: vec![
+                OptionDefinition {
+                    name: "scale".to_string(),
+                    short: None,
+                    long: Some("scale".to_string()),
+                    option_type: ArgumentType::Float,
+                    required: false,
+                    default: None,
+                    description: "Scale".to_string(),
+                    choices: vec![],
+                    ..Default::default()
+                },
+                OptionDefinition {
+                    name: "dry_run".to_string(),
+                    short: None,
+                    long: Some("dry-run".to_string()),
+                    option_type: ArgumentType::Bool,
+                    required: false,
+                    default: None,
+                    description: "Dry run".to_string(),
+                    choices: vec![],
+                    ..Default::default()
+                },
+            ],
+            implementation: "convert_handler".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn raw(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_new_converts_declared_types() {
+        let args = ParsedArgs::new(
+            &create_definition(),
+            &[],
+            raw(&[
+                ("input", "data.csv"),
+                ("count", "-12"),
+                ("scale", "2.5"),
+                ("dry_run", "yes"),
+                ("extra", "value"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(args.get("input"), Some(&Value::Path("data.csv".into())));
+        assert_eq!(args.get("count"), Some(&Value::Integer(-12)));
+        assert_eq!(args.get("scale"), Some(&Value::Float(2.5)));
+        assert_eq!(args.get("dry_run"), Some(&Value::Bool(true)));
+        assert_eq!(args.get("extra"), Some(&Value::String("value".into())));
+
+        // The original strings are kept for map-based handlers
+        assert_eq!(args.get_str("count"), Some("-12"));
+        assert_eq!(args.as_map().len(), 5);
+    }
+
+    #[test]
+    fn test_accessors_check_types() {
+        let args = ParsedArgs::new(
+            &create_definition(),
+            &[],
+            raw(&[("count", "3"), ("dry_run", "false")]),
+        )
+        .unwrap();
+
+        assert_eq!(args.get_i64("count"), Some(3));
+        assert_eq!(args.get_f64("count"), Some(3.0));
+        assert_eq!(args.get_bool("count"), None);
+        assert_eq!(args.get_bool("dry_run"), Some(false));
+        assert_eq!(args.get_path("dry_run"), None);
+        assert_eq!(args.get_i64("missing"), None);
+        assert_eq!(args.get_list("count"), Some(&[Value::Integer(3)][..]));
+    }
+
+    #[test]
+    fn test_new_types_global_options() {
+        let globals = vec![OptionDefinition {
+            name: "jobs".to_string(),
+            short: None,
+            long: Some("jobs".to_string()),
+            option_type: ArgumentType::Integer,
+            required: false,
+            default: None,
+            description: "Parallel jobs".to_string(),
+            choices: vec![],
+            ..Default::default()
+        }];
+
+        let args =
+            ParsedArgs::new(&create_definition(), &globals, raw(&[("global.jobs", "4")])).unwrap();
+        assert_eq!(args.get("global.jobs"), Some(&Value::Integer(4)));
+    }
+
+    #[test]
+    fn test_new_converts_multiple_values_to_list() {
+        let mut definition = create_definition();
+        definition.arguments[1].multiple = true;
+        let counts = multiple_values::join_values(["1", "2", "3"]);
+
+        let args = ParsedArgs::new(&definition, &[], raw(&[("count", &counts)])).unwrap();
+
+        assert_eq!(
+            args.get("count"),
+            Some(&Value::List(vec![
+                Value::Integer(1),
+                Value::Integer(2),
+                Value::Integer(3)
+            ]))
+        );
+
+        #[derive(serde::Deserialize)]
+        struct Counts {
+            count: Vec<u8>,
+        }
+        let counts: Counts = args.deserialize().unwrap();
+        assert_eq!(counts.count, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_new_rejects_mistyped_value() {
+        let result = ParsedArgs::new(&create_definition(), &[], raw(&[("count", "many")]));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_from_untyped_map_parses_on_access() {
+        let args = ParsedArgs::from(raw(&[("count", "7"), ("path", "/tmp/x")]));
+
+        assert_eq!(args.get_i64("count"), Some(7));
+        assert_eq!(args.get_path("path"), Some(Path::new("/tmp/x")));
+        assert_eq!(args.len(), 2);
+        assert!(!args.is_empty());
+    }
+
+    #[test]
+    fn test_from_untyped_map_splits_lists() {
+        let tags = multiple_values::join_values(["a,b", "c"]);
+        let args = ParsedArgs::from(raw(&[("tags", &tags), ("name", "x,y")]));
+
+        assert_eq!(
+            args.get_list("tags"),
+            Some(&[Value::String("a,b".into()), Value::String("c".into())][..])
+        );
+        assert_eq!(args.get_list("name").map(<[Value]>::len), Some(1));
+    }
+
+    // ── deserialize ──────────────────────────────────────────
+
+    #[derive(Debug, serde::Deserialize, PartialEq)]
+    #[serde(rename_all = "lowercase")]
+    enum Mode {
+        Fast,
+        Safe,
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct ConvertArgs {
+        input: PathBuf,
+        count: u32,
+        scale: Option<f64>,
+        #[serde(default)]
+        dry_run: bool,
+    }
+
+    #[test]
+    fn test_new_converts_richer_types() {
+        let mut definition = create_definition();
+        let template = definition.arguments[0].clone();
+        for (name, arg_type) in [
+            ("timeout", ArgumentType::Duration),
+            ("limit", ArgumentType::Bytes),
+            ("endpoint", ArgumentType::Url),
+            ("bind", ArgumentType::Ip),
+            ("since", ArgumentType::DateTime),
+            ("pattern", ArgumentType::Regex),
+        ] {
+            let mut arg = template.clone();
+            arg.name = name.to_string();
+            arg.arg_type = arg_type;
+            definition.arguments.push(arg);
+        }
+
+        let args = ParsedArgs::new(
+            &definition,
+            &[],
+            raw(&[
+                ("timeout", "1m30s"),
+                ("limit", "2K"),
+                ("endpoint", "http://localhost:8080"),
+                ("bind", "0.0.0.0"),
+                ("since", "1970-01-01T00:01:00Z"),
+                ("pattern", "^v\\d+"),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(args.get_duration("timeout"), Some(Duration::from_secs(90)));
+        assert_eq!(args.get_bytes("limit"), Some(2_048));
+        assert_eq!(args.get_str("endpoint"), Some("http://localhost:8080"));
+        assert_eq!(
+            args.get("endpoint").and_then(Value::as_str),
+            Some("http://localhost:8080")
+        );
+        assert_eq!(args.get_ip("bind"), Some(IpAddr::from([0, 0, 0, 0])));
+        assert_eq!(
+            args.get_datetime("since"),
+            Some(std::time::UNIX_EPOCH + Duration::from_secs(60))
+        );
+        assert_eq!(args.get("pattern").and_then(Value::as_str), Some("^v\\d+"));
+        assert_eq!(args.get_duration("limit"), None);
+
+        #[derive(serde::Deserialize)]
+        struct Args {
+            timeout: Duration,
+            limit: u64,
+            bind: std::net::IpAddr,
+            since: SystemTime,
+            endpoint: String,
+        }
+        let parsed: Args = args.deserialize().unwrap();
+        assert_eq!(parsed.timeout, Duration::from_secs(90));
+        assert_eq!(parsed.limit, 2_048);
+        assert!(parsed.bind.is_unspecified());
+        assert_eq!(
+            parsed.since,
+            std::time::UNIX_EPOCH + Duration::from_secs(60)
+        );
+        assert_eq!(parsed.endpoint, "http://localhost:8080");
+    }
+
+    #[test]
+    fn test_deserialize_into_struct() {
+        let args = ParsedArgs::new(
+            &create_definition(),
+            &[],
+            raw(&[("input", "data.csv"), ("count", "3"), ("scale", "0.5")]),
+        )
+        .unwrap();
+
+        let parsed: ConvertArgs = args.deserialize().unwrap();
+        assert_eq!(parsed.input, PathBuf::from("data.csv"));
+        assert_eq!(parsed.count, 3);
+        assert_eq!(parsed.scale, Some(0.5));
+        assert!(!parsed.dry_run);
+    }
+
+    #[test]
+    fn test_deserialize_enum_and_list() {
+        #[derive(serde::Deserialize)]
+        struct Args {
+            mode: Mode,
+            tags: Vec<String>,
+        }
+
+        let mut args = ParsedArgs::from(raw(&[("mode", "safe")]));
+        args.values.insert(
+            "tags".to_string(),
+            Value::List(vec![Value::String("a".into()), Value::Integer(2)]),
+        );
+
+        let parsed: Args = args.deserialize().unwrap();
+        assert_eq!(parsed.mode, Mode::Safe);
+        assert_eq!(parsed.tags, vec!["a".to_string(), "2".to_string()]);
+    }
+
+    #[test]
+    fn test_deserialize_missing_field_names_field() {
+        let args = ParsedArgs::from(raw(&[("input", "data.csv")]));
+
+        let err = args.deserialize::<ConvertArgs>().unwrap_err();
+        match err {
+            crate::error::DynamicCliError::Validation(
+                crate::error::ValidationError::InvalidField {
+                    field, suggestion, ..
+                },
+            ) => {
+                assert_eq!(field, "count");
+                assert!(suggestion.unwrap().contains("Option<T>"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_deserialize_wrong_type_names_field() {
+        #[derive(Debug, serde::Deserialize)]
+        struct Args {
+            #[allow(dead_code)]
+            mode: Mode,
+        }
+
+        let err = ParsedArgs::from(raw(&[("mode", "turbo"), ("count", "1")]))
+            .deserialize::<Args>()
+            .unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains("'mode'"), "{}", message);
+        assert!(message.contains("turbo"), "{}", message);
+
+        let err = ParsedArgs::from(raw(&[("input", "x"), ("count", "-1")]))
+            .deserialize::<ConvertArgs>()
+            .unwrap_err();
+        assert!(err.to_string().contains("'count'"));
+    }
+}
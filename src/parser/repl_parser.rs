@@ -26,6 +26,7 @@
 //!     arguments: vec![],
 //!     options: vec![],
 //!     implementation: "handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! // Dummy handler for example
@@ -48,8 +49,10 @@
 //! assert_eq!(parsed.command_name, "hello");
 //! ```
 
+use crate::context::ExecutionContext;
 use crate::error::{ParseError, Result};
 use crate::parser::cli_parser::CliParser;
+use crate::parser::global_options;
 use crate::registry::CommandRegistry;
 use std::collections::HashMap;
 
@@ -58,7 +61,8 @@ use std::collections::HashMap;
 /// Parses interactive command lines in REPL mode. The parser:
 /// 1. Splits the line into command name and arguments
 /// 2. Resolves the command name (including aliases) via the registry
-/// 3. Delegates to [`CliParser`] for argument parsing
+/// 3. Delegates to [`CliParser`] for argument parsing, including the
+///    registry's global options (accepted before or after the command name)
 ///
 /// # Lifetime
 ///
@@ -82,6 +86,9 @@ use std::collections::HashMap;
 pub struct ReplParser<'a> {
     /// Reference to the command registry for name resolution
     registry: &'a CommandRegistry,
+
+    /// Context handed to the registry's custom validators, if any
+    context: Option<&'a dyn ExecutionContext>,
 }
 
 /// Parsed REPL command
@@ -91,7 +98,8 @@ pub struct ReplParser<'a> {
 ///
 /// # Fields
 ///
-/// - `command_name`: The canonical command name (aliases are resolved)
+/// - `command_name`: The canonical command name (aliases are resolved;
+///   subcommands are named by their path, e.g. `"remote add"`)
 /// - `arguments`: HashMap of argument/option names to their string values
 ///
 /// # Example
@@ -137,14 +145,45 @@ impl<'a> ReplParser<'a> {
     /// let parser = ReplParser::new(&registry);
     /// ```
     pub fn new(registry: &'a CommandRegistry) -> Self {
-        Self { registry }
+        Self {
+            registry,
+            context: None,
+        }
+    }
+
+    /// Check `custom:` validation rules against the given context
+    ///
+    /// The validators themselves come from the registry (see
+    /// [`CommandRegistry::register_validator`]). Without a context,
+    /// `custom:` rules are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::parser::repl_parser::ReplParser;
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::context::ExecutionContext;
+    ///
+    /// # struct MyContext;
+    /// # impl ExecutionContext for MyContext {
+    /// #     fn as_any(&self) -> &dyn std::any::Any { self }
+    /// #     fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+    /// # }
+    /// let registry = CommandRegistry::new();
+    /// let context = MyContext;
+    /// let parser = ReplParser::new(&registry).with_context(&context);
+    /// ```
+    pub fn with_context(mut self, context: &'a dyn ExecutionContext) -> Self {
+        self.context = Some(context);
+        self
     }
 
     /// Parse a REPL command line
     ///
     /// Parses a complete command line as entered in the REPL.
     /// The line is split into tokens, the first token is resolved as
-    /// a command name (or alias), and remaining tokens are parsed
+    /// a command name (or alias), following tokens that name subcommands
+    /// select the nested command, and remaining tokens are parsed
     /// as arguments and options.
     ///
     /// # Arguments
@@ -158,7 +197,8 @@ impl<'a> ReplParser<'a> {
     /// # Errors
     ///
     /// - [`ParseError::UnknownCommand`] if the command is not registered
-    /// - [`ParseError::InvalidSyntax`] if the line is empty or malformed
+    /// - [`ParseError::InvalidSyntax`] if the line is empty or malformed,
+    ///   or if a command that only groups subcommands is given none
     /// - Any errors from [`CliParser`] during argument parsing
     ///
     /// # Example
@@ -190,29 +230,19 @@ impl<'a> ReplParser<'a> {
             .into());
         }
 
-        // First token is the command name
-        let input_name = &tokens[0];
+        // Global options may precede the command name
+        let global_options = self.registry.global_options();
+        let position = global_options::command_position(global_options, &tokens)?;
+        if position >= tokens.len() {
+            return Err(ParseError::InvalidSyntax {
+                details: "No command specified".to_string(),
+                hint: Some("Type a command or 'help' for available commands".to_string()),
+            }
+            .into());
+        }
 
-        // Resolve command name (handles aliases)
-        let command_name = self
-            .registry
-            .resolve_name(input_name)
-            .ok_or_else(|| {
-                // Get list of all available commands for suggestions
-                let available: Vec<String> = self
-                    .registry
-                    .list_commands()
-                    .iter()
-                    .flat_map(|cmd| {
-                        let mut names = vec![cmd.name.clone()];
-                        names.extend(cmd.aliases.clone());
-                        names
-                    })
-                    .collect();
-
-                ParseError::unknown_command_with_suggestions(input_name, &available)
-            })?
-            .to_string();
+        // First non-global tokens name the command (and subcommands)
+        let (command_name, consumed) = self.registry.resolve_command(&tokens[position..])?;
 
         // Get command definition for argument parsing
         let definition = self
@@ -220,10 +250,17 @@ impl<'a> ReplParser<'a> {
             .get_definition(&command_name)
             .expect("Command definition must exist after resolution");
 
-        // Parse arguments using CliParser
-        let remaining_args: Vec<String> = tokens[1..].to_vec();
-        let cli_parser = CliParser::new(definition);
-        let arguments = cli_parser.parse(&remaining_args)?;
+        // Parse arguments using CliParser (leading global options included)
+        let mut cli_parser = CliParser::new(definition).with_global_options(global_options);
+        if let Some(context) = self.context {
+            cli_parser =
+                cli_parser.with_custom_validators(self.registry.custom_validators(context));
+        }
+        if let Some(constraints) = self.registry.constraints(&command_name) {
+            cli_parser = cli_parser.with_constraints(constraints);
+        }
+        let arguments =
+            cli_parser.parse_after_globals(&tokens[..position], &tokens[position + consumed..])?;
 
         Ok(ParsedCommand {
             command_name,
@@ -379,6 +416,7 @@ mod tests {
                 description: "Name to greet".to_string(),
                 validation: vec![],
                 secure: false,
+                ..Default::default()
             }],
             options: vec![OptionDefinition {
                 name: "loud".to_string(),
@@ -389,8 +427,10 @@ mod tests {
                 default: Some("false".to_string()),
                 description: "Loud greeting".to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             implementation: "hello_handler".to_string(),
+            ..Default::default()
         };
 
         registry.register(hello_def, Box::new(TestHandler)).unwrap();
@@ -409,6 +449,7 @@ mod tests {
                     description: "Input file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
                 ArgumentDefinition {
                     name: "output".to_string(),
@@ -417,6 +458,7 @@ mod tests {
                     description: "Output file".to_string(),
                     validation: vec![],
                     secure: false,
+                    ..Default::default()
                 },
             ],
             options: vec![OptionDefinition {
@@ -428,8 +470,10 @@ mod tests {
                 default: Some("false".to_string()),
                 description: "Verbose output".to_string(),
                 choices: vec![],
+                ..Default::default()
             }],
             implementation: "process_handler".to_string(),
+            ..Default::default()
         };
 
         registry
@@ -717,4 +761,249 @@ mod tests {
         let cloned = parsed.clone();
         assert_eq!(parsed, cloned);
     }
+
+    // ========================================================================
+    // Global options
+    // ========================================================================
+
+    /// Helper to create a registry with `--verbose` and `--format` globals
+    fn create_registry_with_globals() -> CommandRegistry {
+        let mut registry = create_test_registry();
+        registry.set_global_options(vec![
+            OptionDefinition {
+                name: "verbose".to_string(),
+                short: None,
+                long: Some("verbose".to_string()),
+                option_type: ArgumentType::Bool,
+                required: false,
+                default: None,
+                description: "Global verbose flag".to_string(),
+                choices: vec![],
+                ..Default::default()
+            },
+            OptionDefinition {
+                name: "format".to_string(),
+                short: Some("f".to_string()),
+                long: Some("format".to_string()),
+                option_type: ArgumentType::String,
+                required: false,
+                default: Some("text".to_string()),
+                description: "Output format".to_string(),
+                choices: vec!["text".to_string(), "json".to_string()],
+                ..Default::default()
+            },
+        ]);
+        registry
+    }
+
+    #[test]
+    fn test_parse_global_options_before_command() {
+        let registry = create_registry_with_globals();
+        let parser = ReplParser::new(&registry);
+
+        let parsed = parser.parse_line("--format json hello Alice").unwrap();
+
+        assert_eq!(parsed.command_name, "hello");
+        assert_eq!(parsed.arguments.get("name"), Some(&"Alice".to_string()));
+        assert_eq!(
+            parsed.arguments.get("global.format"),
+            Some(&"json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_global_options_after_command() {
+        let registry = create_registry_with_globals();
+        let parser = ReplParser::new(&registry);
+
+        let parsed = parser.parse_line("hello Alice -f json").unwrap();
+
+        assert_eq!(
+            parsed.arguments.get("global.format"),
+            Some(&"json".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_global_option_does_not_shadow_local() {
+        let registry = create_registry_with_globals();
+        let parser = ReplParser::new(&registry);
+
+        // `process` declares its own --verbose: the local option wins
+        let parsed = parser.parse_line("process in.txt --verbose").unwrap();
+        assert_eq!(parsed.arguments.get("verbose"), Some(&"true".to_string()));
+        assert_eq!(parsed.arguments.get("global.verbose"), None);
+
+        // Before the command name, --verbose is the global option
+        let parsed = parser.parse_line("--verbose process in.txt").unwrap();
+        assert_eq!(parsed.arguments.get("verbose"), Some(&"false".to_string()));
+        assert_eq!(
+            parsed.arguments.get("global.verbose"),
+            Some(&"true".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_global_option_defaults_applied() {
+        let registry = create_registry_with_globals();
+        let parser = ReplParser::new(&registry);
+
+        let parsed = parser.parse_line("hello").unwrap();
+        assert_eq!(
+            parsed.arguments.get("global.format"),
+            Some(&"text".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_only_global_options_is_error() {
+        let registry = create_registry_with_globals();
+        let parser = ReplParser::new(&registry);
+
+        let result = parser.parse_line("--format json");
+        assert!(matches!(
+            result,
+            Err(crate::error::DynamicCliError::Parse(
+                ParseError::InvalidSyntax { .. }
+            ))
+        ));
+    }
+
+    // ========================================================================
+    // Subcommands
+    // ========================================================================
+
+    /// Helper adding a `config` group with a `set <key> <value>` subcommand
+    fn create_registry_with_subcommands() -> CommandRegistry {
+        let mut registry = create_registry_with_globals();
+        let string_arg = |name: &str| ArgumentDefinition {
+            name: name.to_string(),
+            arg_type: ArgumentType::String,
+            required: true,
+            description: name.to_string(),
+            validation: vec![],
+            secure: false,
+            ..Default::default()
+        };
+        let set_def = CommandDefinition {
+            name: "set".to_string(),
+            aliases: vec!["s".to_string()],
+            description: "Set a value".to_string(),
+            required: false,
+            arguments: vec![string_arg("key"), string_arg("value")],
+            options: vec![],
+            implementation: "config_set".to_string(),
+            ..Default::default()
+        };
+        let config_def = CommandDefinition {
+            name: "config".to_string(),
+            aliases: vec![],
+            description: "Manage configuration".to_string(),
+            required: false,
+            arguments: vec![],
+            options: vec![],
+            implementation: "config".to_string(),
+            subcommands: vec![set_def.clone()],
+            ..Default::default()
+        };
+
+        registry.register_group(config_def).unwrap();
+        registry
+            .register_subcommand("config", set_def, Box::new(TestHandler))
+            .unwrap();
+        registry
+    }
+
+    #[test]
+    fn test_parse_subcommand_with_globals() {
+        let registry = create_registry_with_subcommands();
+        let parser = ReplParser::new(&registry);
+
+        let parsed = parser.parse_line("--verbose config s name Alice").unwrap();
+        assert_eq!(parsed.command_name, "config set");
+        assert_eq!(parsed.arguments.get("key"), Some(&"name".to_string()));
+        assert_eq!(parsed.arguments.get("value"), Some(&"Alice".to_string()));
+        assert_eq!(
+            parsed.arguments.get("global.verbose"),
+            Some(&"true".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_group_without_subcommand_is_error() {
+        let registry = create_registry_with_subcommands();
+        let parser = ReplParser::new(&registry);
+
+        let result = parser.parse_line("config --verbose");
+        assert!(matches!(
+            result,
+            Err(crate::error::DynamicCliError::Parse(
+                ParseError::InvalidSyntax { .. }
+            ))
+        ));
+    }
+
+    struct Lowercase;
+
+    impl crate::validator::ArgumentValidator for Lowercase {
+        fn validate(
+            &self,
+            _context: &dyn ExecutionContext,
+            arg_name: &str,
+            value: &str,
+            _params: &serde_json::Value,
+        ) -> crate::error::Result<()> {
+            if value == value.to_lowercase() {
+                return Ok(());
+            }
+            Err(crate::error::ValidationError::CustomConstraint {
+                arg_name: arg_name.to_string(),
+                reason: "must be lowercase".to_string(),
+                suggestion: None,
+            }
+            .into())
+        }
+    }
+
+    struct TestContext;
+
+    impl ExecutionContext for TestContext {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_parse_line_runs_custom_validators_with_context() {
+        let mut registry = CommandRegistry::new();
+        let mut definition = create_test_registry()
+            .get_definition("hello")
+            .unwrap()
+            .clone();
+        definition.arguments[0].validation = vec![crate::config::schema::ValidationRule::Custom {
+            custom: "lowercase".to_string(),
+            params: serde_json::Value::Null,
+        }];
+        registry
+            .register(definition, Box::new(TestHandler))
+            .unwrap();
+        registry.register_validator("lowercase", Box::new(Lowercase));
+
+        let context = TestContext;
+        let parser = ReplParser::new(&registry).with_context(&context);
+        assert!(parser.parse_line("hello alice").is_ok());
+        assert!(matches!(
+            parser.parse_line("hello Alice"),
+            Err(crate::error::DynamicCliError::Validation(
+                crate::error::ValidationError::CustomConstraint { .. }
+            ))
+        ));
+
+        // Without a context the rule cannot be checked and is skipped
+        assert!(ReplParser::new(&registry).parse_line("hello Alice").is_ok());
+    }
 }
@@ -11,6 +11,14 @@
 //! - **Float**: 64-bit floating point (f64)
 //! - **Bool**: true/false, yes/no, 1/0, on/off (case-insensitive)
 //! - **Path**: File system paths (validated as PathBuf)
+//! - **Duration**: `500ms`, `30s`, `1h30m` (a bare number is seconds)
+//! - **Bytes**: `512`, `4K`, `1.5GiB` (binary multiples, case-insensitive)
+//! - **Url**: `scheme://host[:port][/path]`
+//! - **Ip**: IPv4 or IPv6 address
+//! - **DateTime**: RFC 3339 (`2024-05-01T12:00:00Z`) or a plain date
+//! - **Regex**: Syntactically valid regular expression
+//! - **Enum**: Pass-through; the declared variants are enforced by
+//!   [`CliParser`](crate::parser::CliParser)
 //!
 //! # Example
 //!
@@ -29,7 +37,10 @@
 
 use crate::config::schema::ArgumentType;
 use crate::error::{ParseError, Result};
+use crate::parser::parsed_args::Value;
+use std::net::IpAddr;
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 /// Parse a string value according to its expected type
 ///
@@ -101,9 +112,74 @@ pub fn parse_value(value: &str, arg_type: ArgumentType) -> Result<String> {
             parse_path(value)?;
             Ok(value.to_string())
         }
+
+        ArgumentType::Duration => {
+            parse_duration(value)?;
+            Ok(value.to_string())
+        }
+
+        ArgumentType::Bytes => {
+            parse_bytes(value)?;
+            Ok(value.to_string())
+        }
+
+        ArgumentType::Url => parse_url(value),
+
+        ArgumentType::Ip => {
+            parse_ip(value)?;
+            Ok(value.to_string())
+        }
+
+        ArgumentType::DateTime => {
+            parse_datetime(value)?;
+            Ok(value.to_string())
+        }
+
+        ArgumentType::Regex => parse_regex(value),
+
+        // Variants are declared per argument and checked by the parsers
+        ArgumentType::Enum => Ok(value.to_string()),
     }
 }
 
+/// Parse a string value into a typed [`Value`]
+///
+/// Performs the same checks as [`parse_value`] but keeps the converted
+/// value instead of returning the original string.
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if the value cannot be
+/// converted to the expected type.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_typed_value;
+/// use dynamic_cli::parser::parsed_args::Value;
+/// use dynamic_cli::config::schema::ArgumentType;
+///
+/// assert_eq!(parse_typed_value("42", ArgumentType::Integer).unwrap(), Value::Integer(42));
+/// assert_eq!(parse_typed_value("on", ArgumentType::Bool).unwrap(), Value::Bool(true));
+/// assert!(parse_typed_value("abc", ArgumentType::Float).is_err());
+/// ```
+pub fn parse_typed_value(value: &str, arg_type: ArgumentType) -> Result<Value> {
+    Ok(match arg_type {
+        ArgumentType::String => Value::String(value.to_string()),
+        ArgumentType::Integer => Value::Integer(parse_integer(value)?),
+        ArgumentType::Float => Value::Float(parse_float(value)?),
+        ArgumentType::Bool => Value::Bool(parse_bool(value)?),
+        ArgumentType::Path => Value::Path(parse_path(value)?),
+        ArgumentType::Duration => Value::Duration(parse_duration(value)?),
+        ArgumentType::Bytes => Value::Bytes(parse_bytes(value)?),
+        ArgumentType::Url => Value::Url(parse_url(value)?),
+        ArgumentType::Ip => Value::Ip(parse_ip(value)?),
+        ArgumentType::DateTime => Value::DateTime(parse_datetime(value)?),
+        ArgumentType::Regex => Value::Regex(parse_regex(value)?),
+        ArgumentType::Enum => Value::String(value.to_string()),
+    })
+}
+
 /// Parse a string as a signed integer
 ///
 /// Accepts standard integer formats including:
@@ -302,6 +378,557 @@ pub fn parse_path(value: &str) -> Result<PathBuf> {
     Ok(path)
 }
 
+/// Parse a string as a duration
+///
+/// Accepts a sequence of `<number><unit>` components, optionally
+/// separated by whitespace, whose values are added up:
+/// - Units: `ns`, `us` (or `µs`), `ms`, `s`, `m`, `h`, `d`
+/// - Fractions: "1.5h", "0.25s"
+/// - A bare number is a number of seconds: "30" → 30s
+///
+/// The output of [`format_duration`](crate::utils::format_duration)
+/// ("1h 1m 5s") is accepted.
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if a component has no number,
+/// an unknown unit, or if the total is negative or too large.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+/// assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5400));
+/// assert_eq!(parse_duration("1m 30s").unwrap(), Duration::from_secs(90));
+/// assert_eq!(parse_duration("2").unwrap(), Duration::from_secs(2));
+///
+/// assert!(parse_duration("10 parsecs").is_err());
+/// assert!(parse_duration("h").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<Duration> {
+    let invalid = |details: String| type_error("duration", value, details);
+    let trimmed = value.trim();
+
+    if trimmed.is_empty() {
+        return Err(invalid("empty duration".to_string()));
+    }
+
+    // A bare number is a number of seconds
+    if let Ok(seconds) = trimmed.parse::<f64>() {
+        return Duration::try_from_secs_f64(seconds).map_err(|e| invalid(e.to_string()));
+    }
+
+    let mut nanos = 0.0_f64;
+    let mut rest = trimmed;
+    while !rest.is_empty() {
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        if number_len == 0 {
+            return Err(invalid(
+                "expected a number before each unit, e.g. 1h30m".to_string(),
+            ));
+        }
+        let number: f64 = rest[..number_len]
+            .parse()
+            .map_err(|_| invalid(format!("invalid number '{}'", &rest[..number_len])))?;
+        rest = &rest[number_len..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+            .unwrap_or(rest.len());
+        let unit_nanos = match &rest[..unit_len] {
+            "ns" => 1.0,
+            "us" | "µs" => 1e3,
+            "ms" => 1e6,
+            "s" => 1e9,
+            "m" => 60e9,
+            "h" => 3_600e9,
+            "d" => 86_400e9,
+            "" => return Err(invalid(format!("missing unit after '{}'", number))),
+            unit => {
+                return Err(invalid(format!(
+                    "unknown unit '{}' (expected ns, us, ms, s, m, h or d)",
+                    unit
+                )))
+            }
+        };
+        nanos += number * unit_nanos;
+        rest = rest[unit_len..].trim_start();
+    }
+
+    if nanos >= u64::MAX as f64 {
+        return Err(invalid("duration is too large".to_string()));
+    }
+    Ok(Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Parse a string as a byte size
+///
+/// Accepts a number followed by an optional unit (case-insensitive,
+/// optionally separated by whitespace). Multiples are binary, matching
+/// [`format_bytes`](crate::utils::format_bytes):
+/// - `B` or no unit: bytes
+/// - `K`, `KB`, `KiB`: 1024 bytes
+/// - `M`, `MB`, `MiB`; `G`, `GB`, `GiB`; `T`, `TB`, `TiB`
+///
+/// Fractions are rounded to the nearest byte ("1.5K" → 1536).
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if the number or the unit is
+/// invalid, or if the size does not fit in a u64.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_bytes;
+///
+/// assert_eq!(parse_bytes("512").unwrap(), 512);
+/// assert_eq!(parse_bytes("4K").unwrap(), 4096);
+/// assert_eq!(parse_bytes("1.5 MiB").unwrap(), 1_572_864);
+///
+/// assert!(parse_bytes("12 parsecs").is_err());
+/// assert!(parse_bytes("-1K").is_err());
+/// ```
+pub fn parse_bytes(value: &str) -> Result<u64> {
+    let invalid = |details: String| type_error("bytes", value, details);
+    let trimmed = value.trim();
+
+    let number_len = trimmed
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(trimmed.len());
+    let (number, unit) = trimmed.split_at(number_len);
+    if number.is_empty() {
+        return Err(invalid(
+            "expected a number, e.g. 512, 4K or 1.5GiB".to_string(),
+        ));
+    }
+
+    let multiplier: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "k" | "kb" | "kib" => 1 << 10,
+        "m" | "mb" | "mib" => 1 << 20,
+        "g" | "gb" | "gib" => 1 << 30,
+        "t" | "tb" | "tib" => 1 << 40,
+        other => {
+            return Err(invalid(format!(
+                "unknown unit '{}' (expected B, K, M, G or T)",
+                other
+            )))
+        }
+    };
+
+    let too_large = || invalid("size is too large".to_string());
+    if let Ok(count) = number.parse::<u64>() {
+        return count.checked_mul(multiplier).ok_or_else(too_large);
+    }
+    let count: f64 = number
+        .parse()
+        .map_err(|_| invalid(format!("invalid number '{}'", number)))?;
+    let total = (count * multiplier as f64).round();
+    if total >= u64::MAX as f64 {
+        return Err(too_large());
+    }
+    Ok(total as u64)
+}
+
+/// Parse a string as a URL
+///
+/// Checks the general shape `scheme://host[:port][/path][?query][#fragment]`:
+/// - the scheme starts with a letter and contains only letters, digits,
+///   `+`, `-` and `.`
+/// - the host is not empty (except for `file://` URLs); IPv6 hosts are
+///   written in brackets
+/// - the port, if any, is a number between 0 and 65535
+/// - the URL contains no whitespace
+///
+/// # Returns
+///
+/// The URL unchanged
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if the URL does not have this
+/// shape.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_url;
+///
+/// assert!(parse_url("https://example.com/path?q=1").is_ok());
+/// assert!(parse_url("http://[::1]:8080").is_ok());
+/// assert!(parse_url("file:///tmp/data.csv").is_ok());
+///
+/// assert!(parse_url("example.com").is_err());
+/// assert!(parse_url("http://host:99999").is_err());
+/// ```
+pub fn parse_url(value: &str) -> Result<String> {
+    let invalid = |details: String| type_error("url", value, details);
+
+    if value.chars().any(char::is_whitespace) {
+        return Err(invalid("URLs cannot contain whitespace".to_string()));
+    }
+
+    let (scheme, rest) = value
+        .split_once("://")
+        .ok_or_else(|| invalid("expected scheme://host, e.g. https://example.com".to_string()))?;
+
+    let mut scheme_chars = scheme.chars();
+    let scheme_is_valid = scheme_chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && scheme_chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !scheme_is_valid {
+        return Err(invalid(format!("invalid scheme '{}'", scheme)));
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host_port)| host_port);
+
+    let (host, port) = if let Some(bracketed) = host_port.strip_prefix('[') {
+        let (ipv6, after) = bracketed
+            .split_once(']')
+            .ok_or_else(|| invalid("unterminated IPv6 address".to_string()))?;
+        if ipv6.parse::<std::net::Ipv6Addr>().is_err() {
+            return Err(invalid(format!("invalid IPv6 address '{}'", ipv6)));
+        }
+        let port = match after {
+            "" => None,
+            _ => Some(
+                after
+                    .strip_prefix(':')
+                    .ok_or_else(|| invalid("unexpected text after IPv6 address".to_string()))?,
+            ),
+        };
+        (ipv6, port)
+    } else {
+        match host_port.rsplit_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (host_port, None),
+        }
+    };
+
+    if host.is_empty() && !scheme.eq_ignore_ascii_case("file") {
+        return Err(invalid("missing host".to_string()));
+    }
+    if let Some(port) = port {
+        if port.parse::<u16>().is_err() {
+            return Err(invalid(format!("invalid port '{}'", port)));
+        }
+    }
+
+    Ok(value.to_string())
+}
+
+/// Parse a string as an IPv4 or IPv6 address
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if the string is not a valid
+/// IP address.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_ip;
+///
+/// assert!(parse_ip("192.168.0.1").unwrap().is_ipv4());
+/// assert!(parse_ip("::1").unwrap().is_ipv6());
+///
+/// assert!(parse_ip("256.0.0.1").is_err());
+/// assert!(parse_ip("localhost").is_err());
+/// ```
+pub fn parse_ip(value: &str) -> Result<IpAddr> {
+    value
+        .parse::<IpAddr>()
+        .map_err(|e| type_error("ip", value, e.to_string()))
+}
+
+/// Parse a string as a date and time
+///
+/// Accepts RFC 3339 timestamps and plain dates:
+/// - `2024-05-01T12:30:00Z`, `2024-05-01T12:30:00.250+02:00`
+/// - `2024-05-01T12:30` (seconds are optional)
+/// - `2024-05-01 12:30:00` (space instead of `T`)
+/// - `2024-05-01` (midnight)
+///
+/// Times without an offset are interpreted as UTC.
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] if the string is not in one of
+/// these formats or names a date that does not exist (e.g. February 30).
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_datetime;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(
+///     parse_datetime("1970-01-02T00:00:00Z").unwrap(),
+///     UNIX_EPOCH + Duration::from_secs(86_400)
+/// );
+/// assert_eq!(
+///     parse_datetime("1970-01-01T02:00:00+02:00").unwrap(),
+///     UNIX_EPOCH
+/// );
+///
+/// assert!(parse_datetime("2023-02-29").is_err());
+/// assert!(parse_datetime("yesterday").is_err());
+/// ```
+pub fn parse_datetime(value: &str) -> Result<SystemTime> {
+    let invalid = |details: &str| {
+        type_error(
+            "datetime",
+            value,
+            format!("{} (expected e.g. 2024-05-01T12:30:00Z)", details),
+        )
+    };
+    let trimmed = value.trim();
+
+    let (date, time) = match trimmed.split_once(['T', 't', ' ']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (trimmed, None),
+    };
+
+    let mut date_fields = date.split('-');
+    let (Some(year), Some(month), Some(day), None) = (
+        date_fields.next().and_then(|f| parse_digits(f, 4)),
+        date_fields.next().and_then(|f| parse_digits(f, 2)),
+        date_fields.next().and_then(|f| parse_digits(f, 2)),
+        date_fields.next(),
+    ) else {
+        return Err(invalid("invalid date"));
+    };
+    if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+        return Err(invalid("date does not exist"));
+    }
+
+    let mut seconds = days_from_civil(year, month, day) * 86_400;
+    let mut nanos = 0;
+
+    if let Some(time) = time {
+        let (clock, offset) = split_offset(time).ok_or_else(|| invalid("invalid offset"))?;
+
+        let (clock, fraction) = match clock.split_once('.') {
+            Some((clock, fraction)) => (clock, Some(fraction)),
+            None => (clock, None),
+        };
+        let mut clock_fields = clock.split(':');
+        let (Some(hour), Some(minute), second, None) = (
+            clock_fields.next().and_then(|f| parse_digits(f, 2)),
+            clock_fields.next().and_then(|f| parse_digits(f, 2)),
+            clock_fields.next().map(|f| parse_digits(f, 2)),
+            clock_fields.next(),
+        ) else {
+            return Err(invalid("invalid time"));
+        };
+        let second = match second {
+            None if fraction.is_none() => 0,
+            Some(Some(second)) => second,
+            _ => return Err(invalid("invalid time")),
+        };
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(invalid("time does not exist"));
+        }
+
+        if let Some(fraction) = fraction {
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(invalid("invalid fraction of a second"));
+            }
+            // Keep nanosecond precision, pad shorter fractions with zeros
+            let digits: String = fraction
+                .chars()
+                .chain("000000000".chars())
+                .take(9)
+                .collect();
+            nanos = digits.parse().unwrap_or_default();
+        }
+
+        seconds += i64::from(hour) * 3_600 + i64::from(minute) * 60 + i64::from(second) - offset;
+    }
+
+    let time = if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::new(seconds.unsigned_abs(), nanos))
+    } else {
+        UNIX_EPOCH
+            .checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+            .and_then(|t| t.checked_add(Duration::from_nanos(u64::from(nanos))))
+    };
+    time.ok_or_else(|| invalid("date is out of range"))
+}
+
+/// Format a point in time as an RFC 3339 timestamp in UTC
+///
+/// This is the inverse of [`parse_datetime`]: fractions of a second are
+/// only written when present.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::format_datetime;
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// assert_eq!(format_datetime(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+/// assert_eq!(
+///     format_datetime(UNIX_EPOCH + Duration::from_millis(86_400_500)),
+///     "1970-01-02T00:00:00.500000000Z"
+/// );
+/// ```
+pub fn format_datetime(time: SystemTime) -> String {
+    let (seconds, nanos) = match time.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => (elapsed.as_secs() as i64, elapsed.subsec_nanos()),
+        Err(e) => {
+            let before = e.duration();
+            let mut seconds = -(before.as_secs() as i64);
+            let mut nanos = before.subsec_nanos();
+            if nanos > 0 {
+                seconds -= 1;
+                nanos = 1_000_000_000 - nanos;
+            }
+            (seconds, nanos)
+        }
+    };
+
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let second_of_day = seconds.rem_euclid(86_400);
+    let mut formatted = format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        second_of_day / 3_600,
+        second_of_day % 3_600 / 60,
+        second_of_day % 60
+    );
+    if nanos > 0 {
+        formatted.push_str(&format!(".{:09}", nanos));
+    }
+    formatted.push('Z');
+    formatted
+}
+
+/// Parse a string as a regular expression
+///
+/// The pattern is compiled with the `regex` crate syntax, so that a
+/// malformed pattern is reported when the command line is parsed rather
+/// than when the handler uses it.
+///
+/// # Returns
+///
+/// The pattern unchanged
+///
+/// # Errors
+///
+/// Returns [`ParseError::TypeParseError`] with the syntax error if the
+/// pattern is invalid.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::parser::type_parser::parse_regex;
+///
+/// assert_eq!(parse_regex(r"^\d+$").unwrap(), r"^\d+$");
+/// assert!(parse_regex("(unclosed").is_err());
+/// ```
+pub fn parse_regex(value: &str) -> Result<String> {
+    regex::Regex::new(value).map_err(|e| type_error("regex", value, e.to_string()))?;
+    Ok(value.to_string())
+}
+
+/// Build the error returned by the type parsers
+fn type_error(expected_type: &str, value: &str, details: String) -> crate::error::DynamicCliError {
+    ParseError::TypeParseError {
+        arg_name: "value".to_string(),
+        expected_type: expected_type.to_string(),
+        value: value.to_string(),
+        details: Some(details),
+    }
+    .into()
+}
+
+/// Parse a field of exactly `digits` ASCII digits
+fn parse_digits(field: &str, digits: usize) -> Option<u32> {
+    if field.len() == digits && field.bytes().all(|b| b.is_ascii_digit()) {
+        field.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Split the UTC offset off a time, returning the offset in seconds
+fn split_offset(time: &str) -> Option<(&str, i64)> {
+    if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        return Some((clock, 0));
+    }
+    let Some(sign_pos) = time.rfind(['+', '-']) else {
+        return Some((time, 0));
+    };
+
+    let (clock, offset) = time.split_at(sign_pos);
+    let sign = if offset.starts_with('-') { -1 } else { 1 };
+    let (hours, minutes) = offset[1..].split_once(':')?;
+    let (hours, minutes) = (parse_digits(hours, 2)?, parse_digits(minutes, 2)?);
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some((
+        clock,
+        sign * (i64::from(hours) * 3_600 + i64::from(minutes) * 60),
+    ))
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Number of days between 1970-01-01 and the given date
+///
+/// Proleptic Gregorian calendar, after Howard Hinnant's `days_from_civil`.
+fn days_from_civil(year: u32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`]
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,6 +1041,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_parse_float_decimal() {
         assert_eq!(parse_float("3.14").unwrap(), 3.14);
         assert_eq!(parse_float("-1.5").unwrap(), -1.5);
@@ -448,37 +1076,37 @@ mod tests {
     #[test]
     fn test_parse_bool_true_variants() {
         // All true variants
-        assert_eq!(parse_bool("true").unwrap(), true);
-        assert_eq!(parse_bool("True").unwrap(), true);
-        assert_eq!(parse_bool("TRUE").unwrap(), true);
-        assert_eq!(parse_bool("yes").unwrap(), true);
-        assert_eq!(parse_bool("YES").unwrap(), true);
-        assert_eq!(parse_bool("y").unwrap(), true);
-        assert_eq!(parse_bool("Y").unwrap(), true);
-        assert_eq!(parse_bool("1").unwrap(), true);
-        assert_eq!(parse_bool("on").unwrap(), true);
-        assert_eq!(parse_bool("ON").unwrap(), true);
+        assert!(parse_bool("true").unwrap());
+        assert!(parse_bool("True").unwrap());
+        assert!(parse_bool("TRUE").unwrap());
+        assert!(parse_bool("yes").unwrap());
+        assert!(parse_bool("YES").unwrap());
+        assert!(parse_bool("y").unwrap());
+        assert!(parse_bool("Y").unwrap());
+        assert!(parse_bool("1").unwrap());
+        assert!(parse_bool("on").unwrap());
+        assert!(parse_bool("ON").unwrap());
     }
 
     #[test]
     fn test_parse_bool_false_variants() {
         // All false variants
-        assert_eq!(parse_bool("false").unwrap(), false);
-        assert_eq!(parse_bool("False").unwrap(), false);
-        assert_eq!(parse_bool("FALSE").unwrap(), false);
-        assert_eq!(parse_bool("no").unwrap(), false);
-        assert_eq!(parse_bool("NO").unwrap(), false);
-        assert_eq!(parse_bool("n").unwrap(), false);
-        assert_eq!(parse_bool("N").unwrap(), false);
-        assert_eq!(parse_bool("0").unwrap(), false);
-        assert_eq!(parse_bool("off").unwrap(), false);
-        assert_eq!(parse_bool("OFF").unwrap(), false);
+        assert!(!parse_bool("false").unwrap());
+        assert!(!parse_bool("False").unwrap());
+        assert!(!parse_bool("FALSE").unwrap());
+        assert!(!parse_bool("no").unwrap());
+        assert!(!parse_bool("NO").unwrap());
+        assert!(!parse_bool("n").unwrap());
+        assert!(!parse_bool("N").unwrap());
+        assert!(!parse_bool("0").unwrap());
+        assert!(!parse_bool("off").unwrap());
+        assert!(!parse_bool("OFF").unwrap());
     }
 
     #[test]
     fn test_parse_bool_with_whitespace() {
-        assert_eq!(parse_bool("  true  ").unwrap(), true);
-        assert_eq!(parse_bool("\tfalse\n").unwrap(), false);
+        assert!(parse_bool("  true  ").unwrap());
+        assert!(!parse_bool("\tfalse\n").unwrap());
     }
 
     #[test]
@@ -525,6 +1153,206 @@ mod tests {
         assert!(path.to_str().unwrap().contains("spaces"));
     }
 
+    // ========================================================================
+    // parse_duration tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_duration_units() {
+        assert_eq!(parse_duration("250ns").unwrap(), Duration::from_nanos(250));
+        assert_eq!(parse_duration("3us").unwrap(), Duration::from_micros(3));
+        assert_eq!(parse_duration("3µs").unwrap(), Duration::from_micros(3));
+        assert_eq!(parse_duration("500ms").unwrap(), Duration::from_millis(500));
+        assert_eq!(parse_duration("45s").unwrap(), Duration::from_secs(45));
+        assert_eq!(parse_duration("2m").unwrap(), Duration::from_secs(120));
+        assert_eq!(parse_duration("1d").unwrap(), Duration::from_secs(86_400));
+    }
+
+    #[test]
+    fn test_parse_duration_compound_and_fractions() {
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::from_secs(5_400));
+        assert_eq!(parse_duration("1.5h").unwrap(), Duration::from_secs(5_400));
+        assert_eq!(parse_duration("0.5").unwrap(), Duration::from_millis(500));
+        assert_eq!(
+            parse_duration(&crate::utils::format_duration(Duration::from_secs(3_665))).unwrap(),
+            Duration::from_secs(3_665)
+        );
+    }
+
+    #[test]
+    fn test_parse_duration_invalid() {
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("-5").is_err());
+        assert!(parse_duration("5").is_ok());
+        assert!(parse_duration("5x").is_err());
+        assert!(parse_duration("ms").is_err());
+        assert!(parse_duration("1h30").is_err());
+
+        let message = parse_duration("10 weeks").unwrap_err().to_string();
+        assert!(message.contains("duration"));
+    }
+
+    // ========================================================================
+    // parse_bytes tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_bytes_units() {
+        assert_eq!(parse_bytes("0").unwrap(), 0);
+        assert_eq!(parse_bytes("512B").unwrap(), 512);
+        assert_eq!(parse_bytes("2k").unwrap(), 2_048);
+        assert_eq!(parse_bytes("2 KiB").unwrap(), 2_048);
+        assert_eq!(parse_bytes("3MB").unwrap(), 3 << 20);
+        assert_eq!(parse_bytes("1G").unwrap(), 1 << 30);
+        assert_eq!(parse_bytes("2TiB").unwrap(), 2 << 40);
+        assert_eq!(parse_bytes("1.5K").unwrap(), 1_536);
+    }
+
+    #[test]
+    fn test_parse_bytes_accepts_format_bytes_output() {
+        let formatted = crate::utils::format_bytes(1_536);
+        assert_eq!(parse_bytes(&formatted).unwrap(), 1_536);
+    }
+
+    #[test]
+    fn test_parse_bytes_invalid() {
+        assert!(parse_bytes("").is_err());
+        assert!(parse_bytes("K").is_err());
+        assert!(parse_bytes("-1").is_err());
+        assert!(parse_bytes("1.2.3K").is_err());
+        assert!(parse_bytes("5 apples").is_err());
+        assert!(parse_bytes("99999999999T").is_err());
+    }
+
+    // ========================================================================
+    // parse_url tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_url_valid() {
+        assert!(parse_url("http://localhost").is_ok());
+        assert!(parse_url("https://user:pw@example.com:8443/a?b=c#d").is_ok());
+        assert!(parse_url("postgres+ssl://db.internal:5432/app").is_ok());
+        assert!(parse_url("http://[2001:db8::1]:80/").is_ok());
+        assert!(parse_url("file:///etc/hosts").is_ok());
+    }
+
+    #[test]
+    fn test_parse_url_invalid() {
+        assert!(parse_url("localhost:8080").is_err());
+        assert!(parse_url("1http://example.com").is_err());
+        assert!(parse_url("http://").is_err());
+        assert!(parse_url("http://example.com:port").is_err());
+        assert!(parse_url("http://[::1").is_err());
+        assert!(parse_url("http://[::1]x").is_err());
+        assert!(parse_url("http://exa mple.com").is_err());
+    }
+
+    // ========================================================================
+    // parse_ip tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_ip() {
+        assert_eq!(parse_ip("10.0.0.1").unwrap(), IpAddr::from([10, 0, 0, 1]));
+        assert_eq!(
+            parse_ip("::1").unwrap(),
+            IpAddr::from(std::net::Ipv6Addr::LOCALHOST)
+        );
+        assert!(parse_ip("10.0.0").is_err());
+        assert!(parse_ip("").is_err());
+    }
+
+    // ========================================================================
+    // parse_datetime tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_datetime_formats() {
+        let noon = UNIX_EPOCH + Duration::from_secs(19_844 * 86_400 + 12 * 3_600);
+
+        assert_eq!(parse_datetime("2024-05-01T12:00:00Z").unwrap(), noon);
+        assert_eq!(parse_datetime("2024-05-01t12:00:00z").unwrap(), noon);
+        assert_eq!(parse_datetime("2024-05-01 12:00").unwrap(), noon);
+        assert_eq!(parse_datetime("2024-05-01T14:00:00+02:00").unwrap(), noon);
+        assert_eq!(parse_datetime("2024-05-01T09:30:00-02:30").unwrap(), noon);
+        assert_eq!(
+            parse_datetime("2024-05-01").unwrap(),
+            noon - Duration::from_secs(12 * 3_600)
+        );
+        assert_eq!(
+            parse_datetime("2024-05-01T12:00:00.25Z").unwrap(),
+            noon + Duration::from_millis(250)
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_before_epoch() {
+        assert_eq!(
+            parse_datetime("1969-12-31T23:59:59.5Z").unwrap(),
+            UNIX_EPOCH - Duration::from_millis(500)
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_invalid() {
+        assert!(parse_datetime("2024-13-01").is_err());
+        assert!(parse_datetime("2023-02-29").is_err());
+        assert!(parse_datetime("2024-02-29").is_ok());
+        assert!(parse_datetime("2024-5-1").is_err());
+        assert!(parse_datetime("2024-05-01T24:00").is_err());
+        assert!(parse_datetime("2024-05-01T12").is_err());
+        assert!(parse_datetime("2024-05-01T12:00.5").is_err());
+        assert!(parse_datetime("2024-05-01T12:00:00+2").is_err());
+        assert!(parse_datetime("2024-05-01T12:00:00.Z").is_err());
+    }
+
+    #[test]
+    fn test_format_datetime_roundtrip() {
+        for text in [
+            "2024-02-29T23:59:59Z",
+            "1969-07-20T20:17:40Z",
+            "2000-01-01T00:00:00.000000001Z",
+            "1900-03-01T06:00:00Z",
+        ] {
+            assert_eq!(format_datetime(parse_datetime(text).unwrap()), text);
+        }
+    }
+
+    // ========================================================================
+    // parse_regex tests
+    // ========================================================================
+
+    #[test]
+    fn test_parse_regex() {
+        assert!(parse_regex(r"(?i)^[a-z_]\w*$").is_ok());
+        assert!(parse_regex("").is_ok());
+
+        let message = parse_regex("a{2,1}").unwrap_err().to_string();
+        assert!(message.contains("regex"));
+        assert!(parse_regex("[z-a]").is_err());
+    }
+
+    #[test]
+    fn test_parse_typed_value_richer_types() {
+        assert_eq!(
+            parse_typed_value("2s", ArgumentType::Duration).unwrap(),
+            Value::Duration(Duration::from_secs(2))
+        );
+        assert_eq!(
+            parse_typed_value("1K", ArgumentType::Bytes).unwrap(),
+            Value::Bytes(1_024)
+        );
+        assert_eq!(
+            parse_typed_value("::", ArgumentType::Ip).unwrap(),
+            Value::Ip(IpAddr::from(std::net::Ipv6Addr::UNSPECIFIED))
+        );
+        assert_eq!(
+            parse_typed_value("a|b", ArgumentType::Regex).unwrap(),
+            Value::Regex("a|b".to_string())
+        );
+    }
+
     // ========================================================================
     // Integration tests
     // ========================================================================
@@ -538,6 +1366,12 @@ mod tests {
             ("3.14", ArgumentType::Float),
             ("true", ArgumentType::Bool),
             ("/tmp/file", ArgumentType::Path),
+            ("1h30m", ArgumentType::Duration),
+            ("4K", ArgumentType::Bytes),
+            ("https://example.com", ArgumentType::Url),
+            ("127.0.0.1", ArgumentType::Ip),
+            ("2024-05-01T12:00:00Z", ArgumentType::DateTime),
+            ("^a+$", ArgumentType::Regex),
         ];
 
         for (value, arg_type) in test_cases {
@@ -5,9 +5,16 @@
 //!
 //! # Architecture
 //!
-//! The registry maintains two main data structures:
-//! - A map of command names to their definitions and handlers
-//! - A map of aliases to canonical command names
+//! The registry maintains three main data structures:
+//! - A map of command paths to their definitions and handlers
+//! - A map of aliases to canonical command paths
+//! - A map of command paths to groups (commands without a handler)
+//!
+//! Subcommands are stored under their canonical path, with one word per
+//! level (`"remote add"`); aliases are qualified by their parent's path.
+//!
+//! It also holds the application's global options, which are shared
+//! by every command and consulted by both the CLI and REPL parsers.
 //!
 //! This design allows O(1) lookup by both command name and alias.
 //!
@@ -31,6 +38,7 @@
 //!     arguments: vec![],
 //!     options: vec![],
 //!     implementation: "hello_handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! // Create a handler
@@ -57,9 +65,12 @@
 //! # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 //! ```
 
-use crate::config::schema::CommandDefinition;
-use crate::error::{RegistryError, Result};
+use crate::config::schema::{CommandDefinition, OptionDefinition};
+use crate::context::ExecutionContext;
+use crate::error::{ParseError, RegistryError, Result};
 use crate::executor::CommandHandler;
+use crate::validator::constraint_validator::{parse_constraints, Constraint};
+use crate::validator::custom_validator::{ArgumentValidator, CustomValidators};
 use std::collections::HashMap;
 
 /// Central registry for commands and their handlers
@@ -92,6 +103,7 @@ use std::collections::HashMap;
 /// #     arguments: vec![],
 /// #     options: vec![],
 /// #     implementation: "test_handler".to_string(),
+/// #     ..Default::default()
 /// # };
 /// # struct TestCommand;
 /// # impl CommandHandler for TestCommand {
@@ -106,17 +118,30 @@ use std::collections::HashMap;
 /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
 /// ```
 pub struct CommandRegistry {
-    /// Map of command names to their data
-    /// Key: canonical command name
+    /// Map of command paths to their data
+    /// Key: canonical command path (`"remote add"` for subcommands)
     /// Value: (CommandDefinition, Box<dyn CommandHandler>)
     commands: HashMap<String, (CommandDefinition, Box<dyn CommandHandler>)>,
 
-    /// Map of aliases to canonical command names
-    /// Key: alias
-    /// Value: canonical command name
+    /// Map of aliases to canonical command paths
+    /// Key: alias, qualified by the parent's path
+    /// Value: canonical command path
     ///
     /// This allows O(1) resolution of aliases to command names.
     aliases: HashMap<String, String>,
+
+    /// Commands registered without a handler, which only group subcommands
+    /// Key: canonical command path
+    groups: HashMap<String, CommandDefinition>,
+
+    /// Options accepted by every command (from `global_options`)
+    global_options: Vec<OptionDefinition>,
+
+    /// Validators backing `custom:` validation rules (name -> validator)
+    validators: HashMap<String, Box<dyn ArgumentValidator>>,
+
+    /// Parsed `constraints` of each command, by canonical command path
+    constraints: HashMap<String, Vec<Constraint>>,
 }
 
 impl CommandRegistry {
@@ -134,14 +159,121 @@ impl CommandRegistry {
         Self {
             commands: HashMap::new(),
             aliases: HashMap::new(),
+            groups: HashMap::new(),
+            global_options: Vec::new(),
+            validators: HashMap::new(),
+            constraints: HashMap::new(),
         }
     }
 
+    /// Set the global options accepted by every command
+    ///
+    /// Replaces any previously set global options. Parsers built from
+    /// this registry accept these options before or after the command
+    /// name (see [`global_options`](crate::parser::global_options)).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::config::schema::{ArgumentType, OptionDefinition};
+    ///
+    /// let mut registry = CommandRegistry::new();
+    /// registry.set_global_options(vec![OptionDefinition {
+    ///     name: "verbose".to_string(),
+    ///     short: Some("v".to_string()),
+    ///     long: Some("verbose".to_string()),
+    ///     option_type: ArgumentType::Bool,
+    ///     required: false,
+    ///     default: None,
+    ///     description: "Verbose output".to_string(),
+    ///     choices: vec![],
+    ///     ..Default::default()
+    /// }]);
+    ///
+    /// assert_eq!(registry.global_options().len(), 1);
+    /// ```
+    pub fn set_global_options(&mut self, options: Vec<OptionDefinition>) {
+        self.global_options = options;
+    }
+
+    /// Get the global options accepted by every command
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    ///
+    /// let registry = CommandRegistry::new();
+    /// assert!(registry.global_options().is_empty());
+    /// ```
+    pub fn global_options(&self) -> &[OptionDefinition] {
+        &self.global_options
+    }
+
+    /// Register a validator for `custom:` validation rules
+    ///
+    /// A validator registered under an existing name replaces the
+    /// previous one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::context::ExecutionContext;
+    /// use dynamic_cli::validator::ArgumentValidator;
+    ///
+    /// struct AlwaysOk;
+    /// impl ArgumentValidator for AlwaysOk {
+    ///     fn validate(
+    ///         &self,
+    ///         _: &dyn ExecutionContext,
+    ///         _: &str,
+    ///         _: &str,
+    ///         _: &serde_json::Value,
+    ///     ) -> dynamic_cli::Result<()> {
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register_validator("always_ok", Box::new(AlwaysOk));
+    ///
+    /// assert!(registry.has_validator("always_ok"));
+    /// ```
+    pub fn register_validator(
+        &mut self,
+        name: impl Into<String>,
+        validator: Box<dyn ArgumentValidator>,
+    ) {
+        self.validators.insert(name.into(), validator);
+    }
+
+    /// Check whether a validator is registered under `name`
+    pub fn has_validator(&self, name: &str) -> bool {
+        self.validators.contains_key(name)
+    }
+
+    /// Bind the registered validators to an execution context
+    ///
+    /// The result is passed to
+    /// [`CliParser::with_custom_validators`](crate::parser::CliParser::with_custom_validators)
+    /// so that `custom:` rules are checked while parsing.
+    pub fn custom_validators<'a>(
+        &'a self,
+        context: &'a dyn ExecutionContext,
+    ) -> CustomValidators<'a> {
+        CustomValidators::new(&self.validators, context)
+    }
+
     /// Register a command with its handler
     ///
     /// This method registers a command definition along with its handler.
     /// It also registers all aliases for the command.
     ///
+    /// Declared subcommands are recorded without handlers; attach them
+    /// with [`register_subcommand`](Self::register_subcommand).
+    ///
     /// # Arguments
     ///
     /// * `definition` - The command definition from the configuration
@@ -158,6 +290,9 @@ impl CommandRegistry {
     ///
     /// - [`RegistryError::DuplicateRegistration`] if the command name already exists
     /// - [`RegistryError::DuplicateAlias`] if an alias is already in use
+    /// - [`ConfigError::InvalidSchema`](crate::error::ConfigError::InvalidSchema)
+    ///   if a `constraints` check of the command or of a declared
+    ///   subcommand cannot be parsed
     ///
     /// # Example
     ///
@@ -177,6 +312,7 @@ impl CommandRegistry {
     ///     arguments: vec![],
     ///     options: vec![],
     ///     implementation: "sim_handler".to_string(),
+    ///     ..Default::default()
     /// };
     ///
     /// struct SimCommand;
@@ -203,43 +339,207 @@ impl CommandRegistry {
         definition: CommandDefinition,
         handler: Box<dyn CommandHandler>,
     ) -> Result<()> {
-        let cmd_name = &definition.name;
+        self.insert_node("", definition, Some(handler))
+    }
+
+    /// Register a command that only groups subcommands
+    ///
+    /// A group has no handler of its own: invoking it without a
+    /// subcommand is an error. Its declared subcommands are recorded so
+    /// that they can be resolved and listed; handlers are attached to
+    /// them with [`register_subcommand`](Self::register_subcommand).
+    ///
+    /// # Errors
+    ///
+    /// Same as [`register`](Self::register).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::config::schema::CommandDefinition;
+    ///
+    /// # fn command(name: &str, subcommands: Vec<CommandDefinition>) -> CommandDefinition {
+    /// #     CommandDefinition {
+    /// #         name: name.to_string(),
+    /// #         aliases: vec![],
+    /// #         description: "".to_string(),
+    /// #         required: false,
+    /// #         arguments: vec![],
+    /// #         options: vec![],
+    /// #         implementation: format!("{}_handler", name),
+    /// #         subcommands,
+    /// #         ..Default::default()
+    /// #     }
+    /// # }
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register_group(command("remote", vec![command("add", vec![])]))?;
+    ///
+    /// assert!(registry.contains("remote add"));
+    /// assert!(registry.get_handler("remote").is_none());
+    /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+    /// ```
+    pub fn register_group(&mut self, definition: CommandDefinition) -> Result<()> {
+        self.insert_node("", definition, None)
+    }
+
+    /// Register a subcommand with its handler
+    ///
+    /// `parent` is the path of the parent command, with one word per
+    /// level (`"remote"`, `"remote branch"`); aliases are accepted at
+    /// every level. If the parent already declares a subcommand with
+    /// the same name, the declared entry receives the handler;
+    /// otherwise the subcommand is added to the parent.
+    ///
+    /// # Errors
+    ///
+    /// - [`RegistryError::UnknownParent`] if `parent` is not registered
+    /// - [`RegistryError::DuplicateRegistration`] if the subcommand already has a handler
+    /// - [`RegistryError::DuplicateAlias`] if an alias is already used by a sibling
+    /// - [`ConfigError::InvalidSchema`](crate::error::ConfigError::InvalidSchema)
+    ///   if a `constraints` check cannot be parsed
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::config::schema::CommandDefinition;
+    /// use dynamic_cli::executor::CommandHandler;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn command(name: &str, subcommands: Vec<CommandDefinition>) -> CommandDefinition {
+    /// #     CommandDefinition {
+    /// #         name: name.to_string(),
+    /// #         aliases: vec![],
+    /// #         description: "".to_string(),
+    /// #         required: false,
+    /// #         arguments: vec![],
+    /// #         options: vec![],
+    /// #         implementation: format!("{}_handler", name),
+    /// #         subcommands,
+    /// #         ..Default::default()
+    /// #     }
+    /// # }
+    /// # struct AddCommand;
+    /// # impl CommandHandler for AddCommand {
+    /// #     fn execute(&self, _: &mut dyn dynamic_cli::context::ExecutionContext, _: &HashMap<String, String>) -> dynamic_cli::Result<()> { Ok(()) }
+    /// # }
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register_group(command("remote", vec![command("add", vec![])]))?;
+    /// registry.register_subcommand("remote", command("add", vec![]), Box::new(AddCommand))?;
+    ///
+    /// assert!(registry.get_handler("remote add").is_some());
+    /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+    /// ```
+    pub fn register_subcommand(
+        &mut self,
+        parent: &str,
+        definition: CommandDefinition,
+        handler: Box<dyn CommandHandler>,
+    ) -> Result<()> {
+        let parent_path = self
+            .resolve_name(parent)
+            .ok_or_else(|| RegistryError::UnknownParent {
+                parent: parent.to_string(),
+                subcommand: definition.name.clone(),
+                suggestion: Some(format!("Register '{}' before its subcommands.", parent)),
+            })?
+            .to_string();
+        let path = child_path(&parent_path, &definition.name);
+
+        if let Some(declared) = self.groups.remove(&path) {
+            // Replace the placeholder recorded when the parent was registered
+            self.aliases.retain(|_, target| target != &path);
+            if let Err(e) = self.insert_node(&parent_path, definition, Some(handler)) {
+                self.restore_placeholder(&parent_path, declared);
+                return Err(e);
+            }
+            return Ok(());
+        }
+
+        let declared = definition.clone();
+        self.insert_node(&parent_path, definition, Some(handler))?;
+        if let Some(parent_def) = self.definition_mut(&parent_path) {
+            parent_def.subcommands.push(declared);
+        }
+
+        Ok(())
+    }
+
+    /// Insert a node (and placeholders for its subcommands) under `parent`
+    ///
+    /// Either the whole subtree is inserted or, on error, nothing is.
+    fn insert_node(
+        &mut self,
+        parent: &str,
+        definition: CommandDefinition,
+        handler: Option<Box<dyn CommandHandler>>,
+    ) -> Result<()> {
+        let mut inserted = Vec::new();
+        let result = self.insert_tree(parent, definition, handler, &mut inserted);
+        if result.is_err() {
+            // Every key was new when it was inserted, so removing it from
+            // all maps leaves earlier registrations untouched
+            for key in &inserted {
+                self.commands.remove(key);
+                self.groups.remove(key);
+                self.constraints.remove(key);
+                self.aliases.remove(key);
+            }
+        }
+        result
+    }
+
+    /// Insert a subtree, logging the keys it adds in `inserted`
+    fn insert_tree(
+        &mut self,
+        parent: &str,
+        definition: CommandDefinition,
+        handler: Option<Box<dyn CommandHandler>>,
+        inserted: &mut Vec<String>,
+    ) -> Result<()> {
+        let path = child_path(parent, &definition.name);
 
         // Check if command name is already registered
-        if self.commands.contains_key(cmd_name) {
+        if self.commands.contains_key(&path) || self.groups.contains_key(&path) {
             return Err(RegistryError::DuplicateRegistration {
-                name: cmd_name.clone(),
+                name: path,
                 suggestion: None,
             }
             .into());
         }
 
         // Check if command name conflicts with existing alias
-        if self.aliases.contains_key(cmd_name) {
-            let existing_cmd = self.aliases.get(cmd_name).unwrap();
+        if let Some(existing_cmd) = self.aliases.get(&path) {
             return Err(RegistryError::DuplicateAlias {
-                alias: cmd_name.clone(),
+                alias: definition.name.clone(),
                 existing_command: existing_cmd.clone(),
                 suggestion: None,
             }
             .into());
         }
 
+        // Parse constraints up front, so that parsing only evaluates them;
+        // those of declared subcommands too, before anything is recorded
+        let constraints = parse_constraints(&definition)?;
+        check_subcommand_constraints(&definition)?;
+
         // Check all aliases for conflicts
         for alias in &definition.aliases {
+            let alias_path = child_path(parent, alias);
+
             // Check if alias conflicts with existing command name
-            if self.commands.contains_key(alias) {
+            if self.commands.contains_key(&alias_path) || self.groups.contains_key(&alias_path) {
                 return Err(RegistryError::DuplicateAlias {
                     alias: alias.clone(),
-                    existing_command: alias.clone(),
+                    existing_command: alias_path,
                     suggestion: None,
                 }
                 .into());
             }
 
             // Check if alias conflicts with existing alias
-            if self.aliases.contains_key(alias) {
-                let existing_cmd = self.aliases.get(alias).unwrap();
+            if let Some(existing_cmd) = self.aliases.get(&alias_path) {
                 return Err(RegistryError::DuplicateAlias {
                     alias: alias.clone(),
                     existing_command: existing_cmd.clone(),
@@ -251,29 +551,71 @@ impl CommandRegistry {
 
         // Register all aliases
         for alias in &definition.aliases {
-            self.aliases.insert(alias.clone(), cmd_name.clone());
+            let alias_path = child_path(parent, alias);
+            self.aliases.insert(alias_path.clone(), path.clone());
+            inserted.push(alias_path);
+        }
+
+        // Record declared subcommands; handlers are attached later
+        for child in &definition.subcommands {
+            let declared_path = child_path(&path, &child.name);
+            if !self.commands.contains_key(&declared_path)
+                && !self.groups.contains_key(&declared_path)
+            {
+                self.insert_tree(&path, child.clone(), None, inserted)?;
+            }
         }
 
         // Register the command
-        self.commands
-            .insert(cmd_name.clone(), (definition, handler));
+        inserted.push(path.clone());
+        self.constraints.insert(path.clone(), constraints);
+        match handler {
+            Some(handler) => {
+                self.commands.insert(path, (definition, handler));
+            }
+            None => {
+                self.groups.insert(path, definition);
+            }
+        }
 
         Ok(())
     }
 
+    /// Put back a placeholder removed by a failed subcommand registration
+    fn restore_placeholder(&mut self, parent: &str, declared: CommandDefinition) {
+        let path = child_path(parent, &declared.name);
+        for alias in &declared.aliases {
+            self.aliases
+                .entry(child_path(parent, alias))
+                .or_insert_with(|| path.clone());
+        }
+        self.groups.insert(path, declared);
+    }
+
+    /// Mutable access to a stored definition by canonical path
+    fn definition_mut(&mut self, path: &str) -> Option<&mut CommandDefinition> {
+        match self.commands.get_mut(path) {
+            Some((definition, _)) => Some(definition),
+            None => self.groups.get_mut(path),
+        }
+    }
+
     /// Resolve a name (command or alias) to the canonical command name
     ///
     /// This method checks if the given name is either:
     /// - A registered command name (returns the name itself)
     /// - An alias (returns the canonical command name)
     ///
+    /// Subcommands are addressed by their whitespace-separated path
+    /// (`"remote add"`), and each level may use an alias (`"r a"`).
+    ///
     /// # Arguments
     ///
-    /// * `name` - The name or alias to resolve
+    /// * `name` - The name, alias or path to resolve
     ///
     /// # Returns
     ///
-    /// - `Some(&str)` - The canonical command name
+    /// - `Some(&str)` - The canonical command name (or path)
     /// - `None` - If the name is not registered
     ///
     /// # Example
@@ -294,6 +636,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # struct TestCmd;
     /// # impl CommandHandler for TestCmd {
@@ -310,14 +653,188 @@ impl CommandRegistry {
     /// assert_eq!(registry.resolve_name("unknown"), None);
     /// ```
     pub fn resolve_name(&self, name: &str) -> Option<&str> {
-        // First check if it's a command name
-        // Return reference to the stored name, not the parameter
-        if let Some((cmd_def, _)) = self.commands.get(name) {
-            return Some(cmd_def.name.as_str());
+        // Walk the path one level at a time so that aliases
+        // can be used at every level ("r a" for "remote add")
+        let mut path: Option<&str> = None;
+        for segment in name.split_whitespace() {
+            let candidate = match path {
+                Some(parent) => child_path(parent, segment),
+                None => segment.to_string(),
+            };
+            path = Some(self.lookup(&candidate)?);
+        }
+        path
+    }
+
+    /// Resolve a single qualified name or alias to its canonical path
+    fn lookup(&self, path: &str) -> Option<&str> {
+        // Return reference to the stored key, not the parameter
+        if let Some((key, _)) = self.commands.get_key_value(path) {
+            return Some(key.as_str());
+        }
+        if let Some((key, _)) = self.groups.get_key_value(path) {
+            return Some(key.as_str());
         }
 
         // Then check if it's an alias
-        self.aliases.get(name).map(|s| s.as_str())
+        self.aliases.get(path).map(|s| s.as_str())
+    }
+
+    /// Resolve the command addressed by the leading tokens of a command line
+    ///
+    /// The first token names a top-level command; each following token
+    /// descends into a subcommand for as long as it matches one. The
+    /// remaining tokens are the arguments of the resolved command.
+    ///
+    /// # Arguments
+    ///
+    /// * `tokens` - Command line tokens, starting with the command name
+    ///
+    /// # Returns
+    ///
+    /// The canonical path of the command and the number of tokens consumed
+    ///
+    /// # Errors
+    ///
+    /// - [`ParseError::UnknownCommand`] if the first token is not a command,
+    ///   or if a group is followed by a word that is not one of its subcommands
+    /// - [`ParseError::InvalidSyntax`] if a group is invoked without a subcommand
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::config::schema::CommandDefinition;
+    /// use dynamic_cli::executor::CommandHandler;
+    /// use std::collections::HashMap;
+    ///
+    /// # fn command(name: &str, aliases: Vec<&str>, subcommands: Vec<CommandDefinition>) -> CommandDefinition {
+    /// #     CommandDefinition {
+    /// #         name: name.to_string(),
+    /// #         aliases: aliases.iter().map(|a| a.to_string()).collect(),
+    /// #         description: "".to_string(),
+    /// #         required: false,
+    /// #         arguments: vec![],
+    /// #         options: vec![],
+    /// #         implementation: format!("{}_handler", name),
+    /// #         subcommands,
+    /// #         ..Default::default()
+    /// #     }
+    /// # }
+    /// # struct AddCommand;
+    /// # impl CommandHandler for AddCommand {
+    /// #     fn execute(&self, _: &mut dyn dynamic_cli::context::ExecutionContext, _: &HashMap<String, String>) -> dynamic_cli::Result<()> { Ok(()) }
+    /// # }
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register_group(command("remote", vec!["r"], vec![command("add", vec!["a"], vec![])]))?;
+    /// registry.register_subcommand("remote", command("add", vec!["a"], vec![]), Box::new(AddCommand))?;
+    ///
+    /// let tokens: Vec<String> = ["r", "a", "origin"].iter().map(|s| s.to_string()).collect();
+    /// let (path, consumed) = registry.resolve_command(&tokens)?;
+    ///
+    /// assert_eq!(path, "remote add");
+    /// assert_eq!(consumed, 2);
+    /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+    /// ```
+    pub fn resolve_command(&self, tokens: &[String]) -> Result<(String, usize)> {
+        let first = tokens.first().map(String::as_str).unwrap_or_default();
+        let mut path = self
+            .lookup(first)
+            .ok_or_else(|| {
+                let available = Self::names_of(self.list_commands());
+                ParseError::unknown_command_with_suggestions(first, &available)
+            })?
+            .to_string();
+        let mut consumed = 1;
+
+        // Descend while the next token names a subcommand
+        while let Some(token) = tokens.get(consumed) {
+            match self.lookup(&child_path(&path, token)) {
+                Some(child) => {
+                    path = child.to_string();
+                    consumed += 1;
+                }
+                None => break,
+            }
+        }
+
+        // A group without handler needs one of its subcommands; a leaf
+        // without handler is reported by the caller when dispatching
+        let children = self.subcommands_of(&path);
+        if self.commands.contains_key(&path) || children.is_empty() {
+            return Ok((path, consumed));
+        }
+
+        match tokens.get(consumed) {
+            Some(token) if !token.starts_with('-') => {
+                let unknown = child_path(&path, token);
+                let available: Vec<String> = Self::names_of(children)
+                    .iter()
+                    .map(|name| child_path(&path, name))
+                    .collect();
+                Err(ParseError::unknown_command_with_suggestions(&unknown, &available).into())
+            }
+            _ => Err(ParseError::InvalidSyntax {
+                details: format!("'{}' requires a subcommand", path),
+                hint: Some(format!(
+                    "Available subcommands: {}",
+                    children
+                        .iter()
+                        .filter(|def| !def.hidden)
+                        .map(|def| def.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            }
+            .into()),
+        }
+    }
+
+    /// Get the subcommands of a command by name, alias or path
+    ///
+    /// Returns an empty list if the command has no subcommands or is not
+    /// registered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dynamic_cli::registry::CommandRegistry;
+    /// use dynamic_cli::config::schema::CommandDefinition;
+    ///
+    /// # fn command(name: &str, subcommands: Vec<CommandDefinition>) -> CommandDefinition {
+    /// #     CommandDefinition {
+    /// #         name: name.to_string(),
+    /// #         aliases: vec![],
+    /// #         description: "".to_string(),
+    /// #         required: false,
+    /// #         arguments: vec![],
+    /// #         options: vec![],
+    /// #         implementation: format!("{}_handler", name),
+    /// #         subcommands,
+    /// #         ..Default::default()
+    /// #     }
+    /// # }
+    /// let mut registry = CommandRegistry::new();
+    /// registry.register_group(command("remote", vec![command("add", vec![])]))?;
+    ///
+    /// let children = registry.subcommands_of("remote");
+    /// assert_eq!(children.len(), 1);
+    /// assert_eq!(children[0].name, "add");
+    /// # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+    /// ```
+    pub fn subcommands_of(&self, name: &str) -> Vec<&CommandDefinition> {
+        self.get_definition(name)
+            .map(|def| def.subcommands.iter().collect())
+            .unwrap_or_default()
+    }
+
+    /// Names and aliases of the visible definitions (for suggestions)
+    fn names_of(definitions: Vec<&CommandDefinition>) -> Vec<String> {
+        definitions
+            .iter()
+            .filter(|def| !def.hidden)
+            .flat_map(|def| std::iter::once(def.name.clone()).chain(def.aliases.clone()))
+            .collect()
     }
 
     /// Get the definition of a command by name or alias
@@ -347,6 +864,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # struct TestCmd;
     /// # impl CommandHandler for TestCmd {
@@ -366,7 +884,20 @@ impl CommandRegistry {
     /// ```
     pub fn get_definition(&self, name: &str) -> Option<&CommandDefinition> {
         let canonical_name = self.resolve_name(name)?;
-        self.commands.get(canonical_name).map(|(def, _)| def)
+        self.commands
+            .get(canonical_name)
+            .map(|(def, _)| def)
+            .or_else(|| self.groups.get(canonical_name))
+    }
+
+    /// Get the parsed `constraints` of a command by name or alias
+    ///
+    /// The result is passed to
+    /// [`CliParser::with_constraints`](crate::parser::CliParser::with_constraints)
+    /// so that checks are not parsed again for each invocation.
+    pub fn constraints(&self, name: &str) -> Option<&[Constraint]> {
+        let canonical_name = self.resolve_name(name)?;
+        self.constraints.get(canonical_name).map(Vec::as_slice)
     }
 
     /// Get the handler of a command by name or alias
@@ -399,6 +930,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # struct ExecCmd;
     /// # impl CommandHandler for ExecCmd {
@@ -451,6 +983,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # let def2 = CommandDefinition {
     /// #     name: "cmd2".to_string(),
@@ -460,6 +993,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # struct TestCmd;
     /// # impl CommandHandler for TestCmd {
@@ -476,11 +1010,28 @@ impl CommandRegistry {
     /// }
     /// ```
     pub fn list_commands(&self) -> Vec<&CommandDefinition> {
-        self.commands.values().map(|(def, _)| def).collect()
+        let top_level = |path: &&String| !path.contains(' ');
+
+        self.commands
+            .iter()
+            .filter(|(path, _)| top_level(path))
+            .map(|(_, (def, _))| def)
+            .chain(
+                self.groups
+                    .iter()
+                    .filter(|(path, _)| top_level(path))
+                    .map(|(_, def)| def),
+            )
+            .collect()
     }
 
     /// Get the number of registered commands
     ///
+    /// Counts the commands that have a handler, subcommands included;
+    /// groups without a handler are not counted. This differs from
+    /// [`list_commands`](Self::list_commands), which returns the top-level
+    /// commands and groups.
+    ///
     /// # Example
     ///
     /// ```
@@ -495,6 +1046,8 @@ impl CommandRegistry {
 
     /// Check if the registry is empty
     ///
+    /// True when no command has a handler, even if groups are registered.
+    ///
     /// # Example
     ///
     /// ```
@@ -525,6 +1078,7 @@ impl CommandRegistry {
     /// #     arguments: vec![],
     /// #     options: vec![],
     /// #     implementation: "".to_string(),
+    /// #     ..Default::default()
     /// # };
     /// # struct TestCmd;
     /// # impl CommandHandler for TestCmd {
@@ -540,6 +1094,15 @@ impl CommandRegistry {
     }
 }
 
+/// Build the canonical path of a subcommand from its parent's path
+fn child_path(parent: &str, name: &str) -> String {
+    if parent.is_empty() {
+        name.to_string()
+    } else {
+        format!("{} {}", parent, name)
+    }
+}
+
 // Implement Default for convenience
 impl Default for CommandRegistry {
     fn default() -> Self {
@@ -547,24 +1110,20 @@ impl Default for CommandRegistry {
     }
 }
 
+/// Check that the constraints of every declared subcommand parse
+fn check_subcommand_constraints(definition: &CommandDefinition) -> Result<()> {
+    for child in &definition.subcommands {
+        parse_constraints(child)?;
+        check_subcommand_constraints(child)?;
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use std::any::Any;
 
     // Test fixtures
-    #[derive(Default)]
-    struct TestContext;
-
-    impl crate::context::ExecutionContext for TestContext {
-        fn as_any(&self) -> &dyn Any {
-            self
-        }
-        fn as_any_mut(&mut self) -> &mut dyn Any {
-            self
-        }
-    }
-
     struct TestHandler;
 
     impl CommandHandler for TestHandler {
@@ -586,6 +1145,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: format!("{}_handler", name),
+            ..Default::default()
         }
     }
 
@@ -933,4 +1493,274 @@ mod tests {
         assert!(result.is_ok());
         assert!(registry.contains("test"));
     }
+
+    // Subcommand tests
+    fn create_remote_group() -> CommandDefinition {
+        let mut remote = create_test_definition("remote", vec!["r"]);
+        remote.subcommands = vec![
+            create_test_definition("add", vec!["a"]),
+            create_test_definition("remove", vec!["rm"]),
+        ];
+        remote
+    }
+
+    fn tokens(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_register_subcommand_resolves_paths() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+        registry
+            .register_subcommand(
+                "r",
+                create_test_definition("add", vec!["a"]),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+
+        assert_eq!(registry.resolve_name("remote add"), Some("remote add"));
+        assert_eq!(registry.resolve_name("r a"), Some("remote add"));
+        assert_eq!(registry.resolve_name("remote rm"), Some("remote remove"));
+        assert_eq!(registry.resolve_name("add"), None);
+        assert!(registry.get_handler("remote add").is_some());
+        assert!(registry.get_handler("remote remove").is_none());
+        assert!(registry.get_handler("remote").is_none());
+
+        // Only top-level commands are listed
+        let commands = registry.list_commands();
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].name, "remote");
+    }
+
+    #[test]
+    fn test_register_subcommand_unknown_parent() {
+        let mut registry = CommandRegistry::new();
+        let result = registry.register_subcommand(
+            "remote",
+            create_test_definition("add", vec![]),
+            Box::new(TestHandler),
+        );
+
+        match result.unwrap_err() {
+            crate::error::DynamicCliError::Registry(RegistryError::UnknownParent {
+                parent,
+                ..
+            }) => assert_eq!(parent, "remote"),
+            other => panic!("Expected UnknownParent, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_register_subcommand_twice_fails() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+        let add = || create_test_definition("add", vec!["a"]);
+
+        registry
+            .register_subcommand("remote", add(), Box::new(TestHandler))
+            .unwrap();
+        let result = registry.register_subcommand("remote", add(), Box::new(TestHandler));
+
+        assert!(result.is_err());
+        // The first registration is left intact
+        assert!(registry.get_handler("r a").is_some());
+    }
+
+    #[test]
+    fn test_register_undeclared_subcommand_extends_parent() {
+        let mut registry = CommandRegistry::new();
+        registry
+            .register(
+                create_test_definition("plugin", vec![]),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+        registry
+            .register_subcommand(
+                "plugin",
+                create_test_definition("install", vec![]),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+
+        assert!(registry.get_handler("plugin install").is_some());
+        assert_eq!(registry.subcommands_of("plugin").len(), 1);
+    }
+
+    #[test]
+    fn test_failed_registration_leaves_no_partial_tree() {
+        let mut registry = CommandRegistry::new();
+        let mut remote = create_remote_group();
+        remote
+            .subcommands
+            .push(create_test_definition("fetch", vec!["a"]));
+
+        // "fetch" reuses the alias of "add": nothing of "remote" is kept
+        assert!(registry.register_group(remote).is_err());
+        assert!(!registry.contains("remote"));
+        assert!(!registry.contains("r"));
+        assert!(!registry.contains("remote add"));
+
+        assert!(registry.register_group(create_remote_group()).is_ok());
+    }
+
+    #[test]
+    fn test_subcommand_aliases_are_scoped_to_parent() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+
+        // "add"/"a" under another parent does not conflict with "remote add"
+        let mut branch = create_test_definition("branch", vec![]);
+        branch.subcommands = vec![create_test_definition("add", vec!["a"])];
+        assert!(registry.register_group(branch).is_ok());
+        assert_eq!(registry.resolve_name("branch a"), Some("branch add"));
+    }
+
+    #[test]
+    fn test_resolve_command_consumes_path() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+        registry
+            .register_subcommand(
+                "remote",
+                create_test_definition("add", vec!["a"]),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+
+        let (path, consumed) = registry
+            .resolve_command(&tokens(&["r", "add", "origin", "--force"]))
+            .unwrap();
+        assert_eq!(path, "remote add");
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn test_resolve_command_group_requires_subcommand() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+
+        match registry.resolve_command(&tokens(&["remote"])).unwrap_err() {
+            crate::error::DynamicCliError::Parse(ParseError::InvalidSyntax { hint, .. }) => {
+                let hint = hint.unwrap();
+                assert!(hint.contains("add"));
+                assert!(hint.contains("remove"));
+            }
+            other => panic!("Expected InvalidSyntax, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_command_unknown_subcommand_suggests_children() {
+        let mut registry = CommandRegistry::new();
+        registry.register_group(create_remote_group()).unwrap();
+
+        match registry
+            .resolve_command(&tokens(&["remote", "ad"]))
+            .unwrap_err()
+        {
+            crate::error::DynamicCliError::Parse(ParseError::UnknownCommand {
+                command,
+                suggestions,
+            }) => {
+                assert_eq!(command, "remote ad");
+                assert!(suggestions.contains(&"remote add".to_string()));
+            }
+            other => panic!("Expected UnknownCommand, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolve_command_unknown_root() {
+        let mut registry = CommandRegistry::new();
+        registry
+            .register(
+                create_test_definition("hello", vec!["hi"]),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+
+        match registry.resolve_command(&tokens(&["helo"])).unwrap_err() {
+            crate::error::DynamicCliError::Parse(ParseError::UnknownCommand {
+                suggestions,
+                ..
+            }) => assert!(suggestions.contains(&"hello".to_string())),
+            other => panic!("Expected UnknownCommand, got {:?}", other),
+        }
+    }
+
+    fn constrained_definition(name: &str, check: &str) -> CommandDefinition {
+        let mut definition = create_test_definition(name, vec!["c"]);
+        definition
+            .options
+            .push(crate::config::schema::OptionDefinition {
+                name: "retries".to_string(),
+                short: None,
+                long: Some("retries".to_string()),
+                option_type: crate::config::schema::ArgumentType::Integer,
+                required: false,
+                default: None,
+                description: String::new(),
+                choices: vec![],
+                ..Default::default()
+            });
+        definition
+            .constraints
+            .push(crate::config::schema::ConstraintDefinition {
+                check: check.to_string(),
+                message: None,
+            });
+        definition
+    }
+
+    #[test]
+    fn test_register_parses_constraints_once() {
+        let mut registry = CommandRegistry::new();
+        registry
+            .register(
+                constrained_definition("fetch", "retries <= 10"),
+                Box::new(TestHandler),
+            )
+            .unwrap();
+
+        let constraints = registry.constraints("c").unwrap();
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(
+            constraints[0].comparison,
+            crate::validator::constraint_validator::Comparison::LessOrEqual
+        );
+        assert!(registry.constraints("unknown").is_none());
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_constraints() {
+        let mut registry = CommandRegistry::new();
+        let result = registry.register(
+            constrained_definition("fetch", "retries <= many"),
+            Box::new(TestHandler),
+        );
+        match result.unwrap_err() {
+            crate::error::DynamicCliError::Config(crate::error::ConfigError::InvalidSchema {
+                reason,
+                path,
+                ..
+            }) => {
+                assert!(reason.contains("retries <= many"), "{reason}");
+                assert_eq!(path.as_deref(), Some("fetch.constraints[0]"));
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+        // Nothing was recorded
+        assert!(registry.is_empty());
+        assert_eq!(registry.resolve_name("c"), None);
+
+        // Invalid constraints of declared subcommands are caught up front
+        let mut parent = create_test_definition("remote", vec!["r"]);
+        parent.subcommands = vec![constrained_definition("add", "retries < ")];
+        assert!(registry.register_group(parent).is_err());
+        assert!(registry.is_empty());
+        assert_eq!(registry.resolve_name("r"), None);
+    }
 }
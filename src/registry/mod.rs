@@ -63,6 +63,7 @@
 //!     arguments: vec![],
 //!     options: vec![],
 //!     implementation: "hello_handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! // 3. Create a handler
@@ -115,6 +116,7 @@
 //! #     arguments: vec![],
 //! #     options: vec![],
 //! #     implementation: "".to_string(),
+//! #     ..Default::default()
 //! # };
 //! # struct TestCmd;
 //! # impl CommandHandler for TestCmd {
@@ -144,6 +146,7 @@
 //!     arguments: vec![],
 //!     options: vec![],
 //!     implementation: "simulate_handler".to_string(),
+//!     ..Default::default()
 //! };
 //!
 //! # struct SimCmd;
@@ -176,6 +179,7 @@
 //! #     arguments: vec![],
 //! #     options: vec![],
 //! #     implementation: "".to_string(),
+//! #     ..Default::default()
 //! # };
 //! # let def2 = CommandDefinition {
 //! #     name: "cmd2".to_string(),
@@ -185,6 +189,7 @@
 //! #     arguments: vec![],
 //! #     options: vec![],
 //! #     implementation: "".to_string(),
+//! #     ..Default::default()
 //! # };
 //! # struct TestCmd;
 //! # impl CommandHandler for TestCmd {
@@ -215,6 +220,7 @@
 //! #     arguments: vec![],
 //! #     options: vec![],
 //! #     implementation: "".to_string(),
+//! #     ..Default::default()
 //! # };
 //! # let def2 = CommandDefinition {
 //! #     name: "test".to_string(),
@@ -224,6 +230,7 @@
 //! #     arguments: vec![],
 //! #     options: vec![],
 //! #     implementation: "".to_string(),
+//! #     ..Default::default()
 //! # };
 //! # struct TestCmd;
 //! # impl CommandHandler for TestCmd {
@@ -363,6 +370,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "sim_handler".to_string(),
+            ..Default::default()
         };
 
         let validate_def = CommandDefinition {
@@ -373,6 +381,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "val_handler".to_string(),
+            ..Default::default()
         };
 
         // Register commands
@@ -423,6 +432,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "test_handler".to_string(),
+            ..Default::default()
         };
 
         registry.register(def, Box::new(TestHandler)).unwrap();
@@ -455,6 +465,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "help_handler".to_string(),
+            ..Default::default()
         };
 
         let def2 = CommandDefinition {
@@ -465,6 +476,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "exit_handler".to_string(),
+            ..Default::default()
         };
 
         registry.register(def1, Box::new(TestHandler)).unwrap();
@@ -502,6 +514,7 @@ mod tests {
                     arguments: vec![],
                     options: vec![],
                     implementation: "".to_string(),
+                    ..Default::default()
                 },
                 Box::new(TestHandler),
             )
@@ -517,6 +530,7 @@ mod tests {
                     arguments: vec![],
                     options: vec![],
                     implementation: "".to_string(),
+                    ..Default::default()
                 },
                 Box::new(TestHandler),
             )
@@ -548,6 +562,7 @@ mod tests {
             arguments: vec![],
             options: vec![],
             implementation: "".to_string(),
+            ..Default::default()
         };
 
         // First registration succeeds
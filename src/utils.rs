@@ -14,6 +14,7 @@
 
 use crate::config::schema::ArgumentType;
 use crate::error::{DynamicCliError, ParseError, Result};
+use crate::parser::type_parser;
 use std::time::Duration;
 
 // ============================================================================
@@ -319,7 +320,12 @@ pub fn parse_bool(value: &str) -> Result<bool> {
 /// 2. Integer (parseable as i64)
 /// 3. Float (parseable as f64 and contains '.')
 /// 4. Path (starts with /, ./, ../, or contains \)
-/// 5. String (default)
+/// 5. Ip (IPv4 or IPv6 address)
+/// 6. Url (scheme://host...)
+/// 7. DateTime (RFC 3339 timestamp or YYYY-MM-DD)
+/// 8. Duration (number with a time unit, e.g. 30s, 1h30m)
+/// 9. Bytes (number with a size unit, e.g. 4K, 1.5GiB)
+/// 10. String (default)
 ///
 /// # Example
 ///
@@ -330,6 +336,11 @@ pub fn parse_bool(value: &str) -> Result<bool> {
 /// assert_eq!(detect_type("3.14"), ArgumentType::Float);
 /// assert_eq!(detect_type("true"), ArgumentType::Bool);
 /// assert_eq!(detect_type("/path/to/file"), ArgumentType::Path);
+/// assert_eq!(detect_type("10.0.0.1"), ArgumentType::Ip);
+/// assert_eq!(detect_type("https://example.com"), ArgumentType::Url);
+/// assert_eq!(detect_type("2024-05-01"), ArgumentType::DateTime);
+/// assert_eq!(detect_type("30s"), ArgumentType::Duration);
+/// assert_eq!(detect_type("4GiB"), ArgumentType::Bytes);
 /// assert_eq!(detect_type("hello"), ArgumentType::String);
 /// ```
 pub fn detect_type(value: &str) -> ArgumentType {
@@ -357,6 +368,28 @@ pub fn detect_type(value: &str) -> ArgumentType {
         return ArgumentType::Path;
     }
 
+    if type_parser::parse_ip(value).is_ok() {
+        return ArgumentType::Ip;
+    }
+
+    if value.contains("://") && type_parser::parse_url(value).is_ok() {
+        return ArgumentType::Url;
+    }
+
+    if type_parser::parse_datetime(value).is_ok() {
+        return ArgumentType::DateTime;
+    }
+
+    // Durations and sizes need a unit, bare numbers are handled above
+    if value.ends_with(char::is_alphabetic) {
+        if type_parser::parse_duration(value).is_ok() {
+            return ArgumentType::Duration;
+        }
+        if type_parser::parse_bytes(value).is_ok() {
+            return ArgumentType::Bytes;
+        }
+    }
+
     // Default to string
     ArgumentType::String
 }
@@ -439,6 +472,8 @@ pub mod test_helpers {
                 .map(|name| create_test_command(name, false))
                 .collect(),
             global_options: vec![],
+            include: vec![],
+            schema_version: SCHEMA_VERSION,
         }
     }
 
@@ -452,6 +487,7 @@ pub mod test_helpers {
             arguments: vec![],
             options: vec![],
             implementation: format!("{}_handler", name),
+            ..Default::default()
         }
     }
 
@@ -606,6 +642,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_parse_float_valid() {
         assert_eq!(parse_float("3.14", "pi").unwrap(), 3.14);
         assert_eq!(parse_float("42", "value").unwrap(), 42.0);
@@ -614,15 +651,15 @@ mod tests {
 
     #[test]
     fn test_parse_bool_various() {
-        assert_eq!(parse_bool("true").unwrap(), true);
-        assert_eq!(parse_bool("YES").unwrap(), true);
-        assert_eq!(parse_bool("1").unwrap(), true);
-        assert_eq!(parse_bool("on").unwrap(), true);
+        assert!(parse_bool("true").unwrap());
+        assert!(parse_bool("YES").unwrap());
+        assert!(parse_bool("1").unwrap());
+        assert!(parse_bool("on").unwrap());
 
-        assert_eq!(parse_bool("false").unwrap(), false);
-        assert_eq!(parse_bool("no").unwrap(), false);
-        assert_eq!(parse_bool("0").unwrap(), false);
-        assert_eq!(parse_bool("off").unwrap(), false);
+        assert!(!parse_bool("false").unwrap());
+        assert!(!parse_bool("no").unwrap());
+        assert!(!parse_bool("0").unwrap());
+        assert!(!parse_bool("off").unwrap());
 
         assert!(parse_bool("maybe").is_err());
     }
@@ -653,6 +690,19 @@ mod tests {
         assert_eq!(detect_type("..\\path"), ArgumentType::Path);
     }
 
+    #[test]
+    fn test_detect_type_richer_types() {
+        assert_eq!(detect_type("::1"), ArgumentType::Ip);
+        assert_eq!(detect_type("ftp://files.example.com/a"), ArgumentType::Url);
+        assert_eq!(detect_type("2024-05-01T12:00:00Z"), ArgumentType::DateTime);
+        assert_eq!(detect_type("1h30m"), ArgumentType::Duration);
+        assert_eq!(detect_type("512MB"), ArgumentType::Bytes);
+
+        // Unit-less numbers and unknown units are not durations or sizes
+        assert_eq!(detect_type("1e3"), ArgumentType::String);
+        assert_eq!(detect_type("10 apples"), ArgumentType::String);
+    }
+
     // ========================================================================
     // SECTION 4: PATH TESTS
     // ========================================================================
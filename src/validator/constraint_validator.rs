@@ -0,0 +1,679 @@
+//! Cross-field constraint validation
+//!
+//! This module checks the [`ConstraintDefinition`]s declared on a command:
+//! comparisons such as `end > start` or `retries <= 10` between the
+//! values of its arguments and options. Operands are converted to their
+//! declared types before comparison, so `"10" > "9"` holds for integers
+//! and `90s > 1m` holds for durations.
+//!
+//! # Functions
+//!
+//! - [`parse_constraint`] - Parse a check against a command definition
+//! - [`parse_constraints`] - Parse every constraint of a command
+//! - [`check_constraints`] - Check parsed constraints against values
+//! - [`validate_constraints`] - Parse and check every constraint of a command
+//!
+//! Checks are parsed once, when a command is registered in a
+//! [`CommandRegistry`](crate::registry::CommandRegistry); parsing goes
+//! through [`check_constraints`] with the result.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::config::schema::{
+//!     ArgumentDefinition, ArgumentType, CommandDefinition, ConstraintDefinition,
+//! };
+//! use dynamic_cli::validator::constraint_validator::validate_constraints;
+//! use std::collections::HashMap;
+//!
+//! let argument = |name: &str| ArgumentDefinition {
+//!     name: name.to_string(),
+//!     arg_type: ArgumentType::Integer,
+//!     required: true,
+//!     description: String::new(),
+//!     validation: vec![],
+//!     secure: false,
+//!     ..Default::default()
+//! };
+//! let definition = CommandDefinition {
+//!     name: "slice".to_string(),
+//!     aliases: vec![],
+//!     description: "Take a slice".to_string(),
+//!     required: false,
+//!     arguments: vec![argument("start"), argument("end")],
+//!     options: vec![],
+//!     implementation: "slice_handler".to_string(),
+//!     constraints: vec![ConstraintDefinition {
+//!         check: "end > start".to_string(),
+//!         message: None,
+//!     }],
+//!     ..Default::default()
+//! };
+//!
+//! let mut parsed = HashMap::new();
+//! parsed.insert("start".to_string(), "9".to_string());
+//! parsed.insert("end".to_string(), "10".to_string());
+//! assert!(validate_constraints(&definition, &parsed).is_ok());
+//!
+//! parsed.insert("end".to_string(), "3".to_string());
+//! assert!(validate_constraints(&definition, &parsed).is_err());
+//! ```
+//!
+//! [`ConstraintDefinition`]: crate::config::schema::ConstraintDefinition
+
+use crate::config::schema::{ArgumentType, CommandDefinition};
+use crate::error::{ConfigError, Result, ValidationError};
+use crate::parser::parsed_args::Value;
+use crate::parser::type_parser::parse_typed_value;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Comparison operator of a constraint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `<`
+    Less,
+    /// `<=`
+    LessOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+}
+
+impl Comparison {
+    /// Operators in matching order (two-character operators first)
+    const ALL: [(&'static str, Comparison); 6] = [
+        ("<=", Comparison::LessOrEqual),
+        (">=", Comparison::GreaterOrEqual),
+        ("==", Comparison::Equal),
+        ("!=", Comparison::NotEqual),
+        ("<", Comparison::Less),
+        (">", Comparison::Greater),
+    ];
+
+    /// Get the operator as written in a check
+    pub fn symbol(&self) -> &'static str {
+        Self::ALL
+            .iter()
+            .find(|(_, comparison)| comparison == self)
+            .map(|(symbol, _)| *symbol)
+            .unwrap_or_default()
+    }
+
+    /// Whether the comparison holds for the given ordering of its operands
+    pub fn holds(&self, ordering: Ordering) -> bool {
+        match self {
+            Comparison::Less => ordering == Ordering::Less,
+            Comparison::LessOrEqual => ordering != Ordering::Greater,
+            Comparison::Greater => ordering == Ordering::Greater,
+            Comparison::GreaterOrEqual => ordering != Ordering::Less,
+            Comparison::Equal => ordering == Ordering::Equal,
+            Comparison::NotEqual => ordering != Ordering::Equal,
+        }
+    }
+}
+
+/// One side of a constraint
+#[derive(Debug, Clone, PartialEq)]
+pub enum Operand {
+    /// Value of the named argument or option
+    Field {
+        /// Argument or option name
+        name: String,
+        /// Declared type of the argument or option
+        arg_type: ArgumentType,
+    },
+    /// Fixed value, read with the type of the other operand
+    Literal(String),
+}
+
+/// A parsed constraint, with operands resolved against a command
+#[derive(Debug, Clone, PartialEq)]
+pub struct Constraint {
+    /// Left-hand operand
+    pub left: Operand,
+    /// Comparison operator
+    pub comparison: Comparison,
+    /// Right-hand operand
+    pub right: Operand,
+}
+
+/// Parse a check such as `"end > start"` against a command definition
+///
+/// Bare words naming an argument or option of `definition` become
+/// [`Operand::Field`]s; quoted strings and other words are literals.
+///
+/// # Errors
+///
+/// A description of the problem if the check is malformed, names a
+/// multiple-value argument or option, compares values of different
+/// types, has no field operand, or has a literal that is not a valid
+/// value of the field's type.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::schema::{CommandDefinition, ArgumentType, OptionDefinition};
+/// use dynamic_cli::validator::constraint_validator::{parse_constraint, Comparison, Operand};
+///
+/// # let mut definition = CommandDefinition {
+/// #     name: "run".to_string(),
+/// #     aliases: vec![],
+/// #     description: String::new(),
+/// #     required: false,
+/// #     arguments: vec![],
+/// #     options: vec![],
+/// #     implementation: String::new(),
+/// #     ..Default::default()
+/// # };
+/// definition.options.push(OptionDefinition {
+///     name: "retries".to_string(),
+///     short: None,
+///     long: Some("retries".to_string()),
+///     option_type: ArgumentType::Integer,
+///     required: false,
+///     default: None,
+///     description: String::new(),
+///     choices: vec![],
+///     ..Default::default()
+/// });
+///
+/// let constraint = parse_constraint("retries <= 10", &definition).unwrap();
+/// assert_eq!(constraint.comparison, Comparison::LessOrEqual);
+/// assert_eq!(constraint.right, Operand::Literal("10".to_string()));
+///
+/// assert!(parse_constraint("retries <= many", &definition).is_err());
+/// ```
+pub fn parse_constraint(
+    check: &str,
+    definition: &CommandDefinition,
+) -> std::result::Result<Constraint, String> {
+    let (left, comparison, right) = split_check(check)?;
+    let left = resolve_operand(left, definition)?;
+    let right = resolve_operand(right, definition)?;
+
+    match (&left, &right) {
+        (Operand::Literal(_), Operand::Literal(_)) => {
+            return Err("at least one side must name an argument or option".to_string())
+        }
+        (
+            Operand::Field {
+                name: left_name,
+                arg_type: left_type,
+            },
+            Operand::Field {
+                name: right_name,
+                arg_type: right_type,
+            },
+        ) => {
+            if !comparable_types(*left_type, *right_type) {
+                return Err(format!(
+                    "'{}' ({}) and '{}' ({}) have types that cannot be compared",
+                    left_name,
+                    left_type.as_str(),
+                    right_name,
+                    right_type.as_str()
+                ));
+            }
+        }
+        (Operand::Field { name, arg_type }, Operand::Literal(literal))
+        | (Operand::Literal(literal), Operand::Field { name, arg_type }) => {
+            if parse_typed_value(literal, *arg_type).is_err() {
+                return Err(format!(
+                    "'{}' is not a valid {} value to compare with '{}'",
+                    literal,
+                    arg_type.as_str(),
+                    name
+                ));
+            }
+        }
+    }
+
+    Ok(Constraint {
+        left,
+        comparison,
+        right,
+    })
+}
+
+/// Parse every constraint of a command, in declaration order
+///
+/// # Errors
+///
+/// [`ConfigError::InvalidSchema`] for the first check that cannot be
+/// parsed, at `<command>.constraints[<index>]`
+///
+/// [`ConfigError::InvalidSchema`]: crate::error::ConfigError::InvalidSchema
+pub fn parse_constraints(definition: &CommandDefinition) -> Result<Vec<Constraint>> {
+    definition
+        .constraints
+        .iter()
+        .enumerate()
+        .map(|(idx, declared)| {
+            parse_constraint(&declared.check, definition).map_err(|reason| {
+                ConfigError::InvalidSchema {
+                    reason: format!("Invalid constraint '{}': {}", declared.check, reason),
+                    path: Some(format!("{}.constraints[{}]", definition.name, idx)),
+                    suggestion: Some(
+                        "Write checks as '<name> <op> <name or value>', e.g. 'end > start'."
+                            .to_string(),
+                    ),
+                }
+                .into()
+            })
+        })
+        .collect()
+}
+
+/// Parse and check every constraint of a command against parsed values
+///
+/// Same as [`check_constraints`] with the result of
+/// [`parse_constraints`].
+///
+/// # Errors
+///
+/// Those of [`parse_constraints`] and [`check_constraints`].
+pub fn validate_constraints(
+    definition: &CommandDefinition,
+    parsed: &HashMap<String, String>,
+) -> Result<()> {
+    check_constraints(definition, &parse_constraints(definition)?, parsed)
+}
+
+/// Check the parsed constraints of a command against parsed values
+///
+/// `constraints` are those returned by [`parse_constraints`] for
+/// `definition`, whose declarations give the check text and message.
+/// A constraint whose named operands are not all present (an optional
+/// argument or option that was not given and has no default) is skipped.
+///
+/// # Errors
+///
+/// [`ValidationError::CustomConstraint`] naming the first operand of the
+/// first failing constraint; the reason is the constraint's `message` if
+/// set
+///
+/// [`ValidationError::CustomConstraint`]: crate::error::ValidationError::CustomConstraint
+pub fn check_constraints(
+    definition: &CommandDefinition,
+    constraints: &[Constraint],
+    parsed: &HashMap<String, String>,
+) -> Result<()> {
+    for (declared, constraint) in definition.constraints.iter().zip(constraints) {
+        // The type of a literal is the type of the field it is compared with
+        let literal_type = match (&constraint.left, &constraint.right) {
+            (Operand::Field { arg_type, .. }, _) | (_, Operand::Field { arg_type, .. }) => {
+                *arg_type
+            }
+            _ => continue,
+        };
+        let Some((left_raw, left)) = operand_value(&constraint.left, literal_type, parsed)? else {
+            continue;
+        };
+        let Some((right_raw, right)) = operand_value(&constraint.right, literal_type, parsed)?
+        else {
+            continue;
+        };
+
+        let holds = compare_values(&left, &right)
+            .map(|ordering| constraint.comparison.holds(ordering))
+            .unwrap_or(false);
+        if holds {
+            continue;
+        }
+
+        let fields: Vec<&str> = [&constraint.left, &constraint.right]
+            .into_iter()
+            .filter_map(|operand| match operand {
+                Operand::Field { name, .. } => Some(name.as_str()),
+                Operand::Literal(_) => None,
+            })
+            .collect();
+        let reason = declared.message.clone().unwrap_or_else(|| {
+            let values: Vec<String> =
+                [(&constraint.left, left_raw), (&constraint.right, right_raw)]
+                    .into_iter()
+                    .filter_map(|(operand, raw)| match operand {
+                        Operand::Field { name, .. } => Some(format!("{} = {}", name, raw)),
+                        Operand::Literal(_) => None,
+                    })
+                    .collect();
+            format!("'{}' does not hold ({})", declared.check, values.join(", "))
+        });
+
+        return Err(ValidationError::CustomConstraint {
+            arg_name: fields[0].to_string(),
+            reason,
+            suggestion: Some(format!("Check the value of {}.", fields.join(" and "))),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Split a check into its operands and operator
+fn split_check(check: &str) -> std::result::Result<(&str, Comparison, &str), String> {
+    let mut found = None;
+    let mut quote = None;
+    let mut chars = check.char_indices().peekable();
+
+    while let Some((idx, ch)) = chars.next() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None if ch == '\'' || ch == '"' => quote = Some(ch),
+            None => {
+                let rest = &check[idx..];
+                let Some((symbol, comparison)) = Comparison::ALL
+                    .iter()
+                    .find(|(symbol, _)| rest.starts_with(symbol))
+                else {
+                    if ch == '=' {
+                        return Err("use '==' to compare for equality".to_string());
+                    }
+                    continue;
+                };
+                if found.is_some() {
+                    return Err("a check compares exactly two operands".to_string());
+                }
+                found = Some((idx, symbol.len(), *comparison));
+                if symbol.len() == 2 {
+                    chars.next();
+                }
+            }
+        }
+    }
+    if quote.is_some() {
+        return Err("unterminated quote".to_string());
+    }
+
+    let Some((idx, len, comparison)) = found else {
+        return Err("expected a comparison: <, <=, >, >=, == or !=".to_string());
+    };
+    let left = check[..idx].trim();
+    let right = check[idx + len..].trim();
+    if left.is_empty() || right.is_empty() {
+        return Err("both sides of the comparison are required".to_string());
+    }
+    Ok((left, comparison, right))
+}
+
+/// Turn the text of one side into an operand
+fn resolve_operand(
+    text: &str,
+    definition: &CommandDefinition,
+) -> std::result::Result<Operand, String> {
+    for quote in ['\'', '"'] {
+        if text.len() >= 2 && text.starts_with(quote) && text.ends_with(quote) {
+            return Ok(Operand::Literal(text[1..text.len() - 1].to_string()));
+        }
+    }
+    if text.contains(['\'', '"']) {
+        return Err(format!("'{}' mixes quoted and unquoted text", text));
+    }
+
+    let field = definition
+        .arguments
+        .iter()
+        .find(|arg| arg.name == text)
+        .map(|arg| (arg.arg_type, arg.multiple))
+        .or_else(|| {
+            definition
+                .options
+                .iter()
+                .find(|opt| opt.name == text)
+                .map(|opt| (opt.option_type, opt.multiple))
+        });
+
+    match field {
+        Some((_, true)) => Err(format!(
+            "'{}' takes several values and cannot be compared",
+            text
+        )),
+        Some((arg_type, false)) => Ok(Operand::Field {
+            name: text.to_string(),
+            arg_type,
+        }),
+        None => Ok(Operand::Literal(text.to_string())),
+    }
+}
+
+/// Whether values of the two types can be compared with each other
+fn comparable_types(left: ArgumentType, right: ArgumentType) -> bool {
+    let numeric = |t| matches!(t, ArgumentType::Integer | ArgumentType::Float);
+    left == right || (numeric(left) && numeric(right))
+}
+
+/// Raw text and typed value of an operand, or `None` if it was not given
+fn operand_value(
+    operand: &Operand,
+    literal_type: ArgumentType,
+    parsed: &HashMap<String, String>,
+) -> Result<Option<(String, Value)>> {
+    let (raw, arg_type) = match operand {
+        Operand::Field { name, arg_type } => match parsed.get(name) {
+            Some(raw) => (raw.clone(), *arg_type),
+            None => return Ok(None),
+        },
+        Operand::Literal(text) => (text.clone(), literal_type),
+    };
+    let value = parse_typed_value(&raw, arg_type)?;
+    Ok(Some((raw, value)))
+}
+
+/// Order two typed values, if they are comparable
+fn compare_values(left: &Value, right: &Value) -> Option<Ordering> {
+    match (left, right) {
+        (Value::Integer(a), Value::Integer(b)) => Some(a.cmp(b)),
+        (Value::Integer(_) | Value::Float(_), Value::Integer(_) | Value::Float(_)) => {
+            left.as_f64()?.partial_cmp(&right.as_f64()?)
+        }
+        (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+        (Value::Path(a), Value::Path(b)) => Some(a.cmp(b)),
+        (Value::Duration(a), Value::Duration(b)) => Some(a.cmp(b)),
+        (Value::Bytes(a), Value::Bytes(b)) => Some(a.cmp(b)),
+        (Value::Ip(a), Value::Ip(b)) => Some(a.cmp(b)),
+        (Value::DateTime(a), Value::DateTime(b)) => Some(a.cmp(b)),
+        (
+            Value::String(a) | Value::Url(a) | Value::Regex(a),
+            Value::String(b) | Value::Url(b) | Value::Regex(b),
+        ) => Some(a.cmp(b)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::{ArgumentDefinition, ConstraintDefinition, OptionDefinition};
+    use crate::error::DynamicCliError;
+
+    fn argument(name: &str, arg_type: ArgumentType) -> ArgumentDefinition {
+        ArgumentDefinition {
+            name: name.to_string(),
+            arg_type,
+            required: false,
+            description: String::new(),
+            validation: vec![],
+            secure: false,
+            ..Default::default()
+        }
+    }
+
+    fn option(name: &str, option_type: ArgumentType) -> OptionDefinition {
+        OptionDefinition {
+            name: name.to_string(),
+            short: None,
+            long: Some(name.to_string()),
+            option_type,
+            required: false,
+            default: None,
+            description: String::new(),
+            choices: vec![],
+            ..Default::default()
+        }
+    }
+
+    fn command(checks: &[&str]) -> CommandDefinition {
+        CommandDefinition {
+            name: "cmd".to_string(),
+            aliases: vec![],
+            description: String::new(),
+            required: false,
+            arguments: vec![
+                argument("start", ArgumentType::Integer),
+                argument("end", ArgumentType::Float),
+            ],
+            options: vec![
+                option("timeout", ArgumentType::Duration),
+                option("mode", ArgumentType::String),
+                option("since", ArgumentType::DateTime),
+            ],
+            implementation: "handler".to_string(),
+            constraints: checks
+                .iter()
+                .map(|check| ConstraintDefinition {
+                    check: check.to_string(),
+                    message: None,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn values(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_constraint_operators_and_operands() {
+        let definition = command(&[]);
+
+        let constraint = parse_constraint("end>=start", &definition).unwrap();
+        assert_eq!(constraint.comparison, Comparison::GreaterOrEqual);
+        assert!(matches!(constraint.left, Operand::Field { ref name, .. } if name == "end"));
+
+        let constraint = parse_constraint("mode != 'a > b'", &definition).unwrap();
+        assert_eq!(constraint.comparison, Comparison::NotEqual);
+        assert_eq!(constraint.right, Operand::Literal("a > b".to_string()));
+
+        let constraint = parse_constraint("timeout < 5m", &definition).unwrap();
+        assert_eq!(constraint.right, Operand::Literal("5m".to_string()));
+    }
+
+    #[test]
+    fn test_parse_constraint_errors() {
+        let definition = command(&[]);
+
+        for check in [
+            "end start",
+            "end = start",
+            "end > start > 0",
+            "> start",
+            "1 < 2",
+            "mode == 'open",
+            "timeout < soon",
+            "timeout > start",
+        ] {
+            assert!(
+                parse_constraint(check, &definition).is_err(),
+                "{} should be rejected",
+                check
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_constraint_rejects_multiple_values() {
+        let mut definition = command(&[]);
+        definition.arguments[0].multiple = true;
+
+        let err = parse_constraint("start < 3", &definition).unwrap_err();
+        assert!(err.contains("several values"));
+    }
+
+    #[test]
+    fn test_validate_constraints_compares_typed_values() {
+        let definition = command(&["end > start", "timeout <= 2m", "since < '2030-01-01'"]);
+
+        // 10 > 9 numerically, although "10" < "9" as text
+        let parsed = values(&[
+            ("start", "9"),
+            ("end", "10"),
+            ("timeout", "90s"),
+            ("since", "2024-05-01"),
+        ]);
+        assert!(validate_constraints(&definition, &parsed).is_ok());
+
+        let parsed = values(&[("start", "9"), ("end", "10"), ("timeout", "3m")]);
+        assert!(validate_constraints(&definition, &parsed).is_err());
+    }
+
+    #[test]
+    fn test_parse_constraints_then_check() {
+        let definition = command(&["end > start", "timeout <= 2m"]);
+        let constraints = parse_constraints(&definition).unwrap();
+        assert_eq!(constraints.len(), 2);
+
+        let parsed = values(&[("start", "1"), ("end", "2"), ("timeout", "90s")]);
+        assert!(check_constraints(&definition, &constraints, &parsed).is_ok());
+        let parsed = values(&[("start", "3"), ("end", "2")]);
+        assert!(check_constraints(&definition, &constraints, &parsed).is_err());
+
+        let definition = command(&["end > start", "end >"]);
+        match parse_constraints(&definition).unwrap_err() {
+            DynamicCliError::Config(ConfigError::InvalidSchema { path, .. }) => {
+                assert_eq!(path.as_deref(), Some("cmd.constraints[1]"));
+            }
+            other => panic!("Expected InvalidSchema, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_constraints_reports_values() {
+        let definition = command(&["end > start"]);
+        let parsed = values(&[("start", "5"), ("end", "2.5")]);
+
+        match validate_constraints(&definition, &parsed).unwrap_err() {
+            DynamicCliError::Validation(ValidationError::CustomConstraint {
+                arg_name,
+                reason,
+                suggestion,
+            }) => {
+                assert_eq!(arg_name, "end");
+                assert_eq!(reason, "'end > start' does not hold (end = 2.5, start = 5)");
+                assert_eq!(
+                    suggestion.as_deref(),
+                    Some("Check the value of end and start.")
+                );
+            }
+            other => panic!("Expected CustomConstraint, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_constraints_uses_custom_message() {
+        let mut definition = command(&["end > start"]);
+        definition.constraints[0].message = Some("end must come after start".to_string());
+        let parsed = values(&[("start", "5"), ("end", "5")]);
+
+        let err = validate_constraints(&definition, &parsed).unwrap_err();
+        assert!(err.to_string().contains("end must come after start"));
+    }
+
+    #[test]
+    fn test_validate_constraints_skips_missing_values() {
+        let definition = command(&["end > start", "mode == 'fast'"]);
+        assert!(validate_constraints(&definition, &values(&[("end", "1")])).is_ok());
+        assert!(validate_constraints(&definition, &values(&[("mode", "slow")])).is_err());
+    }
+}
@@ -0,0 +1,143 @@
+//! Application-defined argument validators
+//!
+//! Some constraints cannot be expressed with the built-in rules, for
+//! example "the project id must exist in the local registry file". This
+//! module defines the [`ArgumentValidator`] trait for such checks. A
+//! validator is registered under a name with
+//! [`CliBuilder::register_validator`](crate::CliBuilder::register_validator)
+//! and referenced from the configuration:
+//!
+//! ```yaml
+//! arguments:
+//!   - name: project
+//!     arg_type: string
+//!     validation:
+//!       - custom: project_exists
+//!         params:
+//!           registry: projects.json
+//! ```
+//!
+//! Unknown validator names are rejected by
+//! [`CliBuilder::build`](crate::CliBuilder::build). At parse time, custom
+//! rules run in declaration order together with the built-in rules, so a
+//! failing validator stops the command before its handler runs.
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::context::ExecutionContext;
+//! use dynamic_cli::error::ValidationError;
+//! use dynamic_cli::validator::ArgumentValidator;
+//! use dynamic_cli::Result;
+//!
+//! struct Even;
+//!
+//! impl ArgumentValidator for Even {
+//!     fn validate(
+//!         &self,
+//!         _context: &dyn ExecutionContext,
+//!         arg_name: &str,
+//!         value: &str,
+//!         _params: &serde_json::Value,
+//!     ) -> Result<()> {
+//!         match value.parse::<i64>() {
+//!             Ok(n) if n % 2 == 0 => Ok(()),
+//!             _ => Err(ValidationError::CustomConstraint {
+//!                 arg_name: arg_name.to_string(),
+//!                 reason: format!("'{}' is not an even number", value),
+//!                 suggestion: None,
+//!             }
+//!             .into()),
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::context::ExecutionContext;
+use crate::error::{ConfigError, Result};
+use std::collections::HashMap;
+
+/// Trait for named, application-defined validation rules
+///
+/// Implementations back the `custom:` validation rule. They receive the
+/// execution context (read-only) so that checks can consult application
+/// state, and the rule's `params` as written in the configuration.
+///
+/// # Thread Safety
+///
+/// Like [`CommandHandler`](crate::executor::CommandHandler), validators
+/// must be `Send + Sync`.
+pub trait ArgumentValidator: Send + Sync {
+    /// Check a single argument value
+    ///
+    /// Called once per value; each value of a variadic argument is
+    /// checked separately.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The application's execution context
+    /// * `arg_name` - Name of the argument being validated
+    /// * `value` - The parsed (type-checked) value as a string
+    /// * `params` - The rule's `params`, or `Null` if none were given
+    ///
+    /// # Errors
+    ///
+    /// Return a [`ValidationError`](crate::error::ValidationError),
+    /// typically `CustomConstraint`, to reject the value.
+    fn validate(
+        &self,
+        context: &dyn ExecutionContext,
+        arg_name: &str,
+        value: &str,
+        params: &serde_json::Value,
+    ) -> Result<()>;
+}
+
+/// Registered validators bound to the context they run against
+///
+/// This is what the parsing pipeline needs to evaluate `custom:` rules.
+/// The interfaces build one from the
+/// [`CommandRegistry`](crate::registry::CommandRegistry) and their
+/// execution context; see
+/// [`CliParser::with_custom_validators`](crate::parser::CliParser::with_custom_validators).
+#[derive(Clone, Copy)]
+pub struct CustomValidators<'a> {
+    validators: &'a HashMap<String, Box<dyn ArgumentValidator>>,
+    context: &'a dyn ExecutionContext,
+}
+
+impl<'a> CustomValidators<'a> {
+    /// Bind a set of named validators to an execution context
+    pub fn new(
+        validators: &'a HashMap<String, Box<dyn ArgumentValidator>>,
+        context: &'a dyn ExecutionContext,
+    ) -> Self {
+        Self {
+            validators,
+            context,
+        }
+    }
+
+    /// Run the validator registered as `name` on one value
+    ///
+    /// # Errors
+    ///
+    /// - [`ConfigError::InvalidSchema`] if no validator has that name
+    /// - Whatever error the validator returns
+    pub fn validate(
+        &self,
+        name: &str,
+        arg_name: &str,
+        value: &str,
+        params: &serde_json::Value,
+    ) -> Result<()> {
+        match self.validators.get(name) {
+            Some(validator) => validator.validate(self.context, arg_name, value, params),
+            None => {
+                let mut registered: Vec<String> = self.validators.keys().cloned().collect();
+                registered.sort();
+                Err(ConfigError::unknown_validator(name, arg_name, None, &registered).into())
+            }
+        }
+    }
+}
@@ -62,14 +62,16 @@ pub fn validate_file_exists(path: &Path, arg_name: &str) -> Result<()> {
 
 /// Validate that a file has an allowed extension.
 ///
-/// The comparison is case-insensitive. Extensions should be provided
-/// without the leading dot (e.g., `"yaml"`, not `".yaml"`).
+/// The comparison is case-insensitive. Extensions may be provided with
+/// or without the leading dot (`"yaml"` and `".yaml"` are equivalent), so
+/// that the `extensions: [".yaml"]` form used in configuration files
+/// matches.
 ///
 /// # Arguments
 ///
 /// * `path` - The path whose extension to check
 /// * `arg_name` - The argument name (used in error messages)
-/// * `allowed` - List of allowed extensions (without leading dot)
+/// * `allowed` - List of allowed extensions (leading dot optional)
 ///
 /// # Returns
 ///
@@ -102,7 +104,13 @@ pub fn validate_file_extension(path: &Path, arg_name: &str, allowed: &[String])
         .map(|e| e.to_lowercase());
 
     match ext {
-        Some(ext) if allowed.iter().any(|a| a.to_lowercase() == ext) => Ok(()),
+        Some(ext)
+            if allowed
+                .iter()
+                .any(|a| a.trim_start_matches('.').to_lowercase() == ext) =>
+        {
+            Ok(())
+        }
         _ => Err(ValidationError::InvalidExtension {
             path: path.to_path_buf(),
             arg_name: arg_name.to_string(),
@@ -274,6 +282,17 @@ mod tests {
         assert!(validate_file_extension(path, "config", &allowed).is_ok());
     }
 
+    #[test]
+    fn test_validate_file_extension_dotted_allowed_list() {
+        let allowed = vec![".yaml".to_string(), ".JSON".to_string()];
+        assert!(validate_file_extension(Path::new("config.yaml"), "config", &allowed).is_ok());
+        assert!(validate_file_extension(Path::new("config.json"), "config", &allowed).is_ok());
+        assert!(validate_file_extension(Path::new("config.toml"), "config", &allowed).is_err());
+        // The dot separates the extension, it is not part of it
+        assert!(validate_file_extension(Path::new("config..yaml"), "config", &allowed).is_ok());
+        assert!(validate_file_extension(Path::new(".yaml"), "config", &allowed).is_err());
+    }
+
     // ========================================================================
     // Integration tests
     // ========================================================================
@@ -11,6 +11,9 @@
 //!
 //! - **File validation**: Existence checks and extension restrictions
 //! - **Range validation**: Numeric bounds (min/max)
+//! - **Text validation**: Patterns, length bounds, non-empty, formats
+//! - **Custom validation**: Named validators registered by the application
+//! - **Constraint validation**: Cross-field comparisons such as `end > start`
 //! - **Type-specific validation**: Applied after type parsing
 //!
 //! # Architecture
@@ -29,8 +32,12 @@
 //!
 //! # Submodules
 //!
+//! - [`constraint_validator`]: Cross-field constraints declared on commands
+//! - [`custom_validator`]: Application-defined validators (`custom:` rules)
 //! - [`file_validator`]: File existence and extension validation
 //! - [`range_validator`]: Numeric range validation
+//! - [`string_validator`]: Pattern, length, non-empty and format validation
+//! - [`rule_validator`]: Applies configured rules to parsed arguments
 //!
 //! # Usage Example
 //!
@@ -59,7 +66,10 @@
 //!
 //! ## With Parser Module
 //!
-//! The validator works with values after they've been parsed:
+//! The validator works with values after they've been parsed. The CLI
+//! parser calls [`validate_arguments`] at the end of
+//! [`CliParser::parse`](crate::parser::cli_parser::CliParser::parse), so
+//! rule violations surface before any handler runs:
 //!
 //! ```text
 //! User Input: "simulate input.dat --threshold 0.5"
@@ -94,6 +104,10 @@
 //! - `FileNotFound` - File doesn't exist
 //! - `InvalidExtension` - Wrong file extension
 //! - `OutOfRange` - Value outside min/max bounds
+//! - `PatternMismatch` - Value doesn't match the regular expression
+//! - `InvalidLength` - Value too short or too long
+//! - `EmptyValue` - Value is blank
+//! - `InvalidFormat` - Value not in the expected format (e.g. email)
 //! - `CustomConstraint` - Custom validation failed
 //!
 //! # Complete Workflow Example
@@ -117,6 +131,7 @@
 //!         },
 //!     ],
 //!     secure: false,
+//!     ..Default::default()
 //! };
 //!
 //! // Parse arguments
@@ -162,6 +177,7 @@
 //! Validation rules are matched to argument types:
 //! - `Path` arguments: file existence, extensions
 //! - `Integer`/`Float` arguments: range constraints
+//! - Text arguments: patterns, lengths, non-empty, formats
 //! - All types: custom constraints
 //!
 //! ## No Side Effects
@@ -174,12 +190,22 @@
 //! [`CommandDefinition`]: crate::config::schema::CommandDefinition
 
 // Public submodules
+pub mod constraint_validator;
+pub mod custom_validator;
 pub mod file_validator;
 pub mod range_validator;
+pub mod rule_validator;
+pub mod string_validator;
 
 // Re-export commonly used functions for convenience
+pub use constraint_validator::validate_constraints;
+pub use custom_validator::{ArgumentValidator, CustomValidators};
 pub use file_validator::{validate_file_exists, validate_file_extension};
 pub use range_validator::validate_range;
+pub use rule_validator::{validate_arguments, validate_arguments_with, validate_rules};
+pub use string_validator::{
+    validate_format, validate_length, validate_not_empty, validate_pattern,
+};
 
 #[cfg(test)]
 mod tests {
@@ -264,10 +290,8 @@ mod tests {
         ];
         for rule in &rules {
             match rule {
-                ValidationRule::MustExist { must_exist } => {
-                    if *must_exist {
-                        assert!(validate_file_exists(f.path(), "data").is_ok());
-                    }
+                ValidationRule::MustExist { must_exist } if *must_exist => {
+                    assert!(validate_file_exists(f.path(), "data").is_ok());
                 }
                 ValidationRule::Extensions { extensions } => {
                     assert!(validate_file_extension(f.path(), "data", extensions).is_ok());
@@ -280,7 +304,7 @@ mod tests {
     #[test]
     fn test_validate_fails_at_first_invalid_rule() {
         let f = temp_file_with_ext("txt", "content");
-        let rules = vec![
+        let rules = [
             ValidationRule::MustExist { must_exist: true },
             ValidationRule::Extensions {
                 extensions: vec!["csv".to_string()], // Wrong extension!
@@ -315,14 +339,13 @@ mod tests {
                 },
             ],
             secure: false,
+            ..Default::default()
         };
         let value = f.path().to_str().unwrap();
         for rule in &arg_def.validation {
             match rule {
-                ValidationRule::MustExist { must_exist } => {
-                    if *must_exist {
-                        assert!(validate_file_exists(Path::new(value), &arg_def.name).is_ok());
-                    }
+                ValidationRule::MustExist { must_exist } if *must_exist => {
+                    assert!(validate_file_exists(Path::new(value), &arg_def.name).is_ok());
                 }
                 ValidationRule::Extensions { extensions } => {
                     assert!(
@@ -347,6 +370,7 @@ mod tests {
                 max: None,
             }],
             secure: false,
+            ..Default::default()
         };
         for value in &["0.0", "25.0", "100.0", "-273.15"] {
             let num: f64 = value.parse().unwrap();
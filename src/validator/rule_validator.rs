@@ -0,0 +1,599 @@
+//! Rule-driven argument validation
+//!
+//! This module applies the [`ValidationRule`]s declared on each
+//! [`ArgumentDefinition`] to the values produced by the parser. It is the
+//! glue between the configuration and the individual validators in
+//! [`file_validator`](super::file_validator),
+//! [`range_validator`](super::range_validator) and
+//! [`string_validator`](super::string_validator), and the entry point for
+//! application-defined [`custom_validator`](super::custom_validator)s.
+//!
+//! # Functions
+//!
+//! - [`validate_rules`] - Check a single value against a list of rules
+//! - [`validate_arguments`] - Check every parsed argument of a command
+//! - [`validate_arguments_with`] - Same, with custom validators available
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::validator::rule_validator::validate_rules;
+//! use dynamic_cli::config::schema::{ArgumentType, ValidationRule};
+//!
+//! let rules = vec![ValidationRule::Range { min: Some(0.0), max: Some(1.0) }];
+//!
+//! assert!(validate_rules("0.5", "threshold", ArgumentType::Float, &rules).is_ok());
+//! assert!(validate_rules("1.5", "threshold", ArgumentType::Float, &rules).is_err());
+//! ```
+//!
+//! [`ValidationRule`]: crate::config::schema::ValidationRule
+//! [`ArgumentDefinition`]: crate::config::schema::ArgumentDefinition
+
+use crate::config::schema::{ArgumentType, CommandDefinition, ValidationRule};
+use crate::config::validator::range_type_mismatch;
+use crate::error::Result;
+use crate::parser::{multiple_values, type_parser};
+use crate::validator::custom_validator::CustomValidators;
+use crate::validator::file_validator::{validate_file_exists, validate_file_extension};
+use crate::validator::range_validator::validate_range;
+use crate::validator::string_validator::{
+    validate_format, validate_length, validate_not_empty, validate_pattern,
+};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Validate a single value against a list of validation rules
+///
+/// Rules are applied in declaration order and validation stops at the
+/// first failing rule.
+///
+/// # Arguments
+///
+/// * `value` - The parsed (type-checked) value as a string
+/// * `arg_name` - Name of the argument (for error messages)
+/// * `arg_type` - Declared type of the argument
+/// * `rules` - Validation rules from the configuration
+///
+/// # Rule Semantics
+///
+/// - `MustExist { must_exist: true }`: the value must be an existing path
+/// - `Extensions`: the value's file extension must be in the list
+/// - `Range`: the value must be numeric and within `[min, max]`;
+///   durations are compared in seconds and byte sizes in bytes; values
+///   that are not numeric are left to the type parser, and arguments of
+///   a non-numeric type are a configuration error
+/// - `Pattern`: the value must match the regular expression
+/// - `Length`: the value's length in characters must be within bounds
+/// - `NotEmpty { not_empty: true }`: the value must not be blank
+/// - `Format`: the value must be in the given format (e.g. email)
+/// - `Custom`: skipped here, since no validators are available; see
+///   [`validate_arguments_with`]
+///
+/// # Errors
+///
+/// - [`ValidationError::FileNotFound`] if a path does not exist
+/// - [`ValidationError::InvalidExtension`] if the extension is not allowed
+/// - [`ValidationError::OutOfRange`] if a number is outside its bounds
+/// - [`ValidationError::PatternMismatch`] if the value does not match
+/// - [`ValidationError::InvalidLength`] if the value is too short or long
+/// - [`ValidationError::EmptyValue`] if the value is blank
+/// - [`ValidationError::InvalidFormat`] if the value is not in the format
+/// - [`ConfigError::Inconsistency`] for a `Range` rule on a type that is
+///   not numeric
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::validator::rule_validator::validate_rules;
+/// use dynamic_cli::config::schema::{ArgumentType, ValidationRule};
+///
+/// let rules = vec![ValidationRule::Extensions {
+///     extensions: vec!["csv".to_string()],
+/// }];
+///
+/// assert!(validate_rules("data.csv", "input", ArgumentType::Path, &rules).is_ok());
+/// assert!(validate_rules("data.txt", "input", ArgumentType::Path, &rules).is_err());
+/// ```
+///
+/// [`ValidationError::FileNotFound`]: crate::error::ValidationError::FileNotFound
+/// [`ValidationError::InvalidExtension`]: crate::error::ValidationError::InvalidExtension
+/// [`ValidationError::OutOfRange`]: crate::error::ValidationError::OutOfRange
+/// [`ValidationError::PatternMismatch`]: crate::error::ValidationError::PatternMismatch
+/// [`ValidationError::InvalidLength`]: crate::error::ValidationError::InvalidLength
+/// [`ValidationError::EmptyValue`]: crate::error::ValidationError::EmptyValue
+/// [`ValidationError::InvalidFormat`]: crate::error::ValidationError::InvalidFormat
+/// [`ConfigError::Inconsistency`]: crate::error::ConfigError::Inconsistency
+pub fn validate_rules(
+    value: &str,
+    arg_name: &str,
+    arg_type: ArgumentType,
+    rules: &[ValidationRule],
+) -> Result<()> {
+    apply_rules(value, arg_name, arg_type, rules, None)
+}
+
+/// Apply rules to one value, running `Custom` rules through `custom`
+///
+/// Without validators, `Custom` rules are skipped: the names they use
+/// are checked once by [`CliBuilder::build`](crate::CliBuilder::build).
+fn apply_rules(
+    value: &str,
+    arg_name: &str,
+    arg_type: ArgumentType,
+    rules: &[ValidationRule],
+    custom: Option<CustomValidators<'_>>,
+) -> Result<()> {
+    for rule in rules {
+        match rule {
+            ValidationRule::MustExist { must_exist } => {
+                if *must_exist {
+                    validate_file_exists(Path::new(value), arg_name)?;
+                }
+            }
+            ValidationRule::Extensions { extensions } => {
+                validate_file_extension(Path::new(value), arg_name, extensions)?;
+            }
+            ValidationRule::Range { min, max } => {
+                let number = match arg_type {
+                    ArgumentType::Integer | ArgumentType::Float => value.trim().parse::<f64>().ok(),
+                    ArgumentType::Duration => type_parser::parse_duration(value)
+                        .ok()
+                        .map(|d| d.as_secs_f64()),
+                    ArgumentType::Bytes => type_parser::parse_bytes(value).ok().map(|b| b as f64),
+                    _ => return Err(range_type_mismatch(arg_name, arg_type).into()),
+                };
+                if let Some(number) = number {
+                    validate_range(number, arg_name, *min, *max)?;
+                }
+            }
+            ValidationRule::Pattern { pattern } => {
+                validate_pattern(value, arg_name, pattern)?;
+            }
+            ValidationRule::Length {
+                min_length,
+                max_length,
+            } => {
+                validate_length(value, arg_name, *min_length, *max_length)?;
+            }
+            ValidationRule::NotEmpty { not_empty } => {
+                if *not_empty {
+                    validate_not_empty(value, arg_name)?;
+                }
+            }
+            ValidationRule::Format { format } => {
+                validate_format(value, arg_name, *format)?;
+            }
+            ValidationRule::Custom {
+                custom: name,
+                params,
+            } => {
+                if let Some(validators) = custom {
+                    validators.validate(name, arg_name, value, params)?;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate all parsed arguments of a command against their rules
+///
+/// Only arguments present in `parsed` are checked; missing optional
+/// arguments are not an error here (required-ness is enforced by the
+/// parser). Each value of a variadic argument is checked separately.
+///
+/// # Arguments
+///
+/// * `definition` - The command definition holding the rules
+/// * `parsed` - Parsed argument values keyed by name
+///
+/// # Errors
+///
+/// The first [`ValidationError`](crate::error::ValidationError) returned
+/// by [`validate_rules`].
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::validator::rule_validator::validate_arguments;
+/// use dynamic_cli::config::schema::{
+///     ArgumentDefinition, ArgumentType, CommandDefinition, ValidationRule,
+/// };
+/// use std::collections::HashMap;
+///
+/// let definition = CommandDefinition {
+///     name: "scale".to_string(),
+///     aliases: vec![],
+///     description: "Scale a value".to_string(),
+///     required: false,
+///     arguments: vec![ArgumentDefinition {
+///         name: "factor".to_string(),
+///         arg_type: ArgumentType::Float,
+///         required: true,
+///         description: "Scale factor".to_string(),
+///         validation: vec![ValidationRule::Range { min: Some(0.0), max: None }],
+///         secure: false,
+///         ..Default::default()
+///     }],
+///     options: vec![],
+///     implementation: "scale_handler".to_string(),
+///     ..Default::default()
+/// };
+///
+/// let mut parsed = HashMap::new();
+/// parsed.insert("factor".to_string(), "-2".to_string());
+///
+/// assert!(validate_arguments(&definition, &parsed).is_err());
+/// ```
+pub fn validate_arguments(
+    definition: &CommandDefinition,
+    parsed: &HashMap<String, String>,
+) -> Result<()> {
+    apply_argument_rules(definition, parsed, None)
+}
+
+/// Validate all parsed arguments, running `custom:` rules
+///
+/// Like [`validate_arguments`], but `Custom` rules are dispatched to the
+/// matching registered validator, in declaration order with the other
+/// rules.
+///
+/// # Errors
+///
+/// The first error returned by a built-in rule or a custom validator;
+/// [`ConfigError::InvalidSchema`](crate::error::ConfigError::InvalidSchema)
+/// if a rule names a validator that is not registered.
+pub fn validate_arguments_with(
+    definition: &CommandDefinition,
+    parsed: &HashMap<String, String>,
+    custom: CustomValidators<'_>,
+) -> Result<()> {
+    apply_argument_rules(definition, parsed, Some(custom))
+}
+
+fn apply_argument_rules(
+    definition: &CommandDefinition,
+    parsed: &HashMap<String, String>,
+    custom: Option<CustomValidators<'_>>,
+) -> Result<()> {
+    for arg in &definition.arguments {
+        if arg.validation.is_empty() {
+            continue;
+        }
+        let Some(raw) = parsed.get(&arg.name) else {
+            continue;
+        };
+        if arg.multiple {
+            for value in multiple_values::split_values(raw) {
+                apply_rules(value, &arg.name, arg.arg_type, &arg.validation, custom)?;
+            }
+        } else {
+            apply_rules(raw, &arg.name, arg.arg_type, &arg.validation, custom)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::schema::ArgumentDefinition;
+    use crate::error::{DynamicCliError, ValidationError};
+
+    fn definition_with(arg_type: ArgumentType, rules: Vec<ValidationRule>) -> CommandDefinition {
+        CommandDefinition {
+            name: "cmd".to_string(),
+            aliases: vec![],
+            description: "Test".to_string(),
+            required: false,
+            arguments: vec![ArgumentDefinition {
+                name: "value".to_string(),
+                arg_type,
+                required: false,
+                description: "Value".to_string(),
+                validation: rules,
+                secure: false,
+                ..Default::default()
+            }],
+            options: vec![],
+            implementation: "handler".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_rules_must_exist() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let rules = vec![ValidationRule::MustExist { must_exist: true }];
+        let path = file.path().to_str().unwrap();
+
+        assert!(validate_rules(path, "input", ArgumentType::Path, &rules).is_ok());
+
+        let err =
+            validate_rules("/nonexistent/file", "input", ArgumentType::Path, &rules).unwrap_err();
+        assert!(matches!(
+            err,
+            DynamicCliError::Validation(ValidationError::FileNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rules_must_exist_false_is_noop() {
+        let rules = vec![ValidationRule::MustExist { must_exist: false }];
+        assert!(validate_rules("/nonexistent/file", "input", ArgumentType::Path, &rules).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rules_extensions() {
+        let rules = vec![ValidationRule::Extensions {
+            extensions: vec!["csv".to_string()],
+        }];
+
+        assert!(validate_rules("a.csv", "input", ArgumentType::Path, &rules).is_ok());
+        let err = validate_rules("a.txt", "input", ArgumentType::Path, &rules).unwrap_err();
+        assert!(matches!(
+            err,
+            DynamicCliError::Validation(ValidationError::InvalidExtension { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rules_range() {
+        let rules = vec![ValidationRule::Range {
+            min: Some(1.0),
+            max: Some(10.0),
+        }];
+
+        assert!(validate_rules("5", "count", ArgumentType::Integer, &rules).is_ok());
+        let err = validate_rules("11", "count", ArgumentType::Integer, &rules).unwrap_err();
+        assert!(matches!(
+            err,
+            DynamicCliError::Validation(ValidationError::OutOfRange { .. })
+        ));
+    }
+
+    #[test]
+    fn test_validate_rules_range_on_durations_and_sizes() {
+        let rules = vec![ValidationRule::Range {
+            min: Some(1.0),
+            max: Some(60.0),
+        }];
+
+        assert!(validate_rules("30s", "timeout", ArgumentType::Duration, &rules).is_ok());
+        assert!(validate_rules("1m", "timeout", ArgumentType::Duration, &rules).is_ok());
+        assert!(validate_rules("2m", "timeout", ArgumentType::Duration, &rules).is_err());
+        assert!(validate_rules("500ms", "timeout", ArgumentType::Duration, &rules).is_err());
+
+        let rules = vec![ValidationRule::Range {
+            min: None,
+            max: Some(4096.0),
+        }];
+        assert!(validate_rules("4K", "limit", ArgumentType::Bytes, &rules).is_ok());
+        assert!(validate_rules("4.5K", "limit", ArgumentType::Bytes, &rules).is_err());
+    }
+
+    #[test]
+    fn test_validate_rules_range_on_non_numeric_type() {
+        let rules = vec![ValidationRule::Range {
+            min: Some(1.0),
+            max: None,
+        }];
+
+        assert!(matches!(
+            validate_rules("abc", "name", ArgumentType::String, &rules),
+            Err(DynamicCliError::Config(
+                crate::error::ConfigError::Inconsistency { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rules_text_rules() {
+        use crate::config::schema::StringFormat;
+
+        let rules = vec![
+            ValidationRule::NotEmpty { not_empty: true },
+            ValidationRule::Length {
+                min_length: Some(6),
+                max_length: Some(40),
+            },
+            ValidationRule::Format {
+                format: StringFormat::Email,
+            },
+            ValidationRule::Pattern {
+                pattern: r"@example\.com$".to_string(),
+            },
+        ];
+
+        assert!(validate_rules("bob@example.com", "contact", ArgumentType::String, &rules).is_ok());
+        assert!(matches!(
+            validate_rules("  ", "contact", ArgumentType::String, &rules),
+            Err(DynamicCliError::Validation(
+                ValidationError::EmptyValue { .. }
+            ))
+        ));
+        assert!(matches!(
+            validate_rules("a@b.c", "contact", ArgumentType::String, &rules),
+            Err(DynamicCliError::Validation(
+                ValidationError::InvalidLength { .. }
+            ))
+        ));
+        assert!(matches!(
+            validate_rules("bob.example.com", "contact", ArgumentType::String, &rules),
+            Err(DynamicCliError::Validation(
+                ValidationError::InvalidFormat { .. }
+            ))
+        ));
+        assert!(matches!(
+            validate_rules("bob@example.org", "contact", ArgumentType::String, &rules),
+            Err(DynamicCliError::Validation(
+                ValidationError::PatternMismatch { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_rules_not_empty_false_is_noop() {
+        let rules = vec![ValidationRule::NotEmpty { not_empty: false }];
+        assert!(validate_rules("", "title", ArgumentType::String, &rules).is_ok());
+    }
+
+    struct MaxWords;
+
+    impl crate::validator::ArgumentValidator for MaxWords {
+        fn validate(
+            &self,
+            _context: &dyn crate::context::ExecutionContext,
+            arg_name: &str,
+            value: &str,
+            params: &serde_json::Value,
+        ) -> Result<()> {
+            let max = params["max"].as_u64().unwrap_or(1) as usize;
+            if value.split_whitespace().count() <= max {
+                return Ok(());
+            }
+            Err(ValidationError::CustomConstraint {
+                arg_name: arg_name.to_string(),
+                reason: format!("more than {} words", max),
+                suggestion: None,
+            }
+            .into())
+        }
+    }
+
+    struct NoContext;
+
+    impl crate::context::ExecutionContext for NoContext {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_validate_arguments_with_custom_validator() {
+        let definition = definition_with(
+            ArgumentType::String,
+            vec![
+                ValidationRule::NotEmpty { not_empty: true },
+                ValidationRule::Custom {
+                    custom: "max_words".to_string(),
+                    params: serde_json::json!({ "max": 2 }),
+                },
+            ],
+        );
+        let mut validators: HashMap<String, Box<dyn crate::validator::ArgumentValidator>> =
+            HashMap::new();
+        validators.insert("max_words".to_string(), Box::new(MaxWords));
+        let custom = CustomValidators::new(&validators, &NoContext);
+
+        let mut parsed = HashMap::new();
+        parsed.insert("value".to_string(), "two words".to_string());
+        assert!(validate_arguments_with(&definition, &parsed, custom).is_ok());
+
+        parsed.insert("value".to_string(), "three words here".to_string());
+        assert!(matches!(
+            validate_arguments_with(&definition, &parsed, custom),
+            Err(DynamicCliError::Validation(
+                ValidationError::CustomConstraint { .. }
+            ))
+        ));
+
+        // Built-in rules declared first still run first
+        parsed.insert("value".to_string(), " ".to_string());
+        assert!(matches!(
+            validate_arguments_with(&definition, &parsed, custom),
+            Err(DynamicCliError::Validation(
+                ValidationError::EmptyValue { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_custom_rule_without_validators() {
+        let rules = vec![ValidationRule::Custom {
+            custom: "max_words".to_string(),
+            params: serde_json::Value::Null,
+        }];
+        // Skipped when no validators are available at all
+        assert!(validate_rules("x", "value", ArgumentType::String, &rules).is_ok());
+
+        // An unknown name among registered validators is a configuration error
+
+        let validators = HashMap::new();
+        let custom = CustomValidators::new(&validators, &NoContext);
+        let definition = definition_with(ArgumentType::String, rules);
+        let mut parsed = HashMap::new();
+        parsed.insert("value".to_string(), "x".to_string());
+        assert!(matches!(
+            validate_arguments_with(&definition, &parsed, custom),
+            Err(DynamicCliError::Config(
+                crate::error::ConfigError::InvalidSchema { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_validate_arguments_skips_missing_values() {
+        let definition = definition_with(
+            ArgumentType::Path,
+            vec![ValidationRule::MustExist { must_exist: true }],
+        );
+        assert!(validate_arguments(&definition, &HashMap::new()).is_ok());
+    }
+
+    #[test]
+    fn test_validate_arguments_reports_first_failure() {
+        let definition = definition_with(
+            ArgumentType::Float,
+            vec![ValidationRule::Range {
+                min: Some(0.0),
+                max: Some(1.0),
+            }],
+        );
+        let mut parsed = HashMap::new();
+        parsed.insert("value".to_string(), "2.5".to_string());
+
+        let err = validate_arguments(&definition, &parsed).unwrap_err();
+        match err {
+            DynamicCliError::Validation(ValidationError::OutOfRange { arg_name, .. }) => {
+                assert_eq!(arg_name, "value");
+            }
+            other => panic!("Expected OutOfRange, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_arguments_checks_each_value_of_variadic_argument() {
+        let mut definition = definition_with(
+            ArgumentType::Integer,
+            vec![ValidationRule::Range {
+                min: Some(0.0),
+                max: Some(10.0),
+            }],
+        );
+        definition.arguments[0].multiple = true;
+        let mut parsed = HashMap::new();
+        parsed.insert(
+            "value".to_string(),
+            multiple_values::join_values(["1", "5"]),
+        );
+        assert!(validate_arguments(&definition, &parsed).is_ok());
+
+        parsed.insert(
+            "value".to_string(),
+            multiple_values::join_values(["1", "50"]),
+        );
+        assert!(matches!(
+            validate_arguments(&definition, &parsed),
+            Err(DynamicCliError::Validation(
+                ValidationError::OutOfRange { .. }
+            ))
+        ));
+    }
+}
@@ -0,0 +1,358 @@
+//! Text validation functions
+//!
+//! This module provides functions to validate string values according to
+//! the [`ValidationRule::Pattern`], [`ValidationRule::Length`],
+//! [`ValidationRule::NotEmpty`] and [`ValidationRule::Format`] constraints.
+//!
+//! # Functions
+//!
+//! - [`validate_pattern`] - Check that a value matches a regular expression
+//! - [`validate_length`] - Check that a value's length is within bounds
+//! - [`validate_not_empty`] - Check that a value is not blank
+//! - [`validate_format`] - Check that a value has a well-known format
+//!
+//! # Example
+//!
+//! ```
+//! use dynamic_cli::validator::string_validator::{validate_length, validate_pattern};
+//!
+//! validate_pattern("my-project", "name", "^[a-z][a-z0-9-]*$")?;
+//! validate_length("alice", "username", Some(3), Some(16))?;
+//! # Ok::<(), dynamic_cli::error::DynamicCliError>(())
+//! ```
+//!
+//! [`ValidationRule::Pattern`]: crate::config::schema::ValidationRule::Pattern
+//! [`ValidationRule::Length`]: crate::config::schema::ValidationRule::Length
+//! [`ValidationRule::NotEmpty`]: crate::config::schema::ValidationRule::NotEmpty
+//! [`ValidationRule::Format`]: crate::config::schema::ValidationRule::Format
+
+use crate::config::schema::StringFormat;
+use crate::error::{ConfigError, Result, ValidationError};
+use crate::utils::{is_blank, is_valid_email};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// Patterns compiled so far, by source text
+static PATTERNS: OnceLock<Mutex<HashMap<String, Regex>>> = OnceLock::new();
+
+/// Compile the regular expression of a `pattern` rule, once per pattern
+///
+/// Validating the configuration compiles every pattern it contains;
+/// checking values then reuses the compiled form instead of compiling
+/// the pattern again for each value.
+pub(crate) fn compile_pattern(pattern: &str) -> std::result::Result<Regex, regex::Error> {
+    let mut patterns = PATTERNS
+        .get_or_init(Default::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    if let Some(regex) = patterns.get(pattern) {
+        return Ok(regex.clone());
+    }
+    let regex = Regex::new(pattern)?;
+    patterns.insert(pattern.to_string(), regex.clone());
+    Ok(regex)
+}
+
+/// Validate that a value matches a regular expression
+///
+/// The pattern is unanchored: `"[0-9]"` accepts any value containing a
+/// digit. Use `^` and `$` to constrain the whole value.
+///
+/// # Arguments
+///
+/// * `value` - The value to validate
+/// * `arg_name` - Name of the argument (for error messages)
+/// * `pattern` - The regular expression from the configuration
+///
+/// # Errors
+///
+/// - [`ValidationError::PatternMismatch`] if the value does not match
+/// - [`ConfigError::InvalidSchema`] if the pattern does not compile
+///   (normally caught when the configuration is validated)
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::validator::string_validator::validate_pattern;
+///
+/// assert!(validate_pattern("v1.2.3", "tag", r"^v\d+\.\d+\.\d+$").is_ok());
+/// assert!(validate_pattern("1.2.3", "tag", r"^v\d+\.\d+\.\d+$").is_err());
+/// ```
+pub fn validate_pattern(value: &str, arg_name: &str, pattern: &str) -> Result<()> {
+    let regex = compile_pattern(pattern).map_err(|e| ConfigError::InvalidSchema {
+        reason: format!("Invalid pattern for argument '{}': {}", arg_name, e),
+        path: None,
+        suggestion: None,
+    })?;
+
+    if !regex.is_match(value) {
+        return Err(ValidationError::PatternMismatch {
+            arg_name: arg_name.to_string(),
+            value: value.to_string(),
+            pattern: pattern.to_string(),
+            suggestion: Some(format!("Provide a value matching {}.", pattern)),
+        }
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Validate that a value's length is within bounds
+///
+/// Length is counted in characters (Unicode scalar values), not bytes,
+/// so `"héllo"` has length 5. Both bounds are inclusive.
+///
+/// # Arguments
+///
+/// * `value` - The value to validate
+/// * `arg_name` - Name of the argument (for error messages)
+/// * `min_length` - Optional minimum length
+/// * `max_length` - Optional maximum length
+///
+/// # Errors
+///
+/// [`ValidationError::InvalidLength`] if the value is too short or too long.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::validator::string_validator::validate_length;
+///
+/// assert!(validate_length("alice", "username", Some(3), Some(16)).is_ok());
+/// assert!(validate_length("al", "username", Some(3), Some(16)).is_err());
+/// assert!(validate_length("héllo", "username", None, Some(5)).is_ok());
+/// ```
+pub fn validate_length(
+    value: &str,
+    arg_name: &str,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+) -> Result<()> {
+    let length = value.chars().count();
+
+    let suggestion = match (min_length, max_length) {
+        (Some(min), _) if length < min => {
+            let missing = min - length;
+            format!("Add at least {} more {}.", missing, characters(missing))
+        }
+        (_, Some(max)) if length > max => {
+            let extra = length - max;
+            format!("Remove at least {} {}.", extra, characters(extra))
+        }
+        _ => return Ok(()),
+    };
+
+    let expected = match (min_length, max_length) {
+        (Some(min), Some(max)) if min == max => format!("exactly {min}"),
+        (Some(min), Some(max)) => format!("between {min} and {max}"),
+        (Some(min), None) => format!("at least {min}"),
+        (None, Some(max)) => format!("at most {max}"),
+        (None, None) => unreachable!("no bound was violated"),
+    };
+
+    Err(ValidationError::InvalidLength {
+        arg_name: arg_name.to_string(),
+        expected,
+        got: length,
+        suggestion: Some(suggestion),
+    }
+    .into())
+}
+
+/// Validate that a value is not empty or whitespace-only
+///
+/// # Errors
+///
+/// [`ValidationError::EmptyValue`] if the value is blank.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::validator::string_validator::validate_not_empty;
+///
+/// assert!(validate_not_empty("report", "title").is_ok());
+/// assert!(validate_not_empty("   ", "title").is_err());
+/// ```
+pub fn validate_not_empty(value: &str, arg_name: &str) -> Result<()> {
+    if is_blank(value) {
+        return Err(ValidationError::EmptyValue {
+            arg_name: arg_name.to_string(),
+            suggestion: Some(format!("Provide a non-blank value for {}.", arg_name)),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Validate that a value has the given text format
+///
+/// # Errors
+///
+/// [`ValidationError::InvalidFormat`] if the value is not in the format.
+///
+/// # Example
+///
+/// ```
+/// use dynamic_cli::config::schema::StringFormat;
+/// use dynamic_cli::validator::string_validator::validate_format;
+///
+/// assert!(validate_format("alice@example.com", "contact", StringFormat::Email).is_ok());
+/// assert!(validate_format("alice.example.com", "contact", StringFormat::Email).is_err());
+/// ```
+pub fn validate_format(value: &str, arg_name: &str, format: StringFormat) -> Result<()> {
+    let (valid, suggestion) = match format {
+        StringFormat::Email => (
+            is_valid_email(value),
+            "Provide an address like user@example.com.",
+        ),
+    };
+
+    if !valid {
+        return Err(ValidationError::InvalidFormat {
+            arg_name: arg_name.to_string(),
+            value: value.to_string(),
+            format: format.as_str().to_string(),
+            suggestion: Some(suggestion.to_string()),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Singular or plural noun for a character count
+fn characters(count: usize) -> &'static str {
+    if count == 1 {
+        "character"
+    } else {
+        "characters"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DynamicCliError;
+
+    #[test]
+    fn test_validate_pattern_match_and_mismatch() {
+        assert!(validate_pattern("abc-1", "name", "^[a-z0-9-]+$").is_ok());
+
+        match validate_pattern("ABC", "name", "^[a-z0-9-]+$").unwrap_err() {
+            DynamicCliError::Validation(ValidationError::PatternMismatch {
+                arg_name,
+                value,
+                pattern,
+                suggestion,
+            }) => {
+                assert_eq!(arg_name, "name");
+                assert_eq!(value, "ABC");
+                assert_eq!(pattern, "^[a-z0-9-]+$");
+                assert!(suggestion.unwrap().contains("^[a-z0-9-]+$"));
+            }
+            other => panic!("Expected PatternMismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_pattern_is_unanchored() {
+        assert!(validate_pattern("release-42", "tag", "[0-9]").is_ok());
+    }
+
+    #[test]
+    fn test_compile_pattern_is_cached() {
+        let pattern = "^cached-[0-9]+$";
+        assert!(compile_pattern(pattern).unwrap().is_match("cached-1"));
+        assert!(PATTERNS
+            .get()
+            .unwrap()
+            .lock()
+            .unwrap()
+            .contains_key(pattern));
+
+        // Invalid patterns are reported every time, never cached
+        assert!(compile_pattern("[a-z").is_err());
+        assert!(compile_pattern("[a-z").is_err());
+    }
+
+    #[test]
+    fn test_validate_pattern_invalid_regex() {
+        assert!(matches!(
+            validate_pattern("x", "name", "[a-z"),
+            Err(DynamicCliError::Config(ConfigError::InvalidSchema { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_validate_length_bounds_are_inclusive() {
+        assert!(validate_length("abc", "name", Some(3), Some(5)).is_ok());
+        assert!(validate_length("abcde", "name", Some(3), Some(5)).is_ok());
+        assert!(validate_length("", "name", None, None).is_ok());
+    }
+
+    #[test]
+    fn test_validate_length_too_short() {
+        match validate_length("ab", "name", Some(3), Some(5)).unwrap_err() {
+            DynamicCliError::Validation(ValidationError::InvalidLength {
+                expected,
+                got,
+                suggestion,
+                ..
+            }) => {
+                assert_eq!(expected, "between 3 and 5");
+                assert_eq!(got, 2);
+                assert_eq!(suggestion.unwrap(), "Add at least 1 more character.");
+            }
+            other => panic!("Expected InvalidLength, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_length_too_long() {
+        let err = validate_length("abcdefgh", "code", None, Some(4)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "code must be at most 4 characters long, got 8"
+        );
+        match err {
+            DynamicCliError::Validation(ValidationError::InvalidLength { suggestion, .. }) => {
+                assert_eq!(suggestion.unwrap(), "Remove at least 4 characters.");
+            }
+            other => panic!("Expected InvalidLength, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_validate_length_counts_characters() {
+        assert!(validate_length("日本語", "name", None, Some(3)).is_ok());
+        assert!(validate_length("日本語", "name", Some(4), None).is_err());
+    }
+
+    #[test]
+    fn test_validate_not_empty() {
+        assert!(validate_not_empty("x", "title").is_ok());
+        assert!(matches!(
+            validate_not_empty("", "title"),
+            Err(DynamicCliError::Validation(
+                ValidationError::EmptyValue { .. }
+            ))
+        ));
+        assert!(validate_not_empty(" \t", "title").is_err());
+    }
+
+    #[test]
+    fn test_validate_format_email() {
+        assert!(validate_format("a@b.io", "contact", StringFormat::Email).is_ok());
+
+        match validate_format("a@b", "contact", StringFormat::Email).unwrap_err() {
+            DynamicCliError::Validation(ValidationError::InvalidFormat {
+                format, value, ..
+            }) => {
+                assert_eq!(format, "email");
+                assert_eq!(value, "a@b");
+            }
+            other => panic!("Expected InvalidFormat, got {:?}", other),
+        }
+    }
+}